@@ -1,11 +1,15 @@
 //! Command-line interface definitions.
 
-use clap::{Args, Parser, Subcommand};
+use std::ffi::OsString;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
 use crate::models::{
-    Difficulty, DueFilter, Priority, Recurrence, RecurrenceFilter, ResourceType, SortBy,
-    StatusFilter,
+    CatchUpPolicy, Difficulty, DueFilter, Estimate, Label, Lookback, MonthEndPolicy, Priority,
+    Recurrence, RecurrenceFilter, ResourceType, SortSpec, StatusFilter,
 };
+use crate::utils::id_list::IdRange;
+use crate::utils::substitution::Substitution;
 
 #[derive(Parser)]
 #[command(name = "rustodo")]
@@ -16,24 +20,89 @@ use crate::models::{
 #[command(after_help = "\
 COMMANDS:
   Task Management:
-    add (a), list (ls), done, undone, edit (e), remove (rm), clear, recur, clear-recur
+    add (a), list (ls), done, undone, cancel, edit (e), remove (rm), clear, recur, clear-recur,
+    annotate, open, triage, start, stop, pomodoro, capture
 
   Viewing & Planning:
-    next (n), calendar (cal), stats, search (find), context (ctx), deps, tags
+    ui, agenda (today), next (n), pick, remind, calendar (cal), stats, time, report, digest, search (find), context (ctx), deps, blame, similar, tags
 
   Organization:
-    project, note, resource
+    project, archive, note, resource
 
   System:
-    info, purge, holidays, backup, restore, backup-list, export, import
+    info, demo, doctor, open-data, purge, holidays, backup, restore, backup-list, export, import, publish
+
+  Integrations:
+    jira, todoist
+
+  Plugins:
+    any 'todo-<name>' executable on PATH runs as 'todo <name>'
 
 Run 'todo <COMMAND> --help' for more information on a command.
 ")]
 pub struct Cli {
+    /// Open the data read-only; commands that would modify it fail
+    #[arg(long, global = true)]
+    pub read_only: bool,
+
+    /// Use the sample workspace created by `todo demo` instead of your data
+    #[arg(long, global = true)]
+    pub demo: bool,
+
+    /// Print how long each startup phase took to stderr
+    #[arg(long, global = true)]
+    pub timing: bool,
+
+    /// Truncate long task text in tables instead of wrapping it to the
+    /// terminal width
+    #[arg(long, global = true)]
+    pub no_wrap: bool,
+
+    /// Disable colors (also honored: the `NO_COLOR` environment variable)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Disable colors and use ASCII instead of box-drawing characters, for
+    /// pipes, logs, and screen readers (implied by `TERM=dumb`)
+    #[arg(long, global = true)]
+    pub plain: bool,
+
+    /// Output format for list, agenda, search, tags, project list, stats, deps, report, and digest
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
 
+impl Cli {
+    /// Refuses commands that work on the data files directly, bypassing the
+    /// storage handle that enforces the global flags.
+    pub fn check_file_access(&self) -> anyhow::Result<()> {
+        let (name, writes) = match &self.command {
+            Some(Commands::Backup) => ("backup", true),
+            Some(Commands::Restore { .. }) => ("restore", true),
+            Some(Commands::Holidays(HolidaysCommands::Refresh)) => ("holidays refresh", true),
+            Some(Commands::BackupList) => ("backup-list", false),
+            Some(Commands::OpenData { .. }) => ("open-data", false),
+            _ => return Ok(()),
+        };
+        if self.demo {
+            anyhow::bail!(
+                "`todo {}` works on your real data files and is not available with --demo",
+                name
+            );
+        }
+        if self.read_only && writes {
+            anyhow::bail!(
+                "`todo {}` writes files directly and is not available with --read-only",
+                name
+            );
+        }
+        Ok(())
+    }
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     // ── Task Management ───────────────────────────────────────────────────────
@@ -43,48 +112,57 @@ pub enum Commands {
 
     /// List and filter tasks
     #[command(visible_alias = "ls", hide = true)]
-    List {
-        #[arg(long, value_enum, default_value_t = StatusFilter::All)]
-        status: StatusFilter,
-        #[arg(long, value_enum)]
-        priority: Option<Priority>,
-        #[arg(long, value_enum)]
-        due: Option<DueFilter>,
-        #[arg(long, short = 's', value_enum)]
-        sort: Option<SortBy>,
-        #[arg(long, short = 't', value_delimiter = ',')]
-        tag: Vec<String>,
-        #[arg(long, short = 'p')]
-        project: Option<String>,
-        #[arg(long, short = 'r', value_enum)]
-        recurrence: Option<RecurrenceFilter>,
-    },
+    List(ListArgs),
 
-    /// Mark a task as completed
+    /// Mark one or more tasks as completed
     #[command(visible_alias = "complete", hide = true)]
     Done {
-        #[arg(value_name = "ID")]
-        id: usize,
+        /// Task IDs or ranges, e.g. `3 5 7` or `2-6`
+        #[arg(value_name = "ID", required = true)]
+        ids: Vec<IdRange>,
     },
 
-    /// Mark a completed task as pending
-    #[command(visible_alias = "undo", hide = true)]
+    /// Mark one or more completed or cancelled tasks as pending
+    #[command(hide = true)]
     Undone {
+        /// Task IDs or ranges, e.g. `3 5 7` or `2-6`
+        #[arg(value_name = "ID", required = true)]
+        ids: Vec<IdRange>,
+    },
+
+    /// Close a task that will not be done, keeping it for history
+    #[command(hide = true)]
+    Cancel {
         #[arg(value_name = "ID")]
         id: usize,
+        /// Why the task was dropped
+        #[arg(long, short = 'r')]
+        reason: Option<String>,
     },
 
+    /// Revert the last add, edit, annotate, done, undone, remove, clear, recur, or purge
+    #[command(hide = true)]
+    Undo,
+
+    /// Reapply the last undone operation
+    #[command(hide = true)]
+    Redo,
+
     /// Edit an existing task
     #[command(visible_alias = "e", hide = true)]
     Edit(EditArgs),
 
-    /// Remove a task permanently
+    /// Remove one or more tasks permanently
     #[command(visible_aliases = ["rm", "delete"], hide = true)]
     Remove {
-        #[arg(value_name = "ID")]
-        id: usize,
-        #[arg(long, short = 'y')]
+        /// Task IDs or ranges, e.g. `3 5 7` or `2-6`
+        #[arg(value_name = "ID", required = true)]
+        ids: Vec<IdRange>,
+        #[arg(long, short = 'y', conflicts_with = "interactive")]
         yes: bool,
+        /// Ask about each task separately instead of once for all
+        #[arg(long, short = 'i')]
+        interactive: bool,
     },
 
     /// Clear all tasks
@@ -92,15 +170,42 @@ pub enum Commands {
     Clear {
         #[arg(long, short = 'y')]
         yes: bool,
+        /// Restore the tasks removed by the last clear (within 24h)
+        #[arg(long, conflicts_with = "yes")]
+        undo: bool,
     },
 
     /// Set or change recurrence pattern for a task
-    #[command(hide = true)]
+    #[command(
+        hide = true,
+        args_conflicts_with_subcommands = true,
+        subcommand_negates_reqs = true
+    )]
     Recur {
-        #[arg(value_name = "ID")]
-        id: usize,
-        #[arg(value_enum)]
-        pattern: Recurrence,
+        #[command(subcommand)]
+        action: Option<RecurCommands>,
+        #[arg(value_name = "ID", required = true)]
+        id: Option<usize>,
+        #[arg(value_enum, required = true)]
+        pattern: Option<Recurrence>,
+        /// For monthly tasks: whether a month-end due date stays on the
+        /// same day or snaps to each month's last day
+        #[arg(long, value_enum, value_name = "POLICY")]
+        month_end: Option<MonthEndPolicy>,
+        /// When completed late: create just the next occurrence, backfill
+        /// the overdue ones as missed, or fast-forward past them
+        #[arg(long, value_enum, value_name = "POLICY")]
+        catch_up: Option<CatchUpPolicy>,
+        /// Make each new occurrence depend on the previous one, so the
+        /// series is completed in order (`=false` turns it off)
+        #[arg(
+            long,
+            value_name = "BOOL",
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true"
+        )]
+        recur_chain_deps: Option<bool>,
     },
 
     /// Remove recurrence pattern from a task
@@ -111,6 +216,14 @@ pub enum Commands {
     },
 
     // ── Viewing & Planning ────────────────────────────────────────────────────
+    /// Open the full-screen interface (same as running `todo` alone)
+    #[command(hide = true)]
+    Ui,
+
+    /// Show overdue, due-today, and next-7-days tasks, with blocked ones last
+    #[command(visible_aliases = ["today", "upcoming"], hide = true)]
+    Agenda,
+
     /// Show the most urgent pending tasks ready to work on
     #[command(visible_alias = "n", hide = true)]
     Next {
@@ -118,6 +231,48 @@ pub enum Commands {
         limit: usize,
     },
 
+    /// Go through pending tasks one at a time with single-key actions
+    #[command(hide = true)]
+    Triage,
+
+    /// Send due-date reminders, or set a task's reminder lead time
+    #[command(hide = true)]
+    Remind {
+        /// Task whose lead time to set (with --before or --clear)
+        #[arg(value_name = "ID", conflicts_with = "daemon")]
+        id: Option<usize>,
+        /// Remind this long before the deadline: 30m, 2h, 1d, 1w, or 0
+        #[arg(long, value_name = "LEAD", requires = "id", conflicts_with = "clear")]
+        before: Option<crate::models::ReminderLead>,
+        /// Use the configured default lead time again
+        #[arg(long, requires = "id")]
+        clear: bool,
+        /// Keep running and check periodically
+        #[arg(long)]
+        daemon: bool,
+        /// Seconds between checks in daemon mode
+        #[arg(long, default_value_t = 60, requires = "daemon")]
+        interval: u64,
+    },
+
+    /// Pick a pending task at random, weighted by urgency
+    #[command(hide = true)]
+    Pick {
+        /// Leave out tasks with this tag (repeatable)
+        #[arg(long, value_name = "TAG")]
+        exclude_tag: Vec<String>,
+        /// Only consider tasks in this project
+        #[arg(long)]
+        project: Option<String>,
+        /// Only consider tasks estimated to take at most this long, e.g. 30m
+        /// (tasks without an estimate are left out)
+        #[arg(long, value_name = "DURATION")]
+        max_estimate: Option<Estimate>,
+        /// Offer this many tasks to choose from
+        #[arg(long, short = 'c', default_value_t = 1)]
+        count: usize,
+    },
+
     /// Show a monthly calendar with due dates for tasks and projects
     #[command(visible_alias = "cal", hide = true)]
     Calendar {
@@ -131,6 +286,32 @@ pub enum Commands {
     #[command(subcommand, hide = true)]
     Stats(StatsCommands),
 
+    /// Report on time tracked with start/stop and pomodoro
+    #[command(subcommand, hide = true)]
+    Time(TimeCommands),
+
+    /// Write a Markdown report grouped by project and status
+    #[command(hide = true)]
+    Report {
+        /// Only include tasks in this project
+        #[arg(long, short = 'p')]
+        project: Option<String>,
+        /// Only include tasks closed on or after this date (open tasks are always included)
+        #[arg(long, value_name = "DATE")]
+        since: Option<String>,
+    },
+
+    /// Write a Markdown digest of completed, added, overdue, and upcoming tasks
+    #[command(hide = true)]
+    Digest {
+        /// Period ending today that the digest covers
+        #[arg(long, value_enum, default_value_t = DigestPeriod::Week)]
+        period: DigestPeriod,
+        /// Mail the digest with the sendmail command from config.toml
+        #[arg(long)]
+        send: bool,
+    },
+
     /// Search for tasks by text content
     #[command(visible_alias = "find", hide = true)]
     Search {
@@ -142,22 +323,109 @@ pub enum Commands {
         project: Option<String>,
         #[arg(long, value_enum, default_value_t = StatusFilter::All)]
         status: StatusFilter,
+        /// Treat QUERY as a case-insensitive regular expression
+        #[arg(long, conflicts_with = "fuzzy")]
+        regex: bool,
+        /// Tolerate typos: each word of QUERY may be a couple of edits off
+        #[arg(long)]
+        fuzzy: bool,
+        /// Only search tasks, matching these fields (repeat or comma-separate)
+        #[arg(long = "in", value_enum, value_delimiter = ',', value_name = "FIELD")]
+        fields: Vec<SearchField>,
     },
 
     /// Show everything linked to a task: project, dependencies, notes, resources
-    #[command(visible_alias = "ctx", hide = true)]
+    #[command(visible_aliases = ["ctx", "show"], hide = true)]
     Context {
         #[arg(value_name = "ID")]
         id: usize,
     },
 
     /// Show dependency graph for a task
-    #[command(hide = true)]
+    #[command(
+        hide = true,
+        args_conflicts_with_subcommands = true,
+        subcommand_negates_reqs = true
+    )]
     Deps {
+        #[command(subcommand)]
+        action: Option<DepsCommands>,
+        #[arg(value_name = "ID", required = true)]
+        id: Option<usize>,
+    },
+
+    /// Start tracking time on a task (stops any running one)
+    #[command(hide = true)]
+    Start {
+        #[arg(value_name = "ID")]
+        id: usize,
+    },
+
+    /// Stop the running time tracker
+    #[command(hide = true)]
+    Stop,
+
+    /// Run a pomodoro timer on a task and record the session
+    #[command(hide = true)]
+    Pomodoro {
+        #[arg(value_name = "ID")]
+        id: usize,
+        /// Work interval in minutes
+        #[arg(long, default_value_t = 25, value_parser = clap::value_parser!(u32).range(1..))]
+        work: u32,
+        /// Break interval in minutes (0 to skip)
+        #[arg(long = "break", default_value_t = 5)]
+        brk: u32,
+    },
+
+    /// Listen on a local port and add every line received as a task
+    #[command(hide = true)]
+    Capture {
+        /// Port on 127.0.0.1 to listen on
+        #[arg(long, default_value_t = 7878)]
+        port: u16,
+        /// Project for tasks that do not name one with `@project`
+        #[arg(long, short = 'p')]
+        project: Option<String>,
+    },
+
+    /// Attach file references to a task
+    #[command(hide = true)]
+    Annotate {
+        #[arg(value_name = "ID")]
+        id: usize,
+        /// Existing file to attach (repeatable)
+        #[arg(long, value_name = "PATH", required = true)]
+        file: Vec<std::path::PathBuf>,
+    },
+
+    /// Open a task's attachment with the default application
+    #[command(hide = true)]
+    Open {
+        #[arg(value_name = "ID")]
+        id: usize,
+        /// Attachment number as listed by `todo show`
+        #[arg(long, short = 'a', value_name = "N")]
+        attachment: Option<usize>,
+    },
+
+    /// Show where a task came from (manual, recurrence, import, restore)
+    #[command(hide = true)]
+    Blame {
         #[arg(value_name = "ID")]
         id: usize,
     },
 
+    /// List the tasks most similar to one, by text, tags, and project
+    #[command(hide = true)]
+    Similar {
+        #[arg(value_name = "ID")]
+        id: usize,
+        /// How many tasks to show
+        #[arg(long, short = 'n', default_value_t = 5)]
+        limit: usize,
+    },
+
     /// List all tags with counts, or show hub view for a specific tag
     #[command(hide = true)]
     Tags {
@@ -170,6 +438,17 @@ pub enum Commands {
     #[command(subcommand, hide = true)]
     Project(ProjectCommands),
 
+    /// Close out a finished project and move it to the archive
+    #[command(hide = true)]
+    Archive {
+        /// Project to archive
+        #[arg(long, value_name = "NAME")]
+        project: String,
+        /// Mark every remaining task done without asking
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+
     /// Manage notes (documentation linked to projects, tasks, or resources)
     #[command(subcommand, hide = true)]
     Note(NoteCommands),
@@ -179,18 +458,40 @@ pub enum Commands {
     Resource(ResourceCommands),
 
     // ── System ────────────────────────────────────────────────────────────────
-    /// Export all data to a JSON file
+    /// Export all data to a JSON file, or tasks to a CSV file
     #[command(hide = true)]
     Export {
         #[arg(value_name = "FILE")]
         file: Option<std::path::PathBuf>,
+        #[arg(long, value_enum, default_value_t = ExportFormat::Json)]
+        to: ExportFormat,
+        /// Only export tasks matching these filters (same as `todo list`)
+        #[command(flatten)]
+        filter: FilterArgs,
     },
 
-    /// Import data from a JSON export file
+    /// Write the task list as a read-only HTML or Markdown page to share
+    #[command(hide = true)]
+    Publish {
+        #[arg(value_name = "FILE")]
+        file: Option<std::path::PathBuf>,
+        #[arg(long, value_enum, default_value_t = PublishFormat::Html)]
+        to: PublishFormat,
+        /// Page heading (default "Task list")
+        #[arg(long)]
+        title: Option<String>,
+        /// Only publish tasks matching these filters (same as `todo list`)
+        #[command(flatten)]
+        filter: FilterArgs,
+    },
+
+    /// Import data from a JSON export file, or tasks from CSV or email (.eml / maildir)
     #[command(hide = true)]
     Import {
         #[arg(value_name = "FILE")]
         file: std::path::PathBuf,
+        #[arg(long, value_enum, default_value_t = ImportFormat::Json)]
+        from: ImportFormat,
         #[arg(long)]
         replace: bool,
         #[arg(long, short = 'y')]
@@ -218,6 +519,66 @@ pub enum Commands {
     #[command(hide = true)]
     Info,
 
+    /// Create a sandbox with sample data (use --demo with any command)
+    #[command(hide = true)]
+    Demo,
+
+    /// Compare two export files, or one against the current database
+    #[command(hide = true)]
+    Diff {
+        /// Older export file
+        #[arg(value_name = "FILE_A")]
+        before: std::path::PathBuf,
+        /// Newer export file (default: the current database)
+        #[arg(value_name = "FILE_B")]
+        after: Option<std::path::PathBuf>,
+    },
+
+    /// Run a `todo-<name>` plugin from PATH
+    #[command(external_subcommand)]
+    External(Vec<OsString>),
+
+    /// Print or install a shell completion script
+    #[command(hide = true)]
+    Completions {
+        /// Target shell (detected from $SHELL when omitted)
+        #[arg(value_enum, value_name = "SHELL")]
+        shell: Option<clap_complete::Shell>,
+        /// Write the script to the shell's completion directory
+        #[arg(long)]
+        install: bool,
+        /// Skip confirmation prompt
+        #[arg(long, short = 'y', requires = "install")]
+        yes: bool,
+    },
+
+    /// Print a short task summary for embedding in a shell prompt
+    #[command(hide = true)]
+    Prompt {
+        /// Override the `[prompt] format` setting, e.g. "⚑{pending} ⏰{overdue}"
+        #[arg(long)]
+        template: Option<String>,
+    },
+
+    /// Check the task list for inconsistencies
+    #[command(hide = true)]
+    Doctor {
+        /// Repair the problems found
+        #[arg(long)]
+        fix: bool,
+    },
+
+    /// Print the data location and open it in the file manager
+    #[command(name = "open-data", hide = true)]
+    OpenData {
+        /// Open the database file instead of its directory
+        #[arg(long)]
+        file: bool,
+        /// Only print the resolved paths
+        #[arg(long)]
+        print: bool,
+    },
+
     /// Permanently remove soft-deleted tombstones
     #[command(hide = true)]
     Purge {
@@ -232,6 +593,149 @@ pub enum Commands {
     /// Manage holiday data from holidata.net
     #[command(subcommand, hide = true)]
     Holidays(HolidaysCommands),
+
+    // ── Integrations ──────────────────────────────────────────────────────────
+    /// Import and sync tasks with Jira issues
+    #[command(subcommand, hide = true)]
+    Jira(JiraCommands),
+
+    /// Pull and push tasks with Todoist
+    #[cfg(feature = "todoist")]
+    #[command(subcommand, hide = true)]
+    Todoist(TodoistCommands),
+}
+
+/// Output format selected with the global `--format` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Colored tables for humans (default).
+    #[default]
+    Table,
+    /// A JSON document.
+    Json,
+    /// Tab-separated lines without colors.
+    Plain,
+    /// A Markdown document (`todo report` and `todo digest` only).
+    #[value(alias = "md")]
+    Markdown,
+}
+
+/// Source format for `todo import`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImportFormat {
+    /// A rustodo JSON export (default).
+    Json,
+    /// A CSV file with a header row; see `todo export --to csv`.
+    Csv,
+    /// A single .eml file, or a directory of .eml files.
+    Eml,
+    /// A maildir folder (new/ and cur/).
+    Maildir,
+}
+
+/// File format for `todo export --to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ExportFormat {
+    /// Everything, as a JSON file `todo import` reads back (default).
+    #[default]
+    Json,
+    /// Tasks only: text, priority, tags, project, due, created, completed.
+    Csv,
+}
+
+/// Page format for `todo publish --to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum PublishFormat {
+    /// A self-contained HTML page (default).
+    #[default]
+    Html,
+    /// A Markdown table.
+    #[value(alias = "md")]
+    Markdown,
+}
+
+/// Period for `todo stats show --compare`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ComparePeriod {
+    /// The last 7 days against the 7 days before.
+    LastWeek,
+    /// The last 30 days against the 30 days before.
+    LastMonth,
+}
+
+/// Grouping for `todo time report --by`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum TimeGroup {
+    /// One row per project (default).
+    #[default]
+    Project,
+    /// One row per tag; a task with several tags counts toward each.
+    Tag,
+    /// One row per day in the period.
+    Day,
+}
+
+/// Period covered by `todo digest`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum DigestPeriod {
+    /// Today only.
+    Day,
+    /// The last 7 days (default).
+    #[default]
+    Week,
+    /// The last 30 days.
+    Month,
+}
+
+impl DigestPeriod {
+    /// Length of the period in days.
+    pub fn days(self) -> i64 {
+        match self {
+            Self::Day => 1,
+            Self::Week => 7,
+            Self::Month => 30,
+        }
+    }
+
+    /// Capitalized name used in the default email subject.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Day => "Daily",
+            Self::Week => "Weekly",
+            Self::Month => "Monthly",
+        }
+    }
+}
+
+/// Task field matched by `todo search --in`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SearchField {
+    /// The task description.
+    Text,
+    /// Any of the task's tags.
+    Tags,
+    /// The name of the task's project.
+    Project,
+    /// Title or body of notes linked to the task.
+    Notes,
+}
+
+// ── Time subcommands ──────────────────────────────────────────────────────────
+
+#[derive(Subcommand)]
+pub enum TimeCommands {
+    /// Summarize tracked time over a period
+    Report {
+        /// Period ending today: 7d, 30d, 2w
+        #[arg(long, value_name = "PERIOD", default_value_t = Lookback::WEEK)]
+        last: Lookback,
+        /// Group rows by project, tag, or day
+        #[arg(long, value_enum, default_value_t = TimeGroup::Project)]
+        by: TimeGroup,
+        /// Also write every time entry in the period to this CSV file
+        #[arg(long, value_name = "FILE")]
+        csv: Option<std::path::PathBuf>,
+    },
 }
 
 // ── Stats subcommands ─────────────────────────────────────────────────────────
@@ -240,7 +744,14 @@ pub enum Commands {
 pub enum StatsCommands {
     /// Show productivity statistics (default)
     #[command(hide = true)]
-    Show,
+    Show {
+        /// Compare the current period with the previous one
+        #[arg(long, value_enum, value_name = "PERIOD", conflicts_with = "goal")]
+        compare: Option<ComparePeriod>,
+        /// Show progress on the goals from the [goals] config section
+        #[arg(long)]
+        goal: bool,
+    },
 
     /// Show monthly history chart of tasks created, completed, and deleted
     #[command(hide = true)]
@@ -272,7 +783,14 @@ pub enum ProjectCommands {
     /// Add a new project.
     Add(ProjectAddArgs),
     /// List all projects.
-    List,
+    List {
+        /// Nest projects by `/` in their names, e.g. `Work/Client A`
+        #[arg(long)]
+        tree: bool,
+        /// Show progress bars, overdue counts, and last activity
+        #[arg(long)]
+        progress: bool,
+    },
     /// Show full details of a project.
     Show {
         #[arg(value_name = "ID")]
@@ -382,6 +900,7 @@ pub enum NoteCommands {
 
 #[derive(Args)]
 pub struct NoteAddArgs {
+    /// Note body; `-` reads from stdin
     #[arg(value_name = "BODY", conflicts_with_all = ["editor", "file"])]
     pub body: Option<String>,
     #[arg(long, conflicts_with_all = ["file"])]
@@ -548,14 +1067,149 @@ pub enum SyncCommands {
     Status,
 }
 
-// ── AddArgs ───────────────────────────────────────────────────────────────────
+// ── ListArgs ──────────────────────────────────────────────────────────────────
 
 #[derive(Args)]
+pub struct ListArgs {
+    #[arg(long, value_enum, default_value_t = StatusFilter::All)]
+    pub status: StatusFilter,
+    #[arg(long, value_enum)]
+    pub priority: Option<Priority>,
+    #[arg(long, value_enum)]
+    pub due: Option<DueFilter>,
+    /// Sort keys with optional direction, e.g. `due:asc,priority:desc`
+    #[arg(long, short = 's', value_name = "KEY[:asc|desc],...")]
+    pub sort: Option<SortSpec>,
+    /// Reverse the final order
+    #[arg(long)]
+    pub reverse: bool,
+    #[arg(long, short = 't', value_delimiter = ',')]
+    pub tag: Vec<String>,
+    #[arg(long, short = 'p')]
+    pub project: Option<String>,
+    #[arg(long, short = 'r', value_enum)]
+    pub recurrence: Option<RecurrenceFilter>,
+    /// Hide tasks whose start date is still in the future
+    #[arg(long)]
+    pub started: bool,
+    /// Only tasks created or changed since this date, time, or RFC 3339
+    /// timestamp, e.g. `today`, `yesterday 18:00`
+    #[arg(long, value_name = "WHEN")]
+    pub modified_since: Option<String>,
+    /// Show how long each pending task has existed
+    #[arg(long)]
+    pub age: bool,
+    /// Show whether closed recurring tasks were done on time, late, or missed
+    #[arg(long)]
+    pub timeliness: bool,
+    /// Show each task's urgency score (what `--sort urgency` orders by)
+    #[arg(long)]
+    pub show_urgency: bool,
+    /// Filter expression, e.g. "status:pending and (tag:work or priority:high) and due<friday"
+    #[arg(long, short = 'q', value_name = "EXPR")]
+    pub query: Option<String>,
+    /// Print `<uuid>\t<status>\t<text>` per task, a format kept stable for scripts
+    #[arg(long)]
+    pub flat_uuid: bool,
+}
+
+impl Default for ListArgs {
+    fn default() -> Self {
+        Self {
+            status: StatusFilter::All,
+            priority: None,
+            due: None,
+            sort: None,
+            reverse: false,
+            tag: Vec::new(),
+            project: None,
+            recurrence: None,
+            started: false,
+            modified_since: None,
+            age: false,
+            timeliness: false,
+            show_urgency: false,
+            query: None,
+            flat_uuid: false,
+        }
+    }
+}
+
+// ── FilterArgs ────────────────────────────────────────────────────────────────
+
+/// The task filters of `todo list`, shared with `todo export`.
+#[derive(Args, Debug, Clone)]
+pub struct FilterArgs {
+    #[arg(long, value_enum, default_value_t = StatusFilter::All)]
+    pub status: StatusFilter,
+    #[arg(long, value_enum)]
+    pub priority: Option<Priority>,
+    #[arg(long, value_enum)]
+    pub due: Option<DueFilter>,
+    #[arg(long, short = 't', value_delimiter = ',')]
+    pub tag: Vec<String>,
+    #[arg(long, short = 'p')]
+    pub project: Option<String>,
+    #[arg(long, short = 'r', value_enum)]
+    pub recurrence: Option<RecurrenceFilter>,
+    /// Hide tasks whose start date is still in the future
+    #[arg(long)]
+    pub started: bool,
+    /// Only tasks created or changed since this date, time, or RFC 3339
+    /// timestamp, e.g. `today`, `yesterday 18:00`
+    #[arg(long, value_name = "WHEN")]
+    pub modified_since: Option<String>,
+    /// Filter expression, e.g. "status:pending and (tag:work or priority:high) and due<friday"
+    #[arg(long, short = 'q', value_name = "EXPR")]
+    pub query: Option<String>,
+}
+
+impl Default for FilterArgs {
+    fn default() -> Self {
+        Self {
+            status: StatusFilter::All,
+            priority: None,
+            due: None,
+            tag: Vec::new(),
+            project: None,
+            recurrence: None,
+            started: false,
+            modified_since: None,
+            query: None,
+        }
+    }
+}
+
+impl FilterArgs {
+    /// Whether no filter is set, so every task matches.
+    pub fn is_empty(&self) -> bool {
+        self.status == StatusFilter::All
+            && self.priority.is_none()
+            && self.due.is_none()
+            && self.tag.is_empty()
+            && self.project.is_none()
+            && self.recurrence.is_none()
+            && !self.started
+            && self.modified_since.is_none()
+            && self.query.is_none()
+    }
+}
+
+// ── AddArgs ───────────────────────────────────────────────────────────────────
+
+#[derive(Args, Default)]
 pub struct AddArgs {
+    /// Task description; `-` reads multi-line text from stdin
     #[arg(value_name = "DESCRIPTION")]
     pub text: String,
-    #[arg(long, value_enum, default_value_t = Priority::Medium)]
-    pub priority: Priority,
+    /// Remaining unquoted words; inline `!high`, `#tag`, `@project`, and a
+    /// trailing date (`tomorrow`, `next friday`) are parsed out of them.
+    #[arg(value_name = "WORDS", hide = true)]
+    pub extra: Vec<String>,
+    /// Defaults to `default_priority` from config.toml, then medium; wins
+    /// over an inline `!priority`
+    #[arg(long, value_enum)]
+    pub priority: Option<Priority>,
     #[arg(long, short = 't', value_name = "TAG", value_delimiter = ',')]
     pub tag: Vec<String>,
     #[arg(long, short = 'p', value_name = "PROJECT")]
@@ -566,16 +1220,44 @@ pub struct AddArgs {
     pub recurrence: Option<Recurrence>,
     #[arg(long, value_name = "ID")]
     pub depends_on: Vec<usize>,
+    /// Keep the task blocked until this date
+    #[arg(long, value_name = "DATE|EXPRESSION")]
+    pub wait_until: Option<String>,
+    /// Date the task becomes relevant; `list --started` hides it until then
+    #[arg(long, value_name = "DATE|EXPRESSION")]
+    pub start: Option<String>,
+    /// Expected effort, e.g. 45m, 2h, 1h30m
+    #[arg(long, value_name = "DURATION")]
+    pub estimate: Option<Estimate>,
+    /// Color label shown as a bullet in the task table
+    #[arg(long, value_enum, value_name = "COLOR")]
+    pub label: Option<Label>,
 }
 
 // ── EditArgs ──────────────────────────────────────────────────────────────────
 
-#[derive(Args)]
+#[derive(Args, Default)]
 pub struct EditArgs {
     #[arg(value_name = "ID")]
     pub id: usize,
+    /// More task IDs or ranges to rewrite with --replace-text, e.g. `3 5 7-9`
+    #[arg(
+        value_name = "ID",
+        requires = "replace_text",
+        conflicts_with_all = [
+            "priority", "add_tag", "remove_tag", "project", "clear_project", "due",
+            "clear_due", "clear_tags", "add_dep", "remove_dep", "clear_deps",
+            "wait_until", "clear_wait", "start", "clear_start", "estimate", "clear_estimate", "label", "clear_label", "billable", "rate",
+            "clear_billable", "created", "completed",
+        ]
+    )]
+    pub more_ids: Vec<IdRange>,
+    /// New description; `-` reads multi-line text from stdin
     #[arg(long)]
     pub text: Option<String>,
+    /// Rewrite the description with a sed-style `s/old/new/[gi]` expression
+    #[arg(long, value_name = "EXPR", conflicts_with = "text")]
+    pub replace_text: Option<Substitution>,
     #[arg(long, value_enum)]
     pub priority: Option<Priority>,
     #[arg(long, value_delimiter = ',')]
@@ -598,6 +1280,78 @@ pub struct EditArgs {
     pub remove_dep: Vec<usize>,
     #[arg(long, conflicts_with_all = ["add_dep", "remove_dep"])]
     pub clear_deps: bool,
+    /// Keep the task blocked until this date
+    #[arg(long, value_name = "DATE|EXPRESSION", conflicts_with = "clear_wait")]
+    pub wait_until: Option<String>,
+    #[arg(long, conflicts_with = "wait_until")]
+    pub clear_wait: bool,
+    /// Date the task becomes relevant; `list --started` hides it until then
+    #[arg(long, value_name = "DATE|EXPRESSION", conflicts_with = "clear_start")]
+    pub start: Option<String>,
+    #[arg(long, conflicts_with = "start")]
+    pub clear_start: bool,
+    /// Expected effort, e.g. 45m, 2h, 1h30m
+    #[arg(long, value_name = "DURATION", conflicts_with = "clear_estimate")]
+    pub estimate: Option<Estimate>,
+    #[arg(long, conflicts_with = "estimate")]
+    pub clear_estimate: bool,
+    /// Color label shown as a bullet in the task table
+    #[arg(long, value_enum, value_name = "COLOR", conflicts_with = "clear_label")]
+    pub label: Option<Label>,
+    #[arg(long, conflicts_with = "label")]
+    pub clear_label: bool,
+    /// Mark the task's tracked time as billable
+    #[arg(long, conflicts_with = "clear_billable")]
+    pub billable: bool,
+    /// Hourly rate for this task's billable time (implies --billable)
+    #[arg(long, value_name = "AMOUNT", conflicts_with = "clear_billable")]
+    pub rate: Option<f64>,
+    /// Make the task non-billable and drop its rate
+    #[arg(long)]
+    pub clear_billable: bool,
+    /// Correct the creation date (requires --force-metadata)
+    #[arg(long, value_name = "DATE")]
+    pub created: Option<String>,
+    /// Correct the completion date of a done task (requires --force-metadata)
+    #[arg(long, value_name = "DATE")]
+    pub completed: Option<String>,
+    /// Allow rewriting metadata that is normally set automatically
+    #[arg(long)]
+    pub force_metadata: bool,
+}
+
+// ── Recur subcommands ─────────────────────────────────────────────────────────
+
+#[derive(Subcommand)]
+pub enum RecurCommands {
+    /// Show upcoming occurrence dates without creating anything
+    Preview {
+        #[arg(value_name = "ID")]
+        id: usize,
+        /// How many occurrences to show
+        #[arg(long, short = 'n', default_value_t = 5)]
+        count: usize,
+    },
+    /// Show past occurrences and whether each was completed on time, late,
+    /// or missed
+    History {
+        #[arg(value_name = "ID")]
+        id: usize,
+    },
+}
+
+// ── Deps subcommands ─────────────────────────────────────────────────────────
+
+#[derive(Subcommand)]
+pub enum DepsCommands {
+    /// Explain why a task is blocked: the dependency paths from A to B, or
+    /// to each root blocker when B is omitted
+    Why {
+        #[arg(value_name = "A")]
+        from: usize,
+        #[arg(value_name = "B")]
+        to: Option<usize>,
+    },
 }
 
 // ── Holidays subcommands ──────────────────────────────────────────────────────
@@ -607,3 +1361,26 @@ pub enum HolidaysCommands {
     /// Download or refresh holiday data from holidata.net for the configured locale.
     Refresh,
 }
+
+// ── Jira subcommands ──────────────────────────────────────────────────────────
+
+#[derive(Subcommand)]
+pub enum JiraCommands {
+    /// Import unresolved issues assigned to you as tasks.
+    Pull {
+        /// Jira project key (e.g. ABC)
+        #[arg(long, short = 'p', value_name = "KEY")]
+        project: String,
+    },
+}
+
+// ── Todoist subcommands ───────────────────────────────────────────────────────
+
+#[cfg(feature = "todoist")]
+#[derive(Subcommand)]
+pub enum TodoistCommands {
+    /// Import active Todoist tasks and update the ones already linked.
+    Pull,
+    /// Create, update, and close Todoist tasks to match local ones.
+    Push,
+}
@@ -0,0 +1,174 @@
+//! Handler for `todo agenda` (aliases `today`, `upcoming`).
+//!
+//! The daily overview of pending tasks, in sections:
+//!
+//! | Section     | Tasks                                          |
+//! |-------------|------------------------------------------------|
+//! | Overdue     | due before today                               |
+//! | Today       | due today                                      |
+//! | Next 7 days | due in the seven days after today              |
+//! | Blocked     | waiting or blocked by dependencies, any due date |
+//!
+//! Blocked tasks are listed only at the bottom so the sections above hold
+//! what can be worked on now. Tasks without a due date that are not
+//! blocked are left out — `todo next` covers those.
+
+use anyhow::Result;
+use chrono::{Duration, Local, NaiveDate};
+use colored::Colorize;
+
+use crate::cli::OutputFormat;
+use crate::models::Task;
+use crate::render::{Columns, display_lists, structured};
+use crate::storage::Storage;
+
+/// Days after today covered by the "Next 7 days" section.
+const UPCOMING_DAYS: i64 = 7;
+
+/// Tasks with their display IDs.
+type Rows<'a> = Vec<(usize, &'a Task)>;
+
+/// Pending tasks grouped into agenda sections.
+#[derive(Debug, Default)]
+pub struct Agenda<'a> {
+    pub overdue: Rows<'a>,
+    pub today: Rows<'a>,
+    pub upcoming: Rows<'a>,
+    pub blocked: Rows<'a>,
+}
+
+impl<'a> Agenda<'a> {
+    /// Sorts the pending tasks among `visible` into sections as of `today`.
+    pub fn build(visible: &'a [Task], today: NaiveDate) -> Self {
+        let mut agenda = Agenda::default();
+        let horizon = today + Duration::days(UPCOMING_DAYS);
+
+        for (i, task) in visible.iter().enumerate() {
+            if task.completed {
+                continue;
+            }
+            let row = (i + 1, task);
+            if task.is_blocked(visible) {
+                agenda.blocked.push(row);
+                continue;
+            }
+            match task.due_date {
+                Some(due) if due < today => agenda.overdue.push(row),
+                Some(due) if due == today => agenda.today.push(row),
+                Some(due) if due <= horizon => agenda.upcoming.push(row),
+                _ => {}
+            }
+        }
+        for section in [&mut agenda.overdue, &mut agenda.upcoming] {
+            section.sort_by_key(|&(id, t)| (t.due_date, id));
+        }
+        agenda
+    }
+
+    /// Sections in display order, with their titles and JSON keys.
+    fn sections(&self) -> [(&'static str, &'static str, &Rows<'a>); 4] {
+        [
+            ("Overdue", "overdue", &self.overdue),
+            ("Due today", "today", &self.today),
+            ("Next 7 days", "upcoming", &self.upcoming),
+            ("Blocked", "blocked", &self.blocked),
+        ]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections().iter().all(|(_, _, rows)| rows.is_empty())
+    }
+}
+
+/// `todo agenda`
+pub fn execute(storage: &impl Storage, format: OutputFormat) -> Result<()> {
+    let (tasks, projects, notes) = storage.load_all()?;
+    let resources = storage.load_resources()?;
+    let visible: Vec<Task> = tasks.into_iter().filter(|t| !t.is_deleted()).collect();
+    let agenda = Agenda::build(&visible, Local::now().date_naive());
+
+    match format {
+        OutputFormat::Json => {
+            let mut doc = serde_json::Map::new();
+            for (_, key, rows) in agenda.sections() {
+                let records: Vec<_> = rows
+                    .iter()
+                    .map(|(id, t)| structured::with_id(*id, t))
+                    .collect();
+                doc.insert(key.to_string(), records.into());
+            }
+            return structured::print_json(&doc);
+        }
+        OutputFormat::Plain => {
+            for (_, key, rows) in agenda.sections() {
+                for (id, task) in rows {
+                    let line = structured::task_plain(*id, task, &visible, &projects);
+                    println!("{}\t{}", key, line);
+                }
+            }
+            return Ok(());
+        }
+        OutputFormat::Table | OutputFormat::Markdown => {}
+    }
+
+    if agenda.is_empty() {
+        println!("\n{}\n", "Nothing on the agenda.".dimmed());
+        return Ok(());
+    }
+    for (title, _, rows) in agenda.sections() {
+        if rows.is_empty() {
+            continue;
+        }
+        let title = format!("{}  ({})", title, rows.len());
+        display_lists(
+            rows,
+            &title,
+            &visible,
+            &projects,
+            &notes,
+            &resources,
+            Columns::default(),
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+
+    fn make_task(text: &str, due: Option<NaiveDate>) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, due, None)
+    }
+
+    #[test]
+    fn test_agenda_sections() {
+        let today = Local::now().date_naive();
+        let days = |n| Some(today + Duration::days(n));
+        let late = make_task("Late", days(-2));
+        let now = make_task("Now", days(0));
+        let later = make_task("Later", days(7));
+        let far = make_task("Far", days(8));
+        let mut blocked = make_task("Blocked", days(0));
+        blocked.depends_on = vec![late.uuid];
+        let mut done = make_task("Done", days(-1));
+        done.mark_done();
+        let tasks = vec![
+            later,
+            late,
+            now,
+            far,
+            blocked,
+            done,
+            make_task("Someday", None),
+        ];
+
+        let agenda = Agenda::build(&tasks, today);
+        let ids = |rows: &[(usize, &Task)]| rows.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        assert_eq!(ids(&agenda.overdue), vec![2]);
+        assert_eq!(ids(&agenda.today), vec![3]);
+        assert_eq!(ids(&agenda.upcoming), vec![1]);
+        assert_eq!(ids(&agenda.blocked), vec![5]);
+    }
+}
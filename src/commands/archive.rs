@@ -0,0 +1,354 @@
+//! Handler for `todo archive --project <NAME>`.
+//!
+//! Closes out a finished project in one step:
+//!
+//! 1. Each task still pending is marked done or cancelled — asked one by
+//!    one, or all done with `--yes`.
+//! 2. The project, its tasks, and the notes linked to either are written to
+//!    `<data_dir>/archive/<project>-<date>.json` in the `todo export` format,
+//!    then removed from the live database.
+//! 3. A final report shows how long the project ran, its completion rate,
+//!    and the mean cycle time of its completed tasks.
+//!
+//! Answering `q` at any prompt aborts before anything is changed.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use colored::Colorize;
+
+use crate::commands::portability::Envelope;
+use crate::error::TodoError;
+use crate::models::Task;
+use crate::storage::{EntityType, EventType, Storage, get_db_path};
+use crate::utils::confirm::read_key;
+use crate::utils::suggest;
+
+/// What to do with a task still pending when its project is archived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Closeout {
+    Done,
+    Cancel,
+}
+
+/// Figures for the final project report.
+#[derive(Debug, PartialEq)]
+pub struct ArchiveReport {
+    pub name: String,
+    pub started: DateTime<Utc>,
+    pub finished: DateTime<Utc>,
+    pub total: usize,
+    pub done: usize,
+    pub cancelled: usize,
+    /// Mean days from creation to completion, if anything was completed
+    pub avg_cycle_days: Option<f64>,
+    pub file: PathBuf,
+}
+
+pub fn execute(storage: &impl Storage, project: &str, yes: bool) -> Result<()> {
+    let archive_dir = get_db_path()?
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join("archive");
+
+    let report = if yes {
+        close_out(storage, project, &archive_dir, |_, _| {
+            Ok(Some(Closeout::Done))
+        })?
+    } else {
+        close_out(storage, project, &archive_dir, ask)?
+    };
+
+    match report {
+        Some(report) => print_report(&report),
+        None => println!("{}", "Archive cancelled; nothing was changed.".dimmed()),
+    }
+    Ok(())
+}
+
+/// Resolves pending tasks with `choose`, writes the archive file into
+/// `archive_dir`, and removes the project's data from `storage`.
+///
+/// Returns `None` if `choose` aborted (returned `Ok(None)`).
+pub fn close_out(
+    storage: &impl Storage,
+    project: &str,
+    archive_dir: &Path,
+    mut choose: impl FnMut(usize, &Task) -> Result<Option<Closeout>>,
+) -> Result<Option<ArchiveReport>> {
+    let (mut tasks, mut projects, mut notes) = storage.load_all()?;
+    let project_uuid = suggest::resolve_project_filter(&projects, project)?
+        .ok_or_else(|| TodoError::ProjectNotFound(project.to_owned()))?;
+    let p_index = projects
+        .iter()
+        .position(|p| p.uuid == project_uuid)
+        .expect("resolved project exists");
+
+    let in_project: Vec<usize> = tasks
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| !t.is_deleted() && t.project_id == Some(project_uuid))
+        .map(|(i, _)| i)
+        .collect();
+
+    // Decide everything before touching any data
+    let mut decisions = Vec::new();
+    let pending: Vec<usize> = in_project
+        .iter()
+        .copied()
+        .filter(|&i| !tasks[i].completed)
+        .collect();
+    for (n, &i) in pending.iter().enumerate() {
+        match choose(n + 1, &tasks[i])? {
+            Some(choice) => decisions.push((i, choice)),
+            None => return Ok(None),
+        }
+    }
+
+    for &(i, choice) in &decisions {
+        match choice {
+            Closeout::Done => tasks[i].mark_done(),
+            Closeout::Cancel => tasks[i].cancel(Some("project archived".to_owned())),
+        }
+    }
+    if !projects[p_index].completed {
+        projects[p_index].mark_done();
+    }
+
+    let finished = Utc::now();
+    let archived: Vec<Task> = in_project.iter().map(|&i| tasks[i].clone()).collect();
+    let task_uuids: Vec<_> = in_project.iter().map(|&i| tasks[i].uuid).collect();
+    let linked_notes: Vec<usize> = notes
+        .iter()
+        .enumerate()
+        .filter(|(_, n)| !n.is_deleted())
+        .filter(|(_, n)| {
+            n.project_id == Some(project_uuid) || n.task_id.is_some_and(|t| task_uuids.contains(&t))
+        })
+        .map(|(i, _)| i)
+        .collect();
+
+    let envelope = Envelope {
+        tasks: archived.clone(),
+        projects: vec![projects[p_index].clone()],
+        notes: linked_notes.iter().map(|&i| notes[i].clone()).collect(),
+        resources: Vec::new(),
+    };
+    let file = archive_dir.join(format!(
+        "{}-{}.json",
+        slug(&projects[p_index].name),
+        Local::now().format("%Y-%m-%d")
+    ));
+    std::fs::create_dir_all(archive_dir).context("Failed to create archive directory")?;
+    let json = serde_json::to_string_pretty(&envelope).context("Failed to serialize archive")?;
+    std::fs::write(&file, json)
+        .with_context(|| format!("Failed to write archive file: {}", file.display()))?;
+
+    for &i in &in_project {
+        tasks[i].soft_delete();
+    }
+    for &i in &linked_notes {
+        notes[i].soft_delete();
+    }
+    projects[p_index].soft_delete();
+    storage.save_all(&tasks, &projects, &notes)?;
+    for &i in &in_project {
+        storage.record_event(EntityType::Task, tasks[i].uuid, EventType::Deleted)?;
+    }
+    for &i in &linked_notes {
+        storage.record_event(EntityType::Note, notes[i].uuid, EventType::Deleted)?;
+    }
+    storage.record_event(EntityType::Project, project_uuid, EventType::Deleted)?;
+
+    let cycle_days: Vec<f64> = archived
+        .iter()
+        .filter_map(|t| t.completed_at.map(|at| (at - t.created_at).num_hours()))
+        .map(|hours| hours as f64 / 24.0)
+        .collect();
+    let avg_cycle_days =
+        (!cycle_days.is_empty()).then(|| cycle_days.iter().sum::<f64>() / cycle_days.len() as f64);
+
+    Ok(Some(ArchiveReport {
+        name: projects[p_index].name.clone(),
+        started: projects[p_index].created_at,
+        finished,
+        total: in_project.len(),
+        done: archived
+            .iter()
+            .filter(|t| t.completed && !t.is_cancelled())
+            .count(),
+        cancelled: archived.iter().filter(|t| t.is_cancelled()).count(),
+        avg_cycle_days,
+        file,
+    }))
+}
+
+fn ask(n: usize, task: &Task) -> Result<Option<Closeout>> {
+    loop {
+        print!(
+            "  {} {}  {} ",
+            format!("[{}]", n).dimmed(),
+            task.text.bright_white(),
+            "d done · c cancel · q abort ›".dimmed()
+        );
+        std::io::Write::flush(&mut std::io::stdout())?;
+        let key = read_key()?;
+        println!("{}", key);
+        match key {
+            'd' => return Ok(Some(Closeout::Done)),
+            'c' => return Ok(Some(Closeout::Cancel)),
+            'q' => return Ok(None),
+            _ => {}
+        }
+    }
+}
+
+fn print_report(report: &ArchiveReport) {
+    let days = (report.finished - report.started).num_days();
+    let pct = (report.done * 100)
+        .checked_div(report.total - report.cancelled)
+        .unwrap_or(100);
+    println!(
+        "\n{} Project {} archived\n",
+        "✓".green(),
+        format!("\"{}\"", report.name).bright_white().bold()
+    );
+    println!(
+        "  {:<12} {} days ({} → {})",
+        "Duration".dimmed(),
+        days.to_string().cyan(),
+        report.started.with_timezone(&Local).format("%Y-%m-%d"),
+        report.finished.with_timezone(&Local).format("%Y-%m-%d")
+    );
+    println!(
+        "  {:<12} {} ({} done, {} cancelled)",
+        "Tasks".dimmed(),
+        report.total.to_string().cyan(),
+        report.done,
+        report.cancelled
+    );
+    println!(
+        "  {:<12} {}%",
+        "Completion".dimmed(),
+        pct.to_string().cyan()
+    );
+    if let Some(avg) = report.avg_cycle_days {
+        println!(
+            "  {:<12} {} days avg",
+            "Cycle time".dimmed(),
+            format!("{:.1}", avg).cyan()
+        );
+    }
+    println!(
+        "  {:<12} {}\n",
+        "Archive".dimmed(),
+        report.file.display().to_string().dimmed()
+    );
+}
+
+/// File-name-safe version of a project name.
+fn slug(name: &str) -> String {
+    let slug: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    let slug = slug
+        .split('-')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        "project".to_string()
+    } else {
+        slug
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Note, Priority, Project};
+    use crate::storage::InMemoryStorage;
+
+    fn setup() -> (InMemoryStorage, tempfile::TempDir) {
+        let storage = InMemoryStorage::default();
+        let project = Project::new("Site Launch".into());
+        let task = |text: &str| {
+            Task::new(
+                text.into(),
+                Priority::Medium,
+                vec![],
+                Some(project.uuid),
+                None,
+                None,
+            )
+        };
+        let mut shipped = task("Ship it");
+        shipped.mark_done();
+        let other = Task::new("Unrelated".into(), Priority::Low, vec![], None, None, None);
+        let mut note = Note::new("Launch notes".into());
+        note.project_id = Some(project.uuid);
+
+        storage
+            .save_all(
+                &[shipped, task("Write docs"), task("Polish"), other],
+                &[project],
+                &[note],
+            )
+            .unwrap();
+        (storage, tempfile::tempdir().unwrap())
+    }
+
+    #[test]
+    fn test_close_out_archives_and_removes_project() {
+        let (storage, dir) = setup();
+        let mut answers = [Closeout::Done, Closeout::Cancel].into_iter();
+
+        let report = close_out(&storage, "site launch", dir.path(), |_, _| {
+            Ok(answers.next())
+        })
+        .unwrap()
+        .unwrap();
+
+        assert_eq!((report.total, report.done, report.cancelled), (3, 2, 1));
+        assert!(report.avg_cycle_days.is_some());
+
+        let (tasks, projects, notes) = storage.load_all().unwrap();
+        let live: Vec<_> = tasks.iter().filter(|t| !t.is_deleted()).collect();
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].text, "Unrelated");
+        assert!(projects.iter().all(|p| p.is_deleted()));
+        assert!(notes.iter().all(|n| n.is_deleted()));
+
+        let archived: Envelope =
+            serde_json::from_str(&std::fs::read_to_string(&report.file).unwrap()).unwrap();
+        assert_eq!(archived.tasks.len(), 3);
+        assert!(archived.tasks.iter().all(|t| t.completed));
+        assert_eq!(
+            archived.tasks.iter().filter(|t| t.is_cancelled()).count(),
+            1
+        );
+        assert_eq!(archived.projects[0].name, "Site Launch");
+        assert_eq!(archived.notes.len(), 1);
+    }
+
+    #[test]
+    fn test_abort_changes_nothing() {
+        let (storage, dir) = setup();
+
+        let report = close_out(&storage, "Site Launch", dir.path(), |_, _| Ok(None)).unwrap();
+
+        assert!(report.is_none());
+        let tasks = storage.load().unwrap();
+        assert!(tasks.iter().all(|t| !t.is_deleted()));
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(slug("Site Launch!"), "site-launch");
+        assert_eq!(slug("???"), "project");
+    }
+}
@@ -4,6 +4,8 @@ use anyhow::{Context, Result, bail};
 use colored::Colorize;
 use std::path::PathBuf;
 
+#[cfg(unix)]
+use crate::storage::shared;
 use crate::storage::{backup, get_db_path};
 
 // ── backup ────────────────────────────────────────────────────────────────────
@@ -33,8 +35,16 @@ pub fn execute_backup() -> Result<()> {
 /// `todo restore [FILE]` — restores from a backup file.
 ///
 /// If no file is given, lists available backups and lets the user pick one.
+/// Runs before `main` opens storage, since the database file is replaced.
 pub fn execute_restore(file: Option<PathBuf>, yes: bool) -> Result<()> {
     let db_path = get_db_path()?;
+
+    // A running daemon would keep its connection (and WAL) on the replaced file
+    #[cfg(unix)]
+    if shared::SocketStorage::connect(&shared::socket_path(&db_path)).is_some() {
+        bail!("`todo remind --daemon` is running; stop it before restoring a backup");
+    }
+
     let backup_dir = db_path
         .parent()
         .unwrap_or(std::path::Path::new("."))
@@ -72,7 +82,22 @@ pub fn execute_restore(file: Option<PathBuf>, yes: bool) -> Result<()> {
         }
     }
 
+    // Reading the live tasks also checkpoints the write-ahead log, so any
+    // `-wal` file left afterwards is stale and would corrupt the restored copy
+    let live_before = backup::live_task_uuids(&db_path);
     std::fs::copy(&backup_path, &db_path).context("Failed to restore backup")?;
+    for suffix in ["-wal", "-shm"] {
+        let mut side = db_path.clone().into_os_string();
+        side.push(suffix);
+        let _ = std::fs::remove_file(side);
+    }
+    if let Err(e) = backup::mark_restored(&db_path, &backup_path, &live_before) {
+        eprintln!(
+            "{} Could not record the backup as the restored tasks' source: {}",
+            "!".yellow(),
+            e
+        );
+    }
 
     println!(
         "{} Restored from: {}",
@@ -5,7 +5,6 @@ use std::collections::HashMap;
 use anyhow::Result;
 use chrono::{Datelike, Local, NaiveDate};
 
-use crate::config::Config;
 use crate::render::calendar::{DayInfo, display_calendar};
 use crate::services::holidays::HolidayCache;
 use crate::storage::Storage;
@@ -19,14 +18,8 @@ pub fn execute(storage: &impl Storage, month: Option<u32>, year: Option<i32>) ->
         anyhow::bail!("Invalid month: {}. Must be between 1 and 12.", target_month);
     }
 
-    let cfg = Config::load().unwrap_or_default();
-
-    // Load holiday cache — silently fall back to empty if not configured or unavailable
-    let holidays = if cfg.holidays_locale != "none" && !cfg.holidays_locale.is_empty() {
-        HolidayCache::load(&cfg.holidays_locale, target_year).unwrap_or_default()
-    } else {
-        HolidayCache::default()
-    };
+    // Load holidays — silently empty if not configured or unavailable
+    let holidays = HolidayCache::configured(&[target_year]);
 
     let all_tasks = storage.load()?;
     let all_projects = storage.load_projects()?;
@@ -0,0 +1,358 @@
+//! Handler for `todo capture [--port <PORT>] [--project <NAME>]`.
+//!
+//! Runs a small quick-add server on `127.0.0.1`. Every non-empty line it
+//! receives becomes a task, parsed like unquoted `todo add` input
+//! (`call bank tomorrow !high #finance`). Tasks without an inline `@project`
+//! go to `--project`, if given.
+//!
+//! Two kinds of client are understood on the same port:
+//!
+//! - Plain TCP, for hotkey scripts: `echo "buy milk" | nc localhost 7878`.
+//!   Each line is answered with `ok #<ID>` or `error: <reason>`.
+//! - HTTP `POST` with a text body, for browser extensions and `curl -d`.
+//!   The same per-line answers are returned as the response body.
+//!
+//! Only loopback connections are accepted. Since any web page can make the
+//! browser post to a loopback port, HTTP requests carrying an `Origin`
+//! header are refused unless the origin is listed in the `capture_origins`
+//! setting. Clients are served one at a time, so a connection that sends
+//! nothing for [`READ_TIMEOUT`] is dropped.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+
+use crate::cli::AddArgs;
+use crate::commands::task::add;
+use crate::config::Config;
+use crate::storage::Storage;
+
+/// Largest HTTP body accepted, in bytes.
+const MAX_BODY: usize = 64 * 1024;
+
+/// How long a client may stay silent before it is disconnected.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub fn execute(storage: &impl Storage, port: u16, project: Option<String>) -> Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .with_context(|| format!("Failed to listen on 127.0.0.1:{}", port))?;
+    println!(
+        "{} Capturing on {} (Ctrl-C to stop)",
+        "✎".cyan(),
+        format!("127.0.0.1:{}", port).bright_white()
+    );
+    if let Some(project) = &project {
+        println!("  {} default project: {}", "·".dimmed(), project.cyan());
+    }
+    let origins = &Config::defaults().capture_origins;
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("{} {}", "✗".red(), e);
+                continue;
+            }
+        };
+        // One client failing (bad request, dropped connection) must not stop the server
+        if let Err(e) = serve(storage, stream, project.as_deref(), origins) {
+            eprintln!("{} {}", "✗".red(), e);
+        }
+    }
+    Ok(())
+}
+
+fn serve(
+    storage: &impl Storage,
+    stream: TcpStream,
+    project: Option<&str>,
+    origins: &[String],
+) -> Result<()> {
+    if !stream.peer_addr()?.ip().is_loopback() {
+        return Ok(());
+    }
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    match serve_client(storage, stream, project, origins) {
+        Err(e) if is_timeout(&e) => Ok(()),
+        result => result,
+    }
+}
+
+fn serve_client(
+    storage: &impl Storage,
+    stream: TcpStream,
+    project: Option<&str>,
+    origins: &[String],
+) -> Result<()> {
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+
+    let mut first = String::new();
+    if reader.read_line(&mut first)? == 0 {
+        return Ok(());
+    }
+
+    if let Some(method) = http_method(&first) {
+        let request = read_http_request(&mut reader)?;
+        let cors = match &request.origin {
+            Some(origin) if !origins.contains(origin) => {
+                eprintln!("{} refused request from origin {}", "✗".red(), origin);
+                return write_http(&mut writer, "403 Forbidden", "", "origin not allowed\n");
+            }
+            Some(origin) => format!(
+                "Access-Control-Allow-Origin: {}\r\nVary: Origin\r\n\
+                 Access-Control-Allow-Methods: POST\r\nAccess-Control-Allow-Headers: Content-Type\r\n",
+                origin
+            ),
+            None => String::new(),
+        };
+        match method {
+            "POST" => {
+                let mut reply = String::new();
+                for line in request.body.lines() {
+                    if let Some(answer) = capture_line(storage, line, project) {
+                        reply.push_str(&answer);
+                        reply.push('\n');
+                    }
+                }
+                return write_http(&mut writer, "200 OK", &cors, &reply);
+            }
+            // CORS preflight from an allowed origin
+            "OPTIONS" => return write_http(&mut writer, "204 No Content", &cors, ""),
+            _ => {
+                return write_http(
+                    &mut writer,
+                    "405 Method Not Allowed",
+                    &cors,
+                    "POST one task per line\n",
+                );
+            }
+        }
+    }
+
+    let mut line = first;
+    loop {
+        if let Some(answer) = capture_line(storage, &line, project) {
+            writeln!(writer, "{}", answer)?;
+        }
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+    }
+}
+
+/// Adds one received line as a task and returns the answer for the client,
+/// or `None` for a blank line.
+pub fn capture_line(storage: &impl Storage, line: &str, project: Option<&str>) -> Option<String> {
+    let mut words = line.split_whitespace().map(str::to_owned);
+    let text = words.next()?;
+    let args = AddArgs {
+        text,
+        priority: None,
+        tag: vec![],
+        project: None,
+        due: None,
+        recurrence: None,
+        depends_on: vec![],
+        extra: words.collect(),
+        wait_until: None,
+        start: None,
+        estimate: None,
+        label: None,
+    };
+    let args = AddArgs {
+        project: inline_project(&args).or_else(|| project.map(str::to_owned)),
+        ..args
+    };
+
+    Some(match add::execute_silent(storage, args) {
+        Ok(id) => {
+            println!("{} #{} {}", "+".green(), id, line.trim());
+            format!("ok #{}", id)
+        }
+        Err(e) => {
+            eprintln!("{} {}: {}", "✗".red(), line.trim(), e);
+            format!("error: {}", e)
+        }
+    })
+}
+
+/// The `@project` token in the line, which wins over the default project.
+fn inline_project(args: &AddArgs) -> Option<String> {
+    std::iter::once(&args.text)
+        .chain(&args.extra)
+        .find_map(|w| w.strip_prefix('@').filter(|p| !p.is_empty()))
+        .map(str::to_owned)
+}
+
+/// The method of an HTTP request line, e.g. `POST / HTTP/1.1`.
+fn http_method(line: &str) -> Option<&str> {
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?;
+    parts.next()?;
+    let is_http = parts.next()?.starts_with("HTTP/");
+    (is_http && method.chars().all(|c| c.is_ascii_uppercase())).then_some(method)
+}
+
+/// The parts of an HTTP request the server looks at.
+#[derive(Debug, PartialEq)]
+struct HttpRequest {
+    origin: Option<String>,
+    body: String,
+}
+
+/// Reads the headers after the request line, then the body.
+fn read_http_request(reader: &mut impl BufRead) -> Result<HttpRequest> {
+    let mut length = 0;
+    let mut origin = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            continue;
+        };
+        let name = name.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            length = value.trim().parse().context("Invalid Content-Length")?;
+        } else if name.eq_ignore_ascii_case("origin") {
+            origin = Some(value.trim().to_string());
+        }
+    }
+    anyhow::ensure!(length <= MAX_BODY, "request body too large");
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    Ok(HttpRequest {
+        origin,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+/// Writes a complete plain-text response; `headers` are extra
+/// `Name: value\r\n` lines.
+fn write_http(writer: &mut impl Write, status: &str, headers: &str, body: &str) -> Result<()> {
+    write!(
+        writer,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\n{}\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        headers,
+        body.len(),
+        body
+    )?;
+    Ok(())
+}
+
+/// Whether `error` is the read timeout firing on an idle client.
+fn is_timeout(error: &anyhow::Error) -> bool {
+    error.downcast_ref::<std::io::Error>().is_some_and(|e| {
+        matches!(
+            e.kind(),
+            std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::InMemoryStorage;
+
+    #[test]
+    fn test_capture_line_uses_default_project_unless_inline() {
+        let storage = InMemoryStorage::default();
+
+        assert_eq!(
+            capture_line(&storage, "buy milk #home", Some("Errands")),
+            Some("ok #1".to_string())
+        );
+        assert_eq!(
+            capture_line(&storage, "fix build !high @Work", Some("Errands")),
+            Some("ok #2".to_string())
+        );
+        assert_eq!(capture_line(&storage, "   ", Some("Errands")), None);
+
+        let (tasks, projects, _) = storage.load_all().unwrap();
+        let project_of = |i: usize| {
+            let uuid = tasks[i].project_id.unwrap();
+            projects
+                .iter()
+                .find(|p| p.uuid == uuid)
+                .unwrap()
+                .name
+                .clone()
+        };
+        assert_eq!(tasks[0].text, "buy milk");
+        assert_eq!(tasks[0].tags, vec!["home"]);
+        assert_eq!(project_of(0), "Errands");
+        assert_eq!(tasks[1].priority, Priority::High);
+        assert_eq!(project_of(1), "Work");
+    }
+
+    #[test]
+    fn test_http_request_parsing() {
+        assert_eq!(http_method("POST / HTTP/1.1\r\n"), Some("POST"));
+        assert_eq!(http_method("GET /add HTTP/1.0"), Some("GET"));
+        assert_eq!(http_method("buy milk tomorrow"), None);
+        assert_eq!(http_method("POST the letter"), None);
+
+        let mut request = "Host: x\r\ncontent-length: 9\r\n\r\nbuy milk\nrest".as_bytes();
+        assert_eq!(
+            read_http_request(&mut request).unwrap(),
+            HttpRequest {
+                origin: None,
+                body: "buy milk\n".into()
+            }
+        );
+
+        let mut request = "Origin: https://evil.example\r\n\r\n".as_bytes();
+        let parsed = read_http_request(&mut request).unwrap();
+        assert_eq!(parsed.origin.as_deref(), Some("https://evil.example"));
+    }
+
+    #[test]
+    fn test_serve_refuses_unlisted_origins_and_drops_idle_clients() {
+        use std::io::Read;
+
+        let storage = InMemoryStorage::default();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let origins = vec!["moz-extension://ok".to_string()];
+
+        let client = std::thread::spawn(move || {
+            let send = |request: &str| {
+                let mut stream = TcpStream::connect(addr).unwrap();
+                stream.write_all(request.as_bytes()).unwrap();
+                let mut reply = String::new();
+                stream.read_to_string(&mut reply).unwrap();
+                reply
+            };
+            let evil = send(
+                "POST / HTTP/1.1\r\nOrigin: https://evil.example\r\nContent-Length: 5\r\n\r\nspam\n",
+            );
+            let ok = send(
+                "POST / HTTP/1.1\r\nOrigin: moz-extension://ok\r\nContent-Length: 9\r\n\r\nbuy milk\n",
+            );
+            (evil, ok)
+        });
+        for _ in 0..2 {
+            let (stream, _) = listener.accept().unwrap();
+            serve(&storage, stream, None, &origins).unwrap();
+        }
+        let (evil, ok) = client.join().unwrap();
+
+        assert!(evil.starts_with("HTTP/1.1 403"));
+        assert!(!evil.contains("Access-Control-Allow-Origin"));
+        assert!(ok.starts_with("HTTP/1.1 200"));
+        assert!(ok.contains("Access-Control-Allow-Origin: moz-extension://ok"));
+        assert!(ok.ends_with("ok #1\n"));
+        assert_eq!(storage.load().unwrap().len(), 1);
+    }
+}
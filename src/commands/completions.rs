@@ -0,0 +1,152 @@
+//! Handler for `todo completions [SHELL] [--install]`.
+//!
+//! Prints a completion script for bash, zsh, fish, or PowerShell. With
+//! `--install` the script is written to the shell's conventional per-user
+//! location instead, after a confirmation prompt:
+//!
+//! | Shell      | Path                                              |
+//! |------------|---------------------------------------------------|
+//! | bash       | `$XDG_DATA_HOME/bash-completion/completions/todo` |
+//! | zsh        | `~/.zfunc/_todo`                                  |
+//! | fish       | `$XDG_CONFIG_HOME/fish/completions/todo.fish`     |
+//! | PowerShell | `$XDG_CONFIG_HOME/powershell/todo-completions.ps1` |
+//!
+//! The shell is detected from `$SHELL` (PowerShell on Windows) when not
+//! given explicitly.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow, bail};
+use clap::CommandFactory;
+use clap_complete::Shell;
+use colored::Colorize;
+use directories::BaseDirs;
+
+use crate::cli::Cli;
+
+const BIN_NAME: &str = "todo";
+
+pub fn execute(shell: Option<Shell>, install: bool, yes: bool) -> Result<()> {
+    let shell = shell.or_else(Shell::from_env).ok_or_else(|| {
+        anyhow!("Could not detect your shell — pass one of: bash, zsh, fish, powershell")
+    })?;
+
+    if !install {
+        generate(shell, &mut io::stdout());
+        return Ok(());
+    }
+
+    let dirs = BaseDirs::new().context("Could not determine home directory")?;
+    let path = install_path(shell, dirs.home_dir(), dirs.data_dir(), dirs.config_dir())?;
+
+    if !yes
+        && !crate::utils::confirm(&format!(
+            "Install {} completions to {}? [y/N]:",
+            shell,
+            path.display()
+        ))?
+    {
+        println!("{}", "Cancelled.".dimmed());
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let mut script = Vec::new();
+    generate(shell, &mut script);
+    fs::write(&path, script).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    println!(
+        "{} Installed {} completions to {}",
+        "✓".green(),
+        shell,
+        path.display().to_string().cyan()
+    );
+    if let Some(hint) = activation_hint(shell, &path) {
+        println!("  {}", hint.dimmed());
+    }
+    Ok(())
+}
+
+fn generate(shell: Shell, out: &mut dyn io::Write) {
+    clap_complete::generate(shell, &mut Cli::command(), BIN_NAME, out);
+}
+
+/// Conventional per-user location for a shell's completion script.
+fn install_path(shell: Shell, home: &Path, data_dir: &Path, config_dir: &Path) -> Result<PathBuf> {
+    Ok(match shell {
+        Shell::Bash => data_dir
+            .join("bash-completion")
+            .join("completions")
+            .join(BIN_NAME),
+        Shell::Zsh => home.join(".zfunc").join(format!("_{}", BIN_NAME)),
+        Shell::Fish => config_dir
+            .join("fish")
+            .join("completions")
+            .join(format!("{}.fish", BIN_NAME)),
+        Shell::PowerShell => config_dir
+            .join("powershell")
+            .join(format!("{}-completions.ps1", BIN_NAME)),
+        other => bail!(
+            "--install does not support {}; redirect the output instead",
+            other
+        ),
+    })
+}
+
+/// Extra setup the shell needs before it picks up the installed file.
+fn activation_hint(shell: Shell, path: &Path) -> Option<String> {
+    match shell {
+        Shell::Zsh => Some(format!(
+            "Add `fpath=({} $fpath)` before `compinit` in ~/.zshrc",
+            path.parent()?.display()
+        )),
+        Shell::PowerShell => Some(format!("Add `. {}` to your $PROFILE", path.display())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path_for(shell: Shell) -> PathBuf {
+        install_path(
+            shell,
+            Path::new("/home/u"),
+            Path::new("/home/u/.local/share"),
+            Path::new("/home/u/.config"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_install_paths() {
+        assert_eq!(
+            path_for(Shell::Bash),
+            Path::new("/home/u/.local/share/bash-completion/completions/todo")
+        );
+        assert_eq!(path_for(Shell::Zsh), Path::new("/home/u/.zfunc/_todo"));
+        assert_eq!(
+            path_for(Shell::Fish),
+            Path::new("/home/u/.config/fish/completions/todo.fish")
+        );
+        assert_eq!(
+            path_for(Shell::PowerShell),
+            Path::new("/home/u/.config/powershell/todo-completions.ps1")
+        );
+    }
+
+    #[test]
+    fn test_generated_script_mentions_subcommands() {
+        let mut out = Vec::new();
+        generate(Shell::Fish, &mut out);
+        let script = String::from_utf8(out).unwrap();
+        assert!(script.contains("complete -c todo"));
+        assert!(script.contains("add"));
+    }
+}
@@ -1,12 +1,12 @@
 //! Handler for `todo context <ID>`.
 //!
-//! Shows everything linked to a task: project, dependencies, notes, and
-//! resources (via notes). A "knowledge hub" centred on a single task.
+//! Shows everything linked to a task: project, dependencies, attachments,
+//! notes, and resources (via notes). A "knowledge hub" centred on a single task.
 
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::render::formatting::{note_preview, truncate};
+use crate::render::formatting::{first_line, note_preview, priority_label, rule, truncate};
 use crate::storage::Storage;
 use crate::utils::validation::{resolve_visible_index, visible_indices};
 
@@ -26,12 +26,32 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     println!();
     println!(
         "  {}",
-        format!("Task #{}: {}", id, task.text).bold().bright_white()
+        format!("Task #{}: {}", id, first_line(&task.text))
+            .bold()
+            .bright_white()
     );
-    println!("  {}", "─".repeat(50).dimmed());
+    println!("  {}", rule(50).dimmed());
+
+    // Multi-line text (read from stdin): everything past the first line.
+    let mut rest = task.text.lines().skip(1).peekable();
+    if rest.peek().is_some() {
+        for line in rest {
+            println!("  {}", line);
+        }
+        println!();
+    }
 
     // ── Details ───────────────────────────────────────────────────────────────
-    let status = if task.completed {
+    let status = if task.is_cancelled() {
+        match &task.cancel_reason {
+            Some(reason) => format!(
+                "{} {}",
+                "cancelled".dimmed(),
+                format!("({})", reason).dimmed()
+            ),
+            None => "cancelled".dimmed().to_string(),
+        }
+    } else if task.completed {
         "done".green().to_string()
     } else if is_blocked {
         "blocked".red().to_string()
@@ -39,7 +59,11 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         "pending".yellow().to_string()
     };
     println!("  {}  {}", "Status".dimmed(), status);
-    println!("  {}  {}", "Priority".dimmed(), task.priority.letter());
+    println!(
+        "  {}  {}",
+        "Priority".dimmed(),
+        priority_label(task.priority)
+    );
     let urgency = task.urgency_score(&all_visible);
     let urgency_colored = {
         let s = format!("{:.1}", urgency);
@@ -61,10 +85,18 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         println!("  {}  {}", "Project".dimmed(), project.name.magenta());
     }
 
+    if let Some(start) = task.start_date {
+        println!("  {}  {}", "Start".dimmed(), start);
+    }
+
     if let Some(due) = task.due_date {
         println!("  {}  {}", "Due".dimmed(), due);
     }
 
+    if let Some(estimate) = task.estimate {
+        println!("  {}  {}", "Est".dimmed(), estimate);
+    }
+
     if !task.tags.is_empty() {
         println!(
             "  {}  {}",
@@ -107,6 +139,29 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         }
     }
 
+    // ── Attachments ───────────────────────────────────────────────────────────
+    if !task.attachments.is_empty() {
+        println!();
+        println!("  {}", "Attachments".dimmed());
+        for (i, path) in task.attachments.iter().enumerate() {
+            let missing = if path.exists() {
+                String::new()
+            } else {
+                format!("  {}", "(missing)".red())
+            };
+            println!(
+                "    {}  {}{}",
+                format!("{}.", i + 1).dimmed(),
+                path.display().to_string().cyan(),
+                missing
+            );
+        }
+        println!(
+            "    {}",
+            format!("open with: todo open {} --attachment N", id).dimmed()
+        );
+    }
+
     // ── Notes linked to this task ─────────────────────────────────────────────
     let task_notes: Vec<_> = notes
         .iter()
@@ -174,11 +229,15 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         }
     }
 
-    if task_notes.is_empty() && linked_resources.is_empty() && task.depends_on.is_empty() {
+    if task_notes.is_empty()
+        && linked_resources.is_empty()
+        && task.depends_on.is_empty()
+        && task.attachments.is_empty()
+    {
         println!();
         println!(
             "  {}",
-            "No linked notes, resources or dependencies.".dimmed()
+            "No linked notes, resources, attachments or dependencies.".dimmed()
         );
     }
 
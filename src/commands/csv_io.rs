@@ -0,0 +1,544 @@
+//! Handlers for `todo export --to csv` and `todo import --from csv`.
+//!
+//! One task per row, with a header row naming the columns:
+//!
+//! | Column      | Export                          | Import                                   |
+//! |-------------|---------------------------------|------------------------------------------|
+//! | `text`      | task text                       | required                                 |
+//! | `priority`  | `high`, `medium`, `low`         | same, or `h`/`m`/`l`; empty is medium    |
+//! | `tags`      | space-separated                 | separated by spaces or commas            |
+//! | `project`   | project name                    | found or created by name                 |
+//! | `due`       | `YYYY-MM-DD`                    | `YYYY-MM-DD`                             |
+//! | `created`   | `YYYY-MM-DD`                    | `YYYY-MM-DD`; empty is now               |
+//! | `completed` | completion date, or `cancelled` | a date, `cancelled`, `yes`/`x`; empty is pending |
+//!
+//! On import the columns may come in any order and extra columns are
+//! ignored, so a spreadsheet only needs a `text` column to start with.
+//!
+//! Imported rows become new tasks, and each row's source ID — its `id`
+//! column, or a hash of the row when there is none — is remembered in the
+//! `csv-import` snapshot. Re-running an import skips rows already brought
+//! in, so a large import that failed halfway can simply be run again. Rows
+//! that fail validation are skipped and listed in `<FILE>-errors.csv`.
+
+use std::collections::BTreeMap;
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::{Local, NaiveDate};
+use colored::Colorize;
+
+use crate::cli::FilterArgs;
+use crate::commands::task::list::filter_tasks;
+use crate::models::{Priority, Project, Task, TaskSource, local_midnight};
+use crate::render::formatting::project_name;
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::{csv, validation};
+use uuid::Uuid;
+
+/// Columns written by the export, in order.
+pub const COLUMNS: [&str; 7] = [
+    "text",
+    "priority",
+    "tags",
+    "project",
+    "due",
+    "created",
+    "completed",
+];
+
+/// Value of the `completed` column for cancelled tasks.
+const CANCELLED: &str = "cancelled";
+
+/// Snapshot mapping row source IDs to the tasks imported from them.
+const SNAPSHOT_NAME: &str = "csv-import";
+
+/// Rows imported between progress updates and saves of the ID map.
+const CHUNK: usize = 200;
+
+/// Source ID → task created from that row.
+type ImportMap = BTreeMap<String, Uuid>;
+
+/// One imported row, before projects are resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvTask {
+    /// `id:<value>` from the `id` column, else `row:<hash>` of the row
+    pub key: String,
+    pub text: String,
+    pub priority: Priority,
+    pub tags: Vec<String>,
+    pub project: Option<String>,
+    pub due: Option<NaiveDate>,
+    pub created: Option<NaiveDate>,
+    pub completed: Completion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Completion {
+    Pending,
+    /// Done, on the given date if one was given
+    Done(Option<NaiveDate>),
+    Cancelled,
+}
+
+/// A row that failed validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowError {
+    /// Line number in the file, counting the header as 1
+    pub row: usize,
+    pub message: String,
+}
+
+// ── export ────────────────────────────────────────────────────────────────────
+
+/// `todo export --to csv [FILE]`, limited to tasks matching the list filters.
+pub fn execute_export(
+    storage: &impl Storage,
+    file: Option<PathBuf>,
+    filter: &FilterArgs,
+) -> Result<()> {
+    let (all_tasks, projects, _) = storage.load_all()?;
+    let tasks: Vec<Task> = filter_tasks(&all_tasks, &projects, filter)?
+        .into_iter()
+        .map(|(_, t)| t.clone())
+        .collect();
+
+    let path = file.unwrap_or_else(|| {
+        let date = Local::now().format("%Y-%m-%d");
+        PathBuf::from(format!("rustodo-export-{}.csv", date))
+    });
+    std::fs::write(&path, to_csv(&tasks, &projects))
+        .with_context(|| format!("Failed to write export file: {}", path.display()))?;
+
+    println!(
+        "{} Exported to: {}",
+        "✓".green(),
+        path.display().to_string().cyan()
+    );
+    println!("  {} tasks", tasks.len().to_string().dimmed());
+    Ok(())
+}
+
+/// Renders `tasks` as CSV text with a header row.
+pub fn to_csv(tasks: &[Task], projects: &[Project]) -> String {
+    let date = |d: NaiveDate| d.format("%Y-%m-%d").to_string();
+    let mut out = csv::line(&COLUMNS);
+    out.push('\n');
+    for task in tasks {
+        let completed = if task.is_cancelled() {
+            CANCELLED.to_string()
+        } else if task.completed {
+            task.completed_at
+                .map(|at| date(at.with_timezone(&Local).date_naive()))
+                .unwrap_or_else(|| "yes".to_string())
+        } else {
+            String::new()
+        };
+        let project = match task.project_id {
+            Some(_) => project_name(task.project_id, projects).to_string(),
+            None => String::new(),
+        };
+        let priority = match task.priority {
+            Priority::High => "high",
+            Priority::Medium => "medium",
+            Priority::Low => "low",
+        };
+        out.push_str(&csv::line(&[
+            task.text.clone(),
+            priority.to_string(),
+            task.tags.join(" "),
+            project,
+            task.due_date.map(date).unwrap_or_default(),
+            date(task.created_at.with_timezone(&Local).date_naive()),
+            completed,
+        ]));
+        out.push('\n');
+    }
+    out
+}
+
+// ── import ────────────────────────────────────────────────────────────────────
+
+/// `todo import --from csv <FILE>`
+pub fn execute_import(storage: &impl Storage, file: PathBuf, yes: bool) -> Result<()> {
+    let content = std::fs::read_to_string(&file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let (rows, errors) = parse_lenient(&content)?;
+    if rows.is_empty() && errors.is_empty() {
+        println!("{}", "\nNothing to import — file has no rows.\n".dimmed());
+        return Ok(());
+    }
+
+    let map = load_map(storage)?;
+    let tasks = storage.load()?;
+    let (done, rows): (Vec<_>, Vec<_>) = rows
+        .into_iter()
+        .partition(|row| already_imported(&map, &tasks, &row.key));
+
+    println!(
+        "\n{} Importing from: {}\n",
+        "".blue(),
+        file.display().to_string().cyan()
+    );
+    println!(
+        "  {} tasks will be added",
+        rows.len().to_string().bright_white()
+    );
+    if !done.is_empty() {
+        println!(
+            "  {} rows already imported will be skipped",
+            done.len().to_string().dimmed()
+        );
+    }
+    if !errors.is_empty() {
+        println!(
+            "  {} invalid rows will be skipped",
+            errors.len().to_string().yellow()
+        );
+    }
+    println!();
+    if rows.is_empty() {
+        report_errors(&file, &errors)?;
+        println!("{}", "Nothing new to import.".dimmed());
+        return Ok(());
+    }
+    if !yes && !crate::utils::confirm("Proceed with import? [y/N]:")? {
+        println!("{}", "Import cancelled.".dimmed());
+        return Ok(());
+    }
+
+    let count = apply(storage, rows, &file)?;
+    println!(
+        "{} Import complete: {} tasks",
+        "✓".green(),
+        count.to_string().green()
+    );
+    report_errors(&file, &errors)
+}
+
+/// Writes `errors` to `<FILE>-errors.csv` next to the import and says so.
+fn report_errors(file: &Path, errors: &[RowError]) -> Result<()> {
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let stem = file
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "import".to_string());
+    let path = file.with_file_name(format!("{}-errors.csv", stem));
+    let mut out = csv::line(&["row", "error"]);
+    out.push('\n');
+    for error in errors {
+        out.push_str(&csv::line(&[error.row.to_string(), error.message.clone()]));
+        out.push('\n');
+    }
+    std::fs::write(&path, out).with_context(|| format!("Failed to write {}", path.display()))?;
+    println!(
+        "{} {} rows skipped as invalid; see {}",
+        "!".yellow(),
+        errors.len(),
+        path.display().to_string().cyan()
+    );
+    Ok(())
+}
+
+/// Parses CSV text into rows, reporting the first invalid value with its
+/// line number.
+pub fn parse(content: &str) -> Result<Vec<CsvTask>> {
+    let (rows, errors) = parse_lenient(content)?;
+    if let Some(error) = errors.first() {
+        bail!("row {}: {}", error.row, error.message);
+    }
+    Ok(rows)
+}
+
+/// Like [`parse`], but collects invalid rows instead of stopping at the
+/// first. Only a missing `text` column fails the whole file.
+pub fn parse_lenient(content: &str) -> Result<(Vec<CsvTask>, Vec<RowError>)> {
+    let mut records = csv::parse(content)?.into_iter();
+    let Some(header) = records.next() else {
+        return Ok((Vec::new(), Vec::new()));
+    };
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|h| h.trim().eq_ignore_ascii_case(name))
+    };
+    let Some(text_col) = column("text") else {
+        bail!(
+            "CSV header has no 'text' column (expected: {})",
+            COLUMNS.join(",")
+        );
+    };
+    let cols = COLUMNS.map(column);
+    let id_col = column("id");
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for (n, record) in records.enumerate() {
+        let row = n + 2;
+        match parse_row(&record, text_col, &cols, id_col) {
+            Ok(task) => rows.push(task),
+            Err(e) => errors.push(RowError {
+                row,
+                message: format!("{:#}", e),
+            }),
+        }
+    }
+    Ok((rows, errors))
+}
+
+fn parse_row(
+    record: &[String],
+    text_col: usize,
+    cols: &[Option<usize>; 7],
+    id_col: Option<usize>,
+) -> Result<CsvTask> {
+    let get = |i: usize| {
+        cols[i]
+            .and_then(|c| record.get(c))
+            .map(|v| v.trim())
+            .unwrap_or("")
+    };
+    let date = |i: usize| -> Result<Option<NaiveDate>> {
+        match get(i) {
+            "" => Ok(None),
+            value => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(Some)
+                .map_err(|_| anyhow!("invalid {} date '{}'", COLUMNS[i], value)),
+        }
+    };
+
+    let text = record.get(text_col).map(|t| t.trim()).unwrap_or("");
+    validation::validate_task_text(text)?;
+    let priority = match get(1).to_lowercase().as_str() {
+        "" | "m" | "medium" => Priority::Medium,
+        "h" | "high" => Priority::High,
+        "l" | "low" => Priority::Low,
+        other => bail!("invalid priority '{}'", other),
+    };
+    let tags: Vec<String> = get(2)
+        .split([' ', ','])
+        .map(|t| t.trim_start_matches('#'))
+        .filter(|t| !t.is_empty())
+        .map(str::to_owned)
+        .collect();
+    validation::validate_tags(&tags)?;
+    let completed = match get(6).to_lowercase().as_str() {
+        "" | "no" | "false" => Completion::Pending,
+        CANCELLED => Completion::Cancelled,
+        "yes" | "true" | "x" | "done" => Completion::Done(None),
+        _ => Completion::Done(date(6)?),
+    };
+    let key = match id_col
+        .and_then(|c| record.get(c))
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+    {
+        Some(id) => format!("id:{}", id),
+        None => format!("row:{:016x}", row_hash(record)),
+    };
+
+    Ok(CsvTask {
+        key,
+        text: text.to_string(),
+        priority,
+        tags,
+        project: Some(get(3)).filter(|p| !p.is_empty()).map(str::to_owned),
+        due: date(4)?,
+        created: date(5)?,
+        completed,
+    })
+}
+
+/// FNV-1a over the row's trimmed fields, as a stand-in source ID.
+fn row_hash(record: &[String]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for field in record {
+        for byte in field.trim().bytes().chain([0x1f]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    hash
+}
+
+/// Whether the row with `key` was imported before and its task still exists.
+fn already_imported(map: &ImportMap, tasks: &[Task], key: &str) -> bool {
+    map.get(key)
+        .is_some_and(|uuid| tasks.iter().any(|t| t.uuid == *uuid && !t.is_deleted()))
+}
+
+fn load_map(storage: &impl Storage) -> Result<ImportMap> {
+    match storage.load_snapshot(SNAPSHOT_NAME)? {
+        Some(payload) => Ok(serde_json::from_str(&payload)?),
+        None => Ok(ImportMap::new()),
+    }
+}
+
+fn save_map(storage: &impl Storage, map: &ImportMap) -> Result<()> {
+    storage.save_snapshot(SNAPSHOT_NAME, &serde_json::to_string(map)?)
+}
+
+/// Adds `rows` as new tasks, skipping rows imported before, and returns how
+/// many were added.
+///
+/// Tasks are saved one by one and the ID map every [`CHUNK`] rows, so an
+/// interrupted import keeps what it added and a re-run picks up the rest.
+pub fn apply(storage: &impl Storage, rows: Vec<CsvTask>, file: &Path) -> Result<usize> {
+    let from = file
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| file.display().to_string());
+    let tasks = storage.load()?;
+    let mut map = load_map(storage)?;
+    let rows: Vec<_> = rows
+        .into_iter()
+        .filter(|row| !already_imported(&map, &tasks, &row.key))
+        .collect();
+    let total = rows.len();
+    let progress = total > CHUNK && std::io::stderr().is_terminal();
+
+    let mut count = 0;
+    let result = rows.into_iter().try_for_each(|row| -> Result<()> {
+        let project_id = match row.project {
+            Some(ref name) => Some(Project::resolve_or_create(
+                storage,
+                &storage.load_projects()?,
+                name,
+            )?),
+            None => None,
+        };
+        let mut task = Task::new(row.text, row.priority, row.tags, project_id, row.due, None);
+        if let Some(created) = row.created {
+            task.created_at = local_midnight(created);
+        }
+        match row.completed {
+            Completion::Pending => {}
+            Completion::Done(on) => {
+                task.mark_done();
+                if let Some(on) = on {
+                    task.completed_at = Some(local_midnight(on));
+                }
+            }
+            Completion::Cancelled => task.cancel(None),
+        }
+        task.source = Some(TaskSource::Import(from.clone()));
+        storage.upsert_task(&task)?;
+        storage.record_event(EntityType::Task, task.uuid, EventType::Created)?;
+        map.insert(row.key, task.uuid);
+        count += 1;
+
+        if count % CHUNK == 0 {
+            save_map(storage, &map)?;
+            if progress {
+                eprint!("\r  {} {}/{} rows", "…".dimmed(), count, total);
+                let _ = std::io::stderr().flush();
+            }
+        }
+        Ok(())
+    });
+    if progress {
+        eprintln!();
+    }
+
+    // Keep the map in step with what was saved, even after a failure
+    save_map(storage, &map)?;
+    result.map(|()| count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+
+    #[test]
+    fn test_export_then_import_round_trips() {
+        let project = Project::new("Home, sweet".into());
+        let mut done = Task::new(
+            "Paint \"the\" fence".into(),
+            Priority::High,
+            vec!["diy".into(), "weekend".into()],
+            Some(project.uuid),
+            NaiveDate::from_ymd_opt(2030, 5, 1),
+            None,
+        );
+        done.mark_done();
+        let mut dropped = Task::new("Old idea".into(), Priority::Low, vec![], None, None, None);
+        dropped.cancel(None);
+
+        let csv = to_csv(&[done, dropped], &[project]);
+        assert!(csv.starts_with("text,priority,tags,project,due,created,completed\n"));
+
+        let rows = parse(&csv).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].text, "Paint \"the\" fence");
+        assert_eq!(rows[0].tags, vec!["diy", "weekend"]);
+        assert_eq!(rows[0].project.as_deref(), Some("Home, sweet"));
+        assert_eq!(rows[0].due, NaiveDate::from_ymd_opt(2030, 5, 1));
+        assert!(matches!(rows[0].completed, Completion::Done(Some(_))));
+        assert_eq!(rows[1].completed, Completion::Cancelled);
+
+        let storage = InMemoryStorage::default();
+        assert_eq!(
+            apply(&storage, rows.clone(), Path::new("tasks.csv")).unwrap(),
+            2
+        );
+        // Running the same import again adds nothing
+        assert_eq!(apply(&storage, rows, Path::new("tasks.csv")).unwrap(), 0);
+        let (tasks, projects, _) = storage.load_all().unwrap();
+        assert_eq!(projects[0].name, "Home, sweet");
+        assert_eq!(tasks[0].project_id, Some(projects[0].uuid));
+        assert!(tasks[0].completed && !tasks[0].is_cancelled());
+        assert!(tasks[1].is_cancelled());
+    }
+
+    #[test]
+    fn test_parse_spreadsheet_columns() {
+        let csv =
+            "Notes,Text,Priority,Tags\nignored,Call mom,h,\"family, phone\"\n,Water plants,,\n";
+        let rows = parse(csv).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].priority, Priority::High);
+        assert_eq!(rows[0].tags, vec!["family", "phone"]);
+        assert_eq!(rows[1].priority, Priority::Medium);
+        assert_eq!(rows[1].completed, Completion::Pending);
+
+        assert!(parse("title\nx\n").is_err());
+        let err = parse("text,due\nA,tomorrow\n").unwrap_err();
+        assert!(err.to_string().contains("row 2"));
+    }
+
+    #[test]
+    fn test_parse_lenient_collects_invalid_rows() {
+        let csv = "id,text,priority\n7,Call mom,h\n8,Bad,urgent\n9,,m\n,Water plants,\n";
+        let (rows, errors) = parse_lenient(csv).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].key, "id:7");
+        assert!(rows[1].key.starts_with("row:"));
+        assert_eq!(errors.iter().map(|e| e.row).collect::<Vec<_>>(), vec![3, 4]);
+        assert!(errors[0].message.contains("urgent"));
+    }
+
+    #[test]
+    fn test_apply_resumes_after_partial_import() {
+        let csv = "id,text\n1,First\n2,Second\n3,Third\n";
+        let rows = parse(csv).unwrap();
+        let storage = InMemoryStorage::default();
+
+        // An earlier run got through the first row only
+        assert_eq!(
+            apply(&storage, rows[..1].to_vec(), Path::new("a.csv")).unwrap(),
+            1
+        );
+        assert_eq!(apply(&storage, rows, Path::new("a.csv")).unwrap(), 2);
+        let texts: Vec<_> = storage
+            .load()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(texts, ["First", "Second", "Third"]);
+    }
+}
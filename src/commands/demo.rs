@@ -0,0 +1,234 @@
+//! Handler for `todo demo` and the global `--demo` flag.
+//!
+//! The demo workspace is a separate SQLite database under the system temp
+//! directory (`<tmp>/rustodo-demo/rustodo.db`). `todo demo` recreates it with
+//! sample data; any command run with `--demo` reads and writes that database
+//! instead of the real one, which is never touched.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::{Duration, Local, NaiveDate};
+use colored::Colorize;
+
+use crate::models::{
+    Difficulty, Note, NoteFormat, Priority, Project, Recurrence, Resource, ResourceType, Task,
+    TaskSource,
+};
+use crate::storage::{EntityType, EventType, SqliteStorage, Storage};
+
+/// Location of the demo database.
+pub fn demo_db_path() -> PathBuf {
+    std::env::temp_dir().join("rustodo-demo").join("rustodo.db")
+}
+
+/// Opens the demo workspace, seeding it first if it does not exist yet.
+pub fn open_storage() -> Result<SqliteStorage> {
+    let path = demo_db_path();
+    let fresh = !path.exists();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).context("Failed to create demo directory")?;
+    }
+    let storage = SqliteStorage::open_at(path)?;
+    if fresh {
+        seed(&storage)?;
+    }
+    Ok(storage)
+}
+
+/// `todo demo` — recreates the demo workspace from scratch.
+pub fn execute() -> Result<()> {
+    let path = demo_db_path();
+    if let Some(dir) = path.parent()
+        && dir.exists()
+    {
+        std::fs::remove_dir_all(dir).context("Failed to reset demo directory")?;
+    }
+    let storage = open_storage()?;
+    let tasks = storage.load()?.len();
+    let projects = storage.load_projects()?.len();
+
+    println!(
+        "\n{} Demo workspace ready: {} tasks, {} projects",
+        "✓".green(),
+        tasks,
+        projects
+    );
+    println!(
+        "  {} {}\n",
+        "·".dimmed(),
+        path.display().to_string().dimmed()
+    );
+    println!(
+        "  Add {} to any command to use it, for example:\n",
+        "--demo".cyan()
+    );
+    for example in [
+        "todo --demo list",
+        "todo --demo next",
+        "todo --demo deps 4",
+        "todo --demo project list",
+        "todo --demo stats show",
+        "todo --demo",
+    ] {
+        println!("    {}", example.bright_white());
+    }
+    println!(
+        "\n  Your real task list is not affected. Run {} again to reset.\n",
+        "todo demo".cyan()
+    );
+    Ok(())
+}
+
+/// Fills `storage` with a small, realistic data set exercising most
+/// features: projects, tags, dependencies, recurrence, overdue and completed
+/// tasks, notes, and resources.
+pub fn seed(storage: &impl Storage) -> Result<()> {
+    let today = Local::now().date_naive();
+    let day = |offset: i64| -> Option<NaiveDate> { Some(today + Duration::days(offset)) };
+
+    let mut website = Project::new("Website Redesign".into());
+    website.difficulty = Difficulty::Hard;
+    website.tech = vec!["Rust".into(), "HTMX".into()];
+    website.due_date = day(21);
+    let mut home = Project::new("Home".into());
+    home.difficulty = Difficulty::Easy;
+    let projects = vec![website.clone(), home.clone()];
+
+    let task = |text: &str, p: Priority, tags: &[&str], project: Option<&Project>, due| {
+        let mut task = Task::new(
+            text.into(),
+            p,
+            tags.iter().map(|t| t.to_string()).collect(),
+            project.map(|p| p.uuid),
+            due,
+            None,
+        );
+        task.source = Some(TaskSource::Manual);
+        task
+    };
+
+    let mut wireframes = task(
+        "Sketch wireframes",
+        Priority::High,
+        &["design"],
+        Some(&website),
+        day(-2),
+    );
+    wireframes.mark_done();
+    let mut mockups = task(
+        "Review mockups with team",
+        Priority::High,
+        &["design", "meeting"],
+        Some(&website),
+        day(-1),
+    );
+    let mut landing = task(
+        "Build landing page",
+        Priority::Medium,
+        &["frontend"],
+        Some(&website),
+        day(5),
+    );
+    let mut deploy = task(
+        "Deploy to staging",
+        Priority::Medium,
+        &["ops"],
+        Some(&website),
+        day(10),
+    );
+    let mut standup = task("Team standup", Priority::Low, &["meeting"], None, day(1));
+    standup.recurrence = Some(Recurrence::Daily);
+    let mut rent = task(
+        "Pay rent",
+        Priority::High,
+        &["finance"],
+        Some(&home),
+        day(3),
+    );
+    rent.recurrence = Some(Recurrence::Monthly);
+    let mut review = task(
+        "Weekly review",
+        Priority::Medium,
+        &["planning"],
+        None,
+        day(4),
+    );
+    review.recurrence = Some(Recurrence::Weekly);
+    let plants = task("Water the plants", Priority::Low, &[], Some(&home), day(-3));
+    let mut groceries = task(
+        "Buy groceries",
+        Priority::Medium,
+        &["errands"],
+        Some(&home),
+        None,
+    );
+    groceries.mark_done();
+    let book = task(
+        "Read \"The Rust Programming Language\" ch. 8",
+        Priority::Low,
+        &["learning"],
+        None,
+        None,
+    );
+
+    mockups.depends_on = vec![wireframes.uuid];
+    landing.depends_on = vec![mockups.uuid];
+    deploy.depends_on = vec![landing.uuid];
+
+    let tasks = vec![
+        wireframes, mockups, landing, deploy, standup, rent, review, plants, groceries, book,
+    ];
+
+    let mut guide = Resource::new("HTMX documentation".into());
+    guide.resource_type = Some(ResourceType::Docs);
+    guide.url = Some("https://htmx.org/docs/".into());
+    guide.tags = vec!["frontend".into()];
+
+    let mut brief =
+        Note::new("# Goals\n\n- Faster first paint\n- Accessible navigation\n- Dark mode".into());
+    brief.title = Some("Redesign brief".into());
+    brief.format = NoteFormat::Markdown;
+    brief.project_id = Some(website.uuid);
+    brief.resource_ids = vec![guide.uuid];
+    let mut landlord = Note::new("Transfer before the 5th; reference is the flat number.".into());
+    landlord.title = Some("Rent details".into());
+    landlord.task_id = Some(tasks[5].uuid);
+
+    storage.save_projects(&projects)?;
+    storage.save_resources(std::slice::from_ref(&guide))?;
+    storage.save(&tasks)?;
+    storage.save_notes(&[brief, landlord])?;
+
+    for t in &tasks {
+        storage.record_event(EntityType::Task, t.uuid, EventType::Created)?;
+        if t.completed {
+            storage.record_event(EntityType::Task, t.uuid, EventType::Completed)?;
+        }
+    }
+    for p in &projects {
+        storage.record_event(EntityType::Project, p.uuid, EventType::Created)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+
+    #[test]
+    fn test_seed_covers_main_features() {
+        let storage = InMemoryStorage::default();
+        seed(&storage).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert_eq!(storage.load_projects().unwrap().len(), 2);
+        assert!(tasks.iter().any(|t| t.is_overdue()));
+        assert!(tasks.iter().any(|t| t.completed));
+        assert!(tasks.iter().any(|t| t.is_recurring()));
+        assert!(tasks.iter().any(|t| t.is_blocked(&tasks)));
+        assert!(tasks.iter().all(|t| t.dangling_deps(&tasks).is_empty()));
+        assert_eq!(storage.load_notes().unwrap().len(), 2);
+    }
+}
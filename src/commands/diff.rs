@@ -0,0 +1,64 @@
+//! Handler for `todo diff <FILE_A> [FILE_B]`.
+//!
+//! Compares two export files (see `todo export`), or one export file against
+//! the current database when `FILE_B` is omitted, and prints the tasks that
+//! were added, removed, or changed — with field-level detail for changes.
+//! The comparison itself lives in [`services::task_diff`].
+//!
+//! [`services::task_diff`]: crate::services::task_diff
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::commands::portability::read_export_tasks;
+use crate::render::formatting::first_line;
+use crate::services::task_diff::diff_tasks;
+use crate::storage::Storage;
+
+pub fn execute(storage: &impl Storage, before: PathBuf, after: Option<PathBuf>) -> Result<()> {
+    let old = read_export_tasks(&before)?;
+    let (new, after_label) = match after {
+        Some(path) => (read_export_tasks(&path)?, path.display().to_string()),
+        None => (storage.load()?, "current database".to_string()),
+    };
+
+    println!(
+        "\n{} {} → {}\n",
+        "Diff".bright_white().bold(),
+        before.display().to_string().cyan(),
+        after_label.cyan()
+    );
+
+    let diff = diff_tasks(&old, &new);
+    if diff.is_empty() {
+        println!("{}\n", "  No differences.".dimmed());
+        return Ok(());
+    }
+
+    for task in &diff.added {
+        println!("  {} {}", "+".green(), first_line(&task.text).green());
+    }
+    for task in &diff.removed {
+        println!("  {} {}", "-".red(), first_line(&task.text).red());
+    }
+    for change in &diff.changed {
+        println!(
+            "  {} {}",
+            "~".yellow(),
+            first_line(&change.after.text).bright_white()
+        );
+        for field in &change.fields {
+            println!(
+                "      {:<14} {} → {}",
+                field.field.dimmed(),
+                field.before.red(),
+                field.after.green()
+            );
+        }
+    }
+
+    println!("\n  {}\n", diff.summary().dimmed());
+    Ok(())
+}
@@ -0,0 +1,180 @@
+//! Handler for `todo digest [--period day|week|month] [--send]`.
+//!
+//! Compiles a shareable Markdown summary of the period ending today (see
+//! [`render::markdown::digest`](crate::render::markdown::digest)):
+//!
+//! | Section   | Tasks                                              |
+//! |-----------|----------------------------------------------------|
+//! | Completed | done during the period                             |
+//! | Added     | created during the period                          |
+//! | Overdue   | pending with a due date before today              |
+//! | Upcoming  | pending and due within the next period from today  |
+//!
+//! With `--send` the document is piped to the `sendmail` command from the
+//! `[digest]` config table instead of being printed:
+//!
+//! ```toml
+//! [digest]
+//! sendmail = "/usr/sbin/sendmail -t"
+//! to       = "team@example.com"
+//! subject  = "Weekly digest"   # optional
+//! ```
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result, bail};
+use chrono::{Duration, Local, NaiveDate};
+use colored::Colorize;
+
+use crate::cli::{DigestPeriod, OutputFormat};
+use crate::config::{Config, DigestConfig};
+use crate::models::Task;
+use crate::render::markdown::{self, DigestSection};
+use crate::storage::Storage;
+
+/// `todo digest`
+pub fn execute(
+    storage: &impl Storage,
+    period: DigestPeriod,
+    send: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    if matches!(format, OutputFormat::Json | OutputFormat::Plain) {
+        bail!("todo digest only supports --format markdown");
+    }
+    let config = if send {
+        match Config::load()?.digest {
+            Some(cfg) if !cfg.sendmail.trim().is_empty() && !cfg.to.trim().is_empty() => Some(cfg),
+            _ => bail!("--send needs `sendmail` and `to` in the [digest] section of config.toml"),
+        }
+    } else {
+        None
+    };
+
+    let visible: Vec<Task> = storage
+        .load()?
+        .into_iter()
+        .filter(|t| !t.is_deleted())
+        .collect();
+    let today = Local::now().date_naive();
+    let days = period.days();
+    let from = today - Duration::days(days - 1);
+    let doc = markdown::digest(&sections(&visible, today, days), &visible, from, today);
+
+    match config {
+        Some(cfg) => {
+            send_mail(&cfg, period, &doc)?;
+            println!("{} Digest sent to {}", "✓".green(), cfg.to.cyan());
+        }
+        None => print!("{}", doc),
+    }
+    Ok(())
+}
+
+/// Groups `visible` tasks into digest sections for the `days` ending `today`.
+pub fn sections(visible: &[Task], today: NaiveDate, days: i64) -> Vec<DigestSection<'_>> {
+    let from = today - Duration::days(days - 1);
+    let in_period = |on: NaiveDate| on >= from && on <= today;
+    let rows = || visible.iter().enumerate().map(|(i, t)| (i + 1, t));
+    let pending_due = |keep: &dyn Fn(NaiveDate) -> bool| {
+        let mut tasks: Vec<_> = rows()
+            .filter(|(_, t)| !t.completed && t.due_date.is_some_and(keep))
+            .collect();
+        tasks.sort_by_key(|(id, t)| (t.due_date, *id));
+        tasks
+    };
+
+    vec![
+        DigestSection {
+            heading: "Completed",
+            tasks: rows()
+                .filter(|(_, t)| !t.is_cancelled() && t.completed_on().is_some_and(in_period))
+                .collect(),
+        },
+        DigestSection {
+            heading: "Added",
+            tasks: rows().filter(|(_, t)| in_period(t.created_on())).collect(),
+        },
+        DigestSection {
+            heading: "Overdue",
+            tasks: pending_due(&|due| due < today),
+        },
+        DigestSection {
+            heading: "Upcoming",
+            tasks: pending_due(&|due| due >= today && due < today + Duration::days(days)),
+        },
+    ]
+}
+
+/// Pipes `body` as a plain-text email to the configured sendmail command.
+fn send_mail(cfg: &DigestConfig, period: DigestPeriod, body: &str) -> Result<()> {
+    let mut words = cfg.sendmail.split_whitespace();
+    let program = words.next().unwrap_or_default();
+    let subject = cfg
+        .subject
+        .clone()
+        .unwrap_or_else(|| format!("{} digest", period.label()));
+
+    let mut child = Command::new(program)
+        .args(words)
+        .stdin(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run '{}'", cfg.sendmail))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        write!(
+            stdin,
+            "To: {}\nSubject: {}\nContent-Type: text/markdown; charset=utf-8\n\n{}",
+            cfg.to, subject, body
+        )?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("'{}' exited with {}", cfg.sendmail, status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, local_midnight};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2030, 6, day).unwrap()
+    }
+
+    fn make_task(text: &str, due: Option<NaiveDate>) -> Task {
+        let mut task = Task::new(text.into(), Priority::Medium, vec![], None, due, None);
+        task.created_at = local_midnight(date(1));
+        task
+    }
+
+    #[test]
+    fn test_sections_for_week() {
+        let today = date(14);
+        let mut shipped = make_task("Shipped", None);
+        shipped.mark_done();
+        shipped.completed_at = Some(local_midnight(date(10)));
+        let mut old = shipped.clone();
+        old.completed_at = Some(local_midnight(date(2)));
+        let mut fresh = make_task("Fresh", None);
+        fresh.created_at = local_midnight(today);
+        let late = make_task("Late", Some(date(13)));
+        let soon = make_task("Soon", Some(date(20)));
+        let far = make_task("Far", Some(date(30)));
+        let tasks = vec![shipped, old, fresh, late, soon, far];
+
+        let sections = sections(&tasks, today, 7);
+        let ids: Vec<Vec<usize>> = sections
+            .iter()
+            .map(|s| s.tasks.iter().map(|(id, _)| *id).collect())
+            .collect();
+        assert_eq!(ids, vec![vec![1], vec![3], vec![4], vec![5]]);
+
+        let doc = markdown::digest(&sections, &tasks, date(8), today);
+        assert!(doc.starts_with("# Digest — 2030-06-08 to 2030-06-14\n"));
+        assert!(doc.contains("## Completed (1)\n\n- [x] Shipped — done 2030-06-10"));
+        assert!(doc.contains("## Upcoming (1)\n\n- [ ] Soon — due 2030-06-20"));
+    }
+}
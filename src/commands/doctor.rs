@@ -0,0 +1,263 @@
+//! Handler for `todo doctor`.
+//!
+//! Checks the task list for inconsistencies and, with `--fix`, repairs them.
+//!
+//! | Check                | Repair                                   |
+//! |----------------------|------------------------------------------|
+//! | Dangling dependency  | Remove UUIDs of missing or deleted tasks |
+//! | Shared UUID          | Mark the flagged tasks as reviewed       |
+//!
+//! Shared UUIDs are healed when the data is loaded (see
+//! [`dedupe`](crate::storage::dedupe)); doctor lists the tasks involved so
+//! they can be checked for unwanted copies before being marked reviewed.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::models::Task;
+use crate::storage::{EntityType, EventType, Storage};
+
+/// A visible task whose `depends_on` references missing or deleted tasks.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DanglingDeps {
+    /// 1-based visible ID.
+    pub id: usize,
+    pub text: String,
+    pub count: usize,
+}
+
+/// A visible task that shared its UUID with another one before healing.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SharedUuid {
+    /// 1-based visible ID.
+    pub id: usize,
+    pub text: String,
+    /// Visible ID of the other task, if it is still visible.
+    pub other_id: Option<usize>,
+}
+
+pub fn execute(storage: &impl Storage, fix: bool) -> Result<()> {
+    let mut tasks = storage.load()?;
+    let dangling = find_dangling_deps(&tasks);
+    let shared = find_shared_uuids(&tasks);
+
+    println!("\n{} Checking task list...\n", "".blue());
+
+    if dangling.is_empty() && shared.is_empty() {
+        println!("  {} No problems found\n", "✓".green());
+        return Ok(());
+    }
+
+    for d in &dangling {
+        println!(
+            "  {} #{} {} — {} dangling dependenc{}",
+            "⚠".yellow(),
+            d.id,
+            d.text.bright_white(),
+            d.count,
+            if d.count == 1 { "y" } else { "ies" }
+        );
+    }
+    for s in &shared {
+        let other = s
+            .other_id
+            .map_or_else(|| "a deleted task".to_string(), |id| format!("#{}", id));
+        println!(
+            "  {} #{} {} — shared a UUID with {}; check for an unwanted copy",
+            "⚠".yellow(),
+            s.id,
+            s.text.bright_white(),
+            other
+        );
+    }
+    println!();
+
+    if !fix {
+        if !dangling.is_empty() {
+            println!(
+                "  Run {} to remove these references.\n",
+                "todo doctor --fix".cyan()
+            );
+        } else {
+            println!(
+                "  Run {} to mark these tasks as reviewed.\n",
+                "todo doctor --fix".cyan()
+            );
+        }
+        return Ok(());
+    }
+
+    if !dangling.is_empty() {
+        let pruned = prune_dangling_deps(storage, &mut tasks)?;
+        println!(
+            "{} Removed {} dangling dependenc{}\n",
+            "✓".green(),
+            pruned,
+            if pruned == 1 { "y" } else { "ies" }
+        );
+    }
+    if !shared.is_empty() {
+        let reviewed = clear_shared_uuid_flags(storage, &mut tasks)?;
+        println!(
+            "{} Marked {} task{} as reviewed\n",
+            "✓".green(),
+            reviewed,
+            if reviewed == 1 { "" } else { "s" }
+        );
+    }
+    Ok(())
+}
+
+/// Lists visible tasks with dependencies on missing or deleted tasks.
+pub fn find_dangling_deps(tasks: &[Task]) -> Vec<DanglingDeps> {
+    tasks
+        .iter()
+        .filter(|t| !t.is_deleted())
+        .enumerate()
+        .filter_map(|(i, t)| {
+            let count = t.dangling_deps(tasks).len();
+            (count > 0).then(|| DanglingDeps {
+                id: i + 1,
+                text: t.text.clone(),
+                count,
+            })
+        })
+        .collect()
+}
+
+/// Lists visible tasks flagged as having shared a UUID.
+pub fn find_shared_uuids(tasks: &[Task]) -> Vec<SharedUuid> {
+    let visible: Vec<&Task> = tasks.iter().filter(|t| !t.is_deleted()).collect();
+    visible
+        .iter()
+        .enumerate()
+        .filter_map(|(i, t)| {
+            let other = t.duplicate_of?;
+            Some(SharedUuid {
+                id: i + 1,
+                text: t.text.clone(),
+                other_id: visible.iter().position(|o| o.uuid == other).map(|p| p + 1),
+            })
+        })
+        .collect()
+}
+
+/// Clears the shared-UUID flag on every task and persists the changed
+/// tasks. Returns the number of tasks changed.
+pub fn clear_shared_uuid_flags(storage: &impl Storage, tasks: &mut [Task]) -> Result<usize> {
+    let mut cleared = 0;
+    for task in tasks.iter_mut().filter(|t| t.duplicate_of.is_some()) {
+        task.duplicate_of = None;
+        task.touch();
+        storage.upsert_task(task)?;
+        cleared += 1;
+    }
+    Ok(cleared)
+}
+
+/// Removes dangling dependency UUIDs from visible tasks and persists the
+/// changed tasks. Returns the number of references removed.
+pub fn prune_dangling_deps(storage: &impl Storage, tasks: &mut [Task]) -> Result<usize> {
+    let snapshot = tasks.to_vec();
+    let mut pruned = 0;
+
+    for task in tasks.iter_mut().filter(|t| !t.is_deleted()) {
+        let dangling = task.dangling_deps(&snapshot);
+        if dangling.is_empty() {
+            continue;
+        }
+        task.depends_on.retain(|d| !dangling.contains(d));
+        task.touch();
+        storage.upsert_task(task)?;
+        storage.record_event(EntityType::Task, task.uuid, EventType::Edited)?;
+        pruned += dangling.len();
+    }
+    Ok(pruned)
+}
+
+/// Prints a one-line warning to stderr when the task list has dangling
+/// dependencies. Called by `todo list` on the tasks it already loaded.
+pub fn warn_on_load(tasks: &[Task]) {
+    let count = find_dangling_deps(tasks).len();
+    if count > 0 {
+        eprintln!(
+            "{} {} task{} reference{} missing dependencies — run {}",
+            "⚠".yellow(),
+            count,
+            if count == 1 { "" } else { "s" },
+            if count == 1 { "s" } else { "" },
+            "todo doctor --fix".cyan()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::InMemoryStorage;
+    use uuid::Uuid;
+
+    fn make_task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    #[test]
+    fn test_find_dangling_deps_uses_visible_ids() {
+        let mut deleted = make_task("Gone");
+        deleted.soft_delete();
+        let mut task = make_task("Task");
+        task.depends_on = vec![deleted.uuid, Uuid::new_v4()];
+        let tasks = vec![deleted, make_task("Other"), task];
+
+        let found = find_dangling_deps(&tasks);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, 2);
+        assert_eq!(found[0].count, 2);
+    }
+
+    #[test]
+    fn test_fix_prunes_only_dangling_refs() {
+        let live = make_task("Live");
+        let mut task = make_task("Task");
+        task.depends_on = vec![live.uuid, Uuid::new_v4()];
+        let live_uuid = live.uuid;
+        let storage = InMemoryStorage::default();
+        storage.save(&[live, task]).unwrap();
+
+        execute(&storage, true).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks[1].depends_on, vec![live_uuid]);
+        assert!(find_dangling_deps(&tasks).is_empty());
+    }
+
+    #[test]
+    fn test_check_without_fix_leaves_tasks_untouched() {
+        let mut task = make_task("Task");
+        task.depends_on = vec![Uuid::new_v4()];
+        let storage = InMemoryStorage::default();
+        storage.save(&[task]).unwrap();
+
+        execute(&storage, false).unwrap();
+
+        assert_eq!(storage.load().unwrap()[0].depends_on.len(), 1);
+    }
+
+    #[test]
+    fn test_shared_uuid_tasks_flagged_until_fixed() {
+        let original = make_task("Original");
+        let mut copy = make_task("Copy");
+        copy.uuid = original.uuid;
+        let storage = InMemoryStorage::default();
+        storage.save(&[original, copy]).unwrap();
+
+        let shared = find_shared_uuids(&storage.load().unwrap());
+        assert_eq!(shared.len(), 2);
+        assert_eq!((shared[0].id, shared[0].other_id), (1, Some(2)));
+        assert_eq!((shared[1].id, shared[1].other_id), (2, Some(1)));
+
+        execute(&storage, true).unwrap();
+        assert!(find_shared_uuids(&storage.load().unwrap()).is_empty());
+    }
+}
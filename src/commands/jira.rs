@@ -0,0 +1,180 @@
+//! Handlers for `todo jira pull` and the Jira transition hook on `todo done`.
+//!
+//! Pulled issues become tasks keyed by [`Task::jira_key`]: pulling again
+//! updates the existing task's text, priority, and due date instead of
+//! creating a duplicate.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::Config;
+use crate::models::{Task, TaskSource};
+use crate::services::jira::{JiraClient, JiraIssue};
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::validation::resolve_visible_index;
+
+/// Outcome of applying a batch of issues to the task list.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PullSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+}
+
+/// `todo jira pull --project <KEY>` — imports issues assigned to the user.
+pub fn execute_pull(storage: &impl Storage, project: &str) -> Result<()> {
+    let cfg = Config::load().unwrap_or_default();
+    let jira = cfg.jira.unwrap_or_default();
+    let client = JiraClient::new(&jira)?;
+
+    println!(
+        "\n  Fetching issues assigned to you in {}...",
+        project.bright_white()
+    );
+    let issues = client.assigned_issues(project)?;
+
+    let summary = apply_issues(storage, &issues)?;
+    println!(
+        "{} {} created, {} updated, {} unchanged\n",
+        "✓".green(),
+        summary.created.to_string().green(),
+        summary.updated.to_string().yellow(),
+        summary.unchanged.to_string().dimmed(),
+    );
+    Ok(())
+}
+
+/// Upserts `issues` into storage, matching existing tasks by `jira_key`.
+pub fn apply_issues(storage: &impl Storage, issues: &[JiraIssue]) -> Result<PullSummary> {
+    let mut tasks = storage.load()?;
+    let mut summary = PullSummary::default();
+
+    for issue in issues {
+        let existing = tasks
+            .iter_mut()
+            .find(|t| !t.is_deleted() && t.jira_key.as_deref() == Some(issue.key.as_str()));
+
+        match existing {
+            Some(task) => {
+                let changed = task.text != issue.summary
+                    || task.priority != issue.priority
+                    || task.due_date != issue.due_date;
+                if changed {
+                    task.text = issue.summary.clone();
+                    task.priority = issue.priority;
+                    task.due_date = issue.due_date;
+                    task.touch();
+                    storage.upsert_task(task)?;
+                    storage.record_event(EntityType::Task, task.uuid, EventType::Edited)?;
+                    summary.updated += 1;
+                } else {
+                    summary.unchanged += 1;
+                }
+            }
+            None => {
+                let mut task = Task::new(
+                    issue.summary.clone(),
+                    issue.priority,
+                    vec![],
+                    None,
+                    issue.due_date,
+                    None,
+                );
+                task.jira_key = Some(issue.key.clone());
+                task.source = Some(TaskSource::Import(format!("jira {}", issue.key)));
+                storage.upsert_task(&task)?;
+                storage.record_event(EntityType::Task, task.uuid, EventType::Created)?;
+                tasks.push(task);
+                summary.created += 1;
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Transitions the Jira issue linked to task `id` after it was marked done.
+///
+/// Only runs when `transition_on_done` is configured. Failures are reported
+/// as warnings — the local completion has already been saved.
+pub fn execute_done_hook(storage: &impl Storage, id: usize) -> Result<()> {
+    let cfg = Config::load().unwrap_or_default();
+    let Some(jira) = cfg.jira else {
+        return Ok(());
+    };
+    let Some(ref transition) = jira.transition_on_done else {
+        return Ok(());
+    };
+
+    let tasks = storage.load()?;
+    let Ok(index) = resolve_visible_index(&tasks, id, |t| t.is_deleted()) else {
+        return Ok(());
+    };
+    let Some(ref key) = tasks[index].jira_key else {
+        return Ok(());
+    };
+
+    match JiraClient::new(&jira).and_then(|client| client.transition(key, transition)) {
+        Ok(()) => println!("  {} Jira {} → {}", "↳".dimmed(), key.cyan(), transition),
+        Err(e) => eprintln!(
+            "  {} Could not transition Jira issue {}: {}",
+            "⚠".yellow(),
+            key,
+            e
+        ),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::InMemoryStorage;
+
+    fn issue(key: &str, summary: &str) -> JiraIssue {
+        JiraIssue {
+            key: key.into(),
+            summary: summary.into(),
+            priority: Priority::Medium,
+            due_date: None,
+        }
+    }
+
+    #[test]
+    fn test_apply_issues_creates_linked_tasks() {
+        let storage = InMemoryStorage::default();
+        let summary = apply_issues(
+            &storage,
+            &[issue("ABC-1", "Fix login"), issue("ABC-2", "Docs")],
+        )
+        .unwrap();
+
+        assert_eq!(summary.created, 2);
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks[0].jira_key.as_deref(), Some("ABC-1"));
+        assert_eq!(tasks[1].text, "Docs");
+    }
+
+    #[test]
+    fn test_apply_issues_is_idempotent_and_updates() {
+        let storage = InMemoryStorage::default();
+        apply_issues(&storage, &[issue("ABC-1", "Fix login")]).unwrap();
+
+        let summary = apply_issues(
+            &storage,
+            &[
+                issue("ABC-1", "Fix login page"),
+                issue("ABC-1", "Fix login page"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(summary.created, 0);
+        assert_eq!(summary.updated, 1);
+        assert_eq!(summary.unchanged, 1);
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].text, "Fix login page");
+    }
+}
@@ -0,0 +1,222 @@
+//! Handler for `todo import --from eml|maildir <PATH>`.
+//!
+//! Each message becomes one task: the subject is the task text (with a
+//! trailing `by <date>` / `due <date>` hint turned into the due date) and the
+//! body, when present, is attached as a note linked to the task.
+//!
+//! Messages whose subject matches an existing visible task are skipped so
+//! re-importing the same maildir is harmless.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, bail};
+use chrono::{Local, NaiveDate};
+use colored::Colorize;
+
+use crate::models::{Note, Priority, Task, TaskSource};
+use crate::services::mail::{self, MailMessage};
+use crate::storage::{EntityType, EventType, Storage};
+
+/// Where mail is read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MailSource {
+    /// A single `.eml` file, or a directory of `.eml` files.
+    Eml,
+    /// A maildir folder (`new/` and `cur/`).
+    Maildir,
+}
+
+impl MailSource {
+    /// Label recorded as the imported tasks' source.
+    pub fn label(&self) -> &'static str {
+        match self {
+            MailSource::Eml => "eml",
+            MailSource::Maildir => "maildir",
+        }
+    }
+}
+
+/// A task about to be created from a message.
+#[derive(Debug)]
+pub struct MailTask {
+    pub text: String,
+    pub due: Option<NaiveDate>,
+    pub body: String,
+}
+
+pub fn execute(storage: &impl Storage, path: PathBuf, source: MailSource, yes: bool) -> Result<()> {
+    if !path.exists() {
+        bail!("Path not found: {}", path.display());
+    }
+
+    let files = collect_files(&path, source)?;
+    let messages = files
+        .iter()
+        .map(|f| MailMessage::read(f))
+        .collect::<Result<Vec<_>>>()?;
+
+    let existing = storage.load()?;
+    let (pending, skipped) = plan(&messages, &existing);
+
+    if pending.is_empty() {
+        println!(
+            "\n  {} No new tasks found in {} message{} ({} skipped).\n",
+            "".blue(),
+            messages.len(),
+            if messages.len() == 1 { "" } else { "s" },
+            skipped
+        );
+        return Ok(());
+    }
+
+    println!(
+        "\n{} Importing from: {}\n",
+        "".blue(),
+        path.display().to_string().cyan()
+    );
+    for t in &pending {
+        let due = t
+            .due
+            .map(|d| format!(" (due {})", d.format("%Y-%m-%d")))
+            .unwrap_or_default();
+        println!("  {} {}{}", "+".green(), t.text.bright_white(), due.cyan());
+    }
+    if skipped > 0 {
+        println!(
+            "  {} {} already imported or without subject",
+            "·".dimmed(),
+            skipped
+        );
+    }
+    println!();
+
+    if !yes && !crate::utils::confirm("Create these tasks? [y/N]:")? {
+        println!("{}", "Import cancelled.".dimmed());
+        return Ok(());
+    }
+
+    let count = apply(storage, &pending, source)?;
+    println!(
+        "{} Imported {} task{}",
+        "✓".green(),
+        count,
+        if count == 1 { "" } else { "s" }
+    );
+    Ok(())
+}
+
+fn collect_files(path: &Path, source: MailSource) -> Result<Vec<PathBuf>> {
+    match source {
+        MailSource::Maildir => mail::maildir_files(path),
+        MailSource::Eml if path.is_dir() => Ok(mail::maildir_files(path)?
+            .into_iter()
+            .filter(|p| p.extension().is_some_and(|e| e.eq_ignore_ascii_case("eml")))
+            .collect()),
+        MailSource::Eml => Ok(vec![path.to_path_buf()]),
+    }
+}
+
+/// Turns messages into tasks to create, returning `(tasks, skipped)`.
+///
+/// Past due hints are dropped rather than rejected — an old forwarded email
+/// is still worth capturing.
+pub fn plan(messages: &[MailMessage], existing: &[Task]) -> (Vec<MailTask>, usize) {
+    let today = Local::now().date_naive();
+    let mut pending: Vec<MailTask> = Vec::new();
+    let mut skipped = 0;
+
+    for msg in messages {
+        let (text, due) = msg.split_due_hint();
+        let duplicate = existing
+            .iter()
+            .filter(|t| !t.is_deleted())
+            .map(|t| t.text.as_str())
+            .chain(pending.iter().map(|p| p.text.as_str()))
+            .any(|t| t.eq_ignore_ascii_case(&text));
+
+        if text.trim().is_empty() || duplicate {
+            skipped += 1;
+            continue;
+        }
+        pending.push(MailTask {
+            text,
+            due: due.filter(|d| *d >= today),
+            body: msg.body.clone(),
+        });
+    }
+    (pending, skipped)
+}
+
+/// Persists planned tasks and their body notes. Returns the number created.
+pub fn apply(storage: &impl Storage, pending: &[MailTask], source: MailSource) -> Result<usize> {
+    for item in pending {
+        let mut task = Task::new(
+            item.text.clone(),
+            Priority::Medium,
+            vec![],
+            None,
+            item.due,
+            None,
+        );
+        task.source = Some(TaskSource::Import(source.label().to_string()));
+        storage.upsert_task(&task)?;
+        storage.record_event(EntityType::Task, task.uuid, EventType::Created)?;
+
+        if !item.body.is_empty() {
+            let mut note = Note::new(item.body.clone());
+            note.task_id = Some(task.uuid);
+            storage.upsert_note(&note)?;
+            storage.record_event(EntityType::Note, note.uuid, EventType::Created)?;
+        }
+    }
+    Ok(pending.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+
+    fn msg(subject: &str, body: &str) -> MailMessage {
+        MailMessage {
+            subject: subject.into(),
+            body: body.into(),
+        }
+    }
+
+    #[test]
+    fn test_plan_skips_duplicates_and_empty_subjects() {
+        let existing = vec![Task::new(
+            "Pay rent".into(),
+            Priority::Medium,
+            vec![],
+            None,
+            None,
+            None,
+        )];
+        let messages = vec![
+            msg("pay rent", ""),
+            msg("", "no subject"),
+            msg("Call bank", ""),
+            msg("Call bank", ""),
+        ];
+        let (pending, skipped) = plan(&messages, &existing);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].text, "Call bank");
+        assert_eq!(skipped, 3);
+    }
+
+    #[test]
+    fn test_apply_links_body_note() {
+        let storage = InMemoryStorage::default();
+        let (pending, _) = plan(&[msg("Review PR", "See link"), msg("Lunch", "")], &[]);
+        apply(&storage, &pending, MailSource::Eml).unwrap();
+
+        let tasks = storage.load().unwrap();
+        let notes = storage.load_notes().unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].task_id, Some(tasks[0].uuid));
+        assert_eq!(notes[0].body, "See link");
+    }
+}
@@ -3,15 +3,19 @@
 //! | Submodule          | Subcommand                      |
 //! |--------------------|---------------------------------|
 //! | [`task::add`]      | `todo add`                      |
+//! | [`task::blame`]    | `todo blame <ID>`               |
+//! | [`task::cancel`]   | `todo cancel <ID>`              |
 //! | [`task::clear`]    | `todo clear`                    |
 //! | [`task::clear_recur`] | `todo norecur <ID>`          |
-//! | [`task::deps`]     | `todo deps <ID>`                |
+//! | [`task::deps`]     | `todo deps <ID>` / `deps why`  |
 //! | [`task::done`]     | `todo done <ID>`                |
 //! | [`task::edit`]     | `todo edit <ID>`                |
 //! | [`task::info`]     | `todo info`                     |
 //! | [`task::list`]     | `todo list`                     |
-//! | [`task::recur`]    | `todo recur <ID>`               |
+//! | [`task::recur`]    | `todo recur <ID>` / `recur history` |
 //! | [`task::remove`]   | `todo remove <ID>`              |
+//! | [`task::similar`]  | `todo similar <ID>`             |
+//! | [`task::track`]    | `todo start <ID>` / `todo stop` |
 //! | [`task::undone`]   | `todo undone <ID>`              |
 //! | [`note::add`]      | `todo note add`                 |
 //! | [`note::clear`]    | `todo note clear`               |
@@ -33,15 +37,40 @@
 //! | [`resource::list`] | `todo resource list`            |
 //! | [`resource::remove`]| `todo resource remove <ID>`    |
 //! | [`resource::show`] | `todo resource show <ID>`       |
+//! | [`agenda`]         | `todo agenda`                   |
+//! | [`archive`]        | `todo archive --project <NAME>` |
 //! | [`calendar`]       | `todo calendar [MONTH] [YEAR]`  |
+//! | [`capture`]        | `todo capture [--port <PORT>]`  |
+//! | [`completions`]    | `todo completions [--install]`  |
 //! | [`context`]        | `todo context <ID>`             |
+//! | [`csv_io`]         | `todo export --to csv` / `todo import --from csv` |
+//! | [`demo`]           | `todo demo`                     |
+//! | [`diff`]           | `todo diff <A> [B]`             |
+//! | [`digest`]         | `todo digest [--period <P>]`    |
+//! | [`doctor`]         | `todo doctor [--fix]`           |
 //! | [`holidays_cmd`]   | `todo holidays`                 |
+//! | [`jira`]           | `todo jira pull`                |
+//! | [`mail_import`]    | `todo import --from eml\|maildir` |
 //! | [`next`]           | `todo next`                     |
+//! | [`open_data`]      | `todo open-data`                |
+//! | [`pick`]           | `todo pick`                     |
+//! | [`plugin`]         | `todo <name>` → `todo-<name>`   |
+//! | [`pomodoro`]       | `todo pomodoro <ID>`            |
+//! | [`prompt`]         | `todo prompt`                   |
+//! | [`publish`]        | `todo publish [FILE]`           |
 //! | [`purge`]          | `todo purge`                    |
+//! | [`remind`]         | `todo remind [--daemon]`        |
+//! | [`report`]         | `todo report --format markdown` |
 //! | [`search`]         | `todo search <QUERY>`           |
 //! | [`stats`]          | `todo stats`                    |
+//! | [`stats_compare`]  | `todo stats show --compare`     |
+//! | [`stats_goals`]    | `todo stats show --goal`        |
 //! | [`stats_history`]  | `todo stats history`            |
 //! | [`tags`]           | `todo tags`                     |
+//! | [`time_report`]    | `todo time report`              |
+//! | [`todoist`]        | `todo todoist pull\|push`       |
+//! | [`triage`]         | `todo triage`                   |
+//! | [`undo`]           | `todo undo` / `todo redo`       |
 //! | [`backup`]         | `todo backup`                   |
 //! | [`portability`]    | `todo portability`              |
 
@@ -50,14 +79,40 @@ pub mod project;
 pub mod resource;
 pub mod task;
 
+pub mod agenda;
+pub mod archive;
 pub mod backup;
 pub mod calendar;
+pub mod capture;
+pub mod completions;
 pub mod context;
+pub mod csv_io;
+pub mod demo;
+pub mod diff;
+pub mod digest;
+pub mod doctor;
 pub mod holidays_cmd;
+pub mod jira;
+pub mod mail_import;
 pub mod next;
+pub mod open_data;
+pub mod pick;
+pub mod plugin;
+pub mod pomodoro;
 pub mod portability;
+pub mod prompt;
+pub mod publish;
 pub mod purge;
+pub mod remind;
+pub mod report;
 pub mod search;
 pub mod stats;
+pub mod stats_compare;
+pub mod stats_goals;
 pub mod stats_history;
 pub mod tags;
+pub mod time_report;
+#[cfg(feature = "todoist")]
+pub mod todoist;
+pub mod triage;
+pub mod undo;
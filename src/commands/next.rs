@@ -1,7 +1,8 @@
 //! Handler for `todo next`.
 //!
 //! Shows the top N pending tasks sorted by urgency score, excluding tasks
-//! blocked by unresolved dependencies — only "ready to work" tasks.
+//! blocked by unresolved dependencies or a future `wait_until` date — only
+//! "ready to work" tasks.
 //!
 //! Inspired by Taskwarrior's `task next` command.
 
@@ -38,7 +39,7 @@ pub fn execute(storage: &impl Storage, limit: Option<usize>) -> Result<()> {
             println!(
                 "\n{}\n",
                 format!(
-                    "All {} pending tasks are blocked by dependencies.",
+                    "All {} pending tasks are blocked by dependencies or waiting.",
                     blocked_count
                 )
                 .yellow()
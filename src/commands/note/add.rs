@@ -7,6 +7,7 @@ use crate::cli::NoteAddArgs;
 use crate::models::{Note, Project};
 use crate::services::tag_service::collect_all_tag_names;
 use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::stdin_arg;
 use crate::utils::tag_normalizer::normalize_tags;
 use crate::utils::validation::resolve_visible;
 
@@ -15,7 +16,7 @@ pub fn execute(storage: &impl Storage, args: NoteAddArgs) -> Result<()> {
     let resources = storage.load_resources()?;
 
     let (body, is_markdown) = match (args.body, args.editor, args.file) {
-        (Some(text), false, None) => (text, false),
+        (Some(text), false, None) => (stdin_arg::resolve(text)?, false),
         (None, true, None) => {
             let content = edit::edit_with_builder("", edit::Builder::new().suffix(".md"))?;
             let trimmed = content.trim().to_string();
@@ -9,6 +9,7 @@ use colored::Colorize;
 use crate::cli::NoteEditArgs;
 use crate::models::{NoteFormat, Project};
 use crate::storage::Storage;
+use crate::utils::stdin_arg;
 use crate::utils::validation::{resolve_visible, resolve_visible_index};
 
 pub fn execute(storage: &impl Storage, args: NoteEditArgs) -> Result<()> {
@@ -33,7 +34,7 @@ pub fn execute(storage: &impl Storage, args: NoteEditArgs) -> Result<()> {
             note.format = NoteFormat::Markdown;
             changes.push("body → updated via editor".bright_white().to_string());
         }
-    } else if let Some(new_body) = args.body {
+    } else if let Some(new_body) = args.body.map(stdin_arg::resolve).transpose()? {
         if new_body.trim().is_empty() {
             return Err(anyhow::anyhow!("Note body cannot be empty"));
         }
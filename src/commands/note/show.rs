@@ -4,7 +4,7 @@ use anyhow::Result;
 use colored::Colorize;
 
 use crate::models::NoteFormat;
-use crate::render::formatting::note_preview;
+use crate::render::formatting::{note_preview, rule};
 use crate::storage::Storage;
 use crate::utils::validation::resolve_visible;
 
@@ -24,7 +24,7 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         println!("  {}", format!("Note #{}", id).bold());
     }
 
-    println!("  {}", "─".repeat(50).dimmed());
+    println!("  {}", rule(50).dimmed());
 
     // ── Body ──────────────────────────────────────────────────────────────────
     println!();
@@ -45,7 +45,7 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     println!();
 
     // ── Metadata ──────────────────────────────────────────────────────────────
-    println!("  {}", "─".repeat(50).dimmed());
+    println!("  {}", rule(50).dimmed());
 
     if let Some(ref lang) = note.language {
         println!("  {} {}", "Language:".dimmed(), lang.yellow());
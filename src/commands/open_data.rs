@@ -0,0 +1,60 @@
+//! Handler for `todo open-data`.
+//!
+//! Prints the resolved data directory and database path, then opens the
+//! directory (or, with `--file`, the database itself) using the platform's
+//! default opener: `open` on macOS, `explorer` on Windows, and `xdg-open`
+//! elsewhere. With `--print` nothing is launched.
+
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+
+use crate::storage::get_db_path;
+
+pub fn execute(file: bool, print_only: bool) -> Result<()> {
+    let db_path = get_db_path()?;
+    let data_dir = db_path.parent().unwrap_or(Path::new("."));
+
+    println!("{} {}", "Data dir:".dimmed(), data_dir.display());
+    println!("{} {}", "Database:".dimmed(), db_path.display());
+
+    if print_only {
+        return Ok(());
+    }
+
+    let target = if file { db_path.as_path() } else { data_dir };
+    launch(target).map_err(|e| anyhow::anyhow!("{} — use --print to show the path only", e))?;
+
+    println!(
+        "{} Opened {}",
+        "✓".green(),
+        target.display().to_string().cyan()
+    );
+    Ok(())
+}
+
+/// Opens `target` with the default application, without waiting for it.
+pub(crate) fn launch(target: &Path) -> Result<()> {
+    let opener = opener();
+    Command::new(opener)
+        .arg(target)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to launch '{}'", opener))?;
+    Ok(())
+}
+
+/// The command that opens a path with the user's default application.
+fn opener() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(windows) {
+        "explorer"
+    } else {
+        "xdg-open"
+    }
+}
@@ -0,0 +1,206 @@
+//! Handler for `todo pick`.
+//!
+//! Picks a pending, unblocked task at random, weighted by urgency so that
+//! pressing work comes up more often without starving the rest. With
+//! `--count N`, draws N distinct tasks and asks which one to take;
+//! `--max-estimate` keeps only tasks that fit in the time at hand.
+
+use anyhow::Result;
+use colored::Colorize;
+use uuid::Uuid;
+
+use crate::error::TodoError;
+use crate::models::{Estimate, Task};
+use crate::render::formatting::{due_relative_text, priority_label};
+use crate::storage::Storage;
+use crate::utils::confirm::prompt;
+use crate::utils::suggest;
+use crate::utils::tag_normalizer::has_tag;
+
+pub fn execute(
+    storage: &impl Storage,
+    exclude_tags: &[String],
+    project: Option<&str>,
+    max_estimate: Option<Estimate>,
+    count: usize,
+) -> Result<()> {
+    let (all_tasks, projects, _) = storage.load_all()?;
+    let visible: Vec<&Task> = all_tasks.iter().filter(|t| !t.is_deleted()).collect();
+
+    let project_uuid = match project {
+        Some(name) => match suggest::resolve_project_filter(&projects, name)? {
+            Some(uuid) => Some(uuid),
+            None => return Err(TodoError::ProjectNotFound(name.to_owned()).into()),
+        },
+        None => None,
+    };
+
+    let candidates: Vec<(usize, &Task)> = visible
+        .iter()
+        .enumerate()
+        .map(|(i, t)| (i + 1, *t))
+        .filter(|(_, t)| !t.completed && !t.is_blocked(&all_tasks))
+        .filter(|(_, t)| !exclude_tags.iter().any(|tag| has_tag(&t.tags, tag)))
+        .filter(|(_, t)| project_uuid.is_none() || t.project_id == project_uuid)
+        .filter(|(_, t)| fits_estimate(t, max_estimate))
+        .collect();
+
+    if candidates.is_empty() {
+        println!("{}", "No pending tasks match.".dimmed());
+        return Ok(());
+    }
+
+    let weights: Vec<f64> = candidates
+        .iter()
+        .map(|(_, t)| weight(t.urgency_score(&all_tasks)))
+        .collect();
+    let picked = weighted_sample(&weights, count.max(1), random_unit);
+
+    if let [only] = picked[..] {
+        let (id, task) = candidates[only];
+        println!("\n{} {}\n", "🎲".yellow(), describe(id, task));
+        return Ok(());
+    }
+
+    println!();
+    for (n, &i) in picked.iter().enumerate() {
+        let (id, task) = candidates[i];
+        println!("  {}  {}", format!("{})", n + 1).cyan(), describe(id, task));
+    }
+    println!();
+
+    let answer = prompt(&format!("Pick one [1-{}]:", picked.len()))?;
+    let Some(&i) = answer
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_sub(1))
+        .and_then(|n| picked.get(n))
+    else {
+        println!("{}", "Nothing picked.".dimmed());
+        return Ok(());
+    };
+    let (id, task) = candidates[i];
+    println!("\n{} {}\n", "→".green(), describe(id, task));
+    Ok(())
+}
+
+/// Whether `task` is estimated to take at most `max`. Without a limit every
+/// task fits; with one, tasks that have no estimate do not.
+fn fits_estimate(task: &Task, max: Option<Estimate>) -> bool {
+    match (max, task.estimate) {
+        (None, _) => true,
+        (Some(max), Some(estimate)) => estimate.duration() <= max.duration(),
+        (Some(_), None) => false,
+    }
+}
+
+/// Selection weight for an urgency score: always positive, so every
+/// candidate keeps some chance.
+fn weight(urgency: f32) -> f64 {
+    f64::from(urgency.max(0.0)) + 1.0
+}
+
+/// Draws up to `count` distinct indices into `weights`, each draw
+/// proportional to the remaining weights. `rand` yields values in `[0, 1)`.
+fn weighted_sample(weights: &[f64], count: usize, mut rand: impl FnMut() -> f64) -> Vec<usize> {
+    let mut remaining: Vec<usize> = (0..weights.len()).collect();
+    let mut picked = Vec::new();
+    while picked.len() < count && !remaining.is_empty() {
+        let total: f64 = remaining.iter().map(|&i| weights[i]).sum();
+        let mut target = rand() * total;
+        let mut chosen = remaining.len() - 1;
+        for (pos, &i) in remaining.iter().enumerate() {
+            if target < weights[i] {
+                chosen = pos;
+                break;
+            }
+            target -= weights[i];
+        }
+        picked.push(remaining.remove(chosen));
+    }
+    picked
+}
+
+/// A uniform value in `[0, 1)` from the OS random source behind UUID v4.
+///
+/// Uses the low 53 bits, which sit below the fixed version and variant bits.
+fn random_unit() -> f64 {
+    const BITS: u32 = f64::MANTISSA_DIGITS;
+    let bits = Uuid::new_v4().as_u128() & ((1u128 << BITS) - 1);
+    bits as f64 / (1u64 << BITS) as f64
+}
+
+fn describe(id: usize, task: &Task) -> String {
+    let mut line = format!(
+        "{} {} {}",
+        format!("#{}", id).yellow(),
+        priority_label(task.priority),
+        task.text.bright_white()
+    );
+    if let Some(due) = task.due_date {
+        line.push_str(&format!(
+            "  {}",
+            format!("due {}", due_relative_text(due)).dimmed()
+        ));
+    }
+    if !task.tags.is_empty() {
+        line.push_str(&format!("  {}", task.tags.join(", ").cyan()));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+
+    fn sequence(values: &[f64]) -> impl FnMut() -> f64 + '_ {
+        let mut iter = values.iter().copied();
+        move || iter.next().unwrap_or(0.0)
+    }
+
+    #[test]
+    fn test_weighted_sample_follows_weights() {
+        let weights = [1.0, 3.0];
+        // Total 4: [0, 1) → first, [1, 4) → second
+        assert_eq!(weighted_sample(&weights, 1, sequence(&[0.2])), vec![0]);
+        assert_eq!(weighted_sample(&weights, 1, sequence(&[0.3])), vec![1]);
+    }
+
+    #[test]
+    fn test_weighted_sample_draws_distinct_indices() {
+        let weights = [1.0, 1.0, 1.0];
+        let picked = weighted_sample(&weights, 5, sequence(&[0.0, 0.0, 0.0]));
+        assert_eq!(picked, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_fits_estimate() {
+        let task = |estimate: Option<&str>| {
+            let mut task = Task::new("t".into(), Priority::Medium, vec![], None, None, None);
+            task.estimate = estimate.map(|e| e.parse().unwrap());
+            task
+        };
+        let max = Some("30m".parse().unwrap());
+
+        assert!(fits_estimate(&task(Some("30m")), max));
+        assert!(fits_estimate(&task(Some("15m")), max));
+        assert!(!fits_estimate(&task(Some("1h")), max));
+        assert!(!fits_estimate(&task(None), max));
+        assert!(fits_estimate(&task(None), None));
+    }
+
+    #[test]
+    fn test_weight_is_positive() {
+        assert!(weight(-5.0) > 0.0);
+        assert!(weight(10.0) > weight(1.0));
+    }
+
+    #[test]
+    fn test_random_unit_in_range() {
+        for _ in 0..100 {
+            let x = random_unit();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+}
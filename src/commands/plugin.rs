@@ -0,0 +1,186 @@
+//! Plugin dispatch for unknown subcommands, like `git` and `cargo`.
+//!
+//! `todo foo a b` with no built-in `foo` runs the first `todo-foo`
+//! executable on `PATH` with arguments `a b`. The plugin sees where the data
+//! lives and which global options are active through these variables:
+//!
+//! | Variable             | Value                                            |
+//! |----------------------|--------------------------------------------------|
+//! | `RUSTODO_BIN`        | Path of the running `todo`, for calling back in  |
+//! | `RUSTODO_DATA_DIR`   | Directory holding the database                   |
+//! | `RUSTODO_DB`         | The SQLite database file                         |
+//! | `RUSTODO_CONFIG`     | `config.toml`                                    |
+//! | `RUSTODO_STORAGE`    | Configured backend spec, when not SQLite         |
+//! | `RUSTODO_FORMAT`     | `--format`: `table`, `json`, `plain`, `markdown` |
+//! | `RUSTODO_SORT`       | `default_sort` from the config, when set         |
+//! | `RUSTODO_READ_ONLY`  | `1` with `--read-only` or `--demo`               |
+//! | `RUSTODO_PLAIN`      | `1` with `--plain`                               |
+//! | `NO_COLOR`           | `1` with `--no-color` or `--plain`               |
+//!
+//! On Unix the plugin replaces the `todo` process; elsewhere `todo` waits
+//! for it and exits with its status.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+use clap::{CommandFactory, ValueEnum};
+
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::storage::get_db_path;
+use crate::utils::suggest;
+
+/// Prefix of plugin executables: `todo foo` runs `todo-foo`.
+const PREFIX: &str = "todo-";
+
+/// Runs the plugin for `args[0]` with the remaining arguments. Only returns
+/// on failure, or on platforms without `exec` once the plugin has exited.
+pub fn execute(cli: &Cli, args: &[OsString]) -> Result<i32> {
+    let Some((name, rest)) = args.split_first() else {
+        bail!("missing subcommand");
+    };
+    let name = name.to_string_lossy();
+    let Some(path) = find(&name, std::env::var_os("PATH").as_deref()) else {
+        bail!("{}", unknown_command(&name));
+    };
+
+    let mut command = Command::new(&path);
+    command.args(rest).envs(environment(cli)?);
+    run(command, &path)
+}
+
+/// The first executable named `todo-<name>` in the `PATH`-style `path` list.
+pub fn find(name: &str, path: Option<&std::ffi::OsStr>) -> Option<PathBuf> {
+    if name.is_empty() || name.contains(['/', '\\']) {
+        return None;
+    }
+    let file = format!("{}{}{}", PREFIX, name, std::env::consts::EXE_SUFFIX);
+    std::env::split_paths(path?)
+        .map(|dir| dir.join(&file))
+        .find(|candidate| is_executable(candidate))
+}
+
+/// Variables describing the current invocation, exported to the plugin.
+fn environment(cli: &Cli) -> Result<Vec<(&'static str, OsString)>> {
+    let db = if cli.demo {
+        super::demo::demo_db_path()
+    } else {
+        get_db_path()?
+    };
+    let config = Config::defaults();
+    let flag = |on: bool| on.then(|| OsString::from("1"));
+
+    let mut vars = vec![("RUSTODO_DB", Some(db.clone().into_os_string()))];
+    vars.push((
+        "RUSTODO_DATA_DIR",
+        db.parent().map(|dir| dir.as_os_str().to_owned()),
+    ));
+    vars.push(("RUSTODO_BIN", std::env::current_exe().ok().map(Into::into)));
+    vars.push(("RUSTODO_CONFIG", Config::path().ok().map(Into::into)));
+    vars.push((
+        "RUSTODO_STORAGE",
+        config
+            .storage
+            .as_deref()
+            .map(str::trim)
+            .filter(|spec| !spec.is_empty() && *spec != "sqlite")
+            .map(Into::into),
+    ));
+    vars.push((
+        "RUSTODO_FORMAT",
+        cli.format.to_possible_value().map(|v| v.get_name().into()),
+    ));
+    vars.push((
+        "RUSTODO_SORT",
+        config.default_sort.as_ref().map(|s| s.to_string().into()),
+    ));
+    vars.push(("RUSTODO_READ_ONLY", flag(cli.read_only || cli.demo)));
+    vars.push(("RUSTODO_PLAIN", flag(cli.plain)));
+    vars.push(("NO_COLOR", flag(cli.no_color || cli.plain)));
+
+    Ok(vars
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, value?)))
+        .collect())
+}
+
+/// The error for a subcommand that is neither built in nor a plugin,
+/// suggesting the closest built-in name.
+fn unknown_command(name: &str) -> String {
+    let cmd = Cli::command();
+    let names: Vec<&str> = cmd
+        .get_subcommands()
+        .flat_map(|sub| std::iter::once(sub.get_name()).chain(sub.get_all_aliases()))
+        .collect();
+    let mut msg = format!(
+        "unrecognized subcommand '{}' (no {}{} found on PATH)",
+        name, PREFIX, name
+    );
+    if let Some(suggestion) = suggest::closest(name, names) {
+        msg.push_str(&format!("\n  Did you mean 'todo {}'?", suggestion));
+    }
+    msg
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+#[cfg(unix)]
+fn run(mut command: Command, path: &Path) -> Result<i32> {
+    use std::os::unix::process::CommandExt;
+    let err = command.exec();
+    Err(err).with_context(|| format!("Failed to run {}", path.display()))
+}
+
+#[cfg(not(unix))]
+fn run(mut command: Command, path: &Path) -> Result<i32> {
+    let status = command
+        .status()
+        .with_context(|| format!("Failed to run {}", path.display()))?;
+    Ok(status.code().unwrap_or(1))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn install(dir: &Path, file: &str, mode: u32) {
+        let path = dir.join(file);
+        std::fs::write(&path, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode)).unwrap();
+    }
+
+    #[test]
+    fn test_find_uses_first_executable_on_path() {
+        let first = tempfile::TempDir::new().unwrap();
+        let second = tempfile::TempDir::new().unwrap();
+        install(first.path(), "todo-sync", 0o644);
+        install(second.path(), "todo-sync", 0o755);
+        let path = std::env::join_paths([first.path(), second.path()]).unwrap();
+
+        assert_eq!(
+            find("sync", Some(&path)),
+            Some(second.path().join("todo-sync"))
+        );
+        assert_eq!(find("missing", Some(&path)), None);
+        assert_eq!(find("../sync", Some(&path)), None);
+    }
+
+    #[test]
+    fn test_unknown_command_suggests_builtin() {
+        assert!(unknown_command("agend").contains("Did you mean 'todo agenda'?"));
+        assert!(!unknown_command("zzzzzz").contains("Did you mean"));
+    }
+}
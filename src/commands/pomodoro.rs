@@ -0,0 +1,193 @@
+//! Handler for `todo pomodoro <ID> [--work 25] [--break 5]`.
+//!
+//! Runs a work countdown on one line of the terminal, then a break
+//! countdown. The work interval is saved as a time entry on the task —
+//! marked as a full pomodoro only if it ran to the end — and those count
+//! toward the "Focus Sessions" section of `todo stats show`.
+//!
+//! `q`, Esc, or Ctrl-C ends the current countdown early; an interrupted work
+//! interval is still recorded as tracked time.
+
+use std::collections::BTreeMap;
+use std::io::{self, IsTerminal, Write};
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use colored::Colorize;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use uuid::Uuid;
+
+use crate::commands::task::track::stop_all;
+use crate::error::TodoError;
+use crate::models::{Task, TimeEntry};
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::validation::resolve_visible_index;
+
+pub fn execute(storage: &impl Storage, id: usize, work: u32, brk: u32) -> Result<()> {
+    let mut tasks = storage.load()?;
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+    if tasks[index].completed {
+        return Err(TodoError::TaskAlreadyInStatus {
+            id,
+            status: "completed".to_owned(),
+        }
+        .into());
+    }
+
+    // A running `todo start` clock would double-count the same minutes
+    let start = Utc::now();
+    let stopped = stop_all(&mut tasks, start);
+    if !stopped.is_empty() {
+        storage.save(&tasks)?;
+        for (i, _) in stopped {
+            storage.record_event(EntityType::Task, tasks[i].uuid, EventType::Edited)?;
+        }
+    }
+    let uuid = tasks[index].uuid;
+
+    println!(
+        "\n{} {} {}\n",
+        "🍅".red(),
+        format!("#{}", id).yellow(),
+        tasks[index].text.bright_white()
+    );
+
+    let finished = countdown("Focus", Duration::minutes(work.into()))?;
+    let end = Utc::now();
+    record_session(storage, uuid, start, end, finished)?;
+
+    if !finished {
+        println!("{} Pomodoro interrupted; time recorded.", "·".dimmed());
+        return Ok(());
+    }
+    print!("\x07");
+    println!("{} Pomodoro complete. Take a break.", "✓".green());
+
+    if brk > 0 && countdown("Break", Duration::minutes(brk.into()))? {
+        print!("\x07");
+        println!("{} Break over.", "✓".green());
+    }
+    Ok(())
+}
+
+/// Appends the work interval `start..end` to the task with `uuid`.
+pub fn record_session(
+    storage: &impl Storage,
+    uuid: Uuid,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    finished: bool,
+) -> Result<()> {
+    let mut tasks = storage.load()?;
+    let Some(task) = tasks.iter_mut().find(|t| t.uuid == uuid) else {
+        anyhow::bail!("task no longer exists");
+    };
+    task.time_entries.push(TimeEntry {
+        start,
+        end: Some(end),
+        pomodoro: finished,
+    });
+    task.touch();
+    storage.upsert_task(task)?;
+    storage.record_event(EntityType::Task, uuid, EventType::Edited)?;
+    Ok(())
+}
+
+/// Completed pomodoros per local day, for days on or after `since`.
+pub fn sessions_by_day(tasks: &[Task], since: NaiveDate) -> BTreeMap<NaiveDate, usize> {
+    let mut days = BTreeMap::new();
+    for entry in tasks.iter().flat_map(|t| &t.time_entries) {
+        if let Some(end) = entry.end.filter(|_| entry.pomodoro) {
+            let day = end.with_timezone(&Local).date_naive();
+            if day >= since {
+                *days.entry(day).or_insert(0) += 1;
+            }
+        }
+    }
+    days
+}
+
+/// Redraws `label MM:SS` once a second until `total` has passed.
+///
+/// Returns `false` if the user ended it early.
+fn countdown(label: &str, total: Duration) -> Result<bool> {
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        enable_raw_mode()?;
+    }
+    let result = run_countdown(label, total, interactive);
+    if interactive {
+        disable_raw_mode()?;
+    }
+    println!();
+    result
+}
+
+fn run_countdown(label: &str, total: Duration, interactive: bool) -> Result<bool> {
+    let end = Utc::now() + total;
+    loop {
+        let left = end - Utc::now();
+        if left <= Duration::zero() {
+            return Ok(true);
+        }
+        let secs = left.num_seconds() + 1;
+        print!(
+            "\r  {} {}  {}",
+            label.dimmed(),
+            format!("{:02}:{:02}", secs / 60, secs % 60).cyan().bold(),
+            "q to stop".dimmed()
+        );
+        io::stdout().flush()?;
+
+        let tick = left.min(Duration::seconds(1)).to_std()?;
+        if !interactive {
+            std::thread::sleep(tick);
+            continue;
+        }
+        if event::poll(tick)?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            let quit = match key.code {
+                KeyCode::Char('c') => key.modifiers.contains(KeyModifiers::CONTROL),
+                KeyCode::Char('q') | KeyCode::Esc => true,
+                _ => false,
+            };
+            if quit {
+                return Ok(false);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::InMemoryStorage;
+
+    #[test]
+    fn test_record_session_and_count_by_day() {
+        let storage = InMemoryStorage::default();
+        let task = Task::new("Write".into(), Priority::Medium, vec![], None, None, None);
+        let uuid = task.uuid;
+        storage.save(&[task]).unwrap();
+
+        let end = Utc::now();
+        let start = end - Duration::minutes(25);
+        record_session(&storage, uuid, start, end, true).unwrap();
+        record_session(&storage, uuid, start, end, true).unwrap();
+        record_session(&storage, uuid, start, end, false).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks[0].time_entries.len(), 3);
+        assert_eq!(tasks[0].time_tracked(end), Duration::minutes(75));
+
+        let today = end.with_timezone(&Local).date_naive();
+        let days = sessions_by_day(&tasks, today);
+        assert_eq!(days.get(&today), Some(&2));
+        assert!(sessions_by_day(&tasks, today.succ_opt().unwrap()).is_empty());
+    }
+}
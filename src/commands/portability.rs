@@ -14,43 +14,80 @@
 //!   same way.
 //! - Notes whose `resource_ids` contain unknown UUIDs have those entries
 //!   removed.
+//! - Tasks sharing a UUID within the file (e.g. two exports pasted
+//!   together) keep the first and give the rest new UUIDs, flagged for
+//!   `todo doctor` (see [`dedupe`](crate::storage::dedupe)).
 
 use anyhow::{Context, Result, bail};
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
-use crate::models::{Note, Project, Resource, Task};
-use crate::storage::Storage;
+use crate::cli::FilterArgs;
+use crate::commands::task::list::filter_tasks;
+use crate::models::{Note, Project, Resource, Task, TaskSource};
+use crate::storage::{Storage, dedupe};
 
 // ── envelope ──────────────────────────────────────────────────────────────────
 
-/// The JSON envelope used for export/import.
+/// The JSON envelope used for export/import (and project archives).
 #[derive(Serialize, Deserialize, Default)]
-struct Envelope {
+pub(crate) struct Envelope {
     #[serde(default)]
-    tasks: Vec<Task>,
+    pub(crate) tasks: Vec<Task>,
     #[serde(default)]
-    projects: Vec<Project>,
+    pub(crate) projects: Vec<Project>,
     #[serde(default)]
-    notes: Vec<Note>,
+    pub(crate) notes: Vec<Note>,
     #[serde(default)]
-    resources: Vec<Resource>,
+    pub(crate) resources: Vec<Resource>,
+}
+
+/// Reads the tasks from an export file, as written by `todo export`.
+pub(crate) fn read_export_tasks(file: &Path) -> Result<Vec<Task>> {
+    let content =
+        std::fs::read_to_string(file).context(format!("Failed to read {}", file.display()))?;
+    let envelope: Envelope = serde_json::from_str(&content)
+        .context("Failed to parse export file — is it a valid rustodo JSON export?")?;
+    Ok(envelope.tasks)
 }
 
 // ── export ────────────────────────────────────────────────────────────────────
 
 /// `todo export [FILE]` — serializes all data to a JSON file.
-pub fn execute_export(storage: &impl Storage, file: Option<PathBuf>) -> Result<()> {
+///
+/// With list filters only the matching tasks are exported, along with the
+/// projects they belong to; notes and resources are left out.
+pub fn execute_export(
+    storage: &impl Storage,
+    file: Option<PathBuf>,
+    filter: &FilterArgs,
+) -> Result<()> {
     let (tasks, projects, notes, resources) = storage.load_all_with_resources()?;
 
-    let envelope = Envelope {
-        tasks,
-        projects,
-        notes,
-        resources,
+    let envelope = if filter.is_empty() {
+        Envelope {
+            tasks,
+            projects,
+            notes,
+            resources,
+        }
+    } else {
+        let tasks: Vec<Task> = filter_tasks(&tasks, &projects, filter)?
+            .into_iter()
+            .map(|(_, t)| t.clone())
+            .collect();
+        let projects = projects
+            .into_iter()
+            .filter(|p| tasks.iter().any(|t| t.project_id == Some(p.uuid)))
+            .collect();
+        Envelope {
+            tasks,
+            projects,
+            ..Default::default()
+        }
     };
 
     let json = serde_json::to_string_pretty(&envelope).context("Failed to serialize data")?;
@@ -110,6 +147,7 @@ pub fn execute_import(
 
     // ── Referential integrity check ───────────────────────────────────────────
     let warnings = validate_and_repair(&mut envelope);
+    mark_imported(storage, &mut envelope.tasks, &file)?;
 
     println!(
         "\n{} Importing from: {}\n",
@@ -181,6 +219,28 @@ pub fn execute_import(
     Ok(())
 }
 
+/// Records the import file as the source of tasks new to this database.
+/// Tasks that already exist keep their local provenance.
+fn mark_imported(storage: &impl Storage, tasks: &mut [Task], file: &Path) -> Result<()> {
+    let local: HashMap<Uuid, Option<TaskSource>> = storage
+        .load()?
+        .into_iter()
+        .map(|t| (t.uuid, t.source))
+        .collect();
+    let from = file
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| file.display().to_string());
+
+    for task in tasks.iter_mut() {
+        task.source = match local.get(&task.uuid) {
+            Some(source) => source.clone(),
+            None => Some(TaskSource::Import(from.clone())),
+        };
+    }
+    Ok(())
+}
+
 // ── integrity validation ──────────────────────────────────────────────────────
 
 /// Repairs dangling foreign-key references within the envelope and returns
@@ -189,7 +249,15 @@ pub fn execute_import(
 /// This runs entirely in memory before any data is written, so a corrupt
 /// export file cannot produce an inconsistent database.
 fn validate_and_repair(envelope: &mut Envelope) -> Vec<String> {
-    let mut warnings = Vec::new();
+    let mut warnings: Vec<String> = dedupe::heal_duplicate_uuids(&mut envelope.tasks)
+        .into_iter()
+        .map(|h| {
+            format!(
+                "Task \"{}\": UUID {} already used in the file — given {}.",
+                h.text, h.old, h.new
+            )
+        })
+        .collect();
 
     let project_uuids: HashSet<Uuid> = envelope.projects.iter().map(|p| p.uuid).collect();
     let task_uuids: HashSet<Uuid> = envelope.tasks.iter().map(|t| t.uuid).collect();
@@ -261,7 +329,7 @@ mod tests {
     fn export_and_read(storage: &InMemoryStorage) -> (TempDir, PathBuf) {
         let tmp = TempDir::new().unwrap();
         let path = tmp.path().join("export.json");
-        execute_export(storage, Some(path.clone())).unwrap();
+        execute_export(storage, Some(path.clone()), &FilterArgs::default()).unwrap();
         (tmp, path)
     }
 
@@ -345,6 +413,59 @@ mod tests {
         assert!(storage2.load().unwrap()[0].project_id.is_none());
     }
 
+    #[test]
+    fn test_export_with_filter_keeps_matching_tasks_and_their_projects() {
+        let storage = InMemoryStorage::default();
+        let work = Project::new("Work".into());
+        let mut report = make_task("Report");
+        report.tags = vec!["work".into()];
+        report.project_id = Some(work.uuid);
+        storage
+            .save_projects(&[work, Project::new("Home".into())])
+            .unwrap();
+        storage.save(&[report, make_task("Laundry")]).unwrap();
+        storage.save_notes(&[Note::new("Aside".into())]).unwrap();
+
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("work.json");
+        let filter = FilterArgs {
+            tag: vec!["work".into()],
+            ..Default::default()
+        };
+        execute_export(&storage, Some(path.clone()), &filter).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let envelope: Envelope = serde_json::from_str(&content).unwrap();
+        assert_eq!(envelope.tasks.len(), 1);
+        assert_eq!(envelope.tasks[0].text, "Report");
+        assert_eq!(envelope.projects.len(), 1);
+        assert_eq!(envelope.projects[0].name, "Work");
+        assert!(envelope.notes.is_empty());
+    }
+
+    #[test]
+    fn test_import_heals_duplicate_uuids() {
+        let original = make_task("Original");
+        let mut copy = make_task("Copy");
+        copy.uuid = original.uuid;
+        let envelope = Envelope {
+            tasks: vec![original, copy],
+            ..Default::default()
+        };
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("merged.json");
+        std::fs::write(&path, serde_json::to_string(&envelope).unwrap()).unwrap();
+
+        let storage = InMemoryStorage::default();
+        execute_import(&storage, path, false, true).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_ne!(tasks[0].uuid, tasks[1].uuid);
+        assert_eq!(tasks[0].duplicate_of, Some(tasks[1].uuid));
+        assert_eq!(tasks[1].duplicate_of, Some(tasks[0].uuid));
+    }
+
     #[test]
     fn test_export_import_roundtrip_with_all_entities() {
         let storage = InMemoryStorage::default();
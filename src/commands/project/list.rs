@@ -1,12 +1,27 @@
-//! Handler for `todo project list`.
+//! Handler for `todo project list [--tree] [--progress]`.
+//!
+//! Either flag switches the table to the dashboard view, which adds overdue
+//! counts and the date of the latest activity per project.
 
 use anyhow::Result;
 
+use crate::cli::OutputFormat;
 use crate::error::TodoError;
-use crate::render::display_projects;
+use crate::models::count_by_project;
+use crate::render::project_table::{DashboardOptions, ProjectSummary, display_project_dashboard};
+use crate::render::{display_projects, structured};
 use crate::storage::Storage;
 
 pub fn execute(storage: &impl Storage) -> Result<()> {
+    execute_as(storage, OutputFormat::Table, DashboardOptions::default())
+}
+
+/// Like [`execute`], printing in the given output format and view.
+pub fn execute_as(
+    storage: &impl Storage,
+    format: OutputFormat,
+    options: DashboardOptions,
+) -> Result<()> {
     let (tasks, projects, notes) = storage.load_all()?;
 
     let mut visible: Vec<_> = projects.iter().filter(|p| !p.is_deleted()).collect();
@@ -17,6 +32,41 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
 
     visible.sort_by(|a, b| a.name.cmp(&b.name));
 
-    display_projects(&visible, &tasks, &notes);
+    match format {
+        OutputFormat::Table if options.tree || options.progress => {
+            display_project_dashboard(&visible, &tasks, &notes, options)
+        }
+        OutputFormat::Table | OutputFormat::Markdown => display_projects(&visible, &tasks, &notes),
+        OutputFormat::Json => {
+            let records: Vec<_> = visible
+                .iter()
+                .enumerate()
+                .map(|(i, project)| {
+                    let summary = ProjectSummary::of(&[project], &tasks, &notes);
+                    let mut record = structured::with_id(i + 1, project);
+                    record["tasks_total"] = summary.total.into();
+                    record["tasks_done"] = summary.done.into();
+                    record["tasks_overdue"] = summary.overdue.into();
+                    record["last_activity"] = serde_json::json!(summary.last_activity);
+                    record
+                })
+                .collect();
+            structured::print_json(&records)?;
+        }
+        OutputFormat::Plain => {
+            for (i, project) in visible.iter().enumerate() {
+                let (total, done) = count_by_project(&tasks, project.uuid);
+                let status = if project.completed { "done" } else { "pending" };
+                println!(
+                    "{}\t{}\t{}/{}\t{}",
+                    i + 1,
+                    status,
+                    done,
+                    total,
+                    project.name
+                );
+            }
+        }
+    }
     Ok(())
 }
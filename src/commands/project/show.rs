@@ -4,7 +4,7 @@ use anyhow::Result;
 use colored::Colorize;
 
 use crate::models::count_by_project;
-use crate::render::formatting::{note_preview, truncate};
+use crate::render::formatting::{first_line, note_preview, rule, truncate};
 use crate::storage::Storage;
 use crate::utils::validation::resolve_visible;
 
@@ -52,7 +52,7 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         "  {}",
         format!("Project #{}: {}", id, project.name).bold().cyan()
     );
-    println!("  {}", "─".repeat(50).dimmed());
+    println!("  {}", rule(50).dimmed());
 
     // ── Details ───────────────────────────────────────────────────────────────
     println!("  {}  {}", "Status".dimmed(), status_label);
@@ -122,7 +122,7 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
                 "P".yellow()
             };
 
-            let text = truncate(&task.text, 40);
+            let text = truncate(first_line(&task.text), 40);
             let text_colored = if task.completed {
                 text.dimmed()
             } else if is_blocked {
@@ -0,0 +1,156 @@
+//! Handler for `todo prompt`.
+//!
+//! Prints a one-line, escape-code-free summary such as `⚑3 ⏰1` for shell
+//! prompts (starship `custom` modules, powerlevel10k segments). It runs on
+//! every prompt, so it does a single task load, skips the doctor checks, and
+//! only computes urgency scores when `{urgent}` is in the format.
+//!
+//! The format is split on whitespace into segments. A segment whose
+//! placeholder count is below `min_count` is dropped, so an empty inbox
+//! prints nothing at all.
+
+use anyhow::Result;
+use chrono::Local;
+
+use crate::config::{Config, PromptConfig};
+use crate::models::Task;
+use crate::storage::Storage;
+
+pub fn execute(storage: &impl Storage, template: Option<String>) -> Result<()> {
+    let config = Config::load().map(|c| c.prompt).unwrap_or_default();
+    let format = template.unwrap_or_else(|| config.format.clone());
+
+    let tasks: Vec<Task> = storage
+        .load()?
+        .into_iter()
+        .filter(|t| !t.is_deleted())
+        .collect();
+
+    let counts = PromptCounts::compute(&tasks, &format, &config);
+    let line = render(&format, &counts, config.min_count);
+    if !line.is_empty() {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+struct PromptCounts {
+    pending: usize,
+    overdue: usize,
+    today: usize,
+    urgent: usize,
+}
+
+impl PromptCounts {
+    fn compute(tasks: &[Task], format: &str, config: &PromptConfig) -> Self {
+        let today = Local::now().date_naive();
+        let pending: Vec<_> = tasks.iter().filter(|t| !t.completed).collect();
+
+        let urgent = if format.contains("{urgent}") {
+            pending
+                .iter()
+                .filter(|t| t.urgency_score(tasks) >= config.urgent_score)
+                .count()
+        } else {
+            0
+        };
+
+        Self {
+            pending: pending.len(),
+            overdue: pending.iter().filter(|t| t.is_overdue()).count(),
+            today: pending.iter().filter(|t| t.due_date == Some(today)).count(),
+            urgent,
+        }
+    }
+}
+
+/// Expands placeholders segment by segment, dropping segments whose
+/// counts are all below `min_count`. Segments without placeholders are kept.
+fn render(format: &str, counts: &PromptCounts, min_count: usize) -> String {
+    let placeholders = [
+        ("{pending}", counts.pending),
+        ("{overdue}", counts.overdue),
+        ("{today}", counts.today),
+        ("{urgent}", counts.urgent),
+    ];
+
+    format
+        .split_whitespace()
+        .filter_map(|segment| {
+            let used: Vec<_> = placeholders
+                .iter()
+                .filter(|(name, _)| segment.contains(name))
+                .collect();
+            if !used.is_empty() && used.iter().all(|(_, n)| *n < min_count) {
+                return None;
+            }
+            Some(used.iter().fold(segment.to_string(), |s, (name, n)| {
+                s.replace(name, &n.to_string())
+            }))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(pending: usize, overdue: usize) -> PromptCounts {
+        PromptCounts {
+            pending,
+            overdue,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_render_default_format() {
+        let out = render("⚑{pending} ⏰{overdue}", &counts(3, 1), 1);
+        assert_eq!(out, "⚑3 ⏰1");
+    }
+
+    #[test]
+    fn test_render_drops_segments_below_threshold() {
+        assert_eq!(render("⚑{pending} ⏰{overdue}", &counts(3, 0), 1), "⚑3");
+        assert_eq!(render("⚑{pending} ⏰{overdue}", &counts(0, 0), 1), "");
+        assert_eq!(render("⚑{pending} ⏰{overdue}", &counts(4, 2), 5), "");
+    }
+
+    #[test]
+    fn test_render_keeps_literal_segments_and_zero_threshold() {
+        assert_eq!(
+            render("todo: {pending}/{overdue}", &counts(2, 0), 0),
+            "todo: 2/0"
+        );
+    }
+
+    #[test]
+    fn test_compute_counts_only_pending() {
+        let mut done = Task::new(
+            "done".into(),
+            crate::models::Priority::Low,
+            vec![],
+            None,
+            Some(Local::now().date_naive()),
+            None,
+        );
+        done.mark_done();
+        let open = Task::new(
+            "open".into(),
+            crate::models::Priority::Low,
+            vec![],
+            None,
+            Some(Local::now().date_naive()),
+            None,
+        );
+        let tasks = vec![done, open];
+
+        let counts = PromptCounts::compute(&tasks, "{pending}", &PromptConfig::default());
+        assert_eq!(counts.pending, 1);
+        assert_eq!(counts.today, 1);
+        assert_eq!(counts.overdue, 0);
+        assert_eq!(counts.urgent, 0);
+    }
+}
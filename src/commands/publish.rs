@@ -0,0 +1,99 @@
+//! Handler for `todo publish [FILE] [--to html|markdown] [list filters]`.
+//!
+//! Renders the (filtered) task list as a static, read-only page — HTML
+//! (see [`render::html`](crate::render::html)) or Markdown — so it can be
+//! shared with people who only need to look at it, e.g. by serving the
+//! file from any static host.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use colored::Colorize;
+
+use crate::cli::{FilterArgs, PublishFormat};
+use crate::commands::task::list::filter_tasks;
+use crate::models::Task;
+use crate::render::{html, markdown};
+use crate::storage::Storage;
+
+/// Page title when `--title` is not given.
+const DEFAULT_TITLE: &str = "Task list";
+
+/// `todo publish`
+pub fn execute(
+    storage: &impl Storage,
+    file: Option<PathBuf>,
+    to: PublishFormat,
+    title: Option<String>,
+    filter: &FilterArgs,
+) -> Result<()> {
+    let (all_tasks, projects, _) = storage.load_all()?;
+    let rows = filter_tasks(&all_tasks, &projects, filter)?;
+    let visible: Vec<Task> = all_tasks
+        .iter()
+        .filter(|t| !t.is_deleted())
+        .cloned()
+        .collect();
+    let title = title.as_deref().unwrap_or(DEFAULT_TITLE);
+    let today = Local::now().date_naive();
+
+    let (content, extension) = match to {
+        PublishFormat::Html => (
+            html::task_page(title, &rows, &visible, &projects, today),
+            "html",
+        ),
+        PublishFormat::Markdown => (
+            markdown::task_table(title, &rows, &visible, &projects, today),
+            "md",
+        ),
+    };
+    let path = file.unwrap_or_else(|| PathBuf::from(format!("rustodo-list.{}", extension)));
+    std::fs::write(&path, content)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    println!(
+        "{} Published to: {}",
+        "✓".green(),
+        path.display().to_string().cyan()
+    );
+    println!("  {} tasks", rows.len().to_string().dimmed());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::InMemoryStorage;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_publish_writes_filtered_markdown() {
+        let storage = InMemoryStorage::default();
+        let mut milk = Task::new("Buy milk".into(), Priority::Low, vec![], None, None, None);
+        milk.tags = vec!["shop".into()];
+        let call = Task::new("Call mom".into(), Priority::High, vec![], None, None, None);
+        storage.save(&[milk, call]).unwrap();
+
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("list.md");
+        let filter = FilterArgs {
+            tag: vec!["shop".into()],
+            ..Default::default()
+        };
+        execute(
+            &storage,
+            Some(path.clone()),
+            PublishFormat::Markdown,
+            Some("Groceries".into()),
+            &filter,
+        )
+        .unwrap();
+
+        let md = std::fs::read_to_string(path).unwrap();
+        assert!(md.starts_with("# Groceries\n"));
+        assert!(md.contains("| 1 | Buy milk | low |  | `#shop` | — | Open |"));
+        assert!(!md.contains("Call mom"));
+    }
+}
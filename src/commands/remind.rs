@@ -0,0 +1,325 @@
+//! Handler for `todo remind`.
+//!
+//! | Invocation                          | Effect                                       |
+//! |-------------------------------------|----------------------------------------------|
+//! | `todo remind`                       | Send the reminders that are due, once        |
+//! | `todo remind --daemon [--interval]` | Keep checking every `interval` seconds       |
+//! | `todo remind <ID> --before 2h`      | Set the task's lead time                     |
+//! | `todo remind <ID> --clear`          | Go back to the `remind_before` config default |
+//!
+//! A pending task with a due date gets one reminder `lead` before its
+//! deadline (the end of its due day) and another once it is overdue.
+//! Sent reminders are remembered in the database, keyed by due date, so a
+//! restarted daemon does not repeat them and moving the due date re-arms
+//! them.
+//!
+//! On the SQLite database the daemon also serves the other commands' storage
+//! calls while it runs; see [`crate::storage::shared`].
+//!
+//! Notifications go through `notify-send` on Linux and `osascript` on macOS;
+//! every reminder is also printed to stdout.
+
+use std::collections::BTreeSet;
+use std::process::{Command, Stdio};
+use std::time::Duration as StdDuration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use colored::Colorize;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::models::{ReminderLead, Task};
+use crate::render::formatting::due_at_relative_text;
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::validation::{resolve_visible_index, visible_indices};
+
+/// Snapshot name for the set of reminders already sent.
+const SNAPSHOT_NAME: &str = "reminders";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderKind {
+    /// The deadline is within the task's lead time
+    Upcoming,
+    /// The deadline has passed
+    Overdue,
+}
+
+/// A reminder that is due and has not been sent yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reminder {
+    pub uuid: Uuid,
+    /// Visible ID of the task
+    pub id: usize,
+    pub kind: ReminderKind,
+    /// Identifies this reminder in the sent set
+    pub key: String,
+}
+
+/// `todo remind <ID> --before <LEAD>` / `--clear`
+pub fn execute_set(storage: &impl Storage, id: usize, lead: Option<ReminderLead>) -> Result<()> {
+    let mut tasks = storage.load()?;
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+
+    tasks[index].remind_before = lead;
+    tasks[index].touch();
+    storage.upsert_task(&tasks[index])?;
+    storage.record_event(EntityType::Task, tasks[index].uuid, EventType::Edited)?;
+
+    match lead {
+        Some(lead) => println!(
+            "{} Task #{} will be reminded {} before it is due",
+            "✓".green(),
+            id,
+            lead
+        ),
+        None => println!(
+            "{} Task #{} uses the default reminder ({})",
+            "✓".green(),
+            id,
+            default_lead()
+        ),
+    }
+    if tasks[index].due_date.is_none() {
+        println!("  {} It has no due date yet.", "·".dimmed());
+    }
+    Ok(())
+}
+
+/// `todo remind` — sends whatever is due and returns how many were sent.
+pub fn execute_check(storage: &impl Storage) -> Result<usize> {
+    let tasks = storage.load()?;
+    let before = load_sent(storage)?;
+    let mut sent = before.clone();
+    let due = pending_reminders(&tasks, Utc::now(), default_lead(), &sent);
+
+    for reminder in &due {
+        if let Some(task) = tasks.iter().find(|t| t.uuid == reminder.uuid) {
+            deliver(reminder, task);
+        }
+        sent.insert(reminder.key.clone());
+    }
+
+    // Forget reminders for tasks that are done or gone
+    let live: BTreeSet<String> = tasks
+        .iter()
+        .filter(|t| !t.is_deleted() && !t.completed)
+        .map(|t| t.uuid.to_string())
+        .collect();
+    sent.retain(|key| key.split(':').next().is_some_and(|u| live.contains(u)));
+    if sent != before {
+        save_sent(storage, &sent)?;
+    }
+
+    Ok(due.len())
+}
+
+/// `todo remind --daemon`
+pub fn execute_daemon(storage: &impl Storage, interval: u64) -> Result<()> {
+    watch(storage, interval, std::thread::sleep)
+}
+
+/// `todo remind --daemon` on the SQLite database: between checks it answers
+/// other commands' storage calls on `socket`, making it their only writer.
+#[cfg(unix)]
+pub fn execute_daemon_shared(
+    storage: &crate::storage::SqliteStorage,
+    socket: &std::path::Path,
+    interval: u64,
+) -> Result<()> {
+    let server = crate::storage::shared::Server::bind(storage, socket)?;
+    println!(
+        "{} Sharing the database on {}",
+        "⇄".cyan(),
+        socket.display().to_string().dimmed()
+    );
+    watch(storage, interval, |pause| server.serve_for(pause))
+}
+
+fn watch(storage: &impl Storage, interval: u64, mut pause: impl FnMut(StdDuration)) -> Result<()> {
+    println!(
+        "{} Watching due dates every {}s (Ctrl-C to stop)",
+        "⏰".yellow(),
+        interval
+    );
+    loop {
+        if let Err(e) = execute_check(storage) {
+            eprintln!("{} {}", "✗".red(), e);
+        }
+        pause(StdDuration::from_secs(interval.max(1)));
+    }
+}
+
+/// Reminders due at `now` that are not in `sent`.
+pub fn pending_reminders(
+    tasks: &[Task],
+    now: DateTime<Utc>,
+    default_lead: ReminderLead,
+    sent: &BTreeSet<String>,
+) -> Vec<Reminder> {
+    let visible = visible_indices(tasks, |t| t.is_deleted());
+    let mut due = Vec::new();
+
+    for (pos, &i) in visible.iter().enumerate() {
+        let task = &tasks[i];
+        let (Some(date), Some(deadline)) = (task.due_date, task.deadline()) else {
+            continue;
+        };
+        if task.completed {
+            continue;
+        }
+        let lead = task.remind_before.unwrap_or(default_lead);
+
+        let kind = if now >= deadline {
+            ReminderKind::Overdue
+        } else if !lead.is_zero() && now >= deadline - lead.duration() {
+            ReminderKind::Upcoming
+        } else {
+            continue;
+        };
+        let key = format!("{}:{}:{:?}", task.uuid, date, kind).to_lowercase();
+        if !sent.contains(&key) {
+            due.push(Reminder {
+                uuid: task.uuid,
+                id: pos + 1,
+                kind,
+                key,
+            });
+        }
+    }
+    due
+}
+
+fn default_lead() -> ReminderLead {
+    Config::defaults()
+        .remind_before
+        .unwrap_or(ReminderLead::DEFAULT)
+}
+
+fn deliver(reminder: &Reminder, task: &Task) {
+    let due = due_at_relative_text(task).unwrap_or_default();
+    let (title, line) = match reminder.kind {
+        ReminderKind::Upcoming => ("Task due soon", format!("due {}", due).yellow()),
+        ReminderKind::Overdue => ("Task overdue", format!("was due {}", due).red()),
+    };
+    println!(
+        "{} {} {} {}  {}",
+        Local::now().format("%H:%M").to_string().dimmed(),
+        "⏰".yellow(),
+        format!("#{}", reminder.id).yellow(),
+        task.text.bright_white(),
+        line
+    );
+    notify(title, &format!("#{} {}", reminder.id, task.text));
+}
+
+/// Shows a desktop notification; failures are silent since the reminder
+/// was already printed.
+fn notify(title: &str, body: &str) {
+    let mut command = if cfg!(target_os = "macos") {
+        let mut c = Command::new("osascript");
+        c.arg("-e").arg(format!(
+            "display notification {:?} with title {:?}",
+            body, title
+        ));
+        c
+    } else if cfg!(windows) {
+        return;
+    } else {
+        let mut c = Command::new("notify-send");
+        c.args(["--app-name=rustodo", title, body]);
+        c
+    };
+    let _ = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+}
+
+fn load_sent(storage: &impl Storage) -> Result<BTreeSet<String>> {
+    match storage.load_snapshot(SNAPSHOT_NAME)? {
+        Some(payload) => serde_json::from_str(&payload).context("Reminder state is corrupted"),
+        None => Ok(BTreeSet::new()),
+    }
+}
+
+fn save_sent(storage: &impl Storage, sent: &BTreeSet<String>) -> Result<()> {
+    let payload = serde_json::to_string(sent).context("Failed to serialize reminder state")?;
+    storage.save_snapshot(SNAPSHOT_NAME, &payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, local_midnight};
+    use chrono::{Duration, NaiveDate};
+
+    fn task_due(date: NaiveDate) -> Task {
+        Task::new(
+            "Pay rent".into(),
+            Priority::Medium,
+            vec![],
+            None,
+            Some(date),
+            None,
+        )
+    }
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 5, 10).unwrap()
+    }
+
+    #[test]
+    fn test_upcoming_then_overdue() {
+        let tasks = vec![task_due(date())];
+        let deadline = local_midnight(date().succ_opt().unwrap());
+        let none = BTreeSet::new();
+
+        let early = deadline - Duration::days(2);
+        assert!(pending_reminders(&tasks, early, ReminderLead::DEFAULT, &none).is_empty());
+
+        let soon = deadline - Duration::hours(3);
+        let due = pending_reminders(&tasks, soon, ReminderLead::DEFAULT, &none);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].kind, ReminderKind::Upcoming);
+        assert_eq!(due[0].id, 1);
+
+        let sent: BTreeSet<String> = due.iter().map(|r| r.key.clone()).collect();
+        assert!(pending_reminders(&tasks, soon, ReminderLead::DEFAULT, &sent).is_empty());
+
+        let late = deadline + Duration::minutes(1);
+        let due = pending_reminders(&tasks, late, ReminderLead::DEFAULT, &sent);
+        assert_eq!(due[0].kind, ReminderKind::Overdue);
+    }
+
+    #[test]
+    fn test_per_task_lead_and_completed_tasks() {
+        let mut short = task_due(date());
+        short.remind_before = Some("1h".parse().unwrap());
+        let mut done = task_due(date());
+        done.mark_done();
+        let tasks = vec![short, done];
+        let deadline = local_midnight(date().succ_opt().unwrap());
+        let none = BTreeSet::new();
+
+        let at = deadline - Duration::hours(3);
+        assert!(pending_reminders(&tasks, at, ReminderLead::DEFAULT, &none).is_empty());
+        let at = deadline - Duration::minutes(30);
+        assert_eq!(
+            pending_reminders(&tasks, at, ReminderLead::DEFAULT, &none).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_lead_parsing() {
+        assert_eq!("90m".parse::<ReminderLead>().unwrap().to_string(), "90m");
+        assert_eq!("120m".parse::<ReminderLead>().unwrap().to_string(), "2h");
+        assert_eq!("7d".parse::<ReminderLead>().unwrap().to_string(), "1w");
+        assert!("0".parse::<ReminderLead>().unwrap().is_zero());
+        assert!("soon".parse::<ReminderLead>().is_err());
+        assert!("5x".parse::<ReminderLead>().is_err());
+    }
+}
@@ -0,0 +1,92 @@
+//! Handler for `todo report --format markdown [--project <NAME>] [--since <DATE>]`.
+//!
+//! Prints a Markdown summary grouped by project and status (see
+//! [`render::markdown`](crate::render::markdown)), ready to paste into a
+//! team update or wiki page.
+//!
+//! `--since` limits closed tasks to those done or cancelled on or after the
+//! date; tasks still open are always listed, as they are part of the
+//! current state.
+
+use anyhow::{Result, bail};
+use chrono::{Local, NaiveDate};
+
+use crate::cli::OutputFormat;
+use crate::error::TodoError;
+use crate::models::Task;
+use crate::render::markdown;
+use crate::storage::Storage;
+use crate::utils::{date_parser, suggest};
+
+/// `todo report`
+pub fn execute(
+    storage: &impl Storage,
+    project: Option<String>,
+    since: Option<String>,
+    format: OutputFormat,
+) -> Result<()> {
+    if matches!(format, OutputFormat::Json | OutputFormat::Plain) {
+        bail!("todo report only supports --format markdown");
+    }
+    let since = since.as_deref().map(date_parser::parse_date).transpose()?;
+
+    let (tasks, projects, _) = storage.load_all()?;
+    let visible: Vec<Task> = tasks.into_iter().filter(|t| !t.is_deleted()).collect();
+
+    let project_uuid = match project {
+        Some(ref name) => match suggest::resolve_project_filter(&projects, name)? {
+            Some(uuid) => Some(uuid),
+            None => return Err(TodoError::ProjectNotFound(name.to_owned()).into()),
+        },
+        None => None,
+    };
+
+    let rows: Vec<(usize, &Task)> = visible
+        .iter()
+        .enumerate()
+        .map(|(i, t)| (i + 1, t))
+        .filter(|(_, t)| project_uuid.is_none() || t.project_id == project_uuid)
+        .filter(|(_, t)| since.is_none_or(|since| closed_since(t, since)))
+        .collect();
+
+    let today = Local::now().date_naive();
+    print!(
+        "{}",
+        markdown::report(&rows, &visible, &projects, today, since)
+    );
+    Ok(())
+}
+
+/// Whether `task` belongs in a report starting at `since`: open tasks
+/// always do, closed ones only if they were closed on or after it.
+pub fn closed_since(task: &Task, since: NaiveDate) -> bool {
+    let closed_on = match task.cancelled_at {
+        Some(at) => Some(at.with_timezone(&Local).date_naive()),
+        None if task.completed => task.completed_on(),
+        None => return true,
+    };
+    closed_on.is_none_or(|on| on >= since)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, local_midnight};
+
+    #[test]
+    fn test_closed_since_keeps_open_tasks() {
+        let since = NaiveDate::from_ymd_opt(2030, 3, 1).unwrap();
+        let open = Task::new("a".into(), Priority::Low, vec![], None, None, None);
+        let mut old = open.clone();
+        old.mark_done();
+        old.completed_at = Some(local_midnight(
+            NaiveDate::from_ymd_opt(2030, 2, 27).unwrap(),
+        ));
+        let mut recent = old.clone();
+        recent.completed_at = Some(local_midnight(since));
+
+        assert!(closed_since(&open, since));
+        assert!(!closed_since(&old, since));
+        assert!(closed_since(&recent, since));
+    }
+}
@@ -3,7 +3,7 @@
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::render::formatting::note_preview;
+use crate::render::formatting::{note_preview, rule};
 use crate::storage::Storage;
 use crate::utils::validation::resolve_visible;
 
@@ -23,7 +23,7 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
             .bold()
             .cyan()
     );
-    println!("  {}", "─".repeat(50).dimmed());
+    println!("  {}", rule(50).dimmed());
 
     // ── Metadata ──────────────────────────────────────────────────────────────
     if let Some(rt) = resource.resource_type {
@@ -56,7 +56,7 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     }
 
     println!();
-    println!("  {}", "─".repeat(50).dimmed());
+    println!("  {}", rule(50).dimmed());
 
     // ── Notes that reference this resource ────────────────────────────────────
     let referencing: Vec<(usize, _)> = visible_notes
@@ -3,22 +3,37 @@
 //! Case-insensitive substring search over tasks, notes, projects, and resources.
 //! Searches across: task text, note title/body, project name/tech, resource title/url/description.
 //!
+//! Match modes:
+//! - default   substring match, done by the storage backend.
+//! - `--regex` the query is a (case-insensitive) regular expression.
+//! - `--fuzzy` every word of the query must match a word of the text,
+//!   allowing the typos [`suggest`] tolerates for names (1 edit for words
+//!   of up to 4 characters, 2 for longer ones).
+//!
+//! `--in text,tags,project,notes` searches only tasks, matching the query
+//! against the chosen fields (any of them may match): the description,
+//! tags, project name, or linked notes.
+//!
 //! Filter behaviour:
 //! - `--project` filters tasks, notes, and projects (resources have no project_id).
 //! - `--tag`     filters tasks, notes, and resources (projects have no tags — hidden when --tag is passed).
 //! - `--status`  filters tasks only.
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use colored::Colorize;
+use regex::{Regex, RegexBuilder};
 use uuid::Uuid;
 
+use crate::cli::{OutputFormat, SearchField};
 use crate::error::TodoError;
-use crate::models::StatusFilter;
-use crate::render::display_lists;
+use crate::models::{Note, Project, Resource, StatusFilter, Task};
+use crate::render::formatting::note_preview;
 use crate::render::note_table::display_notes;
 use crate::render::project_table::display_projects;
 use crate::render::resource_table::display_resources;
+use crate::render::{Columns, display_lists, structured};
 use crate::storage::Storage;
+use crate::utils::suggest;
 
 pub fn execute(
     storage: &impl Storage,
@@ -26,14 +41,51 @@ pub fn execute(
     tags: Vec<String>,
     project: Option<String>,
     status: StatusFilter,
+) -> Result<()> {
+    execute_as(
+        storage,
+        query,
+        tags,
+        project,
+        status,
+        SearchOptions::default(),
+        OutputFormat::Table,
+    )
+}
+
+/// How the query is matched against searchable text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchMode {
+    #[default]
+    Substring,
+    Regex,
+    Fuzzy,
+}
+
+/// How and where `todo search` matches the query.
+#[derive(Debug, Clone, Default)]
+pub struct SearchOptions {
+    pub mode: SearchMode,
+    /// Task fields to match (`--in`); empty searches every entity's
+    /// default fields.
+    pub fields: Vec<SearchField>,
+}
+
+/// Like [`execute`], matching as `options` says and printing in the given
+/// output format.
+pub fn execute_as(
+    storage: &impl Storage,
+    query: String,
+    tags: Vec<String>,
+    project: Option<String>,
+    status: StatusFilter,
+    options: SearchOptions,
+    format: OutputFormat,
 ) -> Result<()> {
     // ── Resolve project UUID ───────────────────────────────────────────────────
     let proj_uuid: Option<Uuid> = if let Some(ref project_name) = project {
         let projects = storage.load_projects()?;
-        let uuid = projects
-            .iter()
-            .find(|p| p.name.to_lowercase() == project_name.to_lowercase() && !p.is_deleted())
-            .map(|p| p.uuid);
+        let uuid = suggest::resolve_project_filter(&projects, project_name)?;
 
         if uuid.is_none() {
             return Err(TodoError::ProjectNotFound(project_name.clone()).into());
@@ -44,18 +96,31 @@ pub fn execute(
     };
 
     // ── Search each entity via storage (SQLite uses WHERE LIKE) ───────────────
-    let task_results = storage.search_tasks(&query, &tags, proj_uuid, status)?;
-    let note_results = storage.search_notes(&query, &tags, proj_uuid)?;
-    let project_results = if tags.is_empty() {
-        storage
-            .search_projects(&query)?
+    let (task_results, note_results, project_results, resource_results) =
+        if options.mode == SearchMode::Substring && options.fields.is_empty() {
+            (
+                storage.search_tasks(&query, &tags, proj_uuid, status)?,
+                storage.search_notes(&query, &tags, proj_uuid)?,
+                storage.search_projects(&query)?,
+                storage.search_resources(&query, &tags)?,
+            )
+        } else {
+            let matcher = Matcher::new(&query, options.mode)?;
+            let filter = Filter {
+                tags: &tags,
+                project_id: proj_uuid,
+                status,
+            };
+            search_loaded(storage, &matcher, &options.fields, &filter)?
+        };
+    let project_results: Vec<Project> = if tags.is_empty() {
+        project_results
             .into_iter()
             .filter(|p| proj_uuid.is_none_or(|uuid| p.uuid == uuid))
             .collect()
     } else {
         vec![]
     };
-    let resource_results = storage.search_resources(&query, &tags)?;
 
     if task_results.is_empty()
         && note_results.is_empty()
@@ -65,6 +130,24 @@ pub fn execute(
         return Err(TodoError::NoSearchResults(query).into());
     }
 
+    if format != OutputFormat::Table {
+        let (all_tasks, all_projects, all_notes, all_resources) =
+            storage.load_all_with_resources()?;
+        let results = SearchResults {
+            tasks: &task_results,
+            notes: &note_results,
+            projects: &project_results,
+            resources: &resource_results,
+        };
+        return results.print(
+            format,
+            &all_tasks,
+            &all_projects,
+            &all_notes,
+            &all_resources,
+        );
+    }
+
     // ── Render ────────────────────────────────────────────────────────────────
     let found_total =
         task_results.len() + note_results.len() + project_results.len() + resource_results.len();
@@ -105,6 +188,7 @@ pub fn execute(
             &all_projects,
             &all_notes,
             &all_resources,
+            Columns::default(),
         );
 
         if !project_results.is_empty() {
@@ -153,6 +237,249 @@ pub fn execute(
     Ok(())
 }
 
+/// Query matcher for the modes the storage backend can't evaluate.
+enum Matcher {
+    /// Lowercased query
+    Substring(String),
+    Regex(Regex),
+    /// Lowercased query words
+    Fuzzy(Vec<String>),
+}
+
+impl Matcher {
+    fn new(query: &str, mode: SearchMode) -> Result<Self> {
+        match mode {
+            SearchMode::Regex => match RegexBuilder::new(query).case_insensitive(true).build() {
+                Ok(re) => Ok(Self::Regex(re)),
+                Err(e) => bail!("Invalid regex '{}': {}", query, e),
+            },
+            SearchMode::Fuzzy => Ok(Self::Fuzzy(words(query))),
+            SearchMode::Substring => Ok(Self::Substring(query.to_lowercase())),
+        }
+    }
+
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            Self::Substring(query) => text.to_lowercase().contains(query.as_str()),
+            Self::Regex(re) => re.is_match(text),
+            Self::Fuzzy(query) => {
+                let text = words(text);
+                !query.is_empty()
+                    && query.iter().all(|q| {
+                        text.iter()
+                            .any(|w| w.contains(q.as_str()) || suggest::is_close(q, w))
+                    })
+            }
+        }
+    }
+
+    fn matches_any<'a>(&self, fields: impl IntoIterator<Item = &'a str>) -> bool {
+        fields.into_iter().any(|f| self.matches(f))
+    }
+}
+
+/// Lowercased alphanumeric words of `text`.
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Matching tasks, notes, projects, and resources.
+type Hits = (Vec<Task>, Vec<Note>, Vec<Project>, Vec<Resource>);
+
+/// The `--tag`, `--project`, and `--status` filters.
+struct Filter<'a> {
+    tags: &'a [String],
+    project_id: Option<Uuid>,
+    status: StatusFilter,
+}
+
+/// Searches loaded entities with `matcher`, with the same filters as the
+/// storage `search_*` methods.
+///
+/// Without `fields` every entity is matched over the same fields as the
+/// storage search; with them only tasks are searched, over those fields.
+fn search_loaded(
+    storage: &impl Storage,
+    matcher: &Matcher,
+    fields: &[SearchField],
+    filter: &Filter,
+) -> Result<Hits> {
+    let (tasks, projects, notes, resources) = storage.load_all_with_resources()?;
+    let has_tags = |item_tags: &[String]| filter.tags.iter().all(|tag| item_tags.contains(tag));
+    let in_project = |id: Option<Uuid>| filter.project_id.is_none_or(|uuid| id == Some(uuid));
+
+    let task_matches = |t: &Task| {
+        if fields.is_empty() {
+            return matcher.matches(&t.text);
+        }
+        fields.iter().any(|field| match field {
+            SearchField::Text => matcher.matches(&t.text),
+            SearchField::Tags => matcher.matches_any(t.tags.iter().map(String::as_str)),
+            SearchField::Project => t.project_id.is_some_and(|pid| {
+                projects
+                    .iter()
+                    .any(|p| p.uuid == pid && !p.is_deleted() && matcher.matches(&p.name))
+            }),
+            SearchField::Notes => notes.iter().any(|n| {
+                n.task_id == Some(t.uuid)
+                    && !n.is_deleted()
+                    && matcher.matches_any(n.title.as_deref().into_iter().chain([n.body.as_str()]))
+            }),
+        })
+    };
+    let tasks = tasks
+        .iter()
+        .filter(|t| !t.is_deleted() && t.matches_status(filter.status))
+        .filter(|t| has_tags(&t.tags) && in_project(t.project_id))
+        .filter(|t| task_matches(t))
+        .cloned()
+        .collect();
+    if !fields.is_empty() {
+        return Ok((tasks, vec![], vec![], vec![]));
+    }
+
+    let notes = notes
+        .into_iter()
+        .filter(|n| !n.is_deleted() && has_tags(&n.tags) && in_project(n.project_id))
+        .filter(|n| {
+            matcher.matches_any(
+                [
+                    n.title.as_deref(),
+                    Some(n.body.as_str()),
+                    n.language.as_deref(),
+                ]
+                .into_iter()
+                .flatten()
+                .chain(n.tags.iter().map(String::as_str)),
+            )
+        })
+        .collect();
+    let projects = projects
+        .into_iter()
+        .filter(|p| !p.is_deleted())
+        .filter(|p| {
+            matcher.matches_any(
+                std::iter::once(p.name.as_str()).chain(p.tech.iter().map(String::as_str)),
+            )
+        })
+        .collect();
+    let resources = resources
+        .into_iter()
+        .filter(|r| !r.is_deleted() && has_tags(&r.tags))
+        .filter(|r| {
+            matcher.matches_any(
+                [
+                    Some(r.title.as_str()),
+                    r.url.as_deref(),
+                    r.description.as_deref(),
+                ]
+                .into_iter()
+                .flatten()
+                .chain(r.tags.iter().map(String::as_str)),
+            )
+        })
+        .collect();
+    Ok((tasks, notes, projects, resources))
+}
+
+/// Search hits, printed by `--format json|plain`.
+struct SearchResults<'a> {
+    tasks: &'a [Task],
+    notes: &'a [Note],
+    projects: &'a [Project],
+    resources: &'a [Resource],
+}
+
+impl SearchResults<'_> {
+    fn print(
+        &self,
+        format: OutputFormat,
+        all_tasks: &[Task],
+        all_projects: &[Project],
+        all_notes: &[Note],
+        all_resources: &[Resource],
+    ) -> Result<()> {
+        let visible_tasks: Vec<_> = all_tasks
+            .iter()
+            .filter(|t| !t.is_deleted())
+            .cloned()
+            .collect();
+        let project_uuids = visible_uuids(all_projects, |p| p.is_deleted(), |p| p.uuid);
+        let note_uuids = visible_uuids(all_notes, |n| n.is_deleted(), |n| n.uuid);
+        let resource_uuids = visible_uuids(all_resources, |r| r.is_deleted(), |r| r.uuid);
+        let task_uuids: Vec<Uuid> = visible_tasks.iter().map(|t| t.uuid).collect();
+        let task_id = |uuid| id_of(&task_uuids, uuid);
+        let project_id = |uuid| id_of(&project_uuids, uuid);
+        let note_id = |uuid| id_of(&note_uuids, uuid);
+        let resource_id = |uuid| id_of(&resource_uuids, uuid);
+
+        if format == OutputFormat::Json {
+            let tasks: Vec<_> = self
+                .tasks
+                .iter()
+                .map(|t| structured::with_id(task_id(t.uuid), t))
+                .collect();
+            let projects: Vec<_> = self
+                .projects
+                .iter()
+                .map(|p| structured::with_id(project_id(p.uuid), p))
+                .collect();
+            let notes: Vec<_> = self
+                .notes
+                .iter()
+                .map(|n| structured::with_id(note_id(n.uuid), n))
+                .collect();
+            let resources: Vec<_> = self
+                .resources
+                .iter()
+                .map(|r| structured::with_id(resource_id(r.uuid), r))
+                .collect();
+            return structured::print_json(&serde_json::json!({
+                "tasks": tasks,
+                "projects": projects,
+                "notes": notes,
+                "resources": resources,
+            }));
+        }
+
+        for task in self.tasks {
+            let line =
+                structured::task_plain(task_id(task.uuid), task, &visible_tasks, all_projects);
+            println!("task\t{}", line);
+        }
+        for project in self.projects {
+            println!("project\t{}\t{}", project_id(project.uuid), project.name);
+        }
+        for note in self.notes {
+            println!("note\t{}\t{}", note_id(note.uuid), note_preview(note));
+        }
+        for resource in self.resources {
+            println!(
+                "resource\t{}\t{}",
+                resource_id(resource.uuid),
+                resource.title
+            );
+        }
+        Ok(())
+    }
+}
+
+fn visible_uuids<T>(
+    items: &[T],
+    is_deleted: impl Fn(&T) -> bool,
+    uuid: impl Fn(&T) -> Uuid,
+) -> Vec<Uuid> {
+    items.iter().filter(|i| !is_deleted(i)).map(uuid).collect()
+}
+
+/// Display ID (1-based) of `uuid` in a visible-UUID list, or 0 if absent.
+fn id_of(uuids: &[Uuid], uuid: Uuid) -> usize {
+    uuids.iter().position(|u| *u == uuid).map_or(0, |p| p + 1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,6 +612,89 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_regex_matcher() {
+        let matcher = Matcher::new(r"^fix\s+#\d+$", SearchMode::Regex).unwrap();
+        assert!(matcher.matches("Fix #42"));
+        assert!(!matcher.matches("Fix #42 later"));
+        assert!(Matcher::new("(unclosed", SearchMode::Regex).is_err());
+    }
+
+    #[test]
+    fn test_fuzzy_matcher_tolerates_typos() {
+        let matcher = Matcher::new("dokumentation updte", SearchMode::Fuzzy).unwrap();
+        assert!(matcher.matches("Update the documentation"));
+        assert!(!matcher.matches("Update the changelog"));
+        assert!(
+            Matcher::new("milk", SearchMode::Fuzzy)
+                .unwrap()
+                .matches("Buy milkshake")
+        );
+        assert!(
+            !Matcher::new("mlik", SearchMode::Fuzzy)
+                .unwrap()
+                .matches("Buy silk")
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_search_finds_near_miss() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Renew passport")]).unwrap();
+
+        let search = |mode| {
+            execute_as(
+                &storage,
+                "pasport".into(),
+                vec![],
+                None,
+                StatusFilter::All,
+                SearchOptions {
+                    mode,
+                    ..Default::default()
+                },
+                OutputFormat::Plain,
+            )
+        };
+        assert!(search(SearchMode::Substring).is_err());
+        assert!(search(SearchMode::Fuzzy).is_ok());
+    }
+
+    #[test]
+    fn test_search_in_fields() {
+        let storage = InMemoryStorage::default();
+        let project = Project::new("Garden".into());
+        let mut task = make_task("Water plants");
+        task.tags = vec!["weekly".into()];
+        task.project_id = Some(project.uuid);
+        let mut note = Note::new("Use the blue can".into());
+        note.task_id = Some(task.uuid);
+        storage.save_projects(&[project]).unwrap();
+        storage.save(&[task]).unwrap();
+        storage.save_notes(&[note]).unwrap();
+
+        let search_in = |query: &str, fields: &[SearchField]| {
+            execute_as(
+                &storage,
+                query.into(),
+                vec![],
+                None,
+                StatusFilter::All,
+                SearchOptions {
+                    fields: fields.to_vec(),
+                    ..Default::default()
+                },
+                OutputFormat::Plain,
+            )
+        };
+        assert!(search_in("weekly", &[SearchField::Tags]).is_ok());
+        assert!(search_in("weekly", &[SearchField::Text]).is_err());
+        assert!(search_in("garden", &[SearchField::Project]).is_ok());
+        assert!(search_in("blue can", &[SearchField::Notes]).is_ok());
+        assert!(search_in("blue can", &[SearchField::Text, SearchField::Tags]).is_err());
+        assert!(search_in("blue can", &[SearchField::Text, SearchField::Notes]).is_ok());
+    }
+
     #[test]
     fn test_search_nonexistent_project_fails() {
         let storage = InMemoryStorage::default();
@@ -1,13 +1,26 @@
 //! Handler for `todo stats`.
 
 use anyhow::Result;
-use chrono::{Duration, Local};
+use std::collections::HashSet;
+
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
 use colored::Colorize;
 
-use crate::models::{Priority, count_by_project};
+use crate::cli::OutputFormat;
+use crate::commands::pomodoro;
+use crate::config::Config;
+use crate::i18n::{self, Msg, t, tf};
+use crate::models::{Priority, Task, count_by_project};
+use crate::render::formatting::{format_duration, glyph, progress_bar};
+use crate::render::structured;
 use crate::storage::Storage;
 
 pub fn execute(storage: &impl Storage) -> Result<()> {
+    execute_as(storage, OutputFormat::Table)
+}
+
+/// Like [`execute`], printing in the given output format.
+pub fn execute_as(storage: &impl Storage, format: OutputFormat) -> Result<()> {
     let (all_tasks, projects, all_notes, all_resources) = storage.load_all_with_resources()?;
 
     let tasks: Vec<_> = all_tasks.into_iter().filter(|t| !t.is_deleted()).collect();
@@ -17,7 +30,8 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
         .filter(|r| !r.is_deleted())
         .collect();
 
-    if tasks.is_empty() && notes.is_empty() && resources.is_empty() {
+    if format == OutputFormat::Table && tasks.is_empty() && notes.is_empty() && resources.is_empty()
+    {
         println!("{}", "\nNo data found.\n".dimmed());
         return Ok(());
     }
@@ -25,18 +39,35 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
     let today = Local::now().naive_local().date();
 
     // ── Task metrics ──────────────────────────────────────────────────────────
+    // Cancelled tasks are kept for history but left out of the completion rate
     let total = tasks.len();
-    let completed = tasks.iter().filter(|t| t.completed).count();
-    let pending = total - completed;
+    let cancelled = tasks.iter().filter(|t| t.is_cancelled()).count();
+    let completed = tasks
+        .iter()
+        .filter(|t| t.completed && !t.is_cancelled())
+        .count();
+    let pending = total - completed - cancelled;
     let overdue = tasks.iter().filter(|t| t.is_overdue()).count();
-    let due_soon = tasks.iter().filter(|t| t.is_due_soon(7)).count();
+    let due_soon_days = Config::defaults().due_soon_days();
+    let due_soon = tasks
+        .iter()
+        .filter(|t| t.is_due_soon(due_soon_days))
+        .count();
     let blocked = tasks
         .iter()
         .filter(|t| !t.completed && t.is_blocked(&tasks))
         .count();
     let recurring = tasks.iter().filter(|t| t.recurrence.is_some()).count();
+    let missed: u32 = tasks.iter().map(|t| t.missed).sum();
     let with_deps = tasks.iter().filter(|t| !t.depends_on.is_empty()).count();
-    let pct = percent(completed, total);
+    let pct = percent(completed, total - cancelled);
+    let now = chrono::Utc::now();
+    let time_tracked = tasks
+        .iter()
+        .map(|t| t.time_tracked(now))
+        .fold(Duration::zero(), |total, d| total + d);
+    let streak = completion_streak(&tasks, today);
+    let estimated = estimated_left(&tasks);
 
     // ── Tag metrics ───────────────────────────────────────────────────────────
     let mut tag_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
@@ -56,7 +87,7 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
         }
     }
     let mut top_tags: Vec<(String, usize)> = tag_counts.into_iter().collect();
-    top_tags.sort_by(|a, b| b.1.cmp(&a.1));
+    top_tags.sort_by_key(|t| std::cmp::Reverse(t.1));
 
     // ── Urgency buckets ───────────────────────────────────────────────────────
     let urgent: Vec<_> = tasks
@@ -64,41 +95,117 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
         .filter(|t| !t.completed && t.urgency_score(&tasks) >= 10.0)
         .collect();
 
-    println!("\n{}\n", "Todo Statistics".bright_white().bold());
+    let focus_sessions = pomodoro::sessions_by_day(&tasks, today - Duration::days(6));
+
+    if format != OutputFormat::Table {
+        let summary = serde_json::json!({
+            "tasks": {
+                "total": total,
+                "completed": completed,
+                "pending": pending,
+                "cancelled": cancelled,
+                "overdue": overdue,
+                "due_soon": due_soon,
+                "blocked": blocked,
+                "recurring": recurring,
+                "missed": missed,
+                "with_deps": with_deps,
+                "urgent": urgent.len(),
+                "completion_pct": pct,
+                "time_tracked_minutes": time_tracked.num_minutes(),
+                "estimated_left_minutes": estimated.num_minutes(),
+                "streak_days": streak,
+            },
+            "estimated_left_by_project": projects
+                .iter()
+                .filter(|p| !p.is_deleted())
+                .filter_map(|p| {
+                    let left = estimated_left(tasks.iter().filter(|t| t.project_id == Some(p.uuid)));
+                    (left > Duration::zero())
+                        .then(|| (p.name.clone(), serde_json::json!(left.num_minutes())))
+                })
+                .collect::<serde_json::Map<_, _>>(),
+            "notes": notes.len(),
+            "resources": resources.len(),
+            "top_tags": top_tags
+                .iter()
+                .map(|(tag, count)| serde_json::json!({ "tag": tag, "count": count }))
+                .collect::<Vec<_>>(),
+            "completions_by_weekday": completions_by_weekday(&tasks),
+            "focus_sessions_by_day": focus_sessions
+                .iter()
+                .map(|(day, count)| (day.to_string(), serde_json::json!(count)))
+                .collect::<serde_json::Map<_, _>>(),
+        });
+        if format == OutputFormat::Json {
+            return structured::print_json(&summary);
+        }
+        print_plain("", &summary);
+        return Ok(());
+    }
+
+    println!("\n{}\n", t(Msg::StatsTitle).bright_white().bold());
 
     // ── Overview ──────────────────────────────────────────────────────────────
-    section("Overview");
+    section(t(Msg::StatsOverview));
     if total > 0 {
-        stat_line("Tasks", &format!("{} total", total), None);
+        stat_line(t(Msg::StatsTasks), &tf(Msg::StatsTotal, &[&total]), None);
         stat_line(
-            "Completed",
+            t(Msg::StatsCompleted),
             &format!("{} ({}%)", completed, pct),
             Some(completion_color(pct)),
         );
-        stat_line("Pending", &pending.to_string(), None);
+        stat_line(t(Msg::StatsPending), &pending.to_string(), None);
+        if cancelled > 0 {
+            stat_line(t(Msg::StatsCancelled), &cancelled.to_string(), None);
+        }
         stat_line(
-            "Overdue",
+            t(Msg::StatsOverdue),
             &overdue.to_string(),
             Some(if overdue > 0 { "red" } else { "none" }),
         );
         if due_soon > 0 {
-            stat_line("Due soon", &due_soon.to_string(), Some("yellow"));
+            stat_line(t(Msg::StatsDueSoon), &due_soon.to_string(), Some("yellow"));
         }
         let no_due = tasks
             .iter()
             .filter(|t| !t.completed && t.due_date.is_none())
             .count();
         if no_due > 0 {
-            stat_line("No due date", &no_due.to_string(), None);
+            stat_line(t(Msg::StatsNoDueDate), &no_due.to_string(), None);
         }
         if blocked > 0 {
-            stat_line("Blocked", &blocked.to_string(), Some("yellow"));
+            stat_line(t(Msg::StatsBlocked), &blocked.to_string(), Some("yellow"));
         }
         if recurring > 0 {
-            stat_line("Recurring", &recurring.to_string(), None);
+            stat_line(t(Msg::StatsRecurring), &recurring.to_string(), None);
+        }
+        if missed > 0 {
+            stat_line(t(Msg::StatsMissed), &missed.to_string(), Some("yellow"));
         }
         if with_deps > 0 {
-            stat_line("With deps", &with_deps.to_string(), None);
+            stat_line(t(Msg::StatsWithDeps), &with_deps.to_string(), None);
+        }
+        if time_tracked > Duration::zero() {
+            stat_line(
+                t(Msg::StatsTimeTracked),
+                &format_duration(time_tracked),
+                None,
+            );
+        }
+        if estimated > Duration::zero() {
+            stat_line(
+                t(Msg::StatsEstimatedLeft),
+                &format_duration(estimated),
+                None,
+            );
+        }
+        if streak > 0 {
+            stat_line(
+                t(Msg::StatsStreak),
+                &tf(Msg::StatsStreakDays, &[&streak]),
+                Some("green"),
+            );
         }
     }
     if !notes.is_empty() {
@@ -108,12 +215,10 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
             .count();
         let linked_notes = notes.len() - orphan_notes;
         stat_line(
-            "Notes",
-            &format!(
-                "{} total  ({} linked, {} orphan)",
-                notes.len(),
-                linked_notes,
-                orphan_notes
+            t(Msg::StatsNotes),
+            &tf(
+                Msg::StatsLinkedTotal,
+                &[&notes.len(), &linked_notes, &orphan_notes],
             ),
             None,
         );
@@ -129,12 +234,10 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
             .count();
         let orphan_res = resources.len() - linked_res;
         stat_line(
-            "Resources",
-            &format!(
-                "{} total  ({} linked, {} orphan)",
-                resources.len(),
-                linked_res,
-                orphan_res
+            t(Msg::StatsResources),
+            &tf(
+                Msg::StatsLinkedTotal,
+                &[&resources.len(), &linked_res, &orphan_res],
             ),
             None,
         );
@@ -143,24 +246,26 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
 
     // ── By Priority ───────────────────────────────────────────────────────────
     if total > 0 {
-        section("By Priority");
+        section(t(Msg::StatsByPriority));
         for (label, priority) in &[
-            ("High", Priority::High),
-            ("Medium", Priority::Medium),
-            ("Low", Priority::Low),
+            (Msg::PriorityHigh, Priority::High),
+            (Msg::PriorityMedium, Priority::Medium),
+            (Msg::PriorityLow, Priority::Low),
         ] {
-            let t: Vec<_> = tasks.iter().filter(|t| t.priority == *priority).collect();
+            let t: Vec<_> = tasks
+                .iter()
+                .filter(|t| t.priority == *priority && !t.is_cancelled())
+                .collect();
             if !t.is_empty() {
                 let d = t.iter().filter(|t| t.completed).count();
                 let p = t.len() - d;
                 let bar = progress_bar(d, t.len(), 10);
                 println!(
-                    "  {:<8} {}  {}  ({} pending, {} done)",
-                    label.bright_white(),
+                    "  {:<8} {}  {}  {}",
+                    i18n::t(*label).bright_white(),
                     t.len().to_string().cyan(),
                     bar,
-                    p,
-                    d,
+                    tf(Msg::StatsPriorityCounts, &[&p, &d]),
                 );
             }
         }
@@ -170,15 +275,22 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
     // ── By Project ────────────────────────────────────────────────────────────
     let visible_projects: Vec<_> = projects.iter().filter(|p| !p.is_deleted()).collect();
     if !visible_projects.is_empty() {
-        section("By Project");
+        section(t(Msg::StatsByProject));
         for project in &visible_projects {
             let (total_p, done_p) = count_by_project(&tasks, project.uuid);
+            let left_str = estimate_suffix(estimated_left(
+                tasks.iter().filter(|t| t.project_id == Some(project.uuid)),
+            ));
             let note_count = notes
                 .iter()
                 .filter(|n| n.project_id == Some(project.uuid))
                 .count();
             let note_str = if note_count > 0 {
-                format!("  {} {}", note_count.to_string().dimmed(), "notes".dimmed())
+                format!(
+                    "  {} {}",
+                    note_count.to_string().dimmed(),
+                    t(Msg::StatsNotesSuffix).dimmed()
+                )
             } else {
                 String::new()
             };
@@ -186,29 +298,31 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
                 println!(
                     "  {:<24} {}{}",
                     project.name.bright_white(),
-                    "no tasks".dimmed(),
+                    t(Msg::StatsNoTasks).dimmed(),
                     note_str,
                 );
             } else {
                 let pct_p = percent(done_p, total_p);
                 let bar = progress_bar(done_p, total_p, 10);
-                let task_str = pluralize(total_p, "task");
+                let task_str = i18n::task_count(total_p);
                 println!(
-                    "  {:<24} {:<10}  {}  {}%{}",
+                    "  {:<24} {:<10}  {}  {}%{}{}",
                     project.name.bright_white(),
                     task_str.cyan(),
                     bar,
                     pct_p,
                     note_str,
+                    left_str,
                 );
             }
         }
-        let no_project = tasks.iter().filter(|t| t.project_id.is_none()).count();
-        if no_project > 0 {
+        let no_project: Vec<_> = tasks.iter().filter(|t| t.project_id.is_none()).collect();
+        if !no_project.is_empty() {
             println!(
-                "  {:<24} {}",
-                "(no project)".dimmed(),
-                pluralize(no_project, "task").dimmed(),
+                "  {:<24} {}{}",
+                t(Msg::StatsNoProject).dimmed(),
+                i18n::task_count(no_project.len()).dimmed(),
+                estimate_suffix(estimated_left(no_project.iter().copied())),
             );
         }
         println!();
@@ -216,7 +330,7 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
 
     // ── Urgent tasks ──────────────────────────────────────────────────────────
     if !urgent.is_empty() {
-        section("Urgent  (score >= 10)");
+        section(t(Msg::StatsUrgent));
         let all_vis: Vec<_> = tasks.iter().collect();
         let mut urgent_sorted: Vec<_> = urgent.iter().collect();
         urgent_sorted.sort_by(|a, b| {
@@ -244,7 +358,7 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
 
     // ── Top Tags ──────────────────────────────────────────────────────────────
     if !top_tags.is_empty() {
-        section("Top Tags");
+        section(t(Msg::StatsTopTags));
         for (tag, count) in top_tags.iter().take(8) {
             println!(
                 "  {:<20}  {}",
@@ -255,39 +369,74 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
         println!();
     }
 
+    // ── Completions by weekday ────────────────────────────────────────────────
+    let by_weekday = completions_by_weekday(&tasks);
+    if let Some(&best) = by_weekday.iter().max()
+        && best > 0
+    {
+        section(t(Msg::StatsByWeekday));
+        for (i, count) in by_weekday.iter().enumerate() {
+            let day = Weekday::try_from(i as u8).unwrap_or(Weekday::Mon);
+            let label = format!("{:<8}", i18n::weekday(day));
+            let bar = glyph("█", "#").repeat((count * 20).div_ceil(best));
+            let bar = if *count == best {
+                bar.green().to_string()
+            } else {
+                bar.dimmed().to_string()
+            };
+            println!(
+                "  {}  {:>4}  {}",
+                label.bright_white(),
+                count.to_string().cyan(),
+                bar
+            );
+        }
+        println!();
+    }
+
+    // ── Focus sessions ────────────────────────────────────────────────────────
+    if !focus_sessions.is_empty() {
+        section(t(Msg::StatsFocusSessions));
+        for offset in (0..7).rev() {
+            let day = today - Duration::days(offset);
+            let count = focus_sessions.get(&day).copied().unwrap_or(0);
+            println!(
+                "  {}  {:>4}  {}",
+                format!("{} {}", i18n::weekday(day.weekday()), day.format("%m-%d")).bright_white(),
+                count.to_string().cyan(),
+                "🍅".repeat(count)
+            );
+        }
+        println!();
+    }
+
     // ── Completion rate ───────────────────────────────────────────────────────
-    section("Completion Rate");
+    section(t(Msg::StatsCompletionRate));
     let rate_7 = {
         let cutoff = today - Duration::days(7);
         let done = tasks
             .iter()
-            .filter(|t| t.completed_at.map(|d| d >= cutoff).unwrap_or(false))
-            .count();
-        let created = tasks
-            .iter()
-            .filter(|t| t.created_at.date_naive() >= cutoff)
+            .filter(|t| t.completed_on().is_some_and(|d| d >= cutoff))
             .count();
+        let created = tasks.iter().filter(|t| t.created_on() >= cutoff).count();
         percent(done, created.max(1))
     };
     let rate_30 = {
         let cutoff = today - Duration::days(30);
         let done = tasks
             .iter()
-            .filter(|t| t.completed_at.map(|d| d >= cutoff).unwrap_or(false))
-            .count();
-        let created = tasks
-            .iter()
-            .filter(|t| t.created_at.date_naive() >= cutoff)
+            .filter(|t| t.completed_on().is_some_and(|d| d >= cutoff))
             .count();
+        let created = tasks.iter().filter(|t| t.created_on() >= cutoff).count();
         percent(done, created.max(1))
     };
     stat_line(
-        "7 days",
+        t(Msg::StatsDays7),
         &format!("{}%", rate_7),
         Some(completion_color(rate_7)),
     );
     stat_line(
-        "30 days",
+        t(Msg::StatsDays30),
         &format!("{}%", rate_30),
         Some(completion_color(rate_30)),
     );
@@ -298,6 +447,68 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
 
+/// Counts completed tasks per local weekday, Monday first.
+fn completions_by_weekday(tasks: &[Task]) -> [usize; 7] {
+    let mut counts = [0; 7];
+    for day in tasks.iter().filter_map(|t| t.completed_on()) {
+        counts[day.weekday().num_days_from_monday() as usize] += 1;
+    }
+    counts
+}
+
+/// Streak lengths worth celebrating when `todo done` reaches them.
+pub const STREAK_MILESTONES: [u32; 7] = [3, 7, 14, 30, 50, 100, 365];
+
+/// Consecutive days, up to `today`, with at least one task completed.
+///
+/// A day without completions yet does not break the streak while it is
+/// still today, so the count then runs from yesterday.
+pub fn completion_streak(tasks: &[Task], today: NaiveDate) -> u32 {
+    let days: HashSet<NaiveDate> = tasks
+        .iter()
+        .filter(|t| !t.is_cancelled())
+        .filter_map(|t| t.completed_on())
+        .collect();
+    let from = if days.contains(&today) {
+        today
+    } else {
+        today - Duration::days(1)
+    };
+    (0..)
+        .map(|back| from - Duration::days(back))
+        .take_while(|day| days.contains(day))
+        .count() as u32
+}
+
+/// The milestone crossed when the streak went from `before` to `after`.
+pub fn streak_milestone(before: u32, after: u32) -> Option<u32> {
+    STREAK_MILESTONES
+        .into_iter()
+        .rev()
+        .find(|&m| before < m && m <= after)
+}
+
+/// Total estimate of the pending tasks among `tasks`.
+fn estimated_left<'a>(tasks: impl IntoIterator<Item = &'a Task>) -> Duration {
+    tasks
+        .into_iter()
+        .filter(|t| !t.completed)
+        .filter_map(|t| t.estimate)
+        .fold(Duration::zero(), |total, e| total + e.duration())
+}
+
+/// `  ~2h 30m left` after a project row, or nothing without estimates.
+fn estimate_suffix(left: Duration) -> String {
+    if left > Duration::zero() {
+        format!(
+            "  {}",
+            format!("~{} {}", format_duration(left), t(Msg::StatsLeftSuffix)).dimmed()
+        )
+    } else {
+        String::new()
+    }
+}
+
 fn section(title: &str) {
     println!("{}\n", title.bright_white().underline());
 }
@@ -312,33 +523,31 @@ fn stat_line(label: &str, value: &str, color: Option<&str>) {
     println!("  {:<16} {}", label.dimmed(), val);
 }
 
-fn progress_bar(done: usize, total: usize, width: usize) -> String {
-    let filled = if total == 0 {
-        0
-    } else {
-        (done * width) / total
-    };
-    let empty = width - filled;
-    let bar = format!("{}{}", "█".repeat(filled), "░".repeat(empty));
-    if done == total && total > 0 {
-        bar.green().to_string()
-    } else if filled > 0 {
-        bar.yellow().to_string()
-    } else {
-        bar.dimmed().to_string()
-    }
-}
-
-fn pluralize(count: usize, word: &str) -> String {
-    if count == 1 {
-        format!("{} {}", count, word)
-    } else {
-        format!("{} {}s", count, word)
+/// Flattens `value` into `dotted.key\tvalue` lines for `--format plain`.
+fn print_plain(prefix: &str, value: &serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                print_plain(&key, child);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (i, child) in items.iter().enumerate() {
+                print_plain(&format!("{}.{}", prefix, i), child);
+            }
+        }
+        serde_json::Value::String(text) => println!("{}\t{}", prefix, text),
+        other => println!("{}\t{}", prefix, other),
     }
 }
 
 fn percent(part: usize, total: usize) -> usize {
-    if total == 0 { 0 } else { (part * 100) / total }
+    (part * 100).checked_div(total).unwrap_or(0)
 }
 
 fn completion_color(pct: usize) -> &'static str {
@@ -350,3 +559,72 @@ fn completion_color(pct: usize) -> &'static str {
         "red"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::local_midnight;
+
+    fn done_on(date: NaiveDate) -> Task {
+        let mut task = Task::new("t".into(), Priority::Medium, vec![], None, None, None);
+        task.completed = true;
+        task.completed_at = Some(local_midnight(date));
+        task
+    }
+
+    #[test]
+    fn test_estimated_left_counts_pending_tasks_only() {
+        let estimated = |text: &str, estimate: &str| {
+            let mut task = Task::new(text.into(), Priority::Low, vec![], None, None, None);
+            task.estimate = Some(estimate.parse().unwrap());
+            task
+        };
+        let mut done = estimated("done", "3h");
+        done.mark_done();
+        let tasks = vec![
+            estimated("a", "2h"),
+            estimated("b", "45m"),
+            done,
+            Task::new("none".into(), Priority::Low, vec![], None, None, None),
+        ];
+
+        assert_eq!(estimated_left(&tasks), Duration::minutes(165));
+    }
+
+    #[test]
+    fn test_completions_by_weekday() {
+        // 2026-02-09 is a Monday
+        let monday = NaiveDate::from_ymd_opt(2026, 2, 9).unwrap();
+        let tasks = vec![
+            done_on(monday),
+            done_on(monday + Duration::days(7)),
+            done_on(monday + Duration::days(4)),
+            Task::new("pending".into(), Priority::Low, vec![], None, None, None),
+        ];
+
+        assert_eq!(completions_by_weekday(&tasks), [2, 0, 0, 0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_completion_streak() {
+        let today = NaiveDate::from_ymd_opt(2026, 2, 12).unwrap();
+        let mut tasks: Vec<Task> = (1..=3)
+            .map(|back| done_on(today - Duration::days(back)))
+            .collect();
+        tasks.push(done_on(today - Duration::days(5)));
+
+        // Nothing done today yet: the streak still counts yesterday back
+        assert_eq!(completion_streak(&tasks, today), 3);
+        tasks.push(done_on(today));
+        assert_eq!(completion_streak(&tasks, today), 4);
+        assert_eq!(completion_streak(&tasks, today + Duration::days(2)), 0);
+    }
+
+    #[test]
+    fn test_streak_milestone() {
+        assert_eq!(streak_milestone(2, 3), Some(3));
+        assert_eq!(streak_milestone(3, 3), None);
+        assert_eq!(streak_milestone(6, 7), Some(7));
+        assert_eq!(streak_milestone(8, 9), None);
+    }
+}
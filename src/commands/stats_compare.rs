@@ -0,0 +1,215 @@
+//! Handler for `todo stats show --compare <PERIOD>`.
+//!
+//! Puts the current period (the last 7 or 30 days, ending today) next to
+//! the period before it, with a delta per metric:
+//!
+//! | Metric     | Meaning                                                  |
+//! |------------|----------------------------------------------------------|
+//! | Completed  | Tasks completed during the period                        |
+//! | Overdue    | Tasks past due and still open at the end of the period   |
+//! | Cycle time | Mean days from creation to completion for those finished |
+
+use anyhow::Result;
+use chrono::{Duration, Local, NaiveDate};
+use colored::Colorize;
+
+use crate::cli::ComparePeriod;
+use crate::models::Task;
+use crate::storage::Storage;
+
+pub fn execute(storage: &impl Storage, period: ComparePeriod) -> Result<()> {
+    let tasks: Vec<_> = storage
+        .load()?
+        .into_iter()
+        .filter(|t| !t.is_deleted())
+        .collect();
+
+    let days = match period {
+        ComparePeriod::LastWeek => 7,
+        ComparePeriod::LastMonth => 30,
+    };
+    let today = Local::now().date_naive();
+    let current_start = today - Duration::days(days - 1);
+    let previous_start = current_start - Duration::days(days);
+
+    let current = PeriodMetrics::compute(&tasks, current_start, today);
+    let previous =
+        PeriodMetrics::compute(&tasks, previous_start, current_start.pred_opt().unwrap());
+
+    println!(
+        "\n{}\n",
+        format!("Last {} days vs. the {} before", days, days)
+            .bright_white()
+            .bold()
+    );
+    println!(
+        "  {:<12} {:>8} {:>8}  {}",
+        "".dimmed(),
+        "Now".dimmed(),
+        "Before".dimmed(),
+        "Change".dimmed()
+    );
+
+    compare_line(
+        "Completed",
+        current.completed as f64,
+        previous.completed as f64,
+        0,
+        true,
+    );
+    compare_line(
+        "Overdue",
+        current.overdue as f64,
+        previous.overdue as f64,
+        0,
+        false,
+    );
+    match (current.avg_cycle_days, previous.avg_cycle_days) {
+        (Some(now), Some(before)) => compare_line("Cycle time", now, before, 1, false),
+        (now, before) => println!(
+            "  {:<12} {:>8} {:>8}",
+            "Cycle time".dimmed(),
+            now.map_or("—".into(), |d| format!("{:.1}d", d)),
+            before.map_or("—".into(), |d| format!("{:.1}d", d)),
+        ),
+    }
+    println!();
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+struct PeriodMetrics {
+    completed: usize,
+    overdue: usize,
+    avg_cycle_days: Option<f64>,
+}
+
+impl PeriodMetrics {
+    /// Metrics for the inclusive date range `start..=end`.
+    fn compute(tasks: &[Task], start: NaiveDate, end: NaiveDate) -> Self {
+        let finished: Vec<_> = tasks
+            .iter()
+            .filter(|t| t.completed_on().is_some_and(|d| d >= start && d <= end))
+            .collect();
+
+        let overdue = overdue_at(tasks, end);
+
+        let avg_cycle_days = if finished.is_empty() {
+            None
+        } else {
+            let total_hours: i64 = finished
+                .iter()
+                .filter_map(|t| t.completed_at.map(|at| (at - t.created_at).num_hours()))
+                .sum();
+            Some(total_hours as f64 / 24.0 / finished.len() as f64)
+        };
+
+        Self {
+            completed: finished.len(),
+            overdue,
+            avg_cycle_days,
+        }
+    }
+}
+
+/// Number of tasks past due and still open at the end of `day`.
+pub fn overdue_at(tasks: &[Task], day: NaiveDate) -> usize {
+    tasks
+        .iter()
+        .filter(|t| {
+            t.created_on() <= day
+                && t.due_date.is_some_and(|due| due < day)
+                && t.completed_on().is_none_or(|d| d > day)
+                && t.cancelled_at
+                    .is_none_or(|at| at.with_timezone(&Local).date_naive() > day)
+        })
+        .count()
+}
+
+/// Prints one metric row; `higher_is_better` decides the delta color.
+fn compare_line(label: &str, now: f64, before: f64, decimals: usize, higher_is_better: bool) {
+    let delta = now - before;
+    let arrow = if delta > 0.0 {
+        "↑"
+    } else if delta < 0.0 {
+        "↓"
+    } else {
+        "="
+    };
+    let change = format!("{} {:.*}", arrow, decimals, delta.abs());
+    let change = if delta == 0.0 {
+        change.dimmed()
+    } else if (delta > 0.0) == higher_is_better {
+        change.green()
+    } else {
+        change.red()
+    };
+    println!(
+        "  {:<12} {:>8} {:>8}  {}",
+        label.dimmed(),
+        format!("{:.*}", decimals, now).cyan(),
+        format!("{:.*}", decimals, before),
+        change
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, local_midnight};
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn task(created: u32, due: Option<u32>, completed: Option<u32>) -> Task {
+        let mut t = Task::new(
+            "t".into(),
+            Priority::Medium,
+            vec![],
+            None,
+            due.map(day),
+            None,
+        );
+        t.created_at = local_midnight(day(created));
+        if let Some(c) = completed {
+            t.completed = true;
+            t.completed_at = Some(local_midnight(day(c)));
+        }
+        t
+    }
+
+    #[test]
+    fn test_period_metrics() {
+        let tasks = vec![
+            task(1, None, Some(3)),     // finished in period after 2 days
+            task(2, None, Some(6)),     // finished in period after 4 days
+            task(1, Some(4), None),     // overdue and still open
+            task(1, Some(4), Some(20)), // overdue at period end, done later
+            task(1, Some(4), Some(5)),  // finished before period end
+            task(10, Some(11), None),   // created after the period
+        ];
+
+        let metrics = PeriodMetrics::compute(&tasks, day(1), day(7));
+
+        assert_eq!(metrics.completed, 3);
+        assert_eq!(metrics.overdue, 2);
+        let avg = metrics.avg_cycle_days.unwrap();
+        // (2 + 4 + 4) / 3, with slack for a DST change in the range
+        assert!((avg - 10.0 / 3.0).abs() < 0.1, "avg was {avg}");
+    }
+
+    #[test]
+    fn test_period_metrics_empty() {
+        let metrics = PeriodMetrics::compute(&[], day(1), day(7));
+        assert_eq!(
+            metrics,
+            PeriodMetrics {
+                completed: 0,
+                overdue: 0,
+                avg_cycle_days: None,
+            }
+        );
+    }
+}
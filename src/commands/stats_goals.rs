@@ -0,0 +1,248 @@
+//! Handler for `todo stats show --goal`.
+//!
+//! Checks the goals from the `[goals]` config table against today's
+//! numbers, with the streak of consecutive days each goal was met:
+//!
+//! ```toml
+//! [goals]
+//! daily_completions = 5   # complete at least 5 tasks a day
+//! overdue_below     = 3   # keep fewer than 3 tasks overdue
+//! ```
+//!
+//! A completion goal not yet met today does not break the streak — the
+//! day is still in progress — so its streak counts from yesterday.
+
+use anyhow::Result;
+use chrono::{Duration, Local, NaiveDate};
+use colored::Colorize;
+
+use crate::cli::OutputFormat;
+use crate::commands::stats_compare::overdue_at;
+use crate::config::{Config, GoalsConfig};
+use crate::models::Task;
+use crate::render::structured;
+use crate::storage::Storage;
+
+/// Days looked back when counting a streak.
+const MAX_STREAK: i64 = 365;
+
+/// One configured goal and how today measures up.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GoalStatus {
+    pub label: String,
+    pub target: u32,
+    pub current: usize,
+    pub met: bool,
+    /// Consecutive days the goal was met
+    pub streak: u32,
+}
+
+pub fn execute(storage: &impl Storage, format: OutputFormat) -> Result<()> {
+    let tasks: Vec<Task> = storage
+        .load()?
+        .into_iter()
+        .filter(|t| !t.is_deleted())
+        .collect();
+    let goals = evaluate(&Config::load()?.goals, &tasks, Local::now().date_naive());
+
+    if format != OutputFormat::Table {
+        let records: Vec<_> = goals
+            .iter()
+            .map(|g| {
+                serde_json::json!({
+                    "goal": g.label,
+                    "target": g.target,
+                    "current": g.current,
+                    "met": g.met,
+                    "streak": g.streak,
+                })
+            })
+            .collect();
+        if format == OutputFormat::Json {
+            return structured::print_json(&records);
+        }
+        for g in &goals {
+            println!(
+                "{}\t{}\t{}\t{}\t{}",
+                g.label, g.target, g.current, g.met, g.streak
+            );
+        }
+        return Ok(());
+    }
+
+    if goals.is_empty() {
+        println!(
+            "\n{}\n  Add a {} section to config.toml, e.g. {}\n",
+            "No goals set.".dimmed(),
+            "[goals]".cyan(),
+            "daily_completions = 5".cyan()
+        );
+        return Ok(());
+    }
+
+    println!("\n{}\n", "Goals".bright_white().underline());
+    for g in &goals {
+        let (mark, current) = if g.met {
+            ("✓".green(), g.current.to_string().green())
+        } else {
+            ("✗".red(), g.current.to_string().red())
+        };
+        let streak = format!(
+            "streak {} day{}",
+            g.streak,
+            if g.streak == 1 { "" } else { "s" }
+        );
+        println!(
+            "  {} {:<24} {:>4}  {}",
+            mark,
+            g.label,
+            current,
+            if g.streak > 0 {
+                streak.cyan()
+            } else {
+                streak.dimmed()
+            }
+        );
+    }
+    println!();
+    Ok(())
+}
+
+/// Measures every configured goal as of `today`.
+pub fn evaluate(goals: &GoalsConfig, tasks: &[Task], today: NaiveDate) -> Vec<GoalStatus> {
+    let mut out = Vec::new();
+    // Days before the first task was added don't count towards a streak
+    let since = tasks.iter().map(Task::created_on).min().unwrap_or(today);
+
+    if let Some(target) = goals.daily_completions {
+        let completed_on = |day: NaiveDate| {
+            tasks
+                .iter()
+                .filter(|t| !t.is_cancelled() && t.completed_on() == Some(day))
+                .count()
+        };
+        let met_on = |day| completed_on(day) >= target as usize;
+        let current = completed_on(today);
+        let met = met_on(today);
+        let from = if met {
+            today
+        } else {
+            today - Duration::days(1)
+        };
+        out.push(GoalStatus {
+            label: format!("Complete {} task{}/day", target, plural(target)),
+            target,
+            current,
+            met,
+            streak: streak(from, since, met_on),
+        });
+    }
+
+    if let Some(target) = goals.overdue_below {
+        let met_on = |day| overdue_at(tasks, day) < target as usize;
+        let current = overdue_at(tasks, today);
+        out.push(GoalStatus {
+            label: format!("Overdue under {}", target),
+            target,
+            current,
+            met: current < target as usize,
+            streak: streak(today, since, met_on),
+        });
+    }
+
+    out
+}
+
+/// Consecutive days ending at `from`, back to `since`, on which `met_on` holds.
+fn streak(from: NaiveDate, since: NaiveDate, met_on: impl Fn(NaiveDate) -> bool) -> u32 {
+    (0..MAX_STREAK)
+        .map(|back| from - Duration::days(back))
+        .take_while(|&day| day >= since && met_on(day))
+        .count() as u32
+}
+
+fn plural(n: u32) -> &'static str {
+    if n == 1 { "" } else { "s" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, local_midnight};
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn done_on(d: u32) -> Task {
+        let mut t = Task::new("t".into(), Priority::Medium, vec![], None, None, None);
+        t.created_at = local_midnight(day(1));
+        t.mark_done();
+        t.completed_at = Some(local_midnight(day(d)));
+        t
+    }
+
+    #[test]
+    fn test_completion_streak_skips_unfinished_today() {
+        let goals = GoalsConfig {
+            daily_completions: Some(2),
+            ..Default::default()
+        };
+        // Met on the 7th and 8th, not the 6th; one so far on the 9th
+        let tasks = vec![
+            done_on(6),
+            done_on(7),
+            done_on(7),
+            done_on(8),
+            done_on(8),
+            done_on(9),
+        ];
+
+        let status = &evaluate(&goals, &tasks, day(9))[0];
+        assert_eq!((status.current, status.met, status.streak), (1, false, 2));
+
+        let mut tasks = tasks;
+        tasks.push(done_on(9));
+        let status = &evaluate(&goals, &tasks, day(9))[0];
+        assert_eq!((status.current, status.met, status.streak), (2, true, 3));
+    }
+
+    #[test]
+    fn test_overdue_goal() {
+        let goals = GoalsConfig {
+            overdue_below: Some(1),
+            ..Default::default()
+        };
+        let mut late = Task::new(
+            "late".into(),
+            Priority::Medium,
+            vec![],
+            None,
+            Some(day(5)),
+            None,
+        );
+        late.created_at = local_midnight(day(1));
+
+        let status = &evaluate(&goals, &[late], day(8))[0];
+        assert_eq!(status.label, "Overdue under 1");
+        assert_eq!((status.current, status.met, status.streak), (1, false, 0));
+
+        // Met every day since the 1st, when the first task was added
+        let mut early = Task::new(
+            "early".into(),
+            Priority::Medium,
+            vec![],
+            None,
+            Some(day(9)),
+            None,
+        );
+        early.created_at = local_midnight(day(1));
+        let goals = GoalsConfig {
+            overdue_below: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(evaluate(&goals, &[early], day(8))[0].streak, 8);
+
+        assert!(evaluate(&GoalsConfig::default(), &[], day(8)).is_empty());
+    }
+}
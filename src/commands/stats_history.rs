@@ -8,6 +8,7 @@
 use anyhow::Result;
 use colored::Colorize;
 
+use crate::render::formatting::{glyph, rule};
 use crate::storage::Storage;
 
 pub fn execute(storage: &impl Storage, months: usize) -> Result<()> {
@@ -43,7 +44,7 @@ pub fn execute(storage: &impl Storage, months: usize) -> Result<()> {
         "Count".dimmed(),
         width = bar_width + 2,
     );
-    println!("{}", "─".repeat(bar_width + 32).dimmed());
+    println!("{}", rule(bar_width + 32).dimmed());
 
     for row in rows {
         let filled_a = (row.created * bar_width) / max_count;
@@ -54,10 +55,10 @@ pub fn execute(storage: &impl Storage, months: usize) -> Result<()> {
 
         let bar = format!(
             "{}{}{}{}",
-            "█".repeat(filled_a).green(),
-            "█".repeat(filled_c).yellow(),
-            "█".repeat(filled_d).red(),
-            "░".repeat(empty).dimmed(),
+            glyph("█", "#").repeat(filled_a).green(),
+            glyph("█", "#").repeat(filled_c).yellow(),
+            glyph("█", "#").repeat(filled_d).red(),
+            glyph("░", ".").repeat(empty).dimmed(),
         );
 
         let detail = format!(
@@ -71,14 +72,14 @@ pub fn execute(storage: &impl Storage, months: usize) -> Result<()> {
         println!("  {:<10}  {}  {}", label.dimmed(), bar, detail);
     }
 
-    println!("{}", "─".repeat(bar_width + 32).dimmed());
+    println!("{}", rule(bar_width + 32).dimmed());
     println!(
         "\n  {}  {}  {}  {}  {}  {}  {}\n",
-        "█".green(),
+        glyph("█", "#").green(),
         "Added".dimmed(),
-        "█".yellow(),
+        glyph("█", "#").yellow(),
         "Completed".dimmed(),
-        "█".red(),
+        glyph("█", "#").red(),
         "Deleted".dimmed(),
         format!("(last {} months)", months).dimmed(),
     );
@@ -6,30 +6,61 @@
 use anyhow::Result;
 use colored::Colorize;
 
+use crate::cli::OutputFormat;
 use crate::error::TodoError;
-use crate::models::{Note, Resource, Task};
+use crate::models::{Note, Project, Resource, Task};
+use crate::render::formatting::{note_preview, rule};
+use crate::render::structured;
 use crate::services::tag_service;
 use crate::storage::Storage;
 use crate::utils::tag_normalizer::has_tag;
 
 pub fn execute(storage: &impl Storage, filter: Option<String>) -> Result<()> {
-    let (tasks, _, notes, resources) = storage.load_all_with_resources()?;
+    execute_as(storage, filter, OutputFormat::Table)
+}
+
+/// Like [`execute`], printing in the given output format.
+pub fn execute_as(
+    storage: &impl Storage,
+    filter: Option<String>,
+    format: OutputFormat,
+) -> Result<()> {
+    let (tasks, projects, notes, resources) = storage.load_all_with_resources()?;
 
     match filter {
-        Some(tag) => show_tag_hub(&tasks, &notes, &resources, &tag),
-        None => show_all_tags(&tasks, &notes, &resources),
+        Some(tag) => show_tag_hub(&tasks, &projects, &notes, &resources, &tag, format),
+        None => show_all_tags(&tasks, &notes, &resources, format),
     }
 }
 
 // ── show all tags ─────────────────────────────────────────────────────────────
 
-fn show_all_tags(tasks: &[Task], notes: &[Note], resources: &[Resource]) -> Result<()> {
+fn show_all_tags(
+    tasks: &[Task],
+    notes: &[Note],
+    resources: &[Resource],
+    format: OutputFormat,
+) -> Result<()> {
     let stats = tag_service::collect_tags(tasks, notes, resources);
 
     if stats.is_empty() {
         return Err(TodoError::NoTagsFound.into());
     }
 
+    match format {
+        OutputFormat::Json => return structured::print_json(&stats),
+        OutputFormat::Plain => {
+            for stat in &stats {
+                println!(
+                    "{}\t{}\t{}\t{}",
+                    stat.name, stat.tasks, stat.notes, stat.resources
+                );
+            }
+            return Ok(());
+        }
+        OutputFormat::Table | OutputFormat::Markdown => {}
+    }
+
     let name_w = stats.iter().map(|s| s.name.len()).max().unwrap_or(0);
 
     println!("\nTags:\n");
@@ -71,7 +102,14 @@ fn show_all_tags(tasks: &[Task], notes: &[Note], resources: &[Resource]) -> Resu
 
 // ── show tag hub ──────────────────────────────────────────────────────────────
 
-fn show_tag_hub(tasks: &[Task], notes: &[Note], resources: &[Resource], tag: &str) -> Result<()> {
+fn show_tag_hub(
+    tasks: &[Task],
+    projects: &[Project],
+    notes: &[Note],
+    resources: &[Resource],
+    tag: &str,
+    format: OutputFormat,
+) -> Result<()> {
     // All visible tasks — used to resolve real display IDs
     let all_visible: Vec<_> = tasks.iter().filter(|t| !t.is_deleted()).collect();
 
@@ -101,9 +139,70 @@ fn show_tag_hub(tasks: &[Task], notes: &[Note], resources: &[Resource], tag: &st
         return Err(TodoError::TagNotFound(tag.to_owned()).into());
     }
 
+    let task_id = |uuid| {
+        all_visible
+            .iter()
+            .position(|t| t.uuid == uuid)
+            .map_or(0, |i| i + 1)
+    };
+    let note_id = |uuid| {
+        all_visible_notes
+            .iter()
+            .position(|n| n.uuid == uuid)
+            .map_or(0, |i| i + 1)
+    };
+    let resource_id = |uuid| {
+        all_visible_resources
+            .iter()
+            .position(|r| r.uuid == uuid)
+            .map_or(0, |i| i + 1)
+    };
+
+    match format {
+        OutputFormat::Json => {
+            let tasks: Vec<_> = matched_tasks
+                .iter()
+                .map(|t| structured::with_id(task_id(t.uuid), t))
+                .collect();
+            let notes: Vec<_> = matched_notes
+                .iter()
+                .map(|n| structured::with_id(note_id(n.uuid), n))
+                .collect();
+            let resources: Vec<_> = matched_resources
+                .iter()
+                .map(|r| structured::with_id(resource_id(r.uuid), r))
+                .collect();
+            return structured::print_json(&serde_json::json!({
+                "tag": tag,
+                "tasks": tasks,
+                "notes": notes,
+                "resources": resources,
+            }));
+        }
+        OutputFormat::Plain => {
+            let visible: Vec<Task> = all_visible.iter().map(|t| (*t).clone()).collect();
+            for task in &matched_tasks {
+                let line = structured::task_plain(task_id(task.uuid), task, &visible, projects);
+                println!("task\t{}", line);
+            }
+            for note in &matched_notes {
+                println!("note\t{}\t{}", note_id(note.uuid), note_preview(note));
+            }
+            for resource in &matched_resources {
+                println!(
+                    "resource\t{}\t{}",
+                    resource_id(resource.uuid),
+                    resource.title
+                );
+            }
+            return Ok(());
+        }
+        OutputFormat::Table | OutputFormat::Markdown => {}
+    }
+
     println!();
     println!("  {}", format!("Tag: #{}", tag).bold().cyan());
-    println!("  {}", "─".repeat(40).dimmed());
+    println!("  {}", rule(40).dimmed());
 
     if !matched_tasks.is_empty() {
         println!();
@@ -4,12 +4,15 @@ use anyhow::Result;
 use colored::Colorize;
 
 use crate::cli::AddArgs;
+use crate::config::Config;
 use crate::error::TodoError;
-use crate::models::{Project, Task};
+use crate::i18n::{Msg, tf};
+use crate::models::{Priority, Project, Task, TaskSource};
 use crate::services::tag_service::collect_all_tag_names;
 use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::tag_normalizer::normalize_tags;
 use crate::utils::validation::{self, resolve_uuid_visible, visible_indices};
+use crate::utils::{quick_capture, stdin_arg};
 use crate::{utils::date_parser, utils::validation::validate_task_id};
 
 pub fn execute(storage: &impl Storage, args: AddArgs) -> Result<()> {
@@ -17,25 +20,43 @@ pub fn execute(storage: &impl Storage, args: AddArgs) -> Result<()> {
     Ok(())
 }
 
-pub fn execute_silent(storage: &impl Storage, args: AddArgs) -> Result<()> {
-    execute_inner(storage, args, true)?;
-    Ok(())
+/// Adds without printing or asking about duplicates; returns the new task's ID.
+pub fn execute_silent(storage: &impl Storage, args: AddArgs) -> Result<usize> {
+    execute_inner(storage, args, true)
 }
 
-fn execute_inner(storage: &impl Storage, args: AddArgs, silent: bool) -> Result<usize> {
+fn execute_inner(storage: &impl Storage, mut args: AddArgs, silent: bool) -> Result<usize> {
+    if args.extra.is_empty() {
+        args.text = stdin_arg::resolve(args.text)?;
+    }
+    let args = expand_quick_capture(args);
     validation::validate_task_text(&args.text)?;
     validation::validate_tags(&args.tag)?;
     if let Some(ref p) = args.project {
         validation::validate_project_name(p)?;
     }
 
-    let due = if let Some(ref due_str) = args.due {
-        Some(date_parser::parse_date_not_in_past(due_str)?)
-    } else {
-        None
+    let (due, due_time) = match args.due.as_deref() {
+        Some(due_str) => {
+            let (date, time) = date_parser::parse_due_not_in_past(due_str)?;
+            (Some(date), time)
+        }
+        None => (None, None),
     };
 
+    let wait_until = args
+        .wait_until
+        .as_deref()
+        .map(date_parser::parse_date_not_in_past)
+        .transpose()?;
+    let start_date = args
+        .start
+        .as_deref()
+        .map(date_parser::parse_date)
+        .transpose()?;
+
     validation::validate_due_date(due, false)?;
+    validation::validate_start_date(start_date, due)?;
     validation::validate_recurrence(args.recurrence, due)?;
 
     let mut tasks = storage.load()?;
@@ -100,13 +121,21 @@ fn execute_inner(storage: &impl Storage, args: AddArgs, silent: bool) -> Result<
     // ── Build & persist ───────────────────────────────────────────────────────
     let mut task = Task::new(
         args.text,
-        args.priority,
+        args.priority
+            .or(Config::defaults().default_priority)
+            .unwrap_or(Priority::Medium),
         normalized_tags,
         project_id,
         due,
         args.recurrence,
     );
     task.depends_on = dep_uuids;
+    task.source = Some(TaskSource::Manual);
+    task.wait_until = wait_until;
+    task.start_date = start_date;
+    task.due_time = due_time;
+    task.estimate = args.estimate;
+    task.label = args.label;
     let task_uuid = task.uuid;
     tasks.push(task);
 
@@ -120,15 +149,45 @@ fn execute_inner(storage: &impl Storage, args: AddArgs, silent: bool) -> Result<
             println!("  {} Tag normalized: {}", "~".yellow(), msg.yellow());
         }
         if let Some(pattern) = args.recurrence {
-            println!("{} Added task #{} with {} recurrence", ok, id, pattern);
+            println!("{} {}", ok, tf(Msg::TaskAddedRecurring, &[&id, &pattern]));
         } else {
-            println!("{} Added task #{}", ok, id);
+            println!("{} {}", ok, tf(Msg::TaskAdded, &[&id]));
         }
     }
 
     Ok(id)
 }
 
+/// Joins unquoted trailing words into the description and applies any inline
+/// metadata tokens. Explicit `--priority` / `--project` / `--due` flags take
+/// precedence; inline tags are merged with `--tag`.
+fn expand_quick_capture(mut args: AddArgs) -> AddArgs {
+    if args.extra.is_empty() {
+        return args;
+    }
+
+    let mut words = vec![std::mem::take(&mut args.text)];
+    words.append(&mut args.extra);
+    let capture = quick_capture::parse(&words);
+
+    args.text = capture.text;
+    if args.priority.is_none() {
+        args.priority = capture.priority;
+    }
+    for tag in capture.tags {
+        if !args.tag.contains(&tag) {
+            args.tag.push(tag);
+        }
+    }
+    if args.project.is_none() {
+        args.project = capture.project;
+    }
+    if args.due.is_none() {
+        args.due = capture.due;
+    }
+    args
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,12 +197,7 @@ mod tests {
     fn args(text: &str) -> AddArgs {
         AddArgs {
             text: text.into(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         }
     }
 
@@ -163,7 +217,7 @@ mod tests {
         execute_silent(
             &storage,
             AddArgs {
-                priority: Priority::High,
+                priority: Some(Priority::High),
                 ..args("Task")
             },
         )
@@ -294,4 +348,50 @@ mod tests {
         let tasks = storage.load().unwrap();
         assert_eq!(tasks[1].tags[0], "rust");
     }
+
+    #[test]
+    fn test_add_unquoted_words_parse_inline_metadata() {
+        let storage = InMemoryStorage::default();
+        execute_silent(
+            &storage,
+            AddArgs {
+                extra: vec![
+                    "login".into(),
+                    "bug".into(),
+                    "tomorrow".into(),
+                    "!high".into(),
+                    "#work".into(),
+                ],
+                ..args("fix")
+            },
+        )
+        .unwrap();
+
+        let task = &storage.load().unwrap()[0];
+        assert_eq!(task.text, "fix login bug");
+        assert_eq!(task.priority, Priority::High);
+        assert_eq!(task.tags, vec!["work"]);
+        assert_eq!(
+            task.due_date,
+            Some(chrono::Local::now().date_naive() + chrono::Duration::days(1))
+        );
+    }
+
+    #[test]
+    fn test_add_priority_flag_wins_over_inline() {
+        let storage = InMemoryStorage::default();
+        execute_silent(
+            &storage,
+            AddArgs {
+                extra: vec!["milk".into(), "!low".into()],
+                priority: Some(Priority::High),
+                ..args("buy")
+            },
+        )
+        .unwrap();
+
+        let task = &storage.load().unwrap()[0];
+        assert_eq!(task.text, "buy milk");
+        assert_eq!(task.priority, Priority::High);
+    }
 }
@@ -0,0 +1,152 @@
+//! Handlers for `todo annotate <ID> --file <PATH>` and
+//! `todo open <ID> [--attachment N]`.
+//!
+//! Attachments are file references, not copies: the path is checked and
+//! made absolute when attached, and opened later with the system's default
+//! application. A file moved or deleted afterwards is reported as missing.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+
+use crate::commands::open_data;
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::validation::resolve_visible_index;
+
+/// `todo annotate <ID> --file <PATH>...` — attaches existing files to a task.
+pub fn execute(storage: &impl Storage, id: usize, files: Vec<PathBuf>) -> Result<()> {
+    let mut tasks = storage.load()?;
+
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+
+    // Validate everything before touching the task
+    let paths = files
+        .iter()
+        .map(|file| {
+            let path = file
+                .canonicalize()
+                .with_context(|| format!("Cannot attach {}", file.display()))?;
+            if !path.is_file() {
+                anyhow::bail!("Cannot attach {}: not a file", file.display());
+            }
+            Ok(path)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let task = &mut tasks[index];
+    let mut added = Vec::new();
+    for path in paths {
+        if !task.attachments.contains(&path) {
+            task.attachments.push(path.clone());
+            added.push(path);
+        }
+    }
+
+    if added.is_empty() {
+        println!("{} Already attached to task #{}", "".yellow(), id);
+        return Ok(());
+    }
+
+    task.touch();
+    let task_uuid = task.uuid;
+    storage.upsert_task(task)?;
+    storage.record_event(EntityType::Task, task_uuid, EventType::Edited)?;
+
+    for path in &added {
+        println!(
+            "{} Attached {} to task #{}",
+            "✓".green(),
+            path.display().to_string().cyan(),
+            id
+        );
+    }
+    Ok(())
+}
+
+/// `todo open <ID> [--attachment N]` — opens an attachment (1-based, as
+/// numbered by `todo show`). `N` may be omitted when there is only one.
+pub fn execute_open(storage: &impl Storage, id: usize, attachment: Option<usize>) -> Result<()> {
+    let tasks = storage.load()?;
+
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+    let attachments = &tasks[index].attachments;
+
+    let n = match (attachment, attachments.len()) {
+        (_, 0) => anyhow::bail!(
+            "Task #{} has no attachments. Add one with: todo annotate {} --file <PATH>",
+            id,
+            id
+        ),
+        (Some(n), _) => n,
+        (None, 1) => 1,
+        (None, count) => anyhow::bail!(
+            "Task #{} has {} attachments; choose one with --attachment 1..{} (see todo show {})",
+            id,
+            count,
+            count,
+            id
+        ),
+    };
+    let path = n
+        .checked_sub(1)
+        .and_then(|i| attachments.get(i))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "invalid attachment {} for task #{} (it has {})",
+                n,
+                id,
+                attachments.len()
+            )
+        })?;
+
+    if !path.exists() {
+        anyhow::bail!("Attachment is missing: {}", path.display());
+    }
+    open_data::launch(path)?;
+
+    println!(
+        "{} Opened {}",
+        "✓".green(),
+        path.display().to_string().cyan()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, Task};
+    use crate::storage::InMemoryStorage;
+
+    #[test]
+    fn test_annotate_stores_absolute_path_once() {
+        let storage = InMemoryStorage::default();
+        let task = Task::new("Task".into(), Priority::Medium, vec![], None, None, None);
+        storage.save(&[task]).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("spec.pdf");
+        std::fs::write(&file, "pdf").unwrap();
+
+        execute(&storage, 1, vec![file.clone()]).unwrap();
+        execute(&storage, 1, vec![file.clone()]).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks[0].attachments, vec![file.canonicalize().unwrap()]);
+    }
+
+    #[test]
+    fn test_annotate_missing_file_fails() {
+        let storage = InMemoryStorage::default();
+        let task = Task::new("Task".into(), Priority::Medium, vec![], None, None, None);
+        storage.save(&[task]).unwrap();
+
+        let result = execute(&storage, 1, vec![PathBuf::from("/no/such/file.txt")]);
+
+        assert!(result.is_err());
+        assert!(storage.load().unwrap()[0].attachments.is_empty());
+    }
+}
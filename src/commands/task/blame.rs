@@ -0,0 +1,153 @@
+//! Handler for `todo blame <ID>`.
+//!
+//! Shows where a task came from: its recorded [`TaskSource`], when it was
+//! created, and — for recurring tasks — the chain of parents it was
+//! generated from, oldest last.
+
+use anyhow::Result;
+use colored::Colorize;
+use uuid::Uuid;
+
+use crate::models::Task;
+use crate::storage::Storage;
+use crate::utils::validation::{resolve_visible_index, visible_indices};
+
+pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
+    let tasks = storage.load()?;
+
+    let real_index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+
+    let task = &tasks[real_index];
+    let vis = visible_indices(&tasks, |t| t.is_deleted());
+
+    println!(
+        "\n{} #{}: {}\n",
+        "Task".dimmed(),
+        id,
+        task.text.bright_white()
+    );
+
+    let source = match &task.source {
+        Some(source) => source.describe().bright_white(),
+        None => "unknown (created before provenance was tracked)".dimmed(),
+    };
+    println!("  {} {}", "Source: ".dimmed(), source);
+    println!(
+        "  {} {}",
+        "Created:".dimmed(),
+        task.created_at
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+    );
+    if let Some(key) = &task.jira_key {
+        println!("  {} {}", "Jira:   ".dimmed(), key.cyan());
+    }
+
+    let chain = parent_chain(&tasks, task);
+    if !chain.is_empty() {
+        println!("\n  {}:", "Generated from".dimmed());
+        for link in chain {
+            let Some(uuid) = link else {
+                println!("    {} {}", "?".yellow(), "(parent purged)".dimmed());
+                break;
+            };
+            let real_pos = tasks.iter().position(|t| t.uuid == uuid).unwrap();
+            let parent = &tasks[real_pos];
+            let label = match vis.iter().position(|&i| i == real_pos) {
+                Some(p) => format!("#{}", p + 1),
+                None => "[deleted]".to_string(),
+            };
+            let status = if parent.completed {
+                "✓".green()
+            } else {
+                "◦".yellow()
+            };
+            let due = parent
+                .due_date
+                .map(|d| format!(" (due {})", d.format("%Y-%m-%d")))
+                .unwrap_or_default();
+            println!(
+                "    {} {} — {}{}",
+                status,
+                label,
+                parent.text.dimmed(),
+                due.dimmed()
+            );
+        }
+    }
+
+    println!();
+    Ok(())
+}
+
+/// Walks `parent_id` links upward from `task`, nearest parent first.
+///
+/// A `None` entry marks a parent that no longer exists in storage and ends
+/// the chain. Cycles (which should not occur) are cut at the first repeat.
+pub fn parent_chain(tasks: &[Task], task: &Task) -> Vec<Option<Uuid>> {
+    let mut chain = Vec::new();
+    let mut seen = vec![task.uuid];
+    let mut next = task.parent_id;
+
+    while let Some(uuid) = next {
+        if seen.contains(&uuid) {
+            break;
+        }
+        seen.push(uuid);
+        match tasks.iter().find(|t| t.uuid == uuid) {
+            Some(parent) => {
+                chain.push(Some(uuid));
+                next = parent.parent_id;
+            }
+            None => {
+                chain.push(None);
+                break;
+            }
+        }
+    }
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, Recurrence, TaskSource};
+    use chrono::NaiveDate;
+
+    fn recurring() -> Task {
+        Task::new(
+            "Standup".into(),
+            Priority::Medium,
+            vec![],
+            None,
+            NaiveDate::from_ymd_opt(2026, 1, 5),
+            Some(Recurrence::Daily),
+        )
+    }
+
+    #[test]
+    fn test_parent_chain_follows_recurrence() {
+        let first = recurring();
+        let second = first.create_next_recurrence(first.uuid).unwrap();
+        let third = second.create_next_recurrence(second.uuid).unwrap();
+        assert_eq!(third.source, Some(TaskSource::Recurrence));
+
+        let tasks = vec![first.clone(), second.clone(), third.clone()];
+        assert_eq!(
+            parent_chain(&tasks, &third),
+            vec![Some(second.uuid), Some(first.uuid)]
+        );
+        assert!(parent_chain(&tasks, &first).is_empty());
+    }
+
+    #[test]
+    fn test_parent_chain_marks_purged_parent() {
+        let first = recurring();
+        let second = first.create_next_recurrence(first.uuid).unwrap();
+        assert_eq!(
+            parent_chain(std::slice::from_ref(&second), &second),
+            vec![None]
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! Handler for `todo cancel <ID> [--reason "..."]`.
+//!
+//! Closes a task that will not be done. It leaves every pending view like a
+//! completed task but stays searchable under `--status cancelled`, and it
+//! does not count toward completion rates. Recurring tasks do not spawn a
+//! next occurrence. `todo undone` reopens it.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::error::TodoError;
+use crate::i18n::{Msg, tf};
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::validation::resolve_visible_index;
+
+pub fn execute(storage: &impl Storage, id: usize, reason: Option<String>) -> Result<()> {
+    let mut tasks = storage.load()?;
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+
+    if tasks[index].completed {
+        let status = if tasks[index].is_cancelled() {
+            "cancelled"
+        } else {
+            "completed"
+        };
+        return Err(TodoError::TaskAlreadyInStatus {
+            id,
+            status: status.to_owned(),
+        }
+        .into());
+    }
+
+    let reason = reason
+        .map(|r| r.trim().to_owned())
+        .filter(|r| !r.is_empty());
+    tasks[index].cancel(reason);
+    storage.upsert_task(&tasks[index])?;
+    storage.record_event(EntityType::Task, tasks[index].uuid, EventType::Cancelled)?;
+
+    println!(
+        "{}",
+        tf(Msg::TaskCancelled, &[&format!("#{}", id).dimmed()])
+    );
+    if let Some(reason) = &tasks[index].cancel_reason {
+        println!("  {} {}", "·".dimmed(), reason.dimmed());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, StatusFilter, Task};
+    use crate::storage::InMemoryStorage;
+
+    fn make_task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    #[test]
+    fn test_cancel_closes_without_completing() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("A")]).unwrap();
+
+        execute(&storage, 1, Some("  out of scope ".into())).unwrap();
+
+        let task = &storage.load().unwrap()[0];
+        assert!(task.completed);
+        assert!(task.is_cancelled());
+        assert!(task.completed_at.is_none());
+        assert_eq!(task.cancel_reason.as_deref(), Some("out of scope"));
+        assert!(task.matches_status(StatusFilter::Cancelled));
+        assert!(!task.matches_status(StatusFilter::Done));
+        assert!(!task.matches_status(StatusFilter::Pending));
+    }
+
+    #[test]
+    fn test_cancel_rejects_closed_task_and_undone_reopens() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("A")]).unwrap();
+
+        execute(&storage, 1, None).unwrap();
+        assert!(execute(&storage, 1, None).is_err());
+
+        crate::commands::task::undone::execute(&storage, 1).unwrap();
+        let task = &storage.load().unwrap()[0];
+        assert!(!task.completed);
+        assert!(!task.is_cancelled());
+    }
+}
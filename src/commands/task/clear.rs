@@ -1,12 +1,34 @@
-//! Handler for `todo clear`.
+//! Handler for `todo clear` and `todo clear --undo`.
+//!
+//! Every clear stores the removed tasks (and which notes pointed at them) in a
+//! single `clear` snapshot. `todo clear --undo` restores that snapshot as long
+//! as it is younger than [`UNDO_GRACE_HOURS`]; a newer clear replaces it.
 
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Duration, Utc};
 use colored::Colorize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::models::{Task, TaskSource};
 use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::confirm;
 
+/// Storage key of the snapshot written by `todo clear`.
+const SNAPSHOT_NAME: &str = "clear";
+
+/// How long after a clear `todo clear --undo` is still accepted.
+const UNDO_GRACE_HOURS: i64 = 24;
+
+/// The task list as it was just before a clear.
+#[derive(Debug, Serialize, Deserialize)]
+struct ClearSnapshot {
+    taken_at: DateTime<Utc>,
+    tasks: Vec<Task>,
+    /// `(note, task)` links that the clear removed.
+    note_links: Vec<(Uuid, Uuid)>,
+}
+
 pub fn execute(storage: &impl Storage, yes: bool) -> Result<()> {
     let (mut tasks, projects, mut notes) = storage.load_all()?;
 
@@ -19,7 +41,7 @@ pub fn execute(storage: &impl Storage, yes: bool) -> Result<()> {
 
     if !yes {
         println!(
-            "\n{} {} tasks will be permanently deleted!",
+            "\n{} {} tasks will be removed!",
             "".yellow().bold(),
             visible_count
         );
@@ -35,6 +57,12 @@ pub fn execute(storage: &impl Storage, yes: bool) -> Result<()> {
         .map(|t| t.uuid)
         .collect();
 
+    let mut snapshot = ClearSnapshot {
+        taken_at: Utc::now(),
+        tasks: tasks.iter().filter(|t| !t.is_deleted()).cloned().collect(),
+        note_links: Vec::new(),
+    };
+
     for task in tasks.iter_mut().filter(|t| !t.is_deleted()) {
         task.soft_delete();
     }
@@ -44,6 +72,7 @@ pub fn execute(storage: &impl Storage, yes: bool) -> Result<()> {
         if let Some(tid) = note.task_id
             && deleted_uuids.contains(&tid)
         {
+            snapshot.note_links.push((note.uuid, tid));
             note.task_id = None;
             note.touch();
             notes_updated += 1;
@@ -51,6 +80,7 @@ pub fn execute(storage: &impl Storage, yes: bool) -> Result<()> {
     }
 
     storage.save_all(&tasks, &projects, &notes)?;
+    storage.save_snapshot(SNAPSHOT_NAME, &serde_json::to_string(&snapshot)?)?;
 
     // Record one Deleted event per task
     for uuid in &deleted_uuids {
@@ -70,10 +100,102 @@ pub fn execute(storage: &impl Storage, yes: bool) -> Result<()> {
             if notes_updated == 1 { "" } else { "s" }
         );
     }
+    println!(
+        "  {} Run {} within {}h to restore them",
+        "·".dimmed(),
+        "todo clear --undo".cyan(),
+        UNDO_GRACE_HOURS
+    );
+
+    Ok(())
+}
+
+/// Restores the tasks removed by the last `todo clear`.
+pub fn execute_undo(storage: &impl Storage) -> Result<()> {
+    let Some(payload) = storage.load_snapshot(SNAPSHOT_NAME)? else {
+        bail!("Nothing to undo: no clear snapshot found");
+    };
+    let snapshot: ClearSnapshot =
+        serde_json::from_str(&payload).context("Clear snapshot is corrupted")?;
+
+    if Utc::now() - snapshot.taken_at > Duration::hours(UNDO_GRACE_HOURS) {
+        storage.delete_snapshot(SNAPSHOT_NAME)?;
+        bail!(
+            "The last clear was more than {}h ago and can no longer be undone",
+            UNDO_GRACE_HOURS
+        );
+    }
 
+    let restored = restore(storage, &snapshot)?;
+    storage.delete_snapshot(SNAPSHOT_NAME)?;
+
+    println!(
+        "{} {} task{} restored",
+        "✓".green().bold(),
+        restored,
+        if restored == 1 { "" } else { "s" }
+    );
     Ok(())
 }
 
+/// Brings snapshot tasks back, re-inserting any that were purged meanwhile,
+/// and re-links notes that are still unlinked. Returns the number restored.
+fn restore(storage: &impl Storage, snapshot: &ClearSnapshot) -> Result<usize> {
+    let (mut tasks, projects, mut notes) = storage.load_all()?;
+    let mut restored: Vec<Uuid> = Vec::new();
+    let mut purged: Vec<Task> = Vec::new();
+
+    for saved in &snapshot.tasks {
+        match tasks.iter_mut().find(|t| t.uuid == saved.uuid) {
+            Some(task) if task.is_deleted() => {
+                task.deleted_at = None;
+                task.touch();
+                restored.push(task.uuid);
+            }
+            Some(_) => {}
+            None => {
+                let mut task = saved.clone();
+                task.source = Some(TaskSource::Restored("clear snapshot".to_string()));
+                task.touch();
+                restored.push(task.uuid);
+                purged.push(task);
+            }
+        }
+    }
+
+    // Purged rows go in first without links so parent/dependency references
+    // between them are satisfied when the full list is written below.
+    if !purged.is_empty() {
+        let bare: Vec<Task> = purged
+            .iter()
+            .cloned()
+            .map(|mut t| {
+                t.parent_id = None;
+                t.depends_on.clear();
+                t
+            })
+            .collect();
+        storage.save(&bare)?;
+        tasks.extend(purged);
+    }
+
+    for (note_uuid, task_uuid) in &snapshot.note_links {
+        if let Some(note) = notes
+            .iter_mut()
+            .find(|n| n.uuid == *note_uuid && !n.is_deleted() && n.task_id.is_none())
+        {
+            note.task_id = Some(*task_uuid);
+            note.touch();
+        }
+    }
+
+    storage.save_all(&tasks, &projects, &notes)?;
+    for uuid in &restored {
+        storage.record_event(EntityType::Task, *uuid, EventType::Edited)?;
+    }
+    Ok(restored.len())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,4 +270,68 @@ mod tests {
 
         assert!(!storage.load_notes().unwrap().is_empty());
     }
+
+    #[test]
+    fn test_clear_undo_restores_tasks_and_note_links() {
+        let storage = InMemoryStorage::default();
+        let mut already = make_task("Already deleted");
+        already.soft_delete();
+        let task = make_task("Task");
+        let task_uuid = task.uuid;
+        storage.save(&[already, task]).unwrap();
+        let mut note = Note::new("Note".into());
+        note.task_id = Some(task_uuid);
+        storage.save_notes(&[note]).unwrap();
+
+        execute(&storage, true).unwrap();
+        execute_undo(&storage).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert!(
+            tasks[0].is_deleted(),
+            "previously deleted task stays deleted"
+        );
+        assert!(!tasks[1].is_deleted());
+        assert_eq!(storage.load_notes().unwrap()[0].task_id, Some(task_uuid));
+        assert!(storage.load_snapshot(SNAPSHOT_NAME).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_clear_undo_reinserts_purged_tasks() {
+        let storage = InMemoryStorage::default();
+        let task = make_task("Task");
+        let uuid = task.uuid;
+        storage.save(&[task]).unwrap();
+
+        execute(&storage, true).unwrap();
+        storage.delete_tasks(&[uuid]).unwrap();
+        execute_undo(&storage).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert!(!tasks[0].is_deleted());
+    }
+
+    #[test]
+    fn test_clear_undo_rejects_expired_snapshot() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Task")]).unwrap();
+        execute(&storage, true).unwrap();
+
+        let payload = storage.load_snapshot(SNAPSHOT_NAME).unwrap().unwrap();
+        let mut snapshot: ClearSnapshot = serde_json::from_str(&payload).unwrap();
+        snapshot.taken_at -= Duration::hours(UNDO_GRACE_HOURS + 1);
+        storage
+            .save_snapshot(SNAPSHOT_NAME, &serde_json::to_string(&snapshot).unwrap())
+            .unwrap();
+
+        assert!(execute_undo(&storage).is_err());
+        assert!(storage.load().unwrap()[0].is_deleted());
+    }
+
+    #[test]
+    fn test_clear_undo_without_snapshot_errors() {
+        let storage = InMemoryStorage::default();
+        assert!(execute_undo(&storage).is_err());
+    }
 }
@@ -1,17 +1,37 @@
 //! Handler for `todo deps <ID>`.
 //!
 //! Prints a dependency graph for a single task showing:
-//! - Tasks it depends on, with their completion status
-//! - Tasks that depend on it (reverse edges)
-//! - Whether the task is currently blocked, and by which IDs
+//! - Tasks it depends on, as a tree of transitive dependencies
+//! - Tasks that depend on it (reverse edges), also as a tree
+//!
+//! Each node uses the main table's cues: a `[x]`/`[ ]` checkbox, the
+//! colored priority letter, and the colored due date.
+//! - Whether the task is currently blocked, by which IDs, and any wait date
+//!
+//! `todo deps why <A> [B]` explains a block instead: the shortest
+//! dependency paths from A to B, or from A to each root blocker — a pending
+//! task in A's way that is not itself waiting on anything.
+
+use std::collections::VecDeque;
 
 use anyhow::Result;
 use colored::Colorize;
+use serde_json::{Value, json};
+use uuid::Uuid;
 
+use crate::cli::OutputFormat;
+use crate::models::Task;
+use crate::render::formatting::{first_line, get_due_colored, get_due_text, glyph, priority_label};
+use crate::render::structured;
 use crate::storage::Storage;
 use crate::utils::validation::{resolve_visible_index, visible_indices};
 
 pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
+    execute_as(storage, id, OutputFormat::Table)
+}
+
+/// Like [`execute`], printing in the given output format.
+pub fn execute_as(storage: &impl Storage, id: usize, format: OutputFormat) -> Result<()> {
     let tasks = storage.load()?;
 
     let real_index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
@@ -20,81 +40,64 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     let task = &tasks[real_index];
     let vis = visible_indices(&tasks, |t| t.is_deleted());
 
+    if format != OutputFormat::Table {
+        return print_structured(&tasks, task, id, &vis, format);
+    }
+
     println!(
         "\n{} #{}: {}\n",
         "Task".dimmed(),
         id,
-        task.text.bright_white()
+        first_line(&task.text).bright_white()
     );
 
     // Helper: visible ID for a real array index
     let vis_id =
         |real: usize| -> Option<usize> { vis.iter().position(|&i| i == real).map(|p| p + 1) };
 
-    // === This task depends on ===
+    // === This task depends on (transitively) ===
     if task.depends_on.is_empty() {
         println!("{}", "  No dependencies.".dimmed());
     } else {
         println!("{}:", "  Depends on".dimmed());
-        for dep_uuid in &task.depends_on {
-            if let Some(real_pos) = tasks.iter().position(|t| t.uuid == *dep_uuid) {
-                let dep = &tasks[real_pos];
-                let dep_vis_id = vis_id(real_pos);
-                let status = if dep.completed {
-                    "✓".green()
-                } else {
-                    "◦".red()
-                };
-                let label = if dep.completed {
-                    dep.text.dimmed()
-                } else {
-                    dep.text.bright_white()
-                };
-                if let Some(did) = dep_vis_id {
-                    println!("    {} #{} — {}", status, did, label);
-                } else {
-                    println!("    {} [deleted] — {}", status, label);
-                }
-            } else {
-                println!("    {} — {}", "?".yellow(), "(task not found)".dimmed());
-            }
-        }
+        let mut path = vec![task.uuid];
+        print_tree(
+            &tasks,
+            task,
+            Direction::Upstream,
+            "    ",
+            &mut path,
+            &vis_id,
+        );
     }
 
-    // === Tasks that depend on this one ===
-    let task_uuid = task.uuid;
-    let dependents: Vec<(usize, &_)> = tasks
-        .iter()
-        .enumerate()
-        .filter(|(i, t)| *i != real_index && t.depends_on.contains(&task_uuid) && !t.is_deleted())
-        .filter_map(|(real_pos, t)| vis_id(real_pos).map(|vid| (vid, t)))
-        .collect();
-
+    // === Tasks that depend on this one (transitively) ===
     println!();
-    if dependents.is_empty() {
+    if dependents(&tasks, task.uuid).is_empty() {
         println!("{}", "  No tasks depend on this one.".dimmed());
     } else {
         println!("{}:", "  Required by".dimmed());
-        for (dep_vis_id, dep_task) in &dependents {
-            let status = if dep_task.completed {
-                "✓".green()
-            } else {
-                "◦".yellow()
-            };
-            println!(
-                "    {} #{} — {}",
-                status,
-                dep_vis_id,
-                dep_task.text.bright_white()
-            );
-        }
+        let mut path = vec![task.uuid];
+        print_tree(
+            &tasks,
+            task,
+            Direction::Downstream,
+            "    ",
+            &mut path,
+            &vis_id,
+        );
     }
 
     // === Blocked status ===
     println!();
     let visible_tasks: Vec<_> = tasks.iter().filter(|t| !t.is_deleted()).cloned().collect();
-    if task.is_blocked(&visible_tasks) {
-        let blocking = task.blocking_deps(&visible_tasks);
+    if task.is_waiting()
+        && let Some(date) = task.wait_until
+    {
+        println!("  Waiting until: {}", date.to_string().red());
+    }
+    let blocking = task.blocking_deps(&visible_tasks);
+    if !blocking.is_empty() {
         let ids = blocking
             .iter()
             .filter_map(|uuid| {
@@ -111,3 +114,389 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     println!();
     Ok(())
 }
+
+/// Most paths printed by `todo deps why <A> <B>`.
+const MAX_WHY_PATHS: usize = 20;
+
+/// `todo deps why <A> [B]`.
+pub fn execute_why(
+    storage: &impl Storage,
+    from: usize,
+    to: Option<usize>,
+    format: OutputFormat,
+) -> Result<()> {
+    let tasks = storage.load()?;
+    let resolve = |id| {
+        resolve_visible_index(&tasks, id, |t| t.is_deleted())
+            .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))
+    };
+    let from_index = resolve(from)?;
+    let to_index = to.map(resolve).transpose()?;
+
+    let vis = visible_indices(&tasks, |t| t.is_deleted());
+    let vis_id = |real: usize| vis.iter().position(|&i| i == real).map_or(0, |p| p + 1);
+    let paths = why_paths(&tasks, from_index, to_index);
+
+    match format {
+        OutputFormat::Json => {
+            let paths: Vec<Vec<usize>> = paths
+                .iter()
+                .map(|path| path.iter().map(|&i| vis_id(i)).collect())
+                .collect();
+            return structured::print_json(&json!({
+                "from": from,
+                "to": to,
+                "paths": paths,
+            }));
+        }
+        OutputFormat::Plain => {
+            for path in &paths {
+                let ids: Vec<String> = path.iter().map(|&i| vis_id(i).to_string()).collect();
+                println!("{}", ids.join("\t"));
+            }
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    println!();
+    if paths.is_empty() {
+        let msg = match to {
+            Some(to) => format!("#{} does not depend on #{}.", from, to),
+            None => format!("#{} is not blocked by any task.", from),
+        };
+        println!("  {}\n", msg.dimmed());
+        return Ok(());
+    }
+
+    let heading = match to {
+        Some(to) => format!("#{} depends on #{} through:", from, to),
+        None => format!("#{} is blocked by:", from),
+    };
+    println!("  {}\n", heading.dimmed());
+    for path in &paths {
+        let nodes: Vec<String> = path
+            .iter()
+            .map(|&i| {
+                let task = &tasks[i];
+                let id = format!("#{}", vis_id(i));
+                let text = first_line(&task.text);
+                if task.completed {
+                    format!("{} {}", id.dimmed(), text.dimmed())
+                } else {
+                    format!("{} {}", id.yellow(), text.bright_white())
+                }
+            })
+            .collect();
+        println!("    {}", nodes.join(&format!(" {} ", "→".dimmed())));
+    }
+    println!();
+    Ok(())
+}
+
+/// Shortest dependency paths starting at `tasks[from]`, as real indices.
+///
+/// With a target, every shortest path to `tasks[to]` along `depends_on`
+/// edges (up to [`MAX_WHY_PATHS`]). Without one, one shortest path to each
+/// root blocker, following only pending dependencies. Paths start with
+/// `from`; an empty result means there is no such path.
+pub fn why_paths(tasks: &[Task], from: usize, to: Option<usize>) -> Vec<Vec<usize>> {
+    let pending_only = to.is_none();
+    let deps = |i: usize| -> Vec<usize> {
+        tasks[i]
+            .depends_on
+            .iter()
+            .filter_map(|uuid| tasks.iter().position(|t| t.uuid == *uuid))
+            .filter(|&j| !tasks[j].is_deleted())
+            .filter(|&j| !(pending_only && tasks[j].completed))
+            .collect()
+    };
+
+    // BFS keeping every predecessor at the shortest distance
+    let mut dist: Vec<Option<usize>> = vec![None; tasks.len()];
+    let mut parents: Vec<Vec<usize>> = vec![Vec::new(); tasks.len()];
+    let mut order = Vec::new();
+    let mut queue = VecDeque::from([from]);
+    dist[from] = Some(0);
+    while let Some(i) = queue.pop_front() {
+        order.push(i);
+        let next = dist[i].unwrap_or(0) + 1;
+        for j in deps(i) {
+            match dist[j] {
+                None => {
+                    dist[j] = Some(next);
+                    parents[j].push(i);
+                    queue.push_back(j);
+                }
+                Some(d) if d == next => parents[j].push(i),
+                Some(_) => {}
+            }
+        }
+    }
+
+    match to {
+        Some(to) if to != from && dist[to].is_some() => {
+            let mut paths = Vec::new();
+            collect_paths(&parents, from, to, &mut vec![to], &mut paths);
+            paths
+        }
+        Some(_) => Vec::new(),
+        None => order
+            .into_iter()
+            .filter(|&i| i != from && deps(i).is_empty())
+            .map(|root| {
+                let mut path = vec![root];
+                while let Some(&parent) = path.last().and_then(|&i| parents[i].first()) {
+                    path.push(parent);
+                }
+                path.reverse();
+                path
+            })
+            .collect(),
+    }
+}
+
+/// Walks `parents` back from the end of `suffix` to `from`, pushing each
+/// complete path (in `from`-first order) onto `paths`.
+fn collect_paths(
+    parents: &[Vec<usize>],
+    from: usize,
+    node: usize,
+    suffix: &mut Vec<usize>,
+    paths: &mut Vec<Vec<usize>>,
+) {
+    if paths.len() >= MAX_WHY_PATHS {
+        return;
+    }
+    if node == from {
+        paths.push(suffix.iter().rev().copied().collect());
+        return;
+    }
+    for &parent in &parents[node] {
+        suffix.push(parent);
+        collect_paths(parents, from, parent, suffix, paths);
+        suffix.pop();
+    }
+}
+
+/// `--format json|plain` output: both trees as nested nodes.
+///
+/// Plain prints one node per line as `direction depth id status text`,
+/// depth-first, with `-` for the ID of a deleted or missing task.
+fn print_structured(
+    tasks: &[Task],
+    task: &Task,
+    id: usize,
+    vis: &[usize],
+    format: OutputFormat,
+) -> Result<()> {
+    let vis_id =
+        |real: usize| -> Option<usize> { vis.iter().position(|&i| i == real).map(|p| p + 1) };
+    let upstream = tree_json(
+        tasks,
+        task,
+        Direction::Upstream,
+        &mut vec![task.uuid],
+        &vis_id,
+    );
+    let downstream = tree_json(
+        tasks,
+        task,
+        Direction::Downstream,
+        &mut vec![task.uuid],
+        &vis_id,
+    );
+
+    if format == OutputFormat::Plain {
+        print_plain_nodes("depends_on", 1, &upstream);
+        print_plain_nodes("required_by", 1, &downstream);
+        return Ok(());
+    }
+
+    let visible_tasks: Vec<_> = tasks.iter().filter(|t| !t.is_deleted()).cloned().collect();
+    let blocked_by: Vec<_> = task
+        .blocking_deps(&visible_tasks)
+        .iter()
+        .filter_map(|uuid| tasks.iter().position(|t| t.uuid == *uuid))
+        .filter_map(vis_id)
+        .collect();
+    structured::print_json(&json!({
+        "id": id,
+        "text": task.text,
+        "wait_until": task.wait_until,
+        "blocked_by": blocked_by,
+        "depends_on": upstream,
+        "required_by": downstream,
+    }))
+}
+
+/// JSON nodes for the children of `task`; same traversal as [`print_tree`].
+fn tree_json(
+    tasks: &[Task],
+    task: &Task,
+    direction: Direction,
+    path: &mut Vec<Uuid>,
+    vis_id: &dyn Fn(usize) -> Option<usize>,
+) -> Vec<Value> {
+    children(tasks, task, direction)
+        .into_iter()
+        .map(|child| {
+            let Some(child) = child else {
+                return json!({ "id": null, "missing": true });
+            };
+            let id = tasks
+                .iter()
+                .position(|t| t.uuid == child.uuid)
+                .and_then(vis_id);
+            let cycle = path.contains(&child.uuid);
+            let nested = if cycle {
+                vec![]
+            } else {
+                path.push(child.uuid);
+                let nested = tree_json(tasks, child, direction, path, vis_id);
+                path.pop();
+                nested
+            };
+            json!({
+                "id": id,
+                "text": child.text,
+                "completed": child.completed,
+                "priority": child.priority,
+                "due_date": child.due_date,
+                "cycle": cycle,
+                "children": nested,
+            })
+        })
+        .collect()
+}
+
+fn print_plain_nodes(direction: &str, depth: usize, nodes: &[Value]) {
+    for node in nodes {
+        let id = node["id"]
+            .as_u64()
+            .map_or("-".to_string(), |id| id.to_string());
+        let status = if node["missing"] == true {
+            "missing"
+        } else if node["completed"] == true {
+            "done"
+        } else {
+            "pending"
+        };
+        let text = node["text"].as_str().map(first_line).unwrap_or("-");
+        println!("{}\t{}\t{}\t{}\t{}", direction, depth, id, status, text);
+        if let Some(children) = node["children"].as_array() {
+            print_plain_nodes(direction, depth + 1, children);
+        }
+    }
+}
+
+/// Which edges [`print_tree`] (and the TUI dependency pane) follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// `depends_on` edges: what this task waits for.
+    Upstream,
+    /// Reverse edges: what waits for this task.
+    Downstream,
+}
+
+/// Non-deleted tasks that list `uuid` in their `depends_on`.
+pub fn dependents(tasks: &[Task], uuid: Uuid) -> Vec<&Task> {
+    tasks
+        .iter()
+        .filter(|t| !t.is_deleted() && t.depends_on.contains(&uuid))
+        .collect()
+}
+
+/// Neighbours of `task` along `direction`; `None` for a dangling UUID.
+pub fn children<'a>(tasks: &'a [Task], task: &Task, direction: Direction) -> Vec<Option<&'a Task>> {
+    match direction {
+        Direction::Upstream => task
+            .depends_on
+            .iter()
+            .map(|uuid| tasks.iter().find(|t| t.uuid == *uuid))
+            .collect(),
+        Direction::Downstream => dependents(tasks, task.uuid).into_iter().map(Some).collect(),
+    }
+}
+
+/// Prints the children of `task` with `├─`/`└─` connectors and recurses.
+///
+/// `path` holds the UUIDs from the root down to `task`; a child already on
+/// it is printed once and marked as a cycle instead of being expanded.
+fn print_tree(
+    tasks: &[Task],
+    task: &Task,
+    direction: Direction,
+    prefix: &str,
+    path: &mut Vec<Uuid>,
+    vis_id: &dyn Fn(usize) -> Option<usize>,
+) {
+    let children = children(tasks, task, direction);
+
+    for (i, child) in children.iter().enumerate() {
+        let last = i + 1 == children.len();
+        let connector = if last {
+            glyph("└─ ", "`- ")
+        } else {
+            glyph("├─ ", "|- ")
+        };
+        let Some(child) = child else {
+            println!(
+                "{}{}{} {}",
+                prefix,
+                connector.dimmed(),
+                "?".yellow(),
+                "(task not found)".dimmed()
+            );
+            continue;
+        };
+
+        let real_pos = tasks.iter().position(|t| t.uuid == child.uuid);
+        let id = match real_pos.and_then(vis_id) {
+            Some(vid) => format!("#{}", vid).dimmed(),
+            None => "[deleted]".dimmed(),
+        };
+        let checkbox = if child.completed {
+            "[x]".green()
+        } else if matches!(direction, Direction::Upstream) {
+            "[ ]".red()
+        } else {
+            "[ ]".yellow()
+        };
+        let text = if child.completed {
+            first_line(&child.text).dimmed()
+        } else {
+            first_line(&child.text).bright_white()
+        };
+        let due_text = get_due_text(child);
+        let cycle = path.contains(&child.uuid);
+
+        print!(
+            "{}{}{} {} {} {}",
+            prefix,
+            connector.dimmed(),
+            checkbox,
+            priority_label(child.priority),
+            id,
+            text
+        );
+        if !due_text.is_empty() {
+            print!("  {}", get_due_colored(child, &due_text));
+        }
+        if cycle {
+            print!("  {}", "(cycle)".red());
+        }
+        println!();
+
+        if !cycle {
+            let child_prefix = format!(
+                "{}{}",
+                prefix,
+                if last { "   " } else { glyph("│  ", "|  ") }
+            );
+            path.push(child.uuid);
+            print_tree(tasks, child, direction, &child_prefix, path, vis_id);
+            path.pop();
+        }
+    }
+}
@@ -1,9 +1,14 @@
-//! Handler for `todo done <ID>`.
+//! Handler for `todo done <ID>...`.
 
 use anyhow::Result;
+use chrono::Local;
 use colored::Colorize;
 
+use crate::commands::stats::{completion_streak, streak_milestone};
+use crate::config::{Celebrate, Config};
 use crate::error::TodoError;
+use crate::i18n::{Msg, tf};
+use crate::models::Task;
 use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::validation::resolve_visible_index;
 
@@ -16,58 +21,99 @@ pub fn execute_silent(storage: &impl Storage, id: usize) -> Result<String> {
     execute_inner(storage, id, true)
 }
 
-fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<String> {
+/// `todo done <ID>...` — completes every task or none.
+///
+/// All IDs are checked against the in-memory list (so a task may depend on
+/// one completed earlier in the same call) before a single save.
+pub fn execute_many(storage: &impl Storage, ids: &[usize]) -> Result<()> {
+    if let [id] = ids {
+        return execute(storage, *id);
+    }
+
     let mut tasks = storage.load()?;
+    let streak_before = completion_streak(&tasks, Local::now().date_naive());
+    let mut events = Vec::new();
+    let mut created = Vec::new();
 
-    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
-        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+    for &id in ids {
+        let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+            .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+        check_can_complete(&tasks, index, id)?;
 
-    if tasks[index].completed {
-        return Err(TodoError::TaskAlreadyInStatus {
-            id,
-            status: "completed".to_owned(),
+        tasks[index].mark_done();
+        events.push((tasks[index].uuid, EventType::Completed));
+
+        if let Some((missed, next_task)) = next_recurrence(&tasks, index) {
+            for task in missed {
+                events.push((task.uuid, EventType::Created));
+                events.push((task.uuid, EventType::Cancelled));
+                tasks.push(task);
+            }
+            events.push((next_task.uuid, EventType::Created));
+            created.push(next_task.uuid);
+            tasks.push(next_task);
         }
-        .into());
     }
 
-    let blocking = tasks[index].blocking_deps(&tasks);
-    if !blocking.is_empty() {
-        let vis: Vec<usize> = tasks
-            .iter()
-            .enumerate()
-            .filter(|(_, t)| !t.is_deleted())
-            .map(|(i, _)| i)
-            .collect();
-        let ids = blocking
-            .iter()
-            .filter_map(|uuid| {
-                let real_pos = tasks.iter().position(|t| t.uuid == *uuid)?;
-                let vis_id = vis.iter().position(|&i| i == real_pos).map(|p| p + 1)?;
-                let text = tasks[real_pos].text.clone();
-                Some(format!("#{} \"{}\"", vis_id, text))
-            })
-            .collect::<Vec<_>>()
-            .join(", ");
-        return Err(TodoError::TaskBlocked(id, ids).into());
+    storage.save(&tasks)?;
+    for (uuid, event) in events {
+        storage.record_event(EntityType::Task, uuid, event)?;
     }
 
+    let list = ids
+        .iter()
+        .map(|id| format!("#{}", id))
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!(
+        "{} {}",
+        "✓".green(),
+        tf(Msg::TasksDone, &[&ids.len(), &list.green()])
+    );
+    let vis: Vec<&Task> = tasks.iter().filter(|t| !t.is_deleted()).collect();
+    for (pos, task) in vis.iter().enumerate() {
+        if created.contains(&task.uuid) {
+            println!(
+                "Task {} created (due {})",
+                format!("#{}", pos + 1).yellow(),
+                task.due_date
+                    .map(|d| d.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default()
+            );
+            print_missed(task);
+        }
+    }
+    celebrate(streak_before, &tasks);
+    Ok(())
+}
+
+fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<String> {
+    let mut tasks = storage.load()?;
+
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+
+    check_can_complete(&tasks, index, id)?;
+
+    let streak_before = completion_streak(&tasks, Local::now().date_naive());
     tasks[index].mark_done();
     let task_uuid = tasks[index].uuid;
 
-    if let Some(next_task) = tasks[index].create_next_recurrence(task_uuid) {
-        let next_due = next_task.due_date.unwrap();
-        let already_exists = tasks.iter().any(|t| {
-            !t.completed
-                && t.due_date == Some(next_due)
-                && (t.parent_id == Some(task_uuid) || t.text == next_task.text)
-        });
-
-        if !already_exists {
+    let msg = if tasks[index].recurrence.is_some() && tasks[index].due_date.is_some() {
+        if let Some((missed, next_task)) = next_recurrence(&tasks, index) {
+            let next_due = next_task.due_date.unwrap();
             let next_uuid = next_task.uuid;
-            let next_vis_id = tasks.iter().filter(|t| !t.is_deleted()).count() + 1;
+            let next_vis_id = tasks.iter().filter(|t| !t.is_deleted()).count() + missed.len() + 1;
             storage.upsert_task(&tasks[index])?;
+            for task in &missed {
+                storage.upsert_task(task)?;
+            }
             storage.upsert_task(&next_task)?;
             storage.record_event(EntityType::Task, task_uuid, EventType::Completed)?;
+            for task in &missed {
+                storage.record_event(EntityType::Task, task.uuid, EventType::Created)?;
+                storage.record_event(EntityType::Task, task.uuid, EventType::Cancelled)?;
+            }
             storage.record_event(EntityType::Task, next_uuid, EventType::Created)?;
 
             let msg = format!(
@@ -77,40 +123,136 @@ fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<Stri
                 next_due.format("%Y-%m-%d")
             );
             if !silent {
-                println!("Task {} marked as done.", format!("#{}", id).green());
+                println!("{}", tf(Msg::TaskDone, &[&format!("#{}", id).green()]));
                 println!(
                     "Task {} created (due {})",
                     format!("#{}", next_vis_id).yellow(),
                     next_due.format("%Y-%m-%d")
                 );
+                print_missed(&next_task);
             }
-            Ok(msg)
+            msg
         } else {
             storage.upsert_task(&tasks[index])?;
             storage.record_event(EntityType::Task, task_uuid, EventType::Completed)?;
             if !silent {
-                println!("Task {} marked as done.", format!("#{}", id).green());
+                println!("{}", tf(Msg::TaskDone, &[&format!("#{}", id).green()]));
                 println!(
                     "{}",
                     "Next recurrence already exists, skipping creation.".dimmed()
                 );
             }
-            Ok(format!("Task #{} marked as done.", id))
+            tf(Msg::TaskDone, &[&format!("#{}", id)])
         }
     } else {
         storage.upsert_task(&tasks[index])?;
         storage.record_event(EntityType::Task, task_uuid, EventType::Completed)?;
         if !silent {
-            println!("Task {} marked as done.", format!("#{}", id).green());
+            println!("{}", tf(Msg::TaskDone, &[&format!("#{}", id).green()]));
+        }
+        tf(Msg::TaskDone, &[&format!("#{}", id)])
+    };
+
+    if !silent {
+        celebrate(streak_before, &tasks);
+    }
+    Ok(msg)
+}
+
+/// Prints a message (and rings the bell, if configured) when completing
+/// tasks took the daily streak past a milestone.
+fn celebrate(streak_before: u32, tasks: &[Task]) {
+    let mode = Config::defaults().celebrate;
+    if mode == Celebrate::Off {
+        return;
+    }
+    let after = completion_streak(tasks, Local::now().date_naive());
+    if let Some(days) = streak_milestone(streak_before, after) {
+        let bell = if mode == Celebrate::Bell { "\x07" } else { "" };
+        println!(
+            "{} {}{}",
+            "🎉".bold(),
+            tf(Msg::StreakMilestone, &[&days]).bright_magenta(),
+            bell
+        );
+    }
+}
+
+/// Fails if the task at `index` (shown as `#id`) cannot be completed now.
+fn check_can_complete(tasks: &[Task], index: usize, id: usize) -> Result<()> {
+    if tasks[index].completed {
+        let status = if tasks[index].is_cancelled() {
+            "cancelled"
+        } else {
+            "completed"
+        };
+        return Err(TodoError::TaskAlreadyInStatus {
+            id,
+            status: status.to_owned(),
         }
-        Ok(format!("Task #{} marked as done.", id))
+        .into());
+    }
+
+    if tasks[index].is_waiting()
+        && let Some(date) = tasks[index].wait_until
+    {
+        return Err(TodoError::TaskWaiting(id, date).into());
+    }
+
+    let blocking = tasks[index].blocking_deps(tasks);
+    if !blocking.is_empty() {
+        let vis: Vec<usize> = tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| !t.is_deleted())
+            .map(|(i, _)| i)
+            .collect();
+        let ids = blocking
+            .iter()
+            .filter_map(|uuid| {
+                let real_pos = tasks.iter().position(|t| t.uuid == *uuid)?;
+                let vis_id = vis.iter().position(|&i| i == real_pos).map(|p| p + 1)?;
+                let text = tasks[real_pos].text.clone();
+                Some(format!("#{} \"{}\"", vis_id, text))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(TodoError::TaskBlocked(id, ids).into());
+    }
+    Ok(())
+}
+
+/// The occurrences following the just-completed task at `index` (see
+/// [`Task::catch_up_recurrence`]), unless it is not recurring or the next
+/// occurrence already exists.
+fn next_recurrence(tasks: &[Task], index: usize) -> Option<(Vec<Task>, Task)> {
+    let task_uuid = tasks[index].uuid;
+    let today = chrono::Local::now().date_naive();
+    let (missed, next_task) = tasks[index].catch_up_recurrence(task_uuid, today)?;
+    let next_due = next_task.due_date;
+    let already_exists = tasks.iter().any(|t| {
+        !t.completed
+            && t.due_date == next_due
+            && (t.parent_id == Some(task_uuid) || t.text == next_task.text)
+    });
+    (!already_exists).then_some((missed, next_task))
+}
+
+fn print_missed(next_task: &Task) {
+    if next_task.missed > 0 {
+        println!(
+            "  {} {} missed occurrence(s) skipped ({})",
+            "·".dimmed(),
+            next_task.missed,
+            next_task.catch_up
+        );
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Priority, Task};
+    use crate::models::Priority;
     use crate::storage::InMemoryStorage;
 
     fn make_task(text: &str) -> Task {
@@ -149,6 +291,17 @@ mod tests {
         assert!(err.to_string().contains("completed"));
     }
 
+    #[test]
+    fn test_done_waiting_task_returns_error() {
+        let storage = InMemoryStorage::default();
+        let mut task = make_task("Sign contract");
+        task.wait_until = chrono::Local::now().date_naive().succ_opt();
+        storage.save(&[task]).unwrap();
+
+        let err = execute_silent(&storage, 1).unwrap_err();
+        assert!(err.to_string().contains("waiting until"));
+    }
+
     #[test]
     fn test_done_does_not_affect_other_tasks() {
         let storage = InMemoryStorage::default();
@@ -178,4 +331,31 @@ mod tests {
         assert!(!tasks[0].completed); // deleted stays untouched
         assert!(tasks[1].completed); // active gets marked done
     }
+
+    #[test]
+    fn test_done_many_is_all_or_nothing() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save(&[make_task("Task A"), make_task("Task B")])
+            .unwrap();
+
+        assert!(execute_many(&storage, &[1, 2, 9]).is_err());
+
+        let tasks = storage.load().unwrap();
+        assert!(tasks.iter().all(|t| !t.completed));
+    }
+
+    #[test]
+    fn test_done_many_allows_dependency_in_same_call() {
+        let storage = InMemoryStorage::default();
+        let first = make_task("First");
+        let mut second = make_task("Second");
+        second.depends_on = vec![first.uuid];
+        storage.save(&[first, second]).unwrap();
+
+        execute_many(&storage, &[1, 2]).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert!(tasks.iter().all(|t| t.completed));
+    }
 }
@@ -1,15 +1,18 @@
-//! Handler for `todo edit <ID>`.
+//! Handler for `todo edit <ID>`, and `todo edit <ID>... --replace-text` for
+//! rewriting several descriptions at once.
 
 use anyhow::Result;
+use chrono::NaiveDate;
 use colored::Colorize;
 use uuid::Uuid;
 
 use crate::cli::EditArgs;
 use crate::error::TodoError;
-use crate::models::{Project, detect_cycle};
+use crate::models::{Project, detect_cycle, local_midnight};
+use crate::render::formatting::priority_label;
 use crate::storage::{EntityType, EventType, Storage};
-use crate::utils::date_parser;
 use crate::utils::validation::{self, validate_task_id, visible_indices};
+use crate::utils::{date_parser, id_list, stdin_arg};
 
 pub fn execute(storage: &impl Storage, args: EditArgs) -> Result<()> {
     execute_inner(storage, args, false)?;
@@ -21,11 +24,38 @@ pub fn execute_silent(storage: &impl Storage, args: EditArgs) -> Result<String>
 }
 
 fn execute_inner(storage: &impl Storage, args: EditArgs, silent: bool) -> Result<String> {
-    let due = if let Some(ref due_str) = args.due {
-        Some(date_parser::parse_date(due_str)?)
-    } else {
-        None
-    };
+    let due = args
+        .due
+        .as_deref()
+        .map(date_parser::parse_due)
+        .transpose()?;
+
+    if (args.created.is_some() || args.completed.is_some()) && !args.force_metadata {
+        return Err(anyhow::anyhow!(
+            "--created and --completed rewrite recorded history; pass --force-metadata to confirm"
+        ));
+    }
+    let wait_until = args
+        .wait_until
+        .as_deref()
+        .map(date_parser::parse_date)
+        .transpose()?;
+    let start_date = args
+        .start
+        .as_deref()
+        .map(date_parser::parse_date)
+        .transpose()?;
+    let today = chrono::Local::now().date_naive();
+    let created = args
+        .created
+        .as_deref()
+        .map(|s| parse_past_date(s, today))
+        .transpose()?;
+    let completed = args
+        .completed
+        .as_deref()
+        .map(|s| parse_past_date(s, today))
+        .transpose()?;
 
     let mut tasks = storage.load()?;
     let vis = visible_indices(&tasks, |t| t.is_deleted());
@@ -86,7 +116,7 @@ fn execute_inner(storage: &impl Storage, args: EditArgs, silent: bool) -> Result
 
     let task = &mut tasks[real_index];
 
-    if let Some(new_text) = args.text {
+    if let Some(new_text) = args.text.map(stdin_arg::resolve).transpose()? {
         if new_text.trim().is_empty() {
             return Err(anyhow::anyhow!("Task text cannot be empty"));
         }
@@ -96,11 +126,24 @@ fn execute_inner(storage: &impl Storage, args: EditArgs, silent: bool) -> Result
         }
     }
 
+    if let Some(ref sub) = args.replace_text {
+        let new_text = sub
+            .apply(&task.text)
+            .ok_or_else(|| anyhow::anyhow!("Pattern not found in task #{}", args.id))?;
+        if new_text.trim().is_empty() {
+            return Err(anyhow::anyhow!("Task text cannot be empty"));
+        }
+        if task.text != new_text {
+            changes.push(format!("text → {}", new_text.bright_white()));
+            task.text = new_text;
+        }
+    }
+
     if let Some(new_priority) = args.priority
         && task.priority != new_priority
     {
         task.priority = new_priority;
-        changes.push(format!("priority → {}", new_priority.letter()));
+        changes.push(format!("priority → {}", priority_label(new_priority)));
     }
 
     if args.clear_project {
@@ -167,13 +210,19 @@ fn execute_inner(storage: &impl Storage, args: EditArgs, silent: bool) -> Result
     if args.clear_due {
         if task.due_date.is_some() {
             task.due_date = None;
+            task.due_time = None;
             changes.push("due date → cleared".dimmed().to_string());
         }
-    } else if let Some(new_due) = due
-        && task.due_date != Some(new_due)
+    } else if let Some((new_due, new_time)) = due
+        && (task.due_date, task.due_time) != (Some(new_due), new_time)
     {
         task.due_date = Some(new_due);
-        changes.push(format!("due date → {}", new_due.to_string().cyan()));
+        task.due_time = new_time;
+        let shown = match new_time {
+            Some(time) => format!("{} {}", new_due, time.format("%H:%M")),
+            None => new_due.to_string(),
+        };
+        changes.push(format!("due date → {}", shown.cyan()));
     }
 
     if args.clear_deps {
@@ -209,6 +258,110 @@ fn execute_inner(storage: &impl Storage, args: EditArgs, silent: bool) -> Result
         }
     }
 
+    if args.clear_wait {
+        if task.wait_until.is_some() {
+            task.wait_until = None;
+            changes.push("wait until → cleared".dimmed().to_string());
+        }
+    } else if let Some(date) = wait_until
+        && task.wait_until != Some(date)
+    {
+        task.wait_until = Some(date);
+        changes.push(format!("wait until → {}", date.to_string().cyan()));
+    }
+
+    if args.clear_start {
+        if task.start_date.is_some() {
+            task.start_date = None;
+            changes.push("start → cleared".dimmed().to_string());
+        }
+    } else if let Some(date) = start_date
+        && task.start_date != Some(date)
+    {
+        task.start_date = Some(date);
+        changes.push(format!("start → {}", date.to_string().cyan()));
+    }
+    validation::validate_start_date(task.start_date, task.due_date)?;
+
+    if args.clear_estimate {
+        if task.estimate.is_some() {
+            task.estimate = None;
+            changes.push("estimate → cleared".dimmed().to_string());
+        }
+    } else if let Some(estimate) = args.estimate
+        && task.estimate != Some(estimate)
+    {
+        task.estimate = Some(estimate);
+        changes.push(format!("estimate → {}", estimate.to_string().cyan()));
+    }
+
+    if args.clear_label {
+        if task.label.is_some() {
+            task.label = None;
+            changes.push("label → cleared".dimmed().to_string());
+        }
+    } else if let Some(label) = args.label
+        && task.label != Some(label)
+    {
+        task.label = Some(label);
+        changes.push(format!("label → {} {}", label.bullet(), label));
+    }
+
+    if args.clear_billable {
+        if task.billable || task.hourly_rate.is_some() {
+            task.billable = false;
+            task.hourly_rate = None;
+            changes.push("billable → no".dimmed().to_string());
+        }
+    } else {
+        if let Some(rate) = args.rate {
+            if !rate.is_finite() || rate < 0.0 {
+                return Err(anyhow::anyhow!(
+                    "Invalid rate {}: must be zero or more",
+                    rate
+                ));
+            }
+            if task.hourly_rate != Some(rate) {
+                task.hourly_rate = Some(rate);
+                changes.push(format!("rate → {}", format!("{:.2}/h", rate).cyan()));
+            }
+        }
+        if (args.billable || args.rate.is_some()) && !task.billable {
+            task.billable = true;
+            changes.push(format!("billable → {}", "yes".green()));
+        }
+    }
+
+    if let Some(date) = created
+        && task.created_on() != date
+    {
+        task.created_at = local_midnight(date);
+        changes.push(format!("created → {}", date.to_string().yellow()));
+    }
+
+    if let Some(date) = completed {
+        if !task.completed {
+            return Err(anyhow::anyhow!(
+                "Task #{} is not completed; --completed only corrects done tasks",
+                args.id
+            ));
+        }
+        if task.completed_on() != Some(date) {
+            task.completed_at = Some(local_midnight(date));
+            changes.push(format!("completed → {}", date.to_string().yellow()));
+        }
+    }
+
+    if let (Some(done), created_on) = (task.completed_on(), task.created_on())
+        && done < created_on
+    {
+        return Err(anyhow::anyhow!(
+            "Completion date {} would precede creation date {}",
+            done,
+            created_on
+        ));
+    }
+
     if changes.is_empty() {
         if !silent {
             println!(
@@ -234,27 +387,92 @@ fn execute_inner(storage: &impl Storage, args: EditArgs, silent: bool) -> Result
     Ok(format!("Task #{} updated.", args.id))
 }
 
+/// `todo edit <ID> <ID>... --replace-text EXPR`: applies the substitution to
+/// every listed task. All IDs are validated and every result is checked
+/// before anything is saved; tasks the pattern does not match are skipped.
+pub fn execute_many(storage: &impl Storage, args: EditArgs) -> Result<()> {
+    let Some(sub) = args.replace_text else {
+        return Err(anyhow::anyhow!(
+            "Editing several tasks requires --replace-text"
+        ));
+    };
+    let mut ids = vec![args.id];
+    for id in id_list::expand(&args.more_ids) {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    let mut tasks = storage.load()?;
+    let vis = visible_indices(&tasks, |t| t.is_deleted());
+    let mut edits = Vec::new();
+    let mut unmatched = Vec::new();
+    for &id in &ids {
+        validate_task_id(id, vis.len())?;
+        let index = vis[id - 1];
+        match sub.apply(&tasks[index].text) {
+            Some(text) if text.trim().is_empty() => {
+                return Err(anyhow::anyhow!("Task #{} text would become empty", id));
+            }
+            Some(text) if text != tasks[index].text => edits.push((id, index, text)),
+            _ => unmatched.push(id),
+        }
+    }
+
+    for (_, index, text) in &edits {
+        let task = &mut tasks[*index];
+        task.text = text.clone();
+        task.touch();
+        storage.upsert_task(task)?;
+        storage.record_event(EntityType::Task, task.uuid, EventType::Edited)?;
+    }
+
+    if edits.is_empty() {
+        println!("{} No task text matched; nothing changed.", "".blue());
+        return Ok(());
+    }
+    println!(
+        "{} Replaced text in {} task{}:",
+        "✓".green(),
+        edits.len(),
+        if edits.len() == 1 { "" } else { "s" }
+    );
+    for (id, _, text) in &edits {
+        println!("  • #{} → {}", id, text.bright_white());
+    }
+    if !unmatched.is_empty() {
+        let list = unmatched
+            .iter()
+            .map(|id| format!("#{}", id))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("  {}", format!("unchanged: {}", list).dimmed());
+    }
+    Ok(())
+}
+
+/// Parses a metadata date, rejecting dates after `today`.
+fn parse_past_date(input: &str, today: NaiveDate) -> Result<NaiveDate> {
+    let date = date_parser::parse_date(input)?;
+    if date > today {
+        return Err(anyhow::anyhow!(
+            "Metadata dates cannot be in the future: {}",
+            date
+        ));
+    }
+    Ok(date)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Priority, Task};
+    use crate::models::{Label, Priority, Task};
     use crate::storage::InMemoryStorage;
 
     fn args(id: usize) -> EditArgs {
         EditArgs {
             id,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         }
     }
 
@@ -313,6 +531,88 @@ mod tests {
         assert!(result.contains("No changes"));
     }
 
+    #[test]
+    fn test_edit_replace_text() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save(&[make_task("Fix login on login page")])
+            .unwrap();
+
+        execute_silent(
+            &storage,
+            EditArgs {
+                replace_text: Some("s/login/sign-in/g".parse().unwrap()),
+                ..args(1)
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            storage.load().unwrap()[0].text,
+            "Fix sign-in on sign-in page"
+        );
+    }
+
+    #[test]
+    fn test_edit_replace_text_without_match_fails() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Write docs")]).unwrap();
+
+        let result = execute_silent(
+            &storage,
+            EditArgs {
+                replace_text: Some("s/login/sign-in/".parse().unwrap()),
+                ..args(1)
+            },
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_edit_many_replace_text_skips_unmatched() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save(&[
+                make_task("Teh first"),
+                make_task("Unrelated"),
+                make_task("Fix teh typo"),
+            ])
+            .unwrap();
+
+        execute_many(
+            &storage,
+            EditArgs {
+                more_ids: vec!["2-3".parse().unwrap()],
+                replace_text: Some("s/teh/the/i".parse().unwrap()),
+                ..args(1)
+            },
+        )
+        .unwrap();
+        let texts: Vec<_> = storage
+            .load()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(texts, ["the first", "Unrelated", "Fix the typo"]);
+    }
+
+    #[test]
+    fn test_edit_many_invalid_id_changes_nothing() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Teh first")]).unwrap();
+
+        let result = execute_many(
+            &storage,
+            EditArgs {
+                more_ids: vec!["5".parse().unwrap()],
+                replace_text: Some("s/teh/the/i".parse().unwrap()),
+                ..args(1)
+            },
+        );
+        assert!(result.is_err());
+        assert_eq!(storage.load().unwrap()[0].text, "Teh first");
+    }
+
     // ── priority ──────────────────────────────────────────────────────────────
 
     #[test]
@@ -606,4 +906,160 @@ mod tests {
         let updated = storage.load().unwrap();
         assert!(updated[0].updated_at > original_updated_at);
     }
+
+    // ── metadata ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_edit_created_requires_force_metadata() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Task")]).unwrap();
+
+        let err = execute_silent(
+            &storage,
+            EditArgs {
+                created: Some("2025-01-10".into()),
+                ..args(1)
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("--force-metadata"));
+    }
+
+    #[test]
+    fn test_edit_created_and_completed_with_force_metadata() {
+        let storage = InMemoryStorage::default();
+        let mut task = make_task("Imported");
+        task.mark_done();
+        storage.save(&[task]).unwrap();
+
+        execute_silent(
+            &storage,
+            EditArgs {
+                created: Some("2025-01-10".into()),
+                completed: Some("2025-01-12".into()),
+                force_metadata: true,
+                ..args(1)
+            },
+        )
+        .unwrap();
+
+        let task = &storage.load().unwrap()[0];
+        assert_eq!(
+            task.created_on(),
+            NaiveDate::from_ymd_opt(2025, 1, 10).unwrap()
+        );
+        assert_eq!(task.completed_on(), NaiveDate::from_ymd_opt(2025, 1, 12));
+    }
+
+    #[test]
+    fn test_edit_completed_before_created_fails() {
+        let storage = InMemoryStorage::default();
+        let mut task = make_task("Imported");
+        task.mark_done();
+        storage.save(&[task]).unwrap();
+
+        let result = execute_silent(
+            &storage,
+            EditArgs {
+                created: Some("2025-01-10".into()),
+                completed: Some("2025-01-05".into()),
+                force_metadata: true,
+                ..args(1)
+            },
+        );
+        assert!(result.is_err());
+    }
+
+    // ── label ─────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_edit_set_and_clear_label() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Task")]).unwrap();
+
+        execute_silent(
+            &storage,
+            EditArgs {
+                label: Some(Label::Blue),
+                ..args(1)
+            },
+        )
+        .unwrap();
+        assert_eq!(storage.load().unwrap()[0].label, Some(Label::Blue));
+
+        execute_silent(
+            &storage,
+            EditArgs {
+                clear_label: true,
+                ..args(1)
+            },
+        )
+        .unwrap();
+        assert_eq!(storage.load().unwrap()[0].label, None);
+    }
+
+    #[test]
+    fn test_edit_set_and_clear_estimate() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Task")]).unwrap();
+
+        execute_silent(
+            &storage,
+            EditArgs {
+                estimate: Some("1h30m".parse().unwrap()),
+                ..args(1)
+            },
+        )
+        .unwrap();
+        let estimate = storage.load().unwrap()[0].estimate;
+        assert_eq!(estimate.map(|e| e.duration().num_minutes()), Some(90));
+
+        execute_silent(
+            &storage,
+            EditArgs {
+                clear_estimate: true,
+                ..args(1)
+            },
+        )
+        .unwrap();
+        assert_eq!(storage.load().unwrap()[0].estimate, None);
+    }
+
+    // ── billable ──────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_edit_rate_implies_billable_and_clear_resets() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Task")]).unwrap();
+
+        execute_silent(
+            &storage,
+            EditArgs {
+                rate: Some(80.0),
+                ..args(1)
+            },
+        )
+        .unwrap();
+        let task = &storage.load().unwrap()[0];
+        assert!(task.billable);
+        assert_eq!(task.hourly_rate, Some(80.0));
+
+        execute_silent(
+            &storage,
+            EditArgs {
+                clear_billable: true,
+                ..args(1)
+            },
+        )
+        .unwrap();
+        let task = &storage.load().unwrap()[0];
+        assert!(!task.billable);
+        assert_eq!(task.hourly_rate, None);
+
+        let negative = EditArgs {
+            rate: Some(-1.0),
+            ..args(1)
+        };
+        assert!(execute_silent(&storage, negative).is_err());
+    }
 }
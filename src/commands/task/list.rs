@@ -1,44 +1,226 @@
 //! Handler for `todo list`.
 
+use std::cmp::Ordering;
+
 use anyhow::Result;
+use chrono::Local;
 
+use crate::cli::{FilterArgs, ListArgs, OutputFormat};
+use crate::config::{Config, DEFAULT_AGE_WARNING_DAYS};
 use crate::error::TodoError;
-use crate::models::{DueFilter, Priority, Recurrence, RecurrenceFilter, SortBy, StatusFilter};
-use crate::render::display_lists;
+use crate::i18n::{Msg, t};
+use crate::models::{
+    DueFilter, Priority, Project, Recurrence, RecurrenceFilter, SortBy, SortDir, SortKey, SortSpec,
+    StatusFilter, Task,
+};
+use crate::render::{Columns, display_lists, structured};
 use crate::storage::Storage;
+use crate::utils::date_parser;
+use crate::utils::query::{self, QueryContext};
+use crate::utils::suggest;
+
+pub fn execute(storage: &impl Storage, args: ListArgs) -> Result<()> {
+    execute_as(storage, args, OutputFormat::Table)
+}
+
+/// Sorts by each key of `spec` in turn, later keys breaking ties.
+///
+/// Remaining ties fall back to creation date, then UUID, so the order is
+/// the same on every run. Tasks without a due date stay last under `due`
+/// in either direction.
+pub fn sort_tasks(tasks: &mut [(usize, &Task)], spec: &SortSpec, all_tasks: &[Task]) {
+    tasks.sort_by(|(_, a), (_, b)| {
+        spec.0
+            .iter()
+            .fold(Ordering::Equal, |ord, key| {
+                ord.then_with(|| compare_by(key, a, b, all_tasks))
+            })
+            .then_with(|| a.created_at.cmp(&b.created_at))
+            .then_with(|| a.uuid.cmp(&b.uuid))
+    });
+}
+
+fn compare_by(key: &SortKey, a: &Task, b: &Task, all_tasks: &[Task]) -> Ordering {
+    // Ascending comparison by value, and the key's natural direction
+    let (ascending, natural) = match key.by {
+        SortBy::Priority => (b.priority.order().cmp(&a.priority.order()), SortDir::Desc),
+        SortBy::Due => {
+            return match (a.due_date, b.due_date) {
+                (Some(da), Some(db)) if key.dir == Some(SortDir::Desc) => db.cmp(&da),
+                (Some(da), Some(db)) => da.cmp(&db),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+        }
+        SortBy::Created => (a.created_at.cmp(&b.created_at), SortDir::Asc),
+        SortBy::Age => (
+            // Pending before completed, then oldest first
+            (a.completed, a.created_at).cmp(&(b.completed, b.created_at)),
+            SortDir::Asc,
+        ),
+        SortBy::Urgency => (
+            a.urgency_score(all_tasks)
+                .partial_cmp(&b.urgency_score(all_tasks))
+                .unwrap_or(Ordering::Equal),
+            SortDir::Desc,
+        ),
+    };
+    match key.dir.unwrap_or(natural) {
+        SortDir::Asc => ascending,
+        SortDir::Desc => ascending.reverse(),
+    }
+}
+
+/// Like [`execute`], printing in the given output format.
+pub fn execute_as(storage: &impl Storage, args: ListArgs, format: OutputFormat) -> Result<()> {
+    let ListArgs {
+        status,
+        priority,
+        due,
+        sort,
+        reverse,
+        tag,
+        project,
+        recurrence: recur,
+        started,
+        modified_since,
+        age,
+        timeliness,
+        show_urgency,
+        query,
+        flat_uuid,
+    } = args;
+    let filter = FilterArgs {
+        status,
+        priority,
+        due,
+        tag,
+        project,
+        recurrence: recur,
+        started,
+        modified_since,
+        query,
+    };
 
-#[allow(clippy::too_many_arguments)]
-pub fn execute(
-    storage: &impl Storage,
-    status: StatusFilter,
-    priority: Option<Priority>,
-    due: Option<DueFilter>,
-    sort: Option<SortBy>,
-    tags: Vec<String>,
-    project: Option<String>,
-    recur: Option<RecurrenceFilter>,
-) -> Result<()> {
     let (all_tasks, projects, notes) = storage.load_all()?;
     let resources = storage.load_resources()?;
+    crate::commands::doctor::warn_on_load(&all_tasks);
+
+    let mut indexed_tasks = filter_tasks(&all_tasks, &projects, &filter)?;
+
+    // An empty result is not an error for scripts
+    if indexed_tasks.is_empty() && !flat_uuid {
+        return Err(TodoError::NoTasksFound.into());
+    }
+
+    if let Some(spec) = sort.or_else(|| Config::defaults().default_sort.clone()) {
+        sort_tasks(&mut indexed_tasks, &spec, &all_tasks);
+    }
+    if reverse {
+        indexed_tasks.reverse();
+    }
+
+    if flat_uuid {
+        for (_, task) in &indexed_tasks {
+            println!("{}", structured::task_flat_uuid(task));
+        }
+        return Ok(());
+    }
+
+    let visible: Vec<_> = all_tasks
+        .iter()
+        .filter(|t| !t.is_deleted())
+        .cloned()
+        .collect();
+    match format {
+        OutputFormat::Table | OutputFormat::Markdown => {}
+        OutputFormat::Json => {
+            let records: Vec<_> = indexed_tasks
+                .iter()
+                .map(|(id, t)| structured::with_id(*id, t))
+                .collect();
+            return structured::print_json(&records);
+        }
+        OutputFormat::Plain => {
+            for (id, task) in &indexed_tasks {
+                println!("{}", structured::task_plain(*id, task, &visible, &projects));
+            }
+            return Ok(());
+        }
+    }
+
+    let title = determine_title(
+        status,
+        priority,
+        due,
+        &filter.tag,
+        filter.project.as_deref(),
+        recur,
+    );
+    let columns = Columns {
+        age_warning_days: age.then(|| {
+            Config::load()
+                .ok()
+                .and_then(|c| c.age_warning_days)
+                .unwrap_or(DEFAULT_AGE_WARNING_DAYS)
+        }),
+        timeliness,
+        urgency: show_urgency,
+    };
+    display_lists(
+        &indexed_tasks,
+        &title,
+        &visible,
+        &projects,
+        &notes,
+        &resources,
+        columns,
+    );
+    Ok(())
+}
 
-    let mut indexed_tasks: Vec<(usize, &_)> = all_tasks
+/// Applies `filter` to the visible tasks, keeping their display IDs.
+///
+/// Fails when a tag or project filter leaves nothing out of a non-empty
+/// selection, since that usually means a typo.
+pub fn filter_tasks<'a>(
+    all_tasks: &'a [Task],
+    projects: &[Project],
+    filter: &FilterArgs,
+) -> Result<Vec<(usize, &'a Task)>> {
+    let query = filter.query.as_deref().map(query::parse).transpose()?;
+
+    let mut indexed_tasks: Vec<(usize, &Task)> = all_tasks
         .iter()
         .filter(|t| !t.is_deleted())
         .enumerate()
         .map(|(i, task)| (i + 1, task))
         .collect();
 
-    indexed_tasks.retain(|(_, t)| t.matches_status(status));
+    indexed_tasks.retain(|(_, t)| t.matches_status(filter.status));
+
+    if filter.started {
+        let today = Local::now().date_naive();
+        indexed_tasks.retain(|(_, t)| t.is_started(today));
+    }
+
+    if let Some(ref since) = filter.modified_since {
+        let since = date_parser::parse_instant(since)?;
+        indexed_tasks.retain(|(_, t)| t.last_modified() >= since);
+    }
 
-    if let Some(pri) = priority {
+    if let Some(pri) = filter.priority {
         indexed_tasks.retain(|(_, t)| t.priority == pri);
     }
 
-    if let Some(due_filter) = due {
-        indexed_tasks.retain(|(_, t)| t.matches_due_filter(due_filter));
+    if let Some(due_filter) = filter.due {
+        let soon_days = Config::defaults().due_soon_days();
+        indexed_tasks.retain(|(_, t)| t.matches_due_filter(due_filter, soon_days));
     }
 
     // AND semantics: task must contain ALL specified tags
+    let tags = &filter.tag;
     if !tags.is_empty() {
         let count_before = indexed_tasks.len();
         indexed_tasks.retain(|(_, t)| tags.iter().all(|tag| t.tags.contains(tag)));
@@ -48,12 +230,9 @@ pub fn execute(
     }
 
     // Filter by project: resolve name → UUID, then filter by project_id
-    if let Some(ref project_name) = project {
+    if let Some(ref project_name) = filter.project {
         let count_before = indexed_tasks.len();
-        let proj_uuid = projects
-            .iter()
-            .find(|p| p.name.to_lowercase() == project_name.to_lowercase() && !p.is_deleted())
-            .map(|p| p.uuid);
+        let proj_uuid = suggest::resolve_project_filter(projects, project_name)?;
 
         indexed_tasks.retain(|(_, t)| proj_uuid.is_some() && t.project_id == proj_uuid);
 
@@ -62,7 +241,7 @@ pub fn execute(
         }
     }
 
-    if let Some(recur_filter) = recur {
+    if let Some(recur_filter) = filter.recurrence {
         indexed_tasks.retain(|(_, t)| match recur_filter {
             RecurrenceFilter::Daily => t.recurrence == Some(Recurrence::Daily),
             RecurrenceFilter::Weekly => t.recurrence == Some(Recurrence::Weekly),
@@ -72,52 +251,17 @@ pub fn execute(
         });
     }
 
-    if indexed_tasks.is_empty() {
-        return Err(TodoError::NoTasksFound.into());
+    if let Some(ref query) = query {
+        let ctx = QueryContext {
+            all_tasks,
+            projects,
+            today: Local::now().date_naive(),
+            soon_days: Config::defaults().due_soon_days(),
+        };
+        indexed_tasks.retain(|(_, t)| query.matches(t, &ctx));
     }
 
-    if let Some(sort_by) = sort {
-        match sort_by {
-            SortBy::Priority => {
-                indexed_tasks.sort_by(|(_, a), (_, b)| a.priority.order().cmp(&b.priority.order()));
-            }
-            SortBy::Due => {
-                indexed_tasks.sort_by(|(_, a), (_, b)| match (a.due_date, b.due_date) {
-                    (Some(da), Some(db)) => da.cmp(&db),
-                    (Some(_), None) => std::cmp::Ordering::Less,
-                    (None, Some(_)) => std::cmp::Ordering::Greater,
-                    (None, None) => std::cmp::Ordering::Equal,
-                });
-            }
-            SortBy::Created => {
-                indexed_tasks.sort_by(|(_, a), (_, b)| a.created_at.cmp(&b.created_at));
-            }
-
-            SortBy::Urgency => {
-                indexed_tasks.sort_by(|(_, a), (_, b)| {
-                    b.urgency_score(&all_tasks)
-                        .partial_cmp(&a.urgency_score(&all_tasks))
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-            }
-        }
-    }
-
-    let title = determine_title(status, priority, due, &tags, project.as_deref(), recur);
-    let visible: Vec<_> = all_tasks
-        .iter()
-        .filter(|t| !t.is_deleted())
-        .cloned()
-        .collect();
-    display_lists(
-        &indexed_tasks,
-        &title,
-        &visible,
-        &projects,
-        &notes,
-        &resources,
-    );
-    Ok(())
+    Ok(indexed_tasks)
 }
 
 fn determine_title(
@@ -155,6 +299,7 @@ fn determine_title(
             (StatusFilter::Done, RecurrenceFilter::Monthly) => "Completed monthly recurring tasks",
             (StatusFilter::Done, RecurrenceFilter::Recurring) => "Completed recurring tasks",
             (StatusFilter::Done, RecurrenceFilter::NonRecurring) => "Completed non-recurring tasks",
+            (StatusFilter::Cancelled, _) => "Cancelled tasks",
             (StatusFilter::All, RecurrenceFilter::Daily) => "Daily recurring tasks",
             (StatusFilter::All, RecurrenceFilter::Weekly) => "Weekly recurring tasks",
             (StatusFilter::All, RecurrenceFilter::Monthly) => "Monthly recurring tasks",
@@ -170,8 +315,12 @@ fn determine_title(
         (StatusFilter::Pending, Some(Priority::Low), _) => "Low priority pending tasks",
         (StatusFilter::Pending, None, Some(DueFilter::Overdue)) => "Pending overdue tasks",
         (StatusFilter::Pending, None, Some(DueFilter::Soon)) => "Pending tasks due soon",
-        (StatusFilter::Pending, None, _) => "Pending tasks",
-        (StatusFilter::Done, _, _) => "Completed tasks",
+        (StatusFilter::Pending, None, Some(DueFilter::Today)) => "Pending tasks due today",
+        (StatusFilter::Pending, None, Some(DueFilter::Tomorrow)) => "Pending tasks due tomorrow",
+        (StatusFilter::Pending, None, Some(DueFilter::ThisWeek)) => "Pending tasks due this week",
+        (StatusFilter::Pending, None, _) => t(Msg::ListPending),
+        (StatusFilter::Done, _, _) => t(Msg::ListDone),
+        (StatusFilter::Cancelled, _, _) => "Cancelled tasks",
         (StatusFilter::All, Some(Priority::High), _) => "High priority tasks",
         (StatusFilter::All, Some(Priority::Medium), _) => "Medium priority tasks",
         (StatusFilter::All, Some(Priority::Low), _) => "Low priority tasks",
@@ -179,7 +328,10 @@ fn determine_title(
         (StatusFilter::All, None, Some(DueFilter::Soon)) => "Tasks due soon",
         (StatusFilter::All, None, Some(DueFilter::WithDue)) => "Tasks with due date",
         (StatusFilter::All, None, Some(DueFilter::NoDue)) => "Tasks without due date",
-        _ => "Tasks",
+        (StatusFilter::All, None, Some(DueFilter::Today)) => "Tasks due today",
+        (StatusFilter::All, None, Some(DueFilter::Tomorrow)) => "Tasks due tomorrow",
+        (StatusFilter::All, None, Some(DueFilter::ThisWeek)) => "Tasks due this week",
+        _ => t(Msg::ListAll),
     }
     .to_string()
 }
@@ -189,6 +341,7 @@ mod tests {
     use super::*;
     use crate::models::{Priority, Task};
     use crate::storage::InMemoryStorage;
+    use chrono::Utc;
 
     fn make_task(text: &str) -> Task {
         Task::new(text.into(), Priority::Medium, vec![], None, None, None)
@@ -199,16 +352,7 @@ mod tests {
     }
 
     fn list(storage: &InMemoryStorage) -> Result<()> {
-        execute(
-            storage,
-            StatusFilter::All,
-            None,
-            None,
-            None,
-            vec![],
-            None,
-            None,
-        )
+        execute(storage, ListArgs::default())
     }
 
     #[test]
@@ -235,13 +379,10 @@ mod tests {
 
         let result = execute(
             &storage,
-            StatusFilter::Pending,
-            None,
-            None,
-            None,
-            vec![],
-            None,
-            None,
+            ListArgs {
+                status: StatusFilter::Pending,
+                ..Default::default()
+            },
         );
         assert!(result.is_ok());
     }
@@ -255,13 +396,10 @@ mod tests {
 
         let result = execute(
             &storage,
-            StatusFilter::Done,
-            None,
-            None,
-            None,
-            vec![],
-            None,
-            None,
+            ListArgs {
+                status: StatusFilter::Done,
+                ..Default::default()
+            },
         );
         assert!(result.is_ok());
     }
@@ -275,13 +413,10 @@ mod tests {
 
         let result = execute(
             &storage,
-            StatusFilter::Pending,
-            None,
-            None,
-            None,
-            vec![],
-            None,
-            None,
+            ListArgs {
+                status: StatusFilter::Pending,
+                ..Default::default()
+            },
         );
         assert!(result.is_err());
     }
@@ -298,13 +433,10 @@ mod tests {
 
         let result = execute(
             &storage,
-            StatusFilter::All,
-            Some(Priority::High),
-            None,
-            None,
-            vec![],
-            None,
-            None,
+            ListArgs {
+                priority: Some(Priority::High),
+                ..Default::default()
+            },
         );
         assert!(result.is_ok());
     }
@@ -318,13 +450,10 @@ mod tests {
 
         let result = execute(
             &storage,
-            StatusFilter::All,
-            None,
-            None,
-            None,
-            vec!["rust".into()],
-            None,
-            None,
+            ListArgs {
+                tag: vec!["rust".into()],
+                ..Default::default()
+            },
         );
         assert!(result.is_ok());
     }
@@ -336,13 +465,10 @@ mod tests {
 
         let result = execute(
             &storage,
-            StatusFilter::All,
-            None,
-            None,
-            None,
-            vec!["nonexistent".into()],
-            None,
-            None,
+            ListArgs {
+                tag: vec!["nonexistent".into()],
+                ..Default::default()
+            },
         );
         assert!(result.is_err());
     }
@@ -360,13 +486,10 @@ mod tests {
 
         let result = execute(
             &storage,
-            StatusFilter::All,
-            None,
-            None,
-            None,
-            vec![],
-            Some("Rustodo".into()),
-            None,
+            ListArgs {
+                project: Some("Rustodo".into()),
+                ..Default::default()
+            },
         );
         assert!(result.is_ok());
     }
@@ -381,6 +504,49 @@ mod tests {
         assert!(list(&storage).is_err()); // no visible tasks
     }
 
+    #[test]
+    fn test_filter_started_hides_future_start_dates() {
+        let today = Local::now().date_naive();
+        let mut later = make_task("Later");
+        later.start_date = today.succ_opt();
+        let mut begun = make_task("Begun");
+        begun.start_date = Some(today);
+        let tasks = vec![later, begun, make_task("Undated")];
+
+        let filter = FilterArgs {
+            started: true,
+            ..Default::default()
+        };
+        let shown: Vec<_> = filter_tasks(&tasks, &[], &filter)
+            .unwrap()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(shown, [2, 3]);
+    }
+
+    #[test]
+    fn test_filter_modified_since() {
+        let mut old = make_task("Old");
+        old.created_at = Utc::now() - chrono::Duration::days(3);
+        old.updated_at = Some(old.created_at);
+        let mut legacy = make_task("Legacy");
+        legacy.created_at = old.created_at;
+        legacy.updated_at = None;
+        let tasks = vec![old, legacy, make_task("Fresh")];
+
+        let filter = FilterArgs {
+            modified_since: Some("yesterday".into()),
+            ..Default::default()
+        };
+        let shown: Vec<_> = filter_tasks(&tasks, &[], &filter)
+            .unwrap()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(shown, [3]);
+    }
+
     #[test]
     fn test_list_sort_by_priority() {
         let storage = InMemoryStorage::default();
@@ -393,14 +559,101 @@ mod tests {
 
         let result = execute(
             &storage,
-            StatusFilter::All,
-            None,
-            None,
-            Some(SortBy::Priority),
-            vec![],
-            None,
-            None,
+            ListArgs {
+                sort: Some(SortBy::Priority.into()),
+                ..Default::default()
+            },
         );
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_list_sort_by_age_with_age_column() {
+        let storage = InMemoryStorage::default();
+        let mut old = make_task("Old");
+        old.created_at -= chrono::Duration::days(90);
+        let mut done = make_task("Done");
+        done.mark_done();
+        storage.save(&[make_task("New"), done, old]).unwrap();
+
+        let result = execute(
+            &storage,
+            ListArgs {
+                sort: Some(SortBy::Age.into()),
+                age: true,
+                ..Default::default()
+            },
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_list_sort_by_urgency_with_urgency_column() {
+        let storage = InMemoryStorage::default();
+        let mut urgent = make_task("Urgent");
+        urgent.priority = Priority::High;
+        let mut done = make_task("Done");
+        done.mark_done();
+        storage.save(&[make_task("Later"), done, urgent]).unwrap();
+
+        let result = execute(
+            &storage,
+            ListArgs {
+                sort: Some(SortBy::Urgency.into()),
+                show_urgency: true,
+                ..Default::default()
+            },
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_sort_tasks_composes_keys_with_direction() {
+        let day = |d| chrono::NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        let due = |text: &str, priority, d: Option<u32>| {
+            let mut t = make_task_with_priority(text, priority);
+            t.due_date = d.map(day);
+            t
+        };
+        let tasks = vec![
+            due("a", Priority::Low, Some(2)),
+            due("b", Priority::High, None),
+            due("c", Priority::High, Some(2)),
+            due("d", Priority::Medium, Some(1)),
+        ];
+        let order = |spec: &str| {
+            let mut indexed: Vec<_> = tasks.iter().enumerate().collect();
+            sort_tasks(&mut indexed, &spec.parse().unwrap(), &tasks);
+            indexed
+                .iter()
+                .map(|(_, t)| t.text.as_str())
+                .collect::<String>()
+        };
+
+        assert_eq!(order("due:asc,priority:desc"), "dcab");
+        assert_eq!(order("due:desc,priority:asc"), "acdb");
+        assert_eq!(order("priority,due"), "cbda");
+        assert_eq!(order("priority:asc"), "adbc");
+    }
+
+    #[test]
+    fn test_sort_tasks_breaks_ties_by_created_then_uuid() {
+        let mut tasks: Vec<Task> = ["a", "b", "c"].into_iter().map(make_task).collect();
+        let created = tasks[0].created_at;
+        tasks[1].created_at = created - chrono::Duration::days(1);
+        tasks[0].created_at = created;
+        tasks[2].created_at = created;
+        let (first, second) = if tasks[0].uuid < tasks[2].uuid {
+            ("a", "c")
+        } else {
+            ("c", "a")
+        };
+
+        for input in [tasks.clone(), tasks.iter().rev().cloned().collect()] {
+            let mut indexed: Vec<_> = input.iter().enumerate().collect();
+            sort_tasks(&mut indexed, &SortBy::Priority.into(), &input);
+            let texts: Vec<_> = indexed.iter().map(|(_, t)| t.text.as_str()).collect();
+            assert_eq!(texts, ["b", first, second]);
+        }
+    }
 }
@@ -1,4 +1,7 @@
 pub mod add;
+pub mod annotate;
+pub mod blame;
+pub mod cancel;
 pub mod clear;
 pub mod clear_recur;
 pub mod deps;
@@ -8,4 +11,6 @@ pub mod info;
 pub mod list;
 pub mod recur;
 pub mod remove;
+pub mod similar;
+pub mod track;
 pub mod undone;
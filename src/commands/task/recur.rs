@@ -1,17 +1,38 @@
-//! Handler for `todo recur <ID> <PATTERN>`.
+//! Handlers for `todo recur <ID> <PATTERN>`, `todo recur preview <ID>`, and
+//! `todo recur history <ID>`.
 //!
-//! Sets or updates the recurrence pattern on a task. Requires the task to
+//! Sets or updates the recurrence pattern on a task, and optionally its
+//! month-end policy (`--month-end same-day|last-day`) and what completing it
+//! late does (`--catch-up next|backfill|fast-forward`), and whether each new
+//! occurrence depends on the previous one (`--recur-chain-deps`). Requires the task to
 //! already have a due date — without one there is no base date from which to
-//! calculate the next occurrence.
+//! calculate the next occurrence. `preview` lists the dates the next
+//! occurrences would get, without creating anything. `history` lists the
+//! past occurrences in the task's series and whether each was completed on
+//! time, late, or missed.
 
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::models::Recurrence;
+use crate::models::{CatchUpPolicy, MonthEndPolicy, Recurrence, Task, Timeliness};
+use crate::render::formatting::{first_line, timeliness_colored};
 use crate::storage::Storage;
 use crate::utils::validation::resolve_visible_index;
 
 pub fn execute(storage: &impl Storage, id: usize, pattern: Recurrence) -> Result<()> {
+    execute_with(storage, id, pattern, None, None, None)
+}
+
+/// Like [`execute`], also setting the month-end and catch-up policies when
+/// given.
+pub fn execute_with(
+    storage: &impl Storage,
+    id: usize,
+    pattern: Recurrence,
+    month_end: Option<MonthEndPolicy>,
+    catch_up: Option<CatchUpPolicy>,
+    chain_deps: Option<bool>,
+) -> Result<()> {
     let mut tasks = storage.load()?;
 
     let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
@@ -30,13 +51,28 @@ pub fn execute(storage: &impl Storage, id: usize, pattern: Recurrence) -> Result
     let old_recurrence = task.recurrence;
     task.recurrence = Some(pattern);
 
-    if old_recurrence != Some(pattern) {
+    let policy_changed = month_end.is_some_and(|policy| policy != task.month_end);
+    if let Some(policy) = month_end {
+        task.month_end = policy;
+    }
+    let catch_up_changed = catch_up.is_some_and(|policy| policy != task.catch_up);
+    if let Some(policy) = catch_up {
+        task.catch_up = policy;
+    }
+    let chain_changed = chain_deps.is_some_and(|chain| chain != task.recur_chain_deps);
+    if let Some(chain) = chain_deps {
+        task.recur_chain_deps = chain;
+    }
+    let options_changed = policy_changed || catch_up_changed || chain_changed;
+
+    if old_recurrence != Some(pattern) || options_changed {
         task.touch();
     }
 
     storage.save(&tasks)?;
 
     match old_recurrence {
+        Some(old) if old == pattern && options_changed => {}
         Some(old) if old == pattern => {
             println!(
                 "{} Recurrence already set to {} for task #{}",
@@ -64,5 +100,179 @@ pub fn execute(storage: &impl Storage, id: usize, pattern: Recurrence) -> Result
         }
     }
 
+    if policy_changed {
+        println!(
+            "{} Month-end policy for task #{}: {}",
+            "✓".green(),
+            id,
+            tasks[index].month_end
+        );
+    }
+    if catch_up_changed {
+        println!(
+            "{} Catch-up policy for task #{}: {}",
+            "✓".green(),
+            id,
+            tasks[index].catch_up
+        );
+    }
+    if chain_changed {
+        let state = if tasks[index].recur_chain_deps {
+            "each occurrence depends on the previous one"
+        } else {
+            "occurrences no longer depend on each other"
+        };
+        println!("{} Task #{}: {}", "✓".green(), id, state);
+    }
+
+    Ok(())
+}
+
+/// `todo recur preview <ID> --count N` — prints the next `count` due dates.
+pub fn execute_preview(storage: &impl Storage, id: usize, count: usize) -> Result<()> {
+    let tasks = storage.load()?;
+
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+    let task = &tasks[index];
+
+    let Some(pattern) = task.recurrence else {
+        return Err(anyhow::anyhow!(
+            "Task #{} is not recurring. Set a pattern with: todo recur {} <PATTERN>",
+            id,
+            id
+        ));
+    };
+    let Some(due) = task.due_date else {
+        return Err(anyhow::anyhow!(
+            "Task #{} has no due date. Add one with: todo edit {} --due YYYY-MM-DD",
+            id,
+            id
+        ));
+    };
+
+    println!(
+        "\n{} #{}: {}  {}\n",
+        "Task".dimmed(),
+        id,
+        first_line(&task.text).bright_white(),
+        match task.month_end {
+            MonthEndPolicy::LastDay => format!("({}, {}, due {})", pattern, task.month_end, due),
+            MonthEndPolicy::SameDay => format!("({}, due {})", pattern, due),
+        }
+        .dimmed()
+    );
+    for (n, date) in pattern
+        .upcoming(due, count, task.month_end)
+        .iter()
+        .enumerate()
+    {
+        println!(
+            "  {:>3}  {}  {}",
+            format!("{}.", n + 1).dimmed(),
+            date.to_string().cyan(),
+            date.format("%a").to_string().dimmed()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+/// `todo recur history <ID>` — prints every occurrence in the task's series,
+/// oldest first, with when it was closed and how that compares to its due date.
+pub fn execute_history(storage: &impl Storage, id: usize) -> Result<()> {
+    let tasks = storage.load()?;
+
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+    let task = &tasks[index];
+
+    let Some(pattern) = task.recurrence else {
+        return Err(anyhow::anyhow!(
+            "Task #{} is not recurring. Set a pattern with: todo recur {} <PATTERN>",
+            id,
+            id
+        ));
+    };
+
+    let series = occurrences(&tasks, task);
+    println!(
+        "\n{} #{}: {}  {}\n",
+        "Task".dimmed(),
+        id,
+        first_line(&task.text).bright_white(),
+        format!("({})", pattern).dimmed()
+    );
+    println!(
+        "  {:<10}  {:<10}  {}",
+        "Due".dimmed(),
+        "Closed".dimmed(),
+        "Result".dimmed()
+    );
+    for occurrence in &series {
+        let due = occurrence
+            .due_date
+            .map_or_else(|| "—".to_string(), |d| d.to_string());
+        let closed = if occurrence.is_cancelled() {
+            occurrence.cancelled_at
+        } else {
+            occurrence.completed_at.filter(|_| occurrence.completed)
+        };
+        let closed = closed.map_or_else(
+            || "—".to_string(),
+            |at| at.with_timezone(&chrono::Local).date_naive().to_string(),
+        );
+        let result = if occurrence.completed || occurrence.is_cancelled() {
+            timeliness_colored(occurrence.timeliness())
+        } else {
+            "pending".dimmed()
+        };
+        println!("  {:<10}  {:<10}  {}", due.cyan(), closed.dimmed(), result);
+    }
+
+    let outcomes: Vec<Timeliness> = series.iter().filter_map(|t| t.timeliness()).collect();
+    let on_time = outcomes
+        .iter()
+        .filter(|t| **t == Timeliness::OnTime)
+        .count();
+    if outcomes.is_empty() {
+        println!("\n  {}", "No closed occurrences yet".dimmed());
+    } else {
+        println!(
+            "\n  {} of {} on time ({}%)",
+            on_time,
+            outcomes.len(),
+            on_time * 100 / outcomes.len()
+        );
+    }
+    println!();
     Ok(())
 }
+
+/// The occurrences in `task`'s recurring series, oldest first, found by
+/// following `parent_id` links in both directions. Deleted occurrences are
+/// skipped but still link the series together.
+pub fn occurrences<'a>(tasks: &'a [Task], task: &'a Task) -> Vec<&'a Task> {
+    let mut first = task;
+    for uuid in super::blame::parent_chain(tasks, task)
+        .into_iter()
+        .flatten()
+    {
+        match tasks.iter().find(|t| t.uuid == uuid) {
+            Some(parent) => first = parent,
+            None => break,
+        }
+    }
+
+    let mut series = vec![first];
+    let mut current = first;
+    while let Some(next) = tasks
+        .iter()
+        .find(|t| t.parent_id == Some(current.uuid) && !series.iter().any(|s| s.uuid == t.uuid))
+    {
+        series.push(next);
+        current = next;
+    }
+    series.retain(|t| !t.is_deleted());
+    series
+}
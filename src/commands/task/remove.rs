@@ -1,6 +1,8 @@
 use anyhow::Result;
 use colored::Colorize;
 
+use crate::i18n::{Msg, tf};
+use crate::models::{Note, Project, Task};
 use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::confirm;
 use crate::utils::validation::resolve_visible_index;
@@ -14,6 +16,124 @@ pub fn execute_silent(storage: &impl Storage, id: usize) -> Result<String> {
     execute_inner(storage, id, true, true)
 }
 
+/// `todo remove <ID>...` — one confirmation, then removes every task or none.
+///
+/// With `interactive` each task is asked about on its own instead, and only
+/// the ones answered `y` are removed.
+pub fn execute_many(
+    storage: &impl Storage,
+    ids: &[usize],
+    yes: bool,
+    interactive: bool,
+) -> Result<()> {
+    if interactive {
+        let removed = execute_chosen(storage, ids, |id, task| {
+            confirm(&format!(
+                "{} {} {} Remove? [y/N]:",
+                "".yellow(),
+                format!("#{}", id).dimmed(),
+                task.text.bright_white()
+            ))
+        })?;
+        println!(
+            "{} {}",
+            "✓".green(),
+            format!("Removed {} of {} tasks", removed, ids.len()).dimmed()
+        );
+        return Ok(());
+    }
+    if let [id] = ids {
+        return execute(storage, *id, yes);
+    }
+
+    let (mut tasks, projects, mut notes) = storage.load_all()?;
+    let indices = resolve_all(&tasks, ids)?;
+
+    if !yes {
+        println!();
+        for (&id, &index) in ids.iter().zip(&indices) {
+            println!(
+                "{} {} {}",
+                "".yellow(),
+                format!("#{}", id).dimmed(),
+                tasks[index].text.bright_white()
+            );
+        }
+        if !confirm(&format!("Remove these {} tasks? [y/N]:", ids.len()))? {
+            println!("{} Removal cancelled.", "".yellow());
+            return Ok(());
+        }
+    }
+
+    let removed = remove_indices(storage, &mut tasks, &projects, &mut notes, &indices)?;
+    println!(
+        "{} {}",
+        "✓".green(),
+        format!("Removed {} tasks", removed).dimmed()
+    );
+    Ok(())
+}
+
+/// Asks `choose` about each task in `ids`, then removes the chosen ones in
+/// a single save. Returns how many were removed.
+///
+/// Every ID is validated before the first question.
+pub fn execute_chosen(
+    storage: &impl Storage,
+    ids: &[usize],
+    mut choose: impl FnMut(usize, &Task) -> Result<bool>,
+) -> Result<usize> {
+    let (mut tasks, projects, mut notes) = storage.load_all()?;
+    let indices = resolve_all(&tasks, ids)?;
+
+    let mut chosen = Vec::new();
+    for (&id, &index) in ids.iter().zip(&indices) {
+        if choose(id, &tasks[index])? {
+            chosen.push(index);
+        }
+    }
+    remove_indices(storage, &mut tasks, &projects, &mut notes, &chosen)
+}
+
+fn resolve_all(tasks: &[Task], ids: &[usize]) -> Result<Vec<usize>> {
+    ids.iter()
+        .map(|&id| {
+            resolve_visible_index(tasks, id, |t| t.is_deleted())
+                .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))
+        })
+        .collect()
+}
+
+/// Soft-deletes the tasks at `indices` and unlinks their notes.
+fn remove_indices(
+    storage: &impl Storage,
+    tasks: &mut [Task],
+    projects: &[Project],
+    notes: &mut [Note],
+    indices: &[usize],
+) -> Result<usize> {
+    if indices.is_empty() {
+        return Ok(0);
+    }
+    let mut removed = Vec::new();
+    for &index in indices {
+        tasks[index].soft_delete();
+        removed.push(tasks[index].uuid);
+    }
+    for note in notes.iter_mut().filter(|n| !n.is_deleted()) {
+        if note.task_id.is_some_and(|uuid| removed.contains(&uuid)) {
+            note.task_id = None;
+            note.touch();
+        }
+    }
+
+    storage.save_all(tasks, projects, notes)?;
+    for uuid in &removed {
+        storage.record_event(EntityType::Task, *uuid, EventType::Deleted)?;
+    }
+    Ok(removed.len())
+}
+
 fn execute_inner(storage: &impl Storage, id: usize, yes: bool, silent: bool) -> Result<String> {
     let (mut tasks, projects, mut notes) = storage.load_all()?;
 
@@ -43,7 +163,7 @@ fn execute_inner(storage: &impl Storage, id: usize, yes: bool, silent: bool) ->
     storage.save_all(&tasks, &projects, &notes)?;
     storage.record_event(EntityType::Task, task_uuid, EventType::Deleted)?;
 
-    let msg = format!("Task removed: {}", task_text);
+    let msg = tf(Msg::TaskRemoved, &[&task_text]);
     if !silent {
         println!("{} {}", "✓".green(), msg.as_str().dimmed());
     }
@@ -124,4 +244,48 @@ mod tests {
         assert!(tasks[0].is_deleted()); // was already deleted
         assert!(tasks[1].is_deleted()); // #1 resolved to active, now deleted
     }
+
+    #[test]
+    fn test_remove_many_is_all_or_nothing() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save(&[make_task("Task A"), make_task("Task B")])
+            .unwrap();
+
+        assert!(execute_many(&storage, &[1, 3], true, false).is_err());
+        assert!(storage.load().unwrap().iter().all(|t| !t.is_deleted()));
+
+        execute_many(&storage, &[1, 2], true, false).unwrap();
+        assert!(storage.load().unwrap().iter().all(|t| t.is_deleted()));
+    }
+
+    #[test]
+    fn test_remove_chosen_asks_per_task() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save(&[
+                make_task("Task A"),
+                make_task("Task B"),
+                make_task("Task C"),
+            ])
+            .unwrap();
+
+        let mut asked = Vec::new();
+        let removed = execute_chosen(&storage, &[1, 2, 3], |id, task| {
+            asked.push(id);
+            Ok(task.text != "Task B")
+        })
+        .unwrap();
+
+        assert_eq!(removed, 2);
+        assert_eq!(asked, vec![1, 2, 3]);
+        let deleted: Vec<_> = storage
+            .load()
+            .unwrap()
+            .iter()
+            .map(|t| t.is_deleted())
+            .collect();
+        assert_eq!(deleted, vec![true, false, true]);
+        assert!(execute_chosen(&storage, &[9], |_, _| Ok(true)).is_err());
+    }
 }
@@ -0,0 +1,206 @@
+//! Handler for `todo similar <ID>`.
+//!
+//! Lists the tasks that look most like the given one, to spot a duplicate
+//! before it piles up or to recall how a similar past task was closed.
+//! Similarity combines the text (Sørensen–Dice over character bigrams, so
+//! reordered words still match), shared tags, and the project. Other
+//! occurrences of the same recurring series are left out.
+
+use anyhow::Result;
+use colored::Colorize;
+use strsim::sorensen_dice;
+
+use crate::models::{Project, Task};
+use crate::render::formatting::{first_line, project_name};
+use crate::storage::Storage;
+use crate::utils::validation::{resolve_visible_index, visible_indices};
+
+/// Scores below this are not worth listing.
+const MIN_SCORE: f64 = 0.3;
+
+const TEXT_WEIGHT: f64 = 0.7;
+const TAGS_WEIGHT: f64 = 0.2;
+const PROJECT_WEIGHT: f64 = 0.1;
+
+pub fn execute(storage: &impl Storage, id: usize, limit: usize) -> Result<()> {
+    let (tasks, projects, _) = storage.load_all()?;
+
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+    let task = &tasks[index];
+
+    let matches = most_similar(&tasks, task, limit);
+
+    println!(
+        "\n{} #{}: {}\n",
+        "Similar to".dimmed(),
+        id,
+        first_line(&task.text).bright_white()
+    );
+    if matches.is_empty() {
+        println!("  {}\n", "No similar tasks found".dimmed());
+        return Ok(());
+    }
+
+    let vis = visible_indices(&tasks, |t| t.is_deleted());
+    for (other, score) in matches {
+        let pos = tasks.iter().position(|t| t.uuid == other.uuid).unwrap();
+        let label = match vis.iter().position(|&i| i == pos) {
+            Some(p) => format!("#{}", p + 1),
+            None => "[deleted]".to_string(),
+        };
+        println!(
+            "  {:>5}  {:>4}  {} {}{}",
+            label.dimmed(),
+            format!("{:.0}%", score * 100.0).cyan(),
+            status_mark(other),
+            first_line(&other.text),
+            context(other, &projects).dimmed()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+/// Up to `limit` tasks most similar to `task`, best first, with their
+/// scores. Deleted tasks and `task`'s own recurring series are skipped.
+pub fn most_similar<'a>(tasks: &'a [Task], task: &Task, limit: usize) -> Vec<(&'a Task, f64)> {
+    let series: Vec<_> = match task.recurrence {
+        Some(_) => super::recur::occurrences(tasks, task)
+            .iter()
+            .map(|t| t.uuid)
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let mut scored: Vec<_> = tasks
+        .iter()
+        .filter(|t| !t.is_deleted() && t.uuid != task.uuid && !series.contains(&t.uuid))
+        .map(|t| (t, similarity(task, t)))
+        .filter(|(_, score)| *score >= MIN_SCORE)
+        .collect();
+    scored.sort_by(|(a, x), (b, y)| {
+        y.partial_cmp(x)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| b.created_at.cmp(&a.created_at))
+    });
+    scored.truncate(limit);
+    scored
+}
+
+/// How alike two tasks are, from 0.0 to 1.0. Tags and project only count
+/// when at least one of the tasks has them, so two untagged tasks are
+/// compared on text alone.
+pub fn similarity(a: &Task, b: &Task) -> f64 {
+    let text = sorensen_dice(
+        &first_line(&a.text).to_lowercase(),
+        &first_line(&b.text).to_lowercase(),
+    );
+    let mut score = TEXT_WEIGHT * text;
+    let mut weight = TEXT_WEIGHT;
+
+    if !a.tags.is_empty() || !b.tags.is_empty() {
+        let shared = a.tags.iter().filter(|t| b.tags.contains(t)).count();
+        let union = a.tags.len() + b.tags.len() - shared;
+        score += TAGS_WEIGHT * shared as f64 / union as f64;
+        weight += TAGS_WEIGHT;
+    }
+    if a.project_id.is_some() || b.project_id.is_some() {
+        if a.project_id == b.project_id {
+            score += PROJECT_WEIGHT;
+        }
+        weight += PROJECT_WEIGHT;
+    }
+    score / weight
+}
+
+fn status_mark(task: &Task) -> colored::ColoredString {
+    if task.is_cancelled() {
+        "✗".dimmed()
+    } else if task.completed {
+        "✓".green()
+    } else {
+        "◦".yellow()
+    }
+}
+
+/// `  [Project] #tag · done 2026-01-02` after a match.
+fn context(task: &Task, projects: &[Project]) -> String {
+    let mut parts = Vec::new();
+    if task.project_id.is_some() {
+        parts.push(format!("[{}]", project_name(task.project_id, projects)));
+    }
+    parts.extend(task.tags.iter().map(|t| format!("#{}", t)));
+    if task.is_cancelled() {
+        parts.push(match &task.cancel_reason {
+            Some(reason) => format!("cancelled: {}", reason),
+            None => "cancelled".to_string(),
+        });
+    } else if let Some(done) = task.completed_on() {
+        parts.push(format!("done {}", done));
+    }
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!("  {}", parts.join(" · "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, Recurrence};
+
+    fn task(text: &str, tags: &[&str]) -> Task {
+        Task::new(
+            text.into(),
+            Priority::Medium,
+            tags.iter().map(|t| t.to_string()).collect(),
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_similarity_prefers_matching_text_and_tags() {
+        let base = task("Fix login page redirect", &["web"]);
+        let close = task("Fix the login redirect", &["web"]);
+        let text_only = task("Fix login page redirect", &["api"]);
+        let unrelated = task("Buy groceries", &[]);
+
+        assert!(similarity(&base, &close) > similarity(&base, &unrelated));
+        assert!(similarity(&base, &close) > 0.6);
+        assert!(
+            similarity(&base, &text_only)
+                < similarity(&base, &task("Fix login page redirect", &["web"]))
+        );
+        assert!(similarity(&base, &unrelated) < MIN_SCORE);
+    }
+
+    #[test]
+    fn test_most_similar_ranks_and_skips_own_series() {
+        let base = task("Water the plants", &[]);
+        let mut done = task("Water plants", &[]);
+        done.mark_done();
+        let tasks = vec![
+            base.clone(),
+            task("Pay rent", &[]),
+            done,
+            task("Water the garden plants", &[]),
+        ];
+
+        let found = most_similar(&tasks, &tasks[0], 5);
+        let texts: Vec<_> = found.iter().map(|(t, _)| t.text.as_str()).collect();
+        assert_eq!(texts, vec!["Water plants", "Water the garden plants"]);
+        assert_eq!(most_similar(&tasks, &tasks[0], 1).len(), 1);
+
+        let mut recurring = task("Water plants", &[]);
+        recurring.recurrence = Some(Recurrence::Daily);
+        recurring.due_date = Some(chrono::Local::now().date_naive());
+        let mut next = recurring.create_next_recurrence(recurring.uuid).unwrap();
+        next.text = "Water plants".into();
+        let tasks = vec![recurring, next];
+        assert!(most_similar(&tasks, &tasks[1], 5).is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+//! Handlers for `todo start <ID>` and `todo stop`.
+//!
+//! Only one task's clock runs at a time: starting a task stops whichever
+//! one was running. `todo done` also stops the clock of the task it
+//! completes.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use colored::Colorize;
+
+use crate::error::TodoError;
+use crate::i18n::{Msg, tf};
+use crate::models::Task;
+use crate::render::formatting::format_duration;
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::validation::{resolve_visible_index, visible_indices};
+
+/// `todo start <ID>`
+pub fn execute_start(storage: &impl Storage, id: usize) -> Result<()> {
+    let mut tasks = storage.load()?;
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+
+    if tasks[index].completed {
+        return Err(TodoError::TaskAlreadyInStatus {
+            id,
+            status: "completed".to_owned(),
+        }
+        .into());
+    }
+    if tasks[index].started_at().is_some() {
+        println!("{} Task #{} is already running.", "·".dimmed(), id);
+        return Ok(());
+    }
+
+    let now = Utc::now();
+    let stopped = stop_all(&mut tasks, now);
+    tasks[index].start_timer(now);
+
+    storage.save(&tasks)?;
+    for &(i, _) in &stopped {
+        storage.record_event(EntityType::Task, tasks[i].uuid, EventType::Edited)?;
+    }
+    storage.record_event(EntityType::Task, tasks[index].uuid, EventType::Edited)?;
+
+    print_stopped(&tasks, &stopped);
+    println!(
+        "{} {}",
+        "▶".green(),
+        tf(Msg::TimerStarted, &[&format!("#{}", id).green()])
+    );
+    Ok(())
+}
+
+/// `todo stop`
+pub fn execute_stop(storage: &impl Storage) -> Result<()> {
+    let mut tasks = storage.load()?;
+    let stopped = stop_all(&mut tasks, Utc::now());
+
+    if stopped.is_empty() {
+        println!("{}", "No task is running.".dimmed());
+        return Ok(());
+    }
+
+    storage.save(&tasks)?;
+    for &(i, _) in &stopped {
+        storage.record_event(EntityType::Task, tasks[i].uuid, EventType::Edited)?;
+    }
+    print_stopped(&tasks, &stopped);
+    Ok(())
+}
+
+/// Closes every open time entry, returning each stopped task's index and
+/// the length of the entry just closed.
+pub(crate) fn stop_all(tasks: &mut [Task], now: DateTime<Utc>) -> Vec<(usize, Duration)> {
+    let mut stopped = Vec::new();
+    for (i, task) in tasks.iter_mut().enumerate() {
+        if let Some(start) = task.started_at()
+            && task.stop_timer(now)
+        {
+            stopped.push((i, now - start));
+        }
+    }
+    stopped
+}
+
+fn print_stopped(tasks: &[Task], stopped: &[(usize, Duration)]) {
+    let vis = visible_indices(tasks, |t| t.is_deleted());
+    let now = Utc::now();
+    for &(index, session) in stopped {
+        let id = vis.iter().position(|&i| i == index).map_or(0, |p| p + 1);
+        println!(
+            "{} {}",
+            "■".yellow(),
+            tf(
+                Msg::TimerStopped,
+                &[
+                    &format!("#{}", id).yellow(),
+                    &format_duration(session),
+                    &format_duration(tasks[index].time_tracked(now)),
+                ]
+            )
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::InMemoryStorage;
+
+    fn make_task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    #[test]
+    fn test_start_stops_the_running_task() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("A"), make_task("B")]).unwrap();
+
+        execute_start(&storage, 1).unwrap();
+        execute_start(&storage, 2).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert!(tasks[0].started_at().is_none());
+        assert_eq!(tasks[0].time_entries.len(), 1);
+        assert!(tasks[1].started_at().is_some());
+
+        execute_stop(&storage).unwrap();
+        let tasks = storage.load().unwrap();
+        assert!(tasks.iter().all(|t| t.started_at().is_none()));
+    }
+
+    #[test]
+    fn test_start_rejects_completed_task() {
+        let storage = InMemoryStorage::default();
+        let mut task = make_task("A");
+        task.mark_done();
+        storage.save(&[task]).unwrap();
+
+        assert!(execute_start(&storage, 1).is_err());
+    }
+}
@@ -1,9 +1,10 @@
-//! Handler for `todo undone <ID>`.
+//! Handler for `todo undone <ID>...`.
 
 use anyhow::Result;
 use colored::Colorize;
 
 use crate::error::TodoError;
+use crate::i18n::{Msg, tf};
 use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::validation::resolve_visible_index;
 
@@ -16,6 +17,47 @@ pub fn execute_silent(storage: &impl Storage, id: usize) -> Result<String> {
     execute_inner(storage, id, true)
 }
 
+/// `todo undone <ID>...` — reopens every task or none.
+pub fn execute_many(storage: &impl Storage, ids: &[usize]) -> Result<()> {
+    if let [id] = ids {
+        return execute(storage, *id);
+    }
+
+    let mut tasks = storage.load()?;
+    let mut changed = Vec::new();
+
+    for &id in ids {
+        let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+            .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+        if !tasks[index].completed {
+            return Err(TodoError::TaskAlreadyInStatus {
+                id,
+                status: "pending".to_owned(),
+            }
+            .into());
+        }
+        tasks[index].mark_undone();
+        changed.push(tasks[index].uuid);
+    }
+
+    storage.save(&tasks)?;
+    for uuid in changed {
+        storage.record_event(EntityType::Task, uuid, EventType::Uncompleted)?;
+    }
+
+    let list = ids
+        .iter()
+        .map(|id| format!("#{}", id))
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!(
+        "{} {}",
+        "✓".green(),
+        tf(Msg::TasksUndone, &[&ids.len(), &list.yellow()])
+    );
+    Ok(())
+}
+
 fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<String> {
     let mut tasks = storage.load()?;
 
@@ -36,9 +78,9 @@ fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<Stri
     storage.record_event(EntityType::Task, task_uuid, EventType::Uncompleted)?;
 
     if !silent {
-        println!("Task {} marked as pending.", format!("#{}", id).yellow());
+        println!("{}", tf(Msg::TaskUndone, &[&format!("#{}", id).yellow()]));
     }
-    Ok(format!("Task #{} marked as pending.", id))
+    Ok(tf(Msg::TaskUndone, &[&format!("#{}", id)]))
 }
 
 #[cfg(test)]
@@ -0,0 +1,420 @@
+//! Handler for `todo time report [--last 7d] [--by project|tag|day] [--csv FILE]`.
+//!
+//! Sums the time entries recorded by `todo start` / `todo stop` and
+//! `todo pomodoro` whose start falls inside the period, grouped by project,
+//! tag, or day, and draws each group as a bar. A running entry counts up to
+//! now. `--csv` additionally writes one row per entry, for invoicing.
+//!
+//! Time on tasks marked billable (`todo edit <ID> --billable --rate 80`) is
+//! totalled separately per group and for the whole period, priced at the
+//! task's rate or the `hourly_rate` setting.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use colored::Colorize;
+
+use crate::cli::{OutputFormat, TimeGroup};
+use crate::config::Config;
+use crate::models::{Lookback, Project, Task, TimeEntry};
+use crate::render::formatting::{format_duration, glyph, project_name, rule, truncate};
+use crate::render::structured;
+use crate::storage::Storage;
+use crate::utils::csv;
+
+const BAR_WIDTH: usize = 24;
+
+/// One time entry inside the report period.
+pub struct ReportEntry<'a> {
+    pub task: &'a Task,
+    pub entry: &'a TimeEntry,
+    /// Local day the entry started on
+    pub day: NaiveDate,
+    pub duration: Duration,
+    /// Hourly rate, for billable entries that have one
+    pub rate: Option<f64>,
+}
+
+impl ReportEntry<'_> {
+    /// Invoice amount, for billable entries that have a rate.
+    pub fn amount(&self) -> Option<f64> {
+        self.rate
+            .map(|rate| rate * self.duration.num_seconds() as f64 / 3600.0)
+    }
+}
+
+/// Tracked time for one row of the report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupTotal {
+    pub key: String,
+    pub time: Duration,
+    pub billable: Duration,
+    pub amount: f64,
+}
+
+impl GroupTotal {
+    fn new(key: String) -> Self {
+        Self {
+            key,
+            time: Duration::zero(),
+            billable: Duration::zero(),
+            amount: 0.0,
+        }
+    }
+
+    fn add(&mut self, entry: &ReportEntry) {
+        self.time += entry.duration;
+        if entry.task.billable {
+            self.billable += entry.duration;
+            self.amount += entry.amount().unwrap_or(0.0);
+        }
+    }
+}
+
+pub fn execute(
+    storage: &impl Storage,
+    last: Lookback,
+    by: TimeGroup,
+    csv: Option<&Path>,
+    format: OutputFormat,
+) -> Result<()> {
+    let (tasks, projects, _) = storage.load_all()?;
+    let now = Utc::now();
+    let today = now.with_timezone(&Local).date_naive();
+    let since = last.since(today);
+
+    let entries = entries_between(&tasks, since, today, now, Config::defaults().hourly_rate);
+    let groups = group_totals(&entries, by, &projects, since, today);
+    let mut total = GroupTotal::new("Total".to_string());
+    for e in &entries {
+        total.add(e);
+    }
+
+    if let Some(path) = csv {
+        write_csv(path, &entries, &projects)?;
+    }
+
+    if format != OutputFormat::Table {
+        let record = |g: &GroupTotal| {
+            serde_json::json!({
+                "key": g.key,
+                "minutes": g.time.num_minutes(),
+                "billable_minutes": g.billable.num_minutes(),
+                "amount": round_cents(g.amount),
+            })
+        };
+        let report = serde_json::json!({
+            "since": since,
+            "until": today,
+            "groups": groups.iter().map(record).collect::<Vec<_>>(),
+            "total": record(&total),
+        });
+        if format == OutputFormat::Json {
+            return structured::print_json(&report);
+        }
+        for g in &groups {
+            println!(
+                "{}\t{}\t{}\t{:.2}",
+                g.key,
+                g.time.num_minutes(),
+                g.billable.num_minutes(),
+                g.amount
+            );
+        }
+        return Ok(());
+    }
+
+    println!(
+        "\n{} {}\n",
+        format!("Time tracked, last {} days", last.days())
+            .bright_white()
+            .bold(),
+        format!("({} → {})", since, today).dimmed()
+    );
+
+    if entries.is_empty() {
+        println!("{}\n", "  No time tracked in this period.".dimmed());
+    } else {
+        print_groups(&groups, &total);
+    }
+    if let Some(path) = csv {
+        println!(
+            "{} {} entries written to {}\n",
+            "✓".green(),
+            entries.len(),
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Entries that started on a local day in `since..=until`, oldest first.
+/// Billable tasks without their own rate are priced at `default_rate`.
+pub fn entries_between(
+    tasks: &[Task],
+    since: NaiveDate,
+    until: NaiveDate,
+    now: DateTime<Utc>,
+    default_rate: Option<f64>,
+) -> Vec<ReportEntry<'_>> {
+    let mut entries: Vec<ReportEntry> = tasks
+        .iter()
+        .filter(|t| !t.is_deleted())
+        .flat_map(|task| task.time_entries.iter().map(move |entry| (task, entry)))
+        .filter_map(|(task, entry)| {
+            let day = entry.start.with_timezone(&Local).date_naive();
+            (since..=until).contains(&day).then(|| ReportEntry {
+                task,
+                entry,
+                day,
+                duration: entry.duration(now),
+                rate: task
+                    .billable
+                    .then(|| task.hourly_rate.or(default_rate))
+                    .flatten(),
+            })
+        })
+        .collect();
+    entries.sort_by_key(|e| e.entry.start);
+    entries
+}
+
+/// Total per group. Days come in calendar order with empty days included;
+/// projects and tags come largest first.
+pub fn group_totals(
+    entries: &[ReportEntry],
+    by: TimeGroup,
+    projects: &[Project],
+    since: NaiveDate,
+    until: NaiveDate,
+) -> Vec<GroupTotal> {
+    let mut totals: BTreeMap<String, GroupTotal> = BTreeMap::new();
+    if by == TimeGroup::Day {
+        for day in since.iter_days().take_while(|d| *d <= until) {
+            totals.insert(day.to_string(), GroupTotal::new(day.to_string()));
+        }
+    }
+
+    for e in entries {
+        let keys = match by {
+            TimeGroup::Project => vec![project_name(e.task.project_id, projects).to_string()],
+            TimeGroup::Tag if e.task.tags.is_empty() => vec!["—".to_string()],
+            TimeGroup::Tag => e.task.tags.iter().map(|t| format!("#{}", t)).collect(),
+            TimeGroup::Day => vec![e.day.to_string()],
+        };
+        for key in keys {
+            totals
+                .entry(key.clone())
+                .or_insert_with(|| GroupTotal::new(key))
+                .add(e);
+        }
+    }
+
+    let mut groups: Vec<_> = totals.into_values().collect();
+    if by != TimeGroup::Day {
+        groups.sort_by_key(|g| std::cmp::Reverse(g.time));
+    }
+    groups
+}
+
+fn print_groups(groups: &[GroupTotal], total: &GroupTotal) {
+    let key_w = groups
+        .iter()
+        .map(|g| g.key.chars().count())
+        .max()
+        .unwrap_or(5)
+        .clamp(5, 28);
+    let max = groups
+        .iter()
+        .map(|g| g.time.num_seconds())
+        .max()
+        .unwrap_or(0);
+    let show_billable = total.billable > Duration::zero();
+
+    let billable_cols = |g: &GroupTotal| {
+        if !show_billable {
+            String::new()
+        } else if g.billable > Duration::zero() {
+            format!(
+                "  {:>8}  {:>10}",
+                format_duration(g.billable).green(),
+                format!("{:.2}", g.amount).green()
+            )
+        } else {
+            format!("  {:>8}  {:>10}", "—".dimmed(), "—".dimmed())
+        }
+    };
+
+    if show_billable {
+        println!(
+            "  {:<key_w$}  {:BAR_WIDTH$}  {:>7}  {:>4}  {:>8}  {:>10}",
+            "",
+            "",
+            "Time".dimmed(),
+            "",
+            "Billable".dimmed(),
+            "Amount".dimmed(),
+            key_w = key_w
+        );
+    }
+    for g in groups {
+        let secs = g.time.num_seconds();
+        let filled = (secs as usize * BAR_WIDTH)
+            .checked_div(max as usize)
+            .unwrap_or(0);
+        let pct = (secs * 100)
+            .checked_div(total.time.num_seconds())
+            .unwrap_or(0);
+        println!(
+            "  {:<key_w$}  {}{}  {:>7}  {}{}",
+            truncate(&g.key, key_w),
+            glyph("█", "#").repeat(filled).cyan(),
+            glyph("░", ".").repeat(BAR_WIDTH - filled).dimmed(),
+            format_duration(g.time),
+            format!("{:>3}%", pct).dimmed(),
+            billable_cols(g),
+            key_w = key_w
+        );
+    }
+    let billable_w = if show_billable { 22 } else { 0 };
+    println!("  {}", rule(key_w + BAR_WIDTH + 16 + billable_w).dimmed());
+    println!(
+        "  {:<key_w$}  {:BAR_WIDTH$}  {:>7}  {:>4}{}\n",
+        "Total".bright_white(),
+        "",
+        format_duration(total.time).bright_white().bold(),
+        "",
+        billable_cols(total),
+        key_w = key_w
+    );
+}
+
+fn write_csv(path: &Path, entries: &[ReportEntry], projects: &[Project]) -> Result<()> {
+    let mut out = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create CSV file: {}", path.display()))?;
+    writeln!(
+        out,
+        "date,start,end,minutes,task,project,tags,billable,rate,amount"
+    )?;
+    for e in entries {
+        let time = |at: DateTime<Utc>| at.with_timezone(&Local).format("%H:%M").to_string();
+        let project = match project_name(e.task.project_id, projects) {
+            "—" => "",
+            name => name,
+        };
+        let money = |value: Option<f64>| value.map(|v| format!("{:.2}", v)).unwrap_or_default();
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{},{}",
+            e.day,
+            time(e.entry.start),
+            e.entry.end.map(time).unwrap_or_default(),
+            e.duration.num_minutes(),
+            csv::field(&e.task.text),
+            csv::field(project),
+            csv::field(&e.task.tags.join(" ")),
+            if e.task.billable { "yes" } else { "no" },
+            money(e.rate),
+            money(e.amount()),
+        )?;
+    }
+    Ok(())
+}
+
+fn round_cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, local_midnight};
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn tracked(project: Option<&Project>, tags: &[&str], on: NaiveDate, minutes: i64) -> Task {
+        let mut task = Task::new(
+            "Work".into(),
+            Priority::Medium,
+            tags.iter().map(|t| t.to_string()).collect(),
+            project.map(|p| p.uuid),
+            None,
+            None,
+        );
+        let start = local_midnight(on) + Duration::hours(9);
+        task.time_entries.push(TimeEntry {
+            start,
+            end: Some(start + Duration::minutes(minutes)),
+            pomodoro: false,
+        });
+        task
+    }
+
+    #[test]
+    fn test_groups_by_project_tag_and_day() {
+        let client = Project::new("Client".into());
+        let projects = vec![client.clone()];
+        let tasks = vec![
+            tracked(Some(&client), &["dev", "api"], day(10), 90),
+            tracked(None, &[], day(11), 30),
+            tracked(Some(&client), &["dev"], day(1), 600),
+        ];
+        let now = local_midnight(day(12));
+        let entries = entries_between(&tasks, day(10), day(12), now, None);
+        assert_eq!(entries.len(), 2);
+
+        let by_project = group_totals(&entries, TimeGroup::Project, &projects, day(10), day(12));
+        let rows: Vec<_> = by_project
+            .iter()
+            .map(|g| (g.key.as_str(), g.time.num_minutes()))
+            .collect();
+        assert_eq!(rows, vec![("Client", 90), ("—", 30)]);
+
+        let by_tag = group_totals(&entries, TimeGroup::Tag, &projects, day(10), day(12));
+        assert_eq!(by_tag.len(), 3);
+        assert!(
+            by_tag
+                .iter()
+                .any(|g| g.key == "#api" && g.time == Duration::minutes(90))
+        );
+
+        let by_day = group_totals(&entries, TimeGroup::Day, &projects, day(10), day(12));
+        let minutes: Vec<_> = by_day.iter().map(|g| g.time.num_minutes()).collect();
+        assert_eq!(minutes, vec![90, 30, 0]);
+    }
+
+    #[test]
+    fn test_billable_totals_use_task_or_default_rate() {
+        let client = Project::new("Client".into());
+        let mut own_rate = tracked(Some(&client), &[], day(10), 90);
+        own_rate.billable = true;
+        own_rate.hourly_rate = Some(100.0);
+        let mut default_rate = tracked(Some(&client), &[], day(10), 30);
+        default_rate.billable = true;
+        let unbilled = tracked(Some(&client), &[], day(10), 60);
+        let tasks = vec![own_rate, default_rate, unbilled];
+
+        let entries = entries_between(&tasks, day(10), day(10), Utc::now(), Some(50.0));
+        let groups = group_totals(&entries, TimeGroup::Project, &[client], day(10), day(10));
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].time, Duration::minutes(180));
+        assert_eq!(groups[0].billable, Duration::minutes(120));
+        assert_eq!(round_cents(groups[0].amount), 175.0);
+    }
+
+    #[test]
+    fn test_lookback_parsing() {
+        let today = day(16);
+        assert_eq!("7d".parse::<Lookback>().unwrap().since(today), day(10));
+        assert_eq!("2w".parse::<Lookback>().unwrap().days(), 14);
+        assert_eq!("30".parse::<Lookback>().unwrap().to_string(), "30d");
+        assert!("0d".parse::<Lookback>().is_err());
+        assert!("soon".parse::<Lookback>().is_err());
+    }
+}
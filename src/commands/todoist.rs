@@ -0,0 +1,428 @@
+//! Handlers for `todo todoist pull` and `todo todoist push`.
+//!
+//! | rustodo   | Todoist                                   |
+//! |-----------|-------------------------------------------|
+//! | text      | content                                   |
+//! | priority  | priority (high ↔ p1/p2, medium ↔ p3, low ↔ p4) |
+//! | tags      | labels                                    |
+//! | project   | project, matched by name (Inbox ↔ none)   |
+//! | due date  | due date                                  |
+//!
+//! Which task is which is remembered in a local `todoist` snapshot mapping
+//! task UUIDs to Todoist IDs, so repeated pulls and pushes update instead of
+//! duplicating. A pull marks linked tasks done once they leave Todoist's
+//! active list; a push closes the Todoist task once its local one is done.
+//! Deleting on either side is not propagated.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::models::{Project, Task, TaskSource};
+use crate::services::todoist::{
+    TaskPayload, TodoistClient, TodoistProject, TodoistTask, priority_from_todoist,
+    priority_to_todoist,
+};
+use crate::storage::{EntityType, EventType, Storage};
+
+/// Snapshot name for the UUID → Todoist ID mapping.
+const SNAPSHOT_NAME: &str = "todoist";
+
+/// Todoist's built-in project, mapped to "no project".
+const INBOX: &str = "Inbox";
+
+/// Task UUID → Todoist task ID.
+pub type IdMap = BTreeMap<Uuid, String>;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PullSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub completed: usize,
+    pub unchanged: usize,
+}
+
+/// A change `push` makes in Todoist.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PushAction {
+    Create {
+        uuid: Uuid,
+        payload: TaskPayload,
+        project: Option<String>,
+    },
+    Update {
+        id: String,
+        payload: TaskPayload,
+    },
+    Close {
+        id: String,
+    },
+}
+
+/// `todo todoist pull`
+pub fn execute_pull(storage: &impl Storage) -> Result<()> {
+    let cfg = Config::load().unwrap_or_default();
+    let todoist = cfg.todoist.unwrap_or_default();
+    let client = TodoistClient::new(&todoist)?;
+
+    println!("\n  Fetching active Todoist tasks...");
+    let remote = client.tasks()?;
+    let projects = client.projects()?;
+
+    let mut map = load_map(storage)?;
+    let summary = apply_pull(storage, &remote, &projects, &mut map)?;
+    save_map(storage, &map)?;
+
+    println!(
+        "{} {} created, {} updated, {} completed, {} unchanged\n",
+        "✓".green(),
+        summary.created.to_string().green(),
+        summary.updated.to_string().yellow(),
+        summary.completed.to_string().cyan(),
+        summary.unchanged.to_string().dimmed(),
+    );
+    Ok(())
+}
+
+/// `todo todoist push`
+pub fn execute_push(storage: &impl Storage) -> Result<()> {
+    let cfg = Config::load().unwrap_or_default();
+    let todoist = cfg.todoist.unwrap_or_default();
+    let client = TodoistClient::new(&todoist)?;
+
+    let remote = client.tasks()?;
+    let mut remote_projects = client.projects()?;
+    let (tasks, projects, _) = storage.load_all()?;
+    let mut map = load_map(storage)?;
+
+    let actions = plan_push(&tasks, &projects, &remote, &map);
+    let (mut created, mut updated, mut closed) = (0, 0, 0);
+    for action in actions {
+        match action {
+            PushAction::Create {
+                uuid,
+                mut payload,
+                project,
+            } => {
+                if let Some(name) = project {
+                    payload.project_id =
+                        Some(remote_project_id(&client, &mut remote_projects, &name)?);
+                }
+                let id = client.create_task(&payload)?;
+                map.insert(uuid, id);
+                // Save after every creation so a failure midway cannot duplicate
+                save_map(storage, &map)?;
+                created += 1;
+            }
+            PushAction::Update { id, payload } => {
+                client.update_task(&id, &payload)?;
+                updated += 1;
+            }
+            PushAction::Close { id } => {
+                client.close_task(&id)?;
+                closed += 1;
+            }
+        }
+    }
+
+    println!(
+        "{} {} created, {} updated, {} closed in Todoist\n",
+        "✓".green(),
+        created.to_string().green(),
+        updated.to_string().yellow(),
+        closed.to_string().cyan(),
+    );
+    Ok(())
+}
+
+/// Applies the active remote tasks to storage and records new links in `map`.
+pub fn apply_pull(
+    storage: &impl Storage,
+    remote: &[TodoistTask],
+    remote_projects: &[TodoistProject],
+    map: &mut IdMap,
+) -> Result<PullSummary> {
+    let mut tasks = storage.load()?;
+    let mut summary = PullSummary::default();
+    let by_id: BTreeMap<String, Uuid> = map.iter().map(|(uuid, id)| (id.clone(), *uuid)).collect();
+
+    for item in remote {
+        let project_name = remote_projects
+            .iter()
+            .find(|p| p.id == item.project_id && p.name != INBOX)
+            .map(|p| p.name.as_str());
+        let project_id = match project_name {
+            Some(name) => Some(Project::resolve_or_create(
+                storage,
+                &storage.load_projects()?,
+                name,
+            )?),
+            None => None,
+        };
+
+        let existing = by_id.get(&item.id).and_then(|uuid| {
+            tasks
+                .iter_mut()
+                .find(|t| t.uuid == *uuid && !t.is_deleted())
+        });
+
+        match existing {
+            Some(task) => {
+                // Compare priorities on Todoist's scale so pulling right
+                // after a push never changes anything
+                let changed = task.text != item.content
+                    || priority_to_todoist(task.priority) != item.priority
+                    || task.tags != item.labels
+                    || task.project_id != project_id
+                    || task.due_date != item.due;
+                if changed {
+                    task.text = item.content.clone();
+                    if priority_to_todoist(task.priority) != item.priority {
+                        task.priority = priority_from_todoist(item.priority);
+                    }
+                    task.tags = item.labels.clone();
+                    task.project_id = project_id;
+                    task.due_date = item.due;
+                    task.touch();
+                    storage.upsert_task(task)?;
+                    storage.record_event(EntityType::Task, task.uuid, EventType::Edited)?;
+                    summary.updated += 1;
+                } else {
+                    summary.unchanged += 1;
+                }
+            }
+            None => {
+                let mut task = Task::new(
+                    item.content.clone(),
+                    priority_from_todoist(item.priority),
+                    item.labels.clone(),
+                    project_id,
+                    item.due,
+                    None,
+                );
+                task.source = Some(TaskSource::Import("todoist".to_string()));
+                storage.upsert_task(&task)?;
+                storage.record_event(EntityType::Task, task.uuid, EventType::Created)?;
+                map.insert(task.uuid, item.id.clone());
+                tasks.push(task);
+                summary.created += 1;
+            }
+        }
+    }
+
+    // Linked tasks no longer active in Todoist were completed there
+    for task in tasks.iter_mut() {
+        let gone = map
+            .get(&task.uuid)
+            .is_some_and(|id| !remote.iter().any(|r| &r.id == id));
+        if gone && !task.completed && !task.is_deleted() {
+            task.mark_done();
+            storage.upsert_task(task)?;
+            storage.record_event(EntityType::Task, task.uuid, EventType::Completed)?;
+            summary.completed += 1;
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Works out what `push` has to change in Todoist.
+pub fn plan_push(
+    tasks: &[Task],
+    projects: &[Project],
+    remote: &[TodoistTask],
+    map: &IdMap,
+) -> Vec<PushAction> {
+    let mut actions = Vec::new();
+
+    for task in tasks.iter().filter(|t| !t.is_deleted()) {
+        let project = task
+            .project_id
+            .and_then(|uuid| projects.iter().find(|p| p.uuid == uuid))
+            .map(|p| p.name.clone());
+        let payload = TaskPayload {
+            content: task.text.clone(),
+            priority: priority_to_todoist(task.priority),
+            labels: task.tags.clone(),
+            project_id: None,
+            due: task.due_date,
+        };
+
+        let linked = map
+            .get(&task.uuid)
+            .and_then(|id| remote.iter().find(|r| &r.id == id));
+        match linked {
+            Some(item) if task.completed => actions.push(PushAction::Close {
+                id: item.id.clone(),
+            }),
+            Some(item) => {
+                let changed = item.content != payload.content
+                    || item.priority != payload.priority
+                    || item.labels != payload.labels
+                    || item.due != payload.due;
+                if changed {
+                    actions.push(PushAction::Update {
+                        id: item.id.clone(),
+                        payload,
+                    });
+                }
+            }
+            // Linked but closed in Todoist: left for the next pull
+            None if map.contains_key(&task.uuid) => {}
+            None if !task.completed => actions.push(PushAction::Create {
+                uuid: task.uuid,
+                payload,
+                project,
+            }),
+            None => {}
+        }
+    }
+    actions
+}
+
+/// The Todoist ID of the project named `name`, creating it if needed.
+fn remote_project_id(
+    client: &TodoistClient,
+    remote_projects: &mut Vec<TodoistProject>,
+    name: &str,
+) -> Result<String> {
+    if let Some(project) = remote_projects
+        .iter()
+        .find(|p| p.name.eq_ignore_ascii_case(name))
+    {
+        return Ok(project.id.clone());
+    }
+    let project = client.create_project(name)?;
+    let id = project.id.clone();
+    remote_projects.push(project);
+    Ok(id)
+}
+
+fn load_map(storage: &impl Storage) -> Result<IdMap> {
+    match storage.load_snapshot(SNAPSHOT_NAME)? {
+        Some(payload) => serde_json::from_str(&payload).context("Todoist link state is corrupted"),
+        None => Ok(IdMap::new()),
+    }
+}
+
+fn save_map(storage: &impl Storage, map: &IdMap) -> Result<()> {
+    let payload = serde_json::to_string(map).context("Failed to serialize Todoist links")?;
+    storage.save_snapshot(SNAPSHOT_NAME, &payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::InMemoryStorage;
+
+    fn item(id: &str, content: &str) -> TodoistTask {
+        TodoistTask {
+            id: id.into(),
+            content: content.into(),
+            priority: 1,
+            labels: vec!["home".into()],
+            project_id: "p1".into(),
+            due: None,
+        }
+    }
+
+    fn projects() -> Vec<TodoistProject> {
+        vec![
+            TodoistProject {
+                id: "p1".into(),
+                name: "Chores".into(),
+            },
+            TodoistProject {
+                id: "p0".into(),
+                name: INBOX.into(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_pull_creates_updates_and_completes() {
+        let storage = InMemoryStorage::default();
+        let mut map = IdMap::new();
+
+        let summary = apply_pull(
+            &storage,
+            &[item("1", "Mow lawn"), item("2", "Fix sink")],
+            &projects(),
+            &mut map,
+        )
+        .unwrap();
+        assert_eq!(summary.created, 2);
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks[0].priority, Priority::Low);
+        assert_eq!(tasks[0].tags, vec!["home"]);
+        assert_eq!(storage.load_projects().unwrap()[0].name, "Chores");
+        assert_eq!(map.len(), 2);
+
+        let mut inbox = item("1", "Mow the lawn");
+        inbox.project_id = "p0".into();
+        let summary = apply_pull(&storage, &[inbox], &projects(), &mut map).unwrap();
+        assert_eq!(
+            summary,
+            PullSummary {
+                created: 0,
+                updated: 1,
+                completed: 1,
+                unchanged: 0
+            }
+        );
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks[0].text, "Mow the lawn");
+        assert!(tasks[0].project_id.is_none());
+        assert!(tasks[1].completed);
+    }
+
+    #[test]
+    fn test_plan_push() {
+        let mut linked = Task::new("Mow lawn".into(), Priority::High, vec![], None, None, None);
+        let mut done = Task::new("Fix sink".into(), Priority::Low, vec![], None, None, None);
+        done.mark_done();
+        let project = Project::new("Errands".into());
+        let new = Task::new(
+            "Buy milk".into(),
+            Priority::Medium,
+            vec![],
+            Some(project.uuid),
+            None,
+            None,
+        );
+        let closed_locally = Task::new("Old".into(), Priority::Low, vec![], None, None, None);
+        let mut map = IdMap::new();
+        map.insert(linked.uuid, "1".into());
+        map.insert(done.uuid, "2".into());
+        map.insert(closed_locally.uuid, "3".into());
+        linked.tags = vec!["home".into()];
+
+        let mut remote_linked = item("1", "Mow lawn");
+        remote_linked.priority = 4;
+        let actions = plan_push(
+            &[linked, done, new.clone(), closed_locally],
+            &[project],
+            &[remote_linked, item("2", "Fix sink")],
+            &map,
+        );
+
+        assert_eq!(actions.len(), 2);
+        assert_eq!(actions[0], PushAction::Close { id: "2".into() });
+        match &actions[1] {
+            PushAction::Create {
+                uuid,
+                payload,
+                project,
+            } => {
+                assert_eq!(*uuid, new.uuid);
+                assert_eq!(payload.priority, 2);
+                assert_eq!(project.as_deref(), Some("Errands"));
+            }
+            other => panic!("unexpected action {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! Handler for `todo triage`.
+//!
+//! Steps through pending tasks one at a time, taking a single keypress per
+//! task:
+//!
+//! | Key     | Action                               |
+//! |---------|--------------------------------------|
+//! | `d`     | Mark done                            |
+//! | `s`     | Snooze until tomorrow (`wait_until`) |
+//! | `p`     | Cycle priority H → M → L → H         |
+//! | `t`     | Add a tag                            |
+//! | `x`     | Delete                               |
+//! | `space` | Skip                                 |
+//! | `q`     | Quit                                 |
+//!
+//! Every action is saved as soon as it is taken, so quitting halfway keeps
+//! the work done so far. Tasks that are already snoozed are left out.
+
+use anyhow::Result;
+use chrono::{Duration, Local};
+use colored::Colorize;
+use uuid::Uuid;
+
+use crate::commands::task::{done, remove};
+use crate::models::{Priority, Task};
+use crate::render::formatting::{due_relative_text, priority_label};
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::confirm::{prompt, read_key};
+use crate::utils::tag_normalizer::{collect_existing_tags, has_tag, normalize_tags};
+use crate::utils::validation::{validate_tags, visible_indices};
+
+/// A change `todo triage` can make to the task on screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Done,
+    Snooze,
+    CyclePriority,
+    Tag(String),
+    Delete,
+}
+
+impl Action {
+    /// Whether the task stays on screen afterwards (for further edits).
+    fn keeps_task(&self) -> bool {
+        matches!(self, Action::CyclePriority | Action::Tag(_))
+    }
+}
+
+enum Step {
+    Apply(Action),
+    Skip,
+    Quit,
+}
+
+pub fn execute(storage: &impl Storage) -> Result<()> {
+    let queue: Vec<Uuid> = storage
+        .load()?
+        .iter()
+        .filter(|t| !t.is_deleted() && !t.completed && !t.is_waiting())
+        .map(|t| t.uuid)
+        .collect();
+
+    if queue.is_empty() {
+        println!("{} Nothing to triage.", "✓".green());
+        return Ok(());
+    }
+
+    println!(
+        "\n{} {}\n{}\n",
+        "Triage:".bright_white().bold(),
+        format!("{} pending tasks", queue.len()).dimmed(),
+        "d done · s snooze 1d · p priority · t tag · x delete · space skip · q quit".dimmed()
+    );
+
+    let mut changed = 0;
+    'queue: for (pos, &uuid) in queue.iter().enumerate() {
+        loop {
+            let tasks = storage.load()?;
+            let Some((id, task)) = find(&tasks, uuid) else {
+                continue 'queue;
+            };
+            show(id, task, pos + 1, queue.len());
+
+            let action = match read_step()? {
+                Step::Apply(action) => action,
+                Step::Skip => continue 'queue,
+                Step::Quit => break 'queue,
+            };
+            let keep = action.keeps_task();
+            match apply(storage, uuid, action) {
+                Ok(msg) => {
+                    changed += 1;
+                    println!("  {} {}", "✓".green(), msg.dimmed());
+                }
+                Err(e) => println!("  {} {}", "✗".red(), e),
+            }
+            if !keep {
+                continue 'queue;
+            }
+        }
+    }
+
+    println!("\n{} {} changes saved.\n", "✓".green(), changed);
+    Ok(())
+}
+
+/// Applies `action` to the task with `uuid` and saves it, returning a
+/// one-line summary.
+pub fn apply(storage: &impl Storage, uuid: Uuid, action: Action) -> Result<String> {
+    let mut tasks = storage.load()?;
+    let Some((id, _)) = find(&tasks, uuid) else {
+        anyhow::bail!("task no longer exists");
+    };
+
+    match action {
+        Action::Done => return done::execute_silent(storage, id),
+        Action::Delete => return remove::execute_silent(storage, id),
+        _ => {}
+    }
+
+    let existing_tags = collect_existing_tags(&tasks);
+    let task = tasks
+        .iter_mut()
+        .find(|t| t.uuid == uuid)
+        .expect("task found above");
+    let msg = match action {
+        Action::Snooze => {
+            let until = Local::now().date_naive() + Duration::days(1);
+            task.wait_until = Some(until);
+            format!("Snoozed #{} until {}", id, until)
+        }
+        Action::CyclePriority => {
+            task.priority = next_priority(task.priority);
+            format!("Priority of #{} set to {:?}", id, task.priority)
+        }
+        Action::Tag(tag) => {
+            let (tags, _) = normalize_tags(vec![tag], &existing_tags);
+            validate_tags(&tags)?;
+            let tag = tags.into_iter().next().unwrap_or_default();
+            if has_tag(&task.tags, &tag) {
+                return Ok(format!("#{} is already tagged {}", id, tag));
+            }
+            task.tags.push(tag.clone());
+            format!("Tagged #{} with {}", id, tag)
+        }
+        Action::Done | Action::Delete => unreachable!("handled above"),
+    };
+
+    task.touch();
+    storage.upsert_task(task)?;
+    storage.record_event(EntityType::Task, uuid, EventType::Edited)?;
+    Ok(msg)
+}
+
+/// The task with `uuid` and its visible ID.
+fn find(tasks: &[Task], uuid: Uuid) -> Option<(usize, &Task)> {
+    visible_indices(tasks, |t| t.is_deleted())
+        .into_iter()
+        .enumerate()
+        .find(|&(_, i)| tasks[i].uuid == uuid)
+        .map(|(pos, i)| (pos + 1, &tasks[i]))
+}
+
+fn next_priority(priority: Priority) -> Priority {
+    match priority {
+        Priority::High => Priority::Medium,
+        Priority::Medium => Priority::Low,
+        Priority::Low => Priority::High,
+    }
+}
+
+fn show(id: usize, task: &Task, pos: usize, total: usize) {
+    let mut details = Vec::new();
+    if let Some(due) = task.due_date {
+        details.push(format!("due {}", due_relative_text(due)));
+    }
+    if !task.tags.is_empty() {
+        details.push(task.tags.join(", "));
+    }
+    println!(
+        "{} {} {} {}  {}",
+        format!("[{}/{}]", pos, total).dimmed(),
+        format!("#{}", id).yellow(),
+        priority_label(task.priority),
+        task.text.bright_white(),
+        details.join(" · ").dimmed()
+    );
+}
+
+/// Reads keys until one maps to a step, prompting for the tag after `t`.
+fn read_step() -> Result<Step> {
+    loop {
+        print!("  {} ", "›".dimmed());
+        std::io::Write::flush(&mut std::io::stdout())?;
+        let key = read_key()?;
+        println!("{}", key);
+        let action = match key {
+            'd' => Action::Done,
+            's' => Action::Snooze,
+            'p' => Action::CyclePriority,
+            'x' => Action::Delete,
+            't' => {
+                let tag = prompt("  Tag:")?;
+                if tag.is_empty() {
+                    continue;
+                }
+                Action::Tag(tag)
+            }
+            ' ' => return Ok(Step::Skip),
+            'q' => return Ok(Step::Quit),
+            _ => {
+                println!("  {}", "Unknown key — d s p t x, space or q".dimmed());
+                continue;
+            }
+        };
+        return Ok(Step::Apply(action));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+
+    fn setup(texts: &[&str]) -> (InMemoryStorage, Vec<Uuid>) {
+        let storage = InMemoryStorage::default();
+        let tasks: Vec<Task> = texts
+            .iter()
+            .map(|text| Task::new(text.to_string(), Priority::Medium, vec![], None, None, None))
+            .collect();
+        storage.save(&tasks).unwrap();
+        (storage, tasks.iter().map(|t| t.uuid).collect())
+    }
+
+    fn get(storage: &InMemoryStorage, uuid: Uuid) -> Task {
+        storage
+            .load()
+            .unwrap()
+            .into_iter()
+            .find(|t| t.uuid == uuid)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_done_and_delete_persist() {
+        let (storage, uuids) = setup(&["a", "b"]);
+
+        apply(&storage, uuids[0], Action::Delete).unwrap();
+        apply(&storage, uuids[1], Action::Done).unwrap();
+
+        assert!(get(&storage, uuids[0]).is_deleted());
+        assert!(get(&storage, uuids[1]).completed);
+    }
+
+    #[test]
+    fn test_snooze_priority_and_tag() {
+        let (storage, uuids) = setup(&["a"]);
+
+        apply(&storage, uuids[0], Action::Snooze).unwrap();
+        apply(&storage, uuids[0], Action::CyclePriority).unwrap();
+        apply(&storage, uuids[0], Action::Tag("work".into())).unwrap();
+        apply(&storage, uuids[0], Action::Tag("work".into())).unwrap();
+
+        let task = get(&storage, uuids[0]);
+        assert!(task.is_waiting());
+        assert_eq!(task.priority, Priority::Low);
+        assert_eq!(task.tags, vec!["work".to_string()]);
+    }
+}
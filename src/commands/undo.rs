@@ -0,0 +1,485 @@
+//! Handlers for `todo undo` and `todo redo`.
+//!
+//! Every task mutation run from the command line (add, edit, done, undone,
+//! remove, clear, recur, norecur, purge) is recorded in an operation journal
+//! kept in the `undo_journal` snapshot. An entry holds the tasks and notes
+//! the command touched, as they were before and after it ran.
+//!
+//! `todo undo` writes the "before" rows back and moves the entry to the redo
+//! stack; `todo redo` does the reverse. Rows a command created are
+//! soft-deleted on undo, and rows it removed for good (`purge`) are
+//! re-inserted. Running any other mutation clears the redo stack.
+//!
+//! An entry is only applied while the rows it touched are exactly as it left
+//! them — a task edited since (for example from the TUI, which is not
+//! journaled) makes undo fail instead of silently losing that edit.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::cli::Commands;
+use crate::models::{Note, Task};
+use crate::storage::Storage;
+
+/// Storage key of the journal snapshot.
+const SNAPSHOT_NAME: &str = "undo_journal";
+
+/// Oldest entries are dropped beyond this many.
+const MAX_ENTRIES: usize = 50;
+
+// ── Journal ───────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Journal {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+}
+
+/// One recorded operation. A UUID missing from `before` means the command
+/// created the row; missing from `after` means it deleted the row outright.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    op: String,
+    at: DateTime<Utc>,
+    tasks: Changes<Task>,
+    notes: Changes<Note>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Changes<T> {
+    before: Vec<T>,
+    after: Vec<T>,
+}
+
+/// Tasks and notes as loaded from storage.
+pub struct State {
+    at: DateTime<Utc>,
+    tasks: Vec<Task>,
+    notes: Vec<Note>,
+}
+
+/// Row types the journal can track.
+trait Row: Clone + Serialize + DeserializeOwned {
+    fn uuid(&self) -> Uuid;
+    fn updated_at(&self) -> Option<DateTime<Utc>>;
+    fn soft_delete(&mut self);
+}
+
+impl Row for Task {
+    fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+    fn soft_delete(&mut self) {
+        Task::soft_delete(self);
+    }
+}
+
+impl Row for Note {
+    fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+    fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+    fn soft_delete(&mut self) {
+        Note::soft_delete(self);
+    }
+}
+
+fn same<T: Serialize>(a: &T, b: &T) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+/// Whether `new` differs from `old`, a row loaded at `since`.
+///
+/// Every mutation bumps `updated_at`, so a row still stamped before `since`
+/// is unchanged. Storage keeps whole seconds, so rows stamped within the
+/// same second (or without a stamp) are serialized and compared in full.
+fn changed<T: Row>(old: &T, new: &T, since: DateTime<Utc>) -> bool {
+    match (old.updated_at(), new.updated_at()) {
+        (Some(a), Some(b)) if a != b => true,
+        (Some(a), Some(_)) if a.timestamp() < since.timestamp() => false,
+        _ => !same(old, new),
+    }
+}
+
+fn by_uuid<T: Row>(rows: &[T]) -> HashMap<Uuid, &T> {
+    rows.iter().map(|r| (r.uuid(), r)).collect()
+}
+
+impl<T: Row> Changes<T> {
+    /// Rows that differ between the two full lists, `before` loaded at `since`.
+    fn between(before: &[T], after: &[T], since: DateTime<Utc>) -> Self {
+        let mut old_rows = by_uuid(before);
+        let mut changes = Changes {
+            before: Vec::new(),
+            after: Vec::new(),
+        };
+        for new in after {
+            match old_rows.remove(&new.uuid()) {
+                Some(old) if !changed(old, new, since) => {}
+                Some(old) => {
+                    changes.before.push(old.clone());
+                    changes.after.push(new.clone());
+                }
+                None => changes.after.push(new.clone()),
+            }
+        }
+        // Whatever is left was removed outright; keep the original order.
+        changes.before.extend(
+            before
+                .iter()
+                .filter(|r| old_rows.contains_key(&r.uuid()))
+                .cloned(),
+        );
+        changes
+    }
+
+    fn is_empty(&self) -> bool {
+        self.before.is_empty() && self.after.is_empty()
+    }
+
+    fn uuids(&self) -> Vec<Uuid> {
+        let mut uuids: Vec<Uuid> = self
+            .before
+            .iter()
+            .chain(&self.after)
+            .map(Row::uuid)
+            .collect();
+        uuids.sort();
+        uuids.dedup();
+        uuids
+    }
+
+    /// True when `current` still holds exactly the `after` rows.
+    fn matches(&self, current: &[T]) -> bool {
+        let current = by_uuid(current);
+        let after = by_uuid(&self.after);
+        self.uuids()
+            .into_iter()
+            .all(|uuid| match (current.get(&uuid), after.get(&uuid)) {
+                (Some(a), Some(b)) => same(*a, *b),
+                (None, None) => true,
+                _ => false,
+            })
+    }
+
+    /// Rows to write to turn `after` back into `before`, and the rows that
+    /// no longer exist and must be inserted first.
+    fn reverted(&self) -> (Vec<T>, Vec<T>) {
+        let mut rows = Vec::new();
+        let mut reinserted = Vec::new();
+        for uuid in self.uuids() {
+            let old = self.before.iter().find(|r| r.uuid() == uuid);
+            let new = self.after.iter().find(|r| r.uuid() == uuid);
+            match (old, new) {
+                (Some(old), Some(_)) => rows.push(old.clone()),
+                (Some(old), None) => {
+                    reinserted.push(old.clone());
+                    rows.push(old.clone());
+                }
+                (None, Some(new)) => {
+                    let mut gone = new.clone();
+                    gone.soft_delete();
+                    rows.push(gone);
+                }
+                (None, None) => {}
+            }
+        }
+        (rows, reinserted)
+    }
+}
+
+fn load_journal(storage: &impl Storage) -> Result<Journal> {
+    match storage.load_snapshot(SNAPSHOT_NAME)? {
+        Some(payload) => serde_json::from_str(&payload).context("Undo journal is corrupted"),
+        None => Ok(Journal::default()),
+    }
+}
+
+fn save_journal(storage: &impl Storage, journal: &Journal) -> Result<()> {
+    let payload = serde_json::to_string(journal).context("Failed to serialize undo journal")?;
+    storage.save_snapshot(SNAPSHOT_NAME, &payload)
+}
+
+// ── Recording ─────────────────────────────────────────────────────────────────
+
+/// Name under which `command` is journaled, or `None` if it is not.
+pub fn journal_label(command: &Commands) -> Option<&'static str> {
+    Some(match command {
+        Commands::Add(_) => "add",
+        Commands::Edit(_) => "edit",
+        Commands::Annotate { .. } => "annotate",
+        Commands::Done { .. } => "done",
+        Commands::Undone { .. } => "undone",
+        Commands::Cancel { .. } => "cancel",
+        Commands::Remove { .. } => "remove",
+        Commands::Clear { .. } => "clear",
+        Commands::Recur { .. } => "recur",
+        Commands::ClearRecur { .. } => "norecur",
+        Commands::Purge { .. } => "purge",
+        Commands::Triage => "triage",
+        Commands::Start { .. } => "start",
+        Commands::Stop => "stop",
+        Commands::Remind { id: Some(_), .. } => "remind",
+        _ => return None,
+    })
+}
+
+/// Loads the rows a journaled command may change, before it runs.
+pub fn capture(storage: &impl Storage) -> Result<State> {
+    Ok(State {
+        at: Utc::now(),
+        tasks: storage.load()?,
+        notes: storage.load_notes()?,
+    })
+}
+
+/// Journals what changed since `before` was captured. No-op if nothing did.
+pub fn record(storage: &impl Storage, op: &str, before: State) -> Result<()> {
+    let after = capture(storage)?;
+    let entry = Entry {
+        op: op.to_string(),
+        at: Utc::now(),
+        tasks: Changes::between(&before.tasks, &after.tasks, before.at),
+        notes: Changes::between(&before.notes, &after.notes, before.at),
+    };
+    if entry.tasks.is_empty() && entry.notes.is_empty() {
+        return Ok(());
+    }
+
+    let mut journal = load_journal(storage)?;
+    journal.undo.push(entry);
+    if journal.undo.len() > MAX_ENTRIES {
+        journal.undo.remove(0);
+    }
+    journal.redo.clear();
+    save_journal(storage, &journal)
+}
+
+// ── Undo / redo ───────────────────────────────────────────────────────────────
+
+/// `todo undo` — reverts the most recent journaled operation.
+pub fn execute_undo(storage: &impl Storage) -> Result<()> {
+    let mut journal = load_journal(storage)?;
+    let Some(entry) = journal.undo.pop() else {
+        bail!("Nothing to undo");
+    };
+    let inverse = apply(storage, &entry)?;
+    journal.redo.push(inverse);
+    save_journal(storage, &journal)?;
+
+    println!(
+        "{} Undid {} ({})",
+        "✓".green(),
+        entry.op.cyan(),
+        describe(&entry)
+    );
+    Ok(())
+}
+
+/// `todo redo` — reapplies the most recently undone operation.
+pub fn execute_redo(storage: &impl Storage) -> Result<()> {
+    let mut journal = load_journal(storage)?;
+    let Some(entry) = journal.redo.pop() else {
+        bail!("Nothing to redo");
+    };
+    let inverse = apply(storage, &entry)?;
+    journal.undo.push(inverse);
+    save_journal(storage, &journal)?;
+
+    println!(
+        "{} Redid {} ({})",
+        "✓".green(),
+        entry.op.cyan(),
+        describe(&entry)
+    );
+    Ok(())
+}
+
+/// Writes `entry`'s before-state and returns the entry that undoes that.
+fn apply(storage: &impl Storage, entry: &Entry) -> Result<Entry> {
+    let current = capture(storage)?;
+    if !entry.tasks.matches(&current.tasks) || !entry.notes.matches(&current.notes) {
+        bail!(
+            "Cannot undo '{}': the affected tasks or notes changed since",
+            entry.op
+        );
+    }
+
+    let (task_rows, reinserted) = entry.tasks.reverted();
+    let (note_rows, _) = entry.notes.reverted();
+
+    // Re-inserted rows go in first without links so parent/dependency
+    // references between them are satisfied when the full list is written.
+    let mut tasks = current.tasks.clone();
+    if !reinserted.is_empty() {
+        let bare: Vec<Task> = reinserted
+            .iter()
+            .cloned()
+            .map(|mut t| {
+                t.parent_id = None;
+                t.depends_on.clear();
+                t
+            })
+            .collect();
+        storage.save(&[tasks.clone(), bare].concat())?;
+    }
+    merge(&mut tasks, task_rows);
+    storage.save(&tasks)?;
+
+    let mut notes = current.notes.clone();
+    merge(&mut notes, note_rows);
+    storage.save_notes(&notes)?;
+
+    let now = capture(storage)?;
+    let pick = |rows: &[Task], uuids: &[Uuid]| -> Vec<Task> {
+        rows.iter()
+            .filter(|r| uuids.contains(&r.uuid))
+            .cloned()
+            .collect()
+    };
+    let pick_notes = |rows: &[Note], uuids: &[Uuid]| -> Vec<Note> {
+        rows.iter()
+            .filter(|r| uuids.contains(&r.uuid))
+            .cloned()
+            .collect()
+    };
+    let task_uuids = entry.tasks.uuids();
+    let note_uuids = entry.notes.uuids();
+    Ok(Entry {
+        op: entry.op.clone(),
+        at: Utc::now(),
+        tasks: Changes {
+            before: pick(&current.tasks, &task_uuids),
+            after: pick(&now.tasks, &task_uuids),
+        },
+        notes: Changes {
+            before: pick_notes(&current.notes, &note_uuids),
+            after: pick_notes(&now.notes, &note_uuids),
+        },
+    })
+}
+
+/// Replaces rows in `all` by UUID, appending those not present.
+fn merge<T: Row>(all: &mut Vec<T>, rows: Vec<T>) {
+    let index: HashMap<Uuid, usize> = all.iter().enumerate().map(|(i, r)| (r.uuid(), i)).collect();
+    for row in rows {
+        match index.get(&row.uuid()) {
+            Some(&i) => all[i] = row,
+            None => all.push(row),
+        }
+    }
+}
+
+fn describe(entry: &Entry) -> String {
+    let count = entry.tasks.uuids().len();
+    format!("{} task{}", count, if count == 1 { "" } else { "s" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::InMemoryStorage;
+
+    fn make_task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    /// Runs `mutate` as a journaled operation.
+    fn journaled(storage: &InMemoryStorage, op: &str, mutate: impl FnOnce(&InMemoryStorage)) {
+        let before = capture(storage).unwrap();
+        mutate(storage);
+        record(storage, op, before).unwrap();
+    }
+
+    #[test]
+    fn test_undo_and_redo_edit() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("original")]).unwrap();
+
+        journaled(&storage, "edit", |s| {
+            let mut tasks = s.load().unwrap();
+            tasks[0].text = "changed".into();
+            s.save(&tasks).unwrap();
+        });
+
+        execute_undo(&storage).unwrap();
+        assert_eq!(storage.load().unwrap()[0].text, "original");
+
+        execute_redo(&storage).unwrap();
+        assert_eq!(storage.load().unwrap()[0].text, "changed");
+    }
+
+    #[test]
+    fn test_undo_add_soft_deletes_task() {
+        let storage = InMemoryStorage::default();
+        journaled(&storage, "add", |s| s.save(&[make_task("new")]).unwrap());
+
+        execute_undo(&storage).unwrap();
+        assert!(storage.load().unwrap()[0].is_deleted());
+
+        execute_redo(&storage).unwrap();
+        assert!(!storage.load().unwrap()[0].is_deleted());
+    }
+
+    #[test]
+    fn test_undo_reinserts_hard_deleted_task() {
+        let storage = InMemoryStorage::default();
+        let task = make_task("purged");
+        storage.save(std::slice::from_ref(&task)).unwrap();
+
+        journaled(&storage, "purge", |s| s.delete_tasks(&[task.uuid]).unwrap());
+        assert!(storage.load().unwrap().is_empty());
+
+        execute_undo(&storage).unwrap();
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].text, "purged");
+    }
+
+    #[test]
+    fn test_undo_refuses_when_task_changed_since() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("a")]).unwrap();
+        journaled(&storage, "done", |s| {
+            let mut tasks = s.load().unwrap();
+            tasks[0].mark_done();
+            s.save(&tasks).unwrap();
+        });
+
+        // Untracked change after the journaled one
+        let mut tasks = storage.load().unwrap();
+        tasks[0].text = "edited elsewhere".into();
+        storage.save(&tasks).unwrap();
+
+        assert!(execute_undo(&storage).is_err());
+    }
+
+    #[test]
+    fn test_new_operation_clears_redo() {
+        let storage = InMemoryStorage::default();
+        journaled(&storage, "add", |s| s.save(&[make_task("a")]).unwrap());
+        execute_undo(&storage).unwrap();
+        journaled(&storage, "add", |s| s.save(&[make_task("b")]).unwrap());
+
+        assert!(execute_redo(&storage).is_err());
+    }
+
+    #[test]
+    fn test_nothing_to_undo() {
+        let storage = InMemoryStorage::default();
+        assert!(execute_undo(&storage).is_err());
+        assert!(execute_redo(&storage).is_err());
+    }
+}
@@ -6,6 +6,22 @@
 //!
 //! ```toml
 //! holidays_locale = "pt-BR"  # or "en-US", "none"
+//! holidays_file   = "holidays.txt" # extra days off, `YYYY-MM-DD Name` per line
+//! storage         = "sqlite"  # backend name, optionally "name:opts"
+//! age_warning_days = 30       # `list --age` highlights pending tasks older than this
+//! due_display     = "weekday" # "absolute" (default), "relative", or "weekday"
+//! priority_display = "word"   # "letter" (default), "word", or "bang"
+//! celebrate       = "bell"    # streak milestones on `done`: "message" (default), "bell", or "off"
+//! default_priority = "medium" # `todo add` without --priority
+//! default_sort    = "urgency" # `todo list` without --sort; "due:asc,priority:desc" chains keys
+//! due_soon_days   = 7         # window for `--due soon` and the stats count
+//! data_dir        = "/path/to/data" # database directory (RUSTODO_DATA_DIR wins)
+//! confirm         = true      # false skips confirmation prompts, like --yes
+//! locale          = "pt-BR"   # output language; defaults to $LANG, then English
+//! remind_before   = "1d"      # `todo remind` lead time: 30m, 2h, 1d, 1w, or 0
+//! hourly_rate     = 80.0      # rate for billable tasks without their own
+//! slow_storage_ms = 500       # hint when a storage call takes longer; 0 disables
+//! capture_origins = ["moz-extension://…"] # browser origins `todo capture` accepts
 //!
 //! [theme]
 //! accent         = "#00ffff"
@@ -18,15 +34,43 @@
 //! search_bg      = "#1e1e32"
 //! focused_bg     = "#1e2840"
 //! focused_border = "#00ffff"
+//!
+//! [jira]
+//! base_url           = "https://example.atlassian.net"
+//! email              = "me@example.com"   # omit for bearer-token auth
+//! token              = "..."
+//! transition_on_done = "Done"             # omit to never transition
+//!
+//! [todoist]
+//! token = "..."                           # Settings → Integrations → Developer
+//!
+//! [prompt]
+//! format       = "⚑{pending} ⏰{overdue}"  # also {today} and {urgent}
+//! min_count    = 1                          # hide segments below this count
+//! urgent_score = 10.0                       # urgency counted by {urgent}
+//!
+//! [goals]                                   # `todo stats show --goal`
+//! daily_completions = 5                     # complete at least 5 tasks a day
+//! overdue_below     = 3                     # keep fewer than 3 tasks overdue
+//!
+//! [digest]
+//! sendmail = "/usr/sbin/sendmail -t"        # `todo digest --send` pipes to it
+//! to       = "team@example.com"
+//! subject  = "Weekly digest"                # defaults to "<Period> digest"
 //! ```
 
 use std::fs;
 use std::path::PathBuf;
+use std::sync::LazyLock;
+use std::time::Duration;
 
 use anyhow::Result;
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
 
+use crate::i18n::Locale;
+use crate::models::{Priority, ReminderLead, SortSpec};
+use crate::storage::timed::DEFAULT_SLOW_STORAGE;
 use crate::tui::style::Theme;
 
 // ── Config ────────────────────────────────────────────────────────────────────
@@ -37,20 +81,185 @@ pub struct Config {
     /// Locale for holiday data (e.g. "pt-BR", "en-US", or "none")
     #[serde(default = "default_holidays_locale")]
     pub holidays_locale: String,
+    /// Local holidays file, merged with the locale's table; relative paths
+    /// are resolved against the config directory
+    pub holidays_file: Option<PathBuf>,
     /// TUI color theme
     pub theme: Theme,
+    /// Jira integration (`todo jira pull`)
+    pub jira: Option<JiraConfig>,
+    /// Todoist integration (`todo todoist pull/push`)
+    pub todoist: Option<TodoistConfig>,
+    /// Mail delivery for `todo digest --send`
+    pub digest: Option<DigestConfig>,
+    /// Targets tracked by `todo stats show --goal`
+    pub goals: GoalsConfig,
+    /// Storage backend as `name` or `name:opts` (default: the SQLite database)
+    pub storage: Option<String>,
+    /// Milliseconds a storage call may take before a hint is printed
+    /// (default 500); 0 disables the hint
+    pub slow_storage_ms: Option<u64>,
+    /// Age in days after which `list --age` highlights a pending task (default 30)
+    pub age_warning_days: Option<u32>,
+    /// How due dates are rendered in tables
+    pub due_display: DueDisplay,
+    /// How priorities are rendered in tables
+    pub priority_display: PriorityDisplay,
+    /// What `todo done` does when a completion streak hits a milestone
+    pub celebrate: Celebrate,
+    /// Shell prompt snippet (`todo prompt`)
+    pub prompt: PromptConfig,
+    /// Priority for `todo add` without `--priority` (default medium)
+    pub default_priority: Option<Priority>,
+    /// Sort order for `todo list` without `--sort`
+    pub default_sort: Option<SortSpec>,
+    /// Days ahead that count as "due soon" (default 7)
+    pub due_soon_days: Option<u32>,
+    /// Directory holding the database; `RUSTODO_DATA_DIR` takes precedence
+    pub data_dir: Option<PathBuf>,
+    /// Ask before destructive commands (default true); `false` acts as if
+    /// `--yes` were always passed
+    pub confirm: Option<bool>,
+    /// Output language; unset follows `LC_ALL` / `LC_MESSAGES` / `LANG`
+    pub locale: Option<Locale>,
+    /// Default reminder lead time; per-task `todo remind <ID> --before`
+    /// overrides it (default 1d)
+    pub remind_before: Option<ReminderLead>,
+    /// Hourly rate for billable tasks that do not set their own
+    pub hourly_rate: Option<f64>,
+    /// Browser origins (e.g. an extension's `moz-extension://<id>`) allowed
+    /// to post to `todo capture`; requests from any other origin are refused
+    pub capture_origins: Vec<String>,
+}
+
+/// Rendering style for due dates (`due_display` setting).
+///
+/// `relative` and `weekday` fall back to the absolute date for dates more
+/// than two weeks away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DueDisplay {
+    /// `2026-05-01`
+    #[default]
+    Absolute,
+    /// `today`, `tomorrow`, `in 5 days`, `3 days ago`
+    Relative,
+    /// `today`, `tomorrow`, `Fri`, `next Tue`, `last Mon`
+    Weekday,
+}
+
+/// Rendering style for priorities (`priority_display` setting).
+///
+/// The words and `!` markers carry the priority without relying on the
+/// red/yellow/green coloring, for screen readers and monochrome terminals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PriorityDisplay {
+    /// `H`, `M`, `L`
+    #[default]
+    Letter,
+    /// `high`, `medium`, `low`
+    Word,
+    /// `!!!`, `!!`, `!`
+    Bang,
+}
+
+/// Reaction to completion-streak milestones (`celebrate` setting).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Celebrate {
+    /// Print a one-line message
+    #[default]
+    Message,
+    /// Print the message and ring the terminal bell
+    Bell,
+    /// Stay quiet
+    Off,
+}
+
+/// Connection settings for the Jira integration.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct JiraConfig {
+    /// Base URL of the Jira instance, e.g. `https://example.atlassian.net`
+    pub base_url: String,
+    /// Account email — when set, `token` is sent as a Jira Cloud API token
+    /// via basic auth; otherwise it is sent as a bearer personal access token.
+    pub email: Option<String>,
+    /// API token or personal access token
+    pub token: String,
+    /// Name of the transition to apply when a linked task is marked done
+    pub transition_on_done: Option<String>,
+}
+
+/// Connection settings for the Todoist integration.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct TodoistConfig {
+    /// Personal API token
+    pub token: String,
+    /// REST API root; defaults to Todoist's v2 API
+    pub api_url: Option<String>,
+}
+
+/// Targets tracked by `todo stats show --goal`; unset goals are skipped.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct GoalsConfig {
+    /// Tasks to complete each day
+    pub daily_completions: Option<u32>,
+    /// Overdue tasks to stay below
+    pub overdue_below: Option<u32>,
+}
+
+/// Mail delivery for `todo digest --send`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct DigestConfig {
+    /// Command the message is piped to, e.g. `/usr/sbin/sendmail -t`
+    pub sendmail: String,
+    /// Recipient address(es), written to the `To:` header
+    pub to: String,
+    /// Subject line; defaults to "Weekly digest" and the like
+    pub subject: Option<String>,
 }
 
+/// Output of `todo prompt`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PromptConfig {
+    /// Space-separated segments with `{pending}`, `{overdue}`, `{today}`,
+    /// or `{urgent}` placeholders
+    pub format: String,
+    /// Segments whose count is below this are left out (default 1)
+    pub min_count: usize,
+    /// Urgency score at which a pending task counts toward `{urgent}`
+    pub urgent_score: f32,
+}
+
+impl Default for PromptConfig {
+    fn default() -> Self {
+        Self {
+            format: "⚑{pending} ⏰{overdue}".to_string(),
+            min_count: 1,
+            urgent_score: 10.0,
+        }
+    }
+}
+
+/// Default for [`Config::age_warning_days`].
+pub const DEFAULT_AGE_WARNING_DAYS: u32 = 30;
+
+/// Default for [`Config::due_soon_days`].
+pub const DEFAULT_DUE_SOON_DAYS: u32 = 7;
+
 impl Config {
     /// Load config from disk. Returns defaults if the file doesn't exist yet.
-    /// Creates the file with defaults on first run.
     pub fn load() -> Result<Self> {
         let path = config_path()?;
 
         if !path.exists() {
-            let cfg = Config::default();
-            cfg.save()?;
-            return Ok(cfg);
+            return Ok(Config::default());
         }
 
         let contents = fs::read_to_string(&path)?;
@@ -58,6 +267,15 @@ impl Config {
         Ok(cfg)
     }
 
+    /// Writes a config file with defaults on first run, so users have one to
+    /// edit. Does nothing if it already exists.
+    pub fn create_if_missing() -> Result<()> {
+        if config_path()?.exists() {
+            return Ok(());
+        }
+        Config::default().save()
+    }
+
     /// Save current config to disk.
     pub fn save(&self) -> Result<()> {
         let path = config_path()?;
@@ -69,6 +287,39 @@ impl Config {
         Ok(())
     }
 
+    /// The config file as read once per process, or defaults if it is
+    /// missing or invalid. Unlike [`load`](Self::load) it never writes, so it
+    /// is safe to call while arguments are being parsed.
+    pub fn defaults() -> &'static Config {
+        static DEFAULTS: LazyLock<Config> = LazyLock::new(|| {
+            config_path()
+                .ok()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .and_then(|contents| toml::from_str(&contents).ok())
+                .unwrap_or_default()
+        });
+        &DEFAULTS
+    }
+
+    /// [`due_soon_days`](Self::due_soon_days) or its default.
+    pub fn due_soon_days(&self) -> i64 {
+        self.due_soon_days.unwrap_or(DEFAULT_DUE_SOON_DAYS) as i64
+    }
+
+    /// Threshold for the slow-storage hint, or `None` when disabled.
+    pub fn slow_storage(&self) -> Option<Duration> {
+        match self.slow_storage_ms {
+            None => Some(DEFAULT_SLOW_STORAGE),
+            Some(0) => None,
+            Some(ms) => Some(Duration::from_millis(ms)),
+        }
+    }
+
+    /// Whether destructive commands skip their prompt even without `--yes`.
+    pub fn skip_confirm(&self) -> bool {
+        self.confirm == Some(false)
+    }
+
     /// Return the path to the config file (for `todo info`).
     pub fn path() -> Result<PathBuf> {
         config_path()
@@ -87,6 +338,35 @@ fn config_path() -> Result<PathBuf> {
             .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?;
         dirs.config_dir().to_path_buf()
     };
-    fs::create_dir_all(&config_dir)?;
     Ok(config_dir.join("config.toml"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SortBy;
+
+    #[test]
+    fn test_parse_default_settings() {
+        let config: Config = toml::from_str(
+            r#"
+            default_priority = "high"
+            default_sort = "due"
+            due_soon_days = 3
+            data_dir = "/tmp/rustodo"
+            confirm = false
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.default_priority, Some(Priority::High));
+        assert_eq!(config.default_sort, Some(SortBy::Due.into()));
+        assert_eq!(config.due_soon_days(), 3);
+        assert_eq!(config.data_dir, Some(PathBuf::from("/tmp/rustodo")));
+        assert!(config.skip_confirm());
+
+        let empty: Config = toml::from_str("").unwrap();
+        assert_eq!(empty.due_soon_days(), DEFAULT_DUE_SOON_DAYS as i64);
+        assert!(!empty.skip_confirm());
+    }
+}
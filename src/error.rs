@@ -24,6 +24,9 @@ pub enum TodoError {
     #[error("Project '{0} not found in any task'")]
     ProjectNotFound(String),
 
+    #[error("Project '{name}' not found. Did you mean '{suggestion}'?")]
+    ProjectNotFoundSuggestion { name: String, suggestion: String },
+
     #[error("No tasks found matching the specified filters")]
     NoTasksFound,
 
@@ -69,6 +72,9 @@ pub enum TodoError {
     #[error("Due date cannot be in the past: {date}")]
     DueDateInPast { date: NaiveDate },
 
+    #[error("Start date {start} is after the due date {due}")]
+    StartAfterDue { start: NaiveDate, due: NaiveDate },
+
     // === Recurrence Validation Errors ===
     #[error("Recurring tasks must have a due date. Use --due YYYY-MM-DD")]
     RecurrenceRequiresDueDate,
@@ -80,6 +86,9 @@ pub enum TodoError {
     #[error("Task #{0} is blocked by pending dependencies: {1}")]
     TaskBlocked(usize, String),
 
+    #[error("Task #{0} is waiting until {1}")]
+    TaskWaiting(usize, NaiveDate),
+
     #[error("Dependency cycle detected: {0}")]
     DependencyCycle(String),
 
@@ -0,0 +1,406 @@
+//! Translated user-facing strings.
+//!
+//! Every translatable string is a [`Msg`] variant with one entry per
+//! [`Locale`] catalog. The locale comes from `locale` in `config.toml`, then
+//! `LC_ALL` / `LC_MESSAGES` / `LANG`, and falls back to English:
+//!
+//! ```toml
+//! locale = "pt-BR"  # or "en"
+//! ```
+//!
+//! Templates use `{}` placeholders, filled in order by [`tf`]:
+//!
+//! ```
+//! use rustodo::i18n::{Msg, tf};
+//! println!("{}", tf(Msg::TaskAdded, &[&3]));
+//! ```
+
+use std::fmt::Display;
+use std::sync::LazyLock;
+
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+/// A supported output language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en", alias = "en-US")]
+    En,
+    #[serde(rename = "pt-BR", alias = "pt")]
+    PtBr,
+}
+
+impl Locale {
+    /// Matches a POSIX locale such as `pt_BR.UTF-8` or a tag like `pt-BR`.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let lang = tag.split(['_', '-', '.', '@']).next()?.to_ascii_lowercase();
+        match lang.as_str() {
+            "en" => Some(Locale::En),
+            "pt" => Some(Locale::PtBr),
+            _ => None,
+        }
+    }
+}
+
+/// The locale in effect for this process.
+pub fn locale() -> Locale {
+    // Unit tests assert on English messages whatever the machine's locale.
+    if cfg!(test) {
+        return Locale::En;
+    }
+    static LOCALE: LazyLock<Locale> = LazyLock::new(|| {
+        Config::defaults().locale.unwrap_or_else(|| {
+            ["LC_ALL", "LC_MESSAGES", "LANG"]
+                .iter()
+                .filter_map(|var| std::env::var(var).ok())
+                .find(|value| !value.is_empty())
+                .and_then(|value| Locale::from_tag(&value))
+                .unwrap_or_default()
+        })
+    });
+    *LOCALE
+}
+
+/// A translatable string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Msg {
+    // ── Task commands ─────────────────────────────────────────────────────────
+    TaskAdded,
+    TaskAddedRecurring,
+    TaskDone,
+    TasksDone,
+    StreakMilestone,
+    TaskUndone,
+    TaskCancelled,
+    TasksUndone,
+    TaskRemoved,
+    TimerStarted,
+    TimerStopped,
+
+    // ── Task list ─────────────────────────────────────────────────────────────
+    ListAll,
+    ListPending,
+    ListDone,
+    ListSummary,
+
+    // ── Task table headers ────────────────────────────────────────────────────
+    ColAge,
+    ColTags,
+    ColProject,
+    ColDue,
+    ColTime,
+    ColTimeliness,
+    ColUrgency,
+    ColEstimate,
+    ColTask,
+    ColNotes,
+    ColResources,
+
+    // ── Stats ─────────────────────────────────────────────────────────────────
+    StatsTitle,
+    StatsOverview,
+    StatsTasks,
+    StatsTotal,
+    StatsCompleted,
+    StatsPending,
+    StatsCancelled,
+    StatsOverdue,
+    StatsDueSoon,
+    StatsNoDueDate,
+    StatsBlocked,
+    StatsRecurring,
+    StatsMissed,
+    StatsWithDeps,
+    StatsTimeTracked,
+    StatsEstimatedLeft,
+    StatsStreak,
+    StatsStreakDays,
+    StatsNotes,
+    StatsResources,
+    StatsLinkedTotal,
+    StatsByPriority,
+    StatsPriorityCounts,
+    PriorityHigh,
+    PriorityMedium,
+    PriorityLow,
+    StatsByProject,
+    StatsNoTasks,
+    StatsNoProject,
+    StatsNotesSuffix,
+    StatsLeftSuffix,
+    TaskCountOne,
+    TaskCountMany,
+    StatsUrgent,
+    StatsTopTags,
+    StatsByWeekday,
+    StatsFocusSessions,
+    StatsCompletionRate,
+    StatsDays7,
+    StatsDays30,
+}
+
+impl Msg {
+    fn en(self) -> &'static str {
+        match self {
+            Msg::TaskAdded => "Added task #{}",
+            Msg::TaskAddedRecurring => "Added task #{} with {} recurrence",
+            Msg::TaskDone => "Task {} marked as done.",
+            Msg::TasksDone => "{} tasks marked as done: {}",
+            Msg::StreakMilestone => "{}-day streak! Keep it going.",
+            Msg::TaskUndone => "Task {} marked as pending.",
+            Msg::TaskCancelled => "Task {} cancelled.",
+            Msg::TasksUndone => "{} tasks marked as pending: {}",
+            Msg::TaskRemoved => "Task removed: {}",
+            Msg::TimerStarted => "Started task {}",
+            Msg::TimerStopped => "Stopped task {} after {} ({} total)",
+
+            Msg::ListAll => "Tasks",
+            Msg::ListPending => "Pending tasks",
+            Msg::ListDone => "Completed tasks",
+            Msg::ListSummary => "{} of {} completed ({}%)",
+
+            Msg::ColAge => "Age",
+            Msg::ColTags => "Tags",
+            Msg::ColProject => "Project",
+            Msg::ColDue => "Due",
+            Msg::ColTime => "Time",
+            Msg::ColTimeliness => "Closed",
+            Msg::ColUrgency => "Urg",
+            Msg::ColEstimate => "Est",
+            Msg::ColTask => "Task",
+            Msg::ColNotes => "Notes",
+            Msg::ColResources => "Res",
+
+            Msg::StatsTitle => "Todo Statistics",
+            Msg::StatsOverview => "Overview",
+            Msg::StatsTasks => "Tasks",
+            Msg::StatsTotal => "{} total",
+            Msg::StatsCompleted => "Completed",
+            Msg::StatsPending => "Pending",
+            Msg::StatsCancelled => "Cancelled",
+            Msg::StatsOverdue => "Overdue",
+            Msg::StatsDueSoon => "Due soon",
+            Msg::StatsNoDueDate => "No due date",
+            Msg::StatsBlocked => "Blocked",
+            Msg::StatsRecurring => "Recurring",
+            Msg::StatsMissed => "Missed",
+            Msg::StatsWithDeps => "With deps",
+            Msg::StatsTimeTracked => "Time tracked",
+            Msg::StatsEstimatedLeft => "Estimated left",
+            Msg::StatsStreak => "Streak",
+            Msg::StatsStreakDays => "{} days",
+            Msg::StatsNotes => "Notes",
+            Msg::StatsResources => "Resources",
+            Msg::StatsLinkedTotal => "{} total  ({} linked, {} orphan)",
+            Msg::StatsByPriority => "By Priority",
+            Msg::StatsPriorityCounts => "({} pending, {} done)",
+            Msg::PriorityHigh => "High",
+            Msg::PriorityMedium => "Medium",
+            Msg::PriorityLow => "Low",
+            Msg::StatsByProject => "By Project",
+            Msg::StatsNoTasks => "no tasks",
+            Msg::StatsNoProject => "(no project)",
+            Msg::StatsNotesSuffix => "notes",
+            Msg::StatsLeftSuffix => "left",
+            Msg::TaskCountOne => "{} task",
+            Msg::TaskCountMany => "{} tasks",
+            Msg::StatsUrgent => "Urgent  (score >= 10)",
+            Msg::StatsTopTags => "Top Tags",
+            Msg::StatsByWeekday => "Completions by Weekday",
+            Msg::StatsFocusSessions => "Focus Sessions",
+            Msg::StatsCompletionRate => "Completion Rate",
+            Msg::StatsDays7 => "7 days",
+            Msg::StatsDays30 => "30 days",
+        }
+    }
+
+    fn pt_br(self) -> &'static str {
+        match self {
+            Msg::TaskAdded => "Tarefa #{} adicionada",
+            Msg::TaskAddedRecurring => "Tarefa #{} adicionada com recorrência {}",
+            Msg::TaskDone => "Tarefa {} concluída.",
+            Msg::TasksDone => "{} tarefas concluídas: {}",
+            Msg::StreakMilestone => "Sequência de {} dias! Continue assim.",
+            Msg::TaskUndone => "Tarefa {} reaberta.",
+            Msg::TaskCancelled => "Tarefa {} cancelada.",
+            Msg::TasksUndone => "{} tarefas reabertas: {}",
+            Msg::TaskRemoved => "Tarefa removida: {}",
+            Msg::TimerStarted => "Tarefa {} iniciada",
+            Msg::TimerStopped => "Tarefa {} parada após {} ({} no total)",
+
+            Msg::ListAll => "Tarefas",
+            Msg::ListPending => "Tarefas pendentes",
+            Msg::ListDone => "Tarefas concluídas",
+            Msg::ListSummary => "{} de {} concluídas ({}%)",
+
+            Msg::ColAge => "Idade",
+            Msg::ColTags => "Tags",
+            Msg::ColProject => "Projeto",
+            Msg::ColDue => "Prazo",
+            Msg::ColTime => "Tempo",
+            Msg::ColTimeliness => "Fechada",
+            Msg::ColUrgency => "Urg",
+            Msg::ColEstimate => "Est",
+            Msg::ColTask => "Tarefa",
+            Msg::ColNotes => "Notas",
+            Msg::ColResources => "Rec",
+
+            Msg::StatsTitle => "Estatísticas",
+            Msg::StatsOverview => "Visão geral",
+            Msg::StatsTasks => "Tarefas",
+            Msg::StatsTotal => "{} no total",
+            Msg::StatsCompleted => "Concluídas",
+            Msg::StatsPending => "Pendentes",
+            Msg::StatsCancelled => "Canceladas",
+            Msg::StatsOverdue => "Atrasadas",
+            Msg::StatsDueSoon => "Vencem em breve",
+            Msg::StatsNoDueDate => "Sem prazo",
+            Msg::StatsBlocked => "Bloqueadas",
+            Msg::StatsRecurring => "Recorrentes",
+            Msg::StatsMissed => "Perdidas",
+            Msg::StatsWithDeps => "Com dependências",
+            Msg::StatsTimeTracked => "Tempo registrado",
+            Msg::StatsEstimatedLeft => "Estimativa restante",
+            Msg::StatsStreak => "Sequência",
+            Msg::StatsStreakDays => "{} dias",
+            Msg::StatsNotes => "Notas",
+            Msg::StatsResources => "Recursos",
+            Msg::StatsLinkedTotal => "{} no total  ({} vinculados, {} avulsos)",
+            Msg::StatsByPriority => "Por prioridade",
+            Msg::StatsPriorityCounts => "({} pendentes, {} concluídas)",
+            Msg::PriorityHigh => "Alta",
+            Msg::PriorityMedium => "Média",
+            Msg::PriorityLow => "Baixa",
+            Msg::StatsByProject => "Por projeto",
+            Msg::StatsNoTasks => "sem tarefas",
+            Msg::StatsNoProject => "(sem projeto)",
+            Msg::StatsNotesSuffix => "notas",
+            Msg::StatsLeftSuffix => "restantes",
+            Msg::TaskCountOne => "{} tarefa",
+            Msg::TaskCountMany => "{} tarefas",
+            Msg::StatsUrgent => "Urgentes  (pontuação >= 10)",
+            Msg::StatsTopTags => "Tags mais usadas",
+            Msg::StatsByWeekday => "Conclusões por dia da semana",
+            Msg::StatsFocusSessions => "Sessões de foco",
+            Msg::StatsCompletionRate => "Taxa de conclusão",
+            Msg::StatsDays7 => "7 dias",
+            Msg::StatsDays30 => "30 dias",
+        }
+    }
+
+    /// This string in `locale`.
+    pub fn in_locale(self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::En => self.en(),
+            Locale::PtBr => self.pt_br(),
+        }
+    }
+}
+
+/// `msg` in the current locale.
+pub fn t(msg: Msg) -> &'static str {
+    msg.in_locale(locale())
+}
+
+/// `msg` in the current locale with its `{}` placeholders filled from `args`.
+pub fn tf(msg: Msg, args: &[&dyn Display]) -> String {
+    fill(t(msg), args)
+}
+
+/// Replaces each `{}` in `template` with the next item of `args`.
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut parts = template.split("{}").peekable();
+    while let Some(part) = parts.next() {
+        out.push_str(part);
+        if parts.peek().is_some()
+            && let Some(arg) = args.next()
+        {
+            out.push_str(&arg.to_string());
+        }
+    }
+    out
+}
+
+/// `"1 task"` / `"3 tasks"` in the current locale.
+pub fn task_count(count: usize) -> String {
+    let msg = if count == 1 {
+        Msg::TaskCountOne
+    } else {
+        Msg::TaskCountMany
+    };
+    tf(msg, &[&count])
+}
+
+/// Short weekday name in the current locale.
+pub fn weekday(day: Weekday) -> &'static str {
+    match locale() {
+        Locale::En => match day {
+            Weekday::Mon => "Mon",
+            Weekday::Tue => "Tue",
+            Weekday::Wed => "Wed",
+            Weekday::Thu => "Thu",
+            Weekday::Fri => "Fri",
+            Weekday::Sat => "Sat",
+            Weekday::Sun => "Sun",
+        },
+        Locale::PtBr => match day {
+            Weekday::Mon => "seg",
+            Weekday::Tue => "ter",
+            Weekday::Wed => "qua",
+            Weekday::Thu => "qui",
+            Weekday::Fri => "sex",
+            Weekday::Sat => "sáb",
+            Weekday::Sun => "dom",
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_placeholders_in_order() {
+        assert_eq!(
+            fill("{} tasks marked as done: {}", &[&2, &"#1, #2"]),
+            "2 tasks marked as done: #1, #2"
+        );
+        assert_eq!(fill("no placeholders", &[&1]), "no placeholders");
+    }
+
+    #[test]
+    fn test_locale_from_tag() {
+        assert_eq!(Locale::from_tag("pt_BR.UTF-8"), Some(Locale::PtBr));
+        assert_eq!(Locale::from_tag("pt-BR"), Some(Locale::PtBr));
+        assert_eq!(Locale::from_tag("en_US.UTF-8"), Some(Locale::En));
+        assert_eq!(Locale::from_tag("C"), None);
+    }
+
+    #[test]
+    fn test_catalogs_have_same_placeholders() {
+        for msg in [
+            Msg::TaskAdded,
+            Msg::TaskAddedRecurring,
+            Msg::TasksDone,
+            Msg::StreakMilestone,
+            Msg::TimerStopped,
+            Msg::ListSummary,
+            Msg::StatsStreakDays,
+            Msg::StatsLinkedTotal,
+            Msg::StatsPriorityCounts,
+            Msg::TaskCountMany,
+        ] {
+            assert_eq!(
+                msg.en().matches("{}").count(),
+                msg.pt_br().matches("{}").count(),
+                "{:?}",
+                msg
+            );
+        }
+    }
+}
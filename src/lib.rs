@@ -15,11 +15,13 @@
 //! | [`storage`] | Storage trait with JSON and in-memory implementations |
 //! | [`tui`] | Terminal User Interface (Ratatui) |
 //! | [`config`] |
+//! | [`i18n`] | Translated output strings (en, pt-BR) |
 
 pub mod cli;
 pub mod commands;
 pub mod config;
 pub mod error;
+pub mod i18n;
 pub mod models;
 pub mod render;
 pub mod services;
@@ -3,19 +3,75 @@ A modern, powerful task manager built with Rust.
 */
 
 use std::process;
+use std::time::Instant;
 
 use anyhow::Result;
 use clap::Parser;
 use colored::Colorize;
 
+#[cfg(feature = "todoist")]
+use rustodo::cli::TodoistCommands;
 use rustodo::cli::{
-    Cli, Commands, HolidaysCommands, NoteCommands, ProjectCommands, ResourceCommands, StatsCommands,
+    Cli, Commands, DepsCommands, ExportFormat, HolidaysCommands, ImportFormat, JiraCommands,
+    NoteCommands, OutputFormat, ProjectCommands, RecurCommands, ResourceCommands, StatsCommands,
+    TimeCommands,
 };
 use rustodo::commands;
-use rustodo::storage::{SqliteStorage, Storage, backup, get_db_path};
+use rustodo::commands::mail_import::MailSource;
+use rustodo::render::project_table::DashboardOptions;
+use rustodo::storage::recovery::{self, Recovery};
+use rustodo::storage::registry::StorageRegistry;
+#[cfg(unix)]
+use rustodo::storage::shared::{self, SocketStorage};
+use rustodo::storage::timed::SlowHint;
+use rustodo::storage::{
+    ReadOnlyStorage, SqliteStorage, Storage, TimedStorage, backup, get_db_path,
+};
+use rustodo::utils::id_list;
+use rustodo::utils::timing::Timing;
 
 fn main() {
+    let start = Instant::now();
     let cli = Cli::parse();
+    let mut timing = Timing::since(start, cli.timing);
+    timing.mark("parse args");
+    if cli.no_wrap {
+        rustodo::render::formatting::disable_wrap();
+    }
+    rustodo::render::formatting::configure_output(cli.no_color, cli.plain);
+
+    // Completions need neither the database nor the config
+    if let Some(Commands::Completions {
+        shell,
+        install,
+        yes,
+    }) = cli.command
+    {
+        let result = commands::completions::execute(shell, install, yes);
+        timing.mark("run command");
+        timing.report();
+        if let Err(e) = result {
+            eprintln!("{} {}", "✗".red(), e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    // Plugins find the data themselves through the exported environment
+    if let Some(Commands::External(args)) = &cli.command {
+        match commands::plugin::execute(&cli, args) {
+            Ok(code) => process::exit(code),
+            Err(e) => {
+                eprintln!("{} {}", "✗".red(), e);
+                process::exit(2);
+            }
+        }
+    }
+
+    if let Err(e) = cli.check_file_access() {
+        eprintln!("{} {}", "✗".red(), e);
+        process::exit(1);
+    }
 
     let db_path = match get_db_path() {
         Ok(p) => p,
@@ -25,15 +81,133 @@ fn main() {
         }
     };
 
+    // Restore replaces the database file, so it runs before any connection is open
+    if let Some(Commands::Restore { file, yes }) = cli.command {
+        let yes = yes || rustodo::config::Config::defaults().skip_confirm();
+        let result = commands::backup::execute_restore(file, yes);
+        timing.mark("run command");
+        timing.report();
+        if let Err(e) = result {
+            eprintln!("{} {}", "✗".red(), e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    if cli.demo {
+        match commands::demo::open_storage() {
+            Ok(s) if cli.read_only => run_or_exit(cli, &ReadOnlyStorage::new(s), &mut timing),
+            Ok(s) => run_or_exit(cli, &s, &mut timing),
+            Err(e) => {
+                eprintln!("{} Failed to open demo workspace: {}", "✗".red(), e);
+                process::exit(1);
+            }
+        }
+        timing.report();
+        return;
+    }
+
+    // A configured non-default backend bypasses the SQLite setup entirely
+    let backend = rustodo::config::Config::defaults()
+        .storage
+        .clone()
+        .filter(|spec| !spec.trim().is_empty() && spec.trim() != "sqlite");
+    if let Some(spec) = backend {
+        let storage = match StorageRegistry::with_builtins().open(&spec) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("{} Failed to initialize storage: {}", "✗".red(), e);
+                process::exit(1);
+            }
+        };
+        if cli.read_only {
+            run_or_exit(cli, &ReadOnlyStorage::new(storage), &mut timing);
+        } else {
+            run_or_exit(cli, &storage, &mut timing);
+        }
+        timing.report();
+        return;
+    }
+
+    if cli.read_only {
+        match SqliteStorage::open_read_only() {
+            Ok(s) => run_or_exit(cli, &ReadOnlyStorage::new(s), &mut timing),
+            Err(e) => {
+                eprintln!("{} Failed to initialize storage: {}", "✗".red(), e);
+                process::exit(1);
+            }
+        }
+        timing.report();
+        return;
+    }
+
+    // While `todo remind --daemon` runs it is the only writer; commands hand
+    // it their storage calls instead of opening the database themselves
+    #[cfg(unix)]
+    if !matches!(cli.command, Some(Commands::Remind { daemon: true, .. }))
+        && let Some(shared) = SocketStorage::connect(&shared::socket_path(&db_path))
+    {
+        run_or_exit(cli, &shared, &mut timing);
+        let _ = backup::backup_if_needed(&db_path, 10, 60);
+        timing.mark("backup");
+        timing.report();
+        return;
+    }
+
     let storage = match SqliteStorage::new() {
         Ok(s) => s,
         Err(e) => {
             eprintln!("{} Failed to initialize storage: {}", "✗".red(), e);
-            process::exit(1);
+            if !recovery::looks_corrupt(&db_path) || !offer_recovery(&db_path) {
+                process::exit(1);
+            }
+            match SqliteStorage::new() {
+                Ok(s) => s,
+                Err(e) => {
+                    eprintln!("{} Failed to initialize storage: {}", "✗".red(), e);
+                    process::exit(1);
+                }
+            }
         }
     };
 
-    if let Err(e) = run(cli, &storage) {
+    #[cfg(unix)]
+    if let Some(Commands::Remind {
+        daemon: true,
+        interval,
+        ..
+    }) = cli.command
+    {
+        let socket = shared::socket_path(&db_path);
+        if let Err(e) = commands::remind::execute_daemon_shared(&storage, &socket, interval) {
+            eprintln!("{} {}", "✗".red(), e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    run_or_exit(cli, &storage, &mut timing);
+
+    // Backup after successful write operations (best-effort)
+    let _ = backup::backup_if_needed(&db_path, 10, 60);
+    timing.mark("backup");
+    timing.report();
+}
+
+/// Runs the command, printing the error chain and exiting on failure.
+fn run_or_exit(cli: Cli, storage: &impl Storage, timing: &mut Timing) {
+    timing.mark("open storage");
+    let config = rustodo::config::Config::defaults();
+    let storage = TimedStorage::new(
+        storage,
+        config.slow_storage(),
+        SlowHint::for_backend(config.storage.as_deref()),
+    );
+    let result = run(cli, &storage);
+    timing.mark("run command");
+    timing.record("  storage", storage.spent());
+    if let Err(e) = result {
+        timing.report();
         eprintln!("{} {}", "✗".red(), e);
 
         let mut source = e.source();
@@ -44,79 +218,256 @@ fn main() {
 
         process::exit(1);
     }
+}
 
-    // Backup after successful write operations (best-effort)
-    let _ = backup::backup_if_needed(&db_path, 10, 60);
+/// Asks whether to recover a corrupted database and runs the recovery.
+/// Returns true if a usable database path is in place afterwards.
+fn offer_recovery(db_path: &std::path::Path) -> bool {
+    eprintln!(
+        "\n{} The database at {} appears to be corrupted.",
+        "!".yellow(),
+        db_path.display().to_string().cyan()
+    );
+    match rustodo::utils::confirm(
+        "Move it aside and recover (salvage rows, else latest backup)? [y/N]:",
+    ) {
+        Ok(true) => {}
+        _ => return false,
+    }
+
+    match recovery::recover(db_path) {
+        Ok(outcome) => {
+            match outcome {
+                Recovery::Salvaged { tasks, notes } => eprintln!(
+                    "{} Salvaged {} task{} and {} note{} from the damaged file",
+                    "✓".green(),
+                    tasks,
+                    if tasks == 1 { "" } else { "s" },
+                    notes,
+                    if notes == 1 { "" } else { "s" }
+                ),
+                Recovery::Backup(path) => eprintln!(
+                    "{} Restored latest backup: {}",
+                    "✓".green(),
+                    path.display().to_string().cyan()
+                ),
+                Recovery::Empty => eprintln!(
+                    "{} Nothing could be recovered — starting with an empty database",
+                    "!".yellow()
+                ),
+            }
+            eprintln!(
+                "  {} Damaged file kept at {}\n",
+                "·".dimmed(),
+                recovery::corrupt_path(db_path).display()
+            );
+            true
+        }
+        Err(e) => {
+            eprintln!("{} Recovery failed: {}", "✗".red(), e);
+            false
+        }
+    }
 }
 
 fn run(cli: Cli, storage: &impl Storage) -> Result<()> {
     // Ensure config.toml is created on first run
-    let _ = rustodo::config::Config::load();
+    if !cli.read_only {
+        let _ = rustodo::config::Config::create_if_missing();
+    }
 
     let Some(command) = cli.command else {
         return rustodo::tui::run(storage);
     };
 
-    match command {
+    if cli.format == OutputFormat::Markdown
+        && !matches!(command, Commands::Report { .. } | Commands::Digest { .. })
+    {
+        anyhow::bail!("--format markdown is only supported by `todo report` and `todo digest`");
+    }
+
+    let journal_op = commands::undo::journal_label(&command);
+    let before = match journal_op {
+        Some(_) => Some(commands::undo::capture(storage)?),
+        None => None,
+    };
+
+    // `confirm = false` in config.toml acts like `--yes` on every prompt
+    let skip_confirm = rustodo::config::Config::defaults().skip_confirm();
+
+    let result = match command {
         Commands::Add(args) => commands::task::add::execute(storage, args),
 
-        Commands::List {
-            status,
-            priority,
-            due,
-            sort,
-            tag,
-            project,
-            recurrence: recur,
-        } => {
-            commands::task::list::execute(storage, status, priority, due, sort, tag, project, recur)
-        }
+        Commands::Ui => rustodo::tui::run(storage),
+
+        Commands::Triage => commands::triage::execute(storage),
+
+        Commands::Start { id } => commands::task::track::execute_start(storage, id),
 
-        Commands::Done { id } => commands::task::done::execute(storage, id),
+        Commands::Stop => commands::task::track::execute_stop(storage),
 
-        Commands::Undone { id } => commands::task::undone::execute(storage, id),
+        Commands::Pomodoro { id, work, brk } => commands::pomodoro::execute(storage, id, work, brk),
+
+        Commands::Capture { port, project } => commands::capture::execute(storage, port, project),
+
+        Commands::List(args) => commands::task::list::execute_as(storage, args, cli.format),
+
+        Commands::Done { ids } => {
+            let ids = id_list::expand(&ids);
+            commands::task::done::execute_many(storage, &ids)?;
+            for id in ids {
+                commands::jira::execute_done_hook(storage, id)?;
+            }
+            Ok(())
+        }
 
-        Commands::Remove { id, yes } => commands::task::remove::execute(storage, id, yes),
+        Commands::Undone { ids } => {
+            commands::task::undone::execute_many(storage, &id_list::expand(&ids))
+        }
 
+        Commands::Cancel { id, reason } => commands::task::cancel::execute(storage, id, reason),
+
+        Commands::Remove {
+            ids,
+            yes,
+            interactive,
+        } => commands::task::remove::execute_many(
+            storage,
+            &id_list::expand(&ids),
+            yes || skip_confirm,
+            interactive,
+        ),
+
+        Commands::Edit(args) if !args.more_ids.is_empty() => {
+            commands::task::edit::execute_many(storage, args)
+        }
         Commands::Edit(args) => commands::task::edit::execute(storage, args),
 
-        Commands::Clear { yes } => commands::task::clear::execute(storage, yes),
+        Commands::Clear { yes, undo } => {
+            if undo {
+                commands::task::clear::execute_undo(storage)
+            } else {
+                commands::task::clear::execute(storage, yes || skip_confirm)
+            }
+        }
 
         Commands::Search {
             query,
             tag,
             project,
             status,
-        } => commands::search::execute(storage, query, tag, project, status),
+            regex,
+            fuzzy,
+            fields,
+        } => {
+            let mode = if regex {
+                commands::search::SearchMode::Regex
+            } else if fuzzy {
+                commands::search::SearchMode::Fuzzy
+            } else {
+                commands::search::SearchMode::Substring
+            };
+            let options = commands::search::SearchOptions { mode, fields };
+            commands::search::execute_as(storage, query, tag, project, status, options, cli.format)
+        }
 
         // `todo stats` with no subcommand → show overview
         // `todo stats show`    → overview
         // `todo stats history` → monthly activity chart
         Commands::Stats(sub) => match sub {
-            StatsCommands::Show => commands::stats::execute(storage),
+            StatsCommands::Show {
+                compare: None,
+                goal: false,
+            } => commands::stats::execute_as(storage, cli.format),
+            StatsCommands::Show { goal: true, .. } => {
+                commands::stats_goals::execute(storage, cli.format)
+            }
+            StatsCommands::Show {
+                compare: Some(period),
+                ..
+            } => commands::stats_compare::execute(storage, period),
             StatsCommands::History { months } => commands::stats_history::execute(storage, months),
             StatsCommands::HistoryClear { all, days, yes } => {
-                commands::stats_history::execute_clear(storage, all, days, yes)
+                commands::stats_history::execute_clear(storage, all, days, yes || skip_confirm)
             }
         },
 
+        Commands::Time(TimeCommands::Report { last, by, csv }) => {
+            commands::time_report::execute(storage, last, by, csv.as_deref(), cli.format)
+        }
+
+        Commands::Report { project, since } => {
+            commands::report::execute(storage, project, since, cli.format)
+        }
+
+        Commands::Digest { period, send } => {
+            commands::digest::execute(storage, period, send, cli.format)
+        }
+
         Commands::Calendar { month, year } => commands::calendar::execute(storage, month, year),
 
+        Commands::Agenda => commands::agenda::execute(storage, cli.format),
+
         Commands::Next { limit } => commands::next::execute(storage, Some(limit)),
 
-        Commands::Tags { tag } => commands::tags::execute(storage, tag),
+        Commands::Remind {
+            id,
+            before,
+            clear,
+            daemon,
+            interval,
+        } => match id {
+            Some(id) if before.is_some() || clear => {
+                commands::remind::execute_set(storage, id, before)
+            }
+            Some(_) => Err(anyhow::anyhow!(
+                "use --before <LEAD> or --clear with a task ID"
+            )),
+            None if daemon => commands::remind::execute_daemon(storage, interval),
+            None => {
+                if commands::remind::execute_check(storage)? == 0 {
+                    println!("{}", "No reminders due.".dimmed());
+                }
+                Ok(())
+            }
+        },
+
+        Commands::Pick {
+            exclude_tag,
+            project,
+            max_estimate,
+            count,
+        } => commands::pick::execute(
+            storage,
+            &exclude_tag,
+            project.as_deref(),
+            max_estimate,
+            count,
+        ),
+
+        Commands::Tags { tag } => commands::tags::execute_as(storage, tag, cli.format),
+
+        Commands::Archive { project, yes } => {
+            commands::archive::execute(storage, &project, yes || skip_confirm)
+        }
 
         Commands::Project(sub) => match sub {
             ProjectCommands::Add(args) => commands::project::add::execute(storage, args),
-            ProjectCommands::List => commands::project::list::execute(storage),
+            ProjectCommands::List { tree, progress } => commands::project::list::execute_as(
+                storage,
+                cli.format,
+                DashboardOptions { tree, progress },
+            ),
             ProjectCommands::Show { id } => commands::project::show::execute(storage, id),
             ProjectCommands::Edit(args) => commands::project::edit::execute(storage, args),
             ProjectCommands::Done { id } => commands::project::done::execute(storage, id),
             ProjectCommands::Undone { id } => commands::project::undone::execute(storage, id),
             ProjectCommands::Remove { id, yes } => {
-                commands::project::remove::execute(storage, id, yes)
+                commands::project::remove::execute(storage, id, yes || skip_confirm)
+            }
+            ProjectCommands::Clear { yes } => {
+                commands::project::clear::execute(storage, yes || skip_confirm)
             }
-            ProjectCommands::Clear { yes } => commands::project::clear::execute(storage, yes),
         },
 
         Commands::Note(sub) => match sub {
@@ -125,8 +476,12 @@ fn run(cli: Cli, storage: &impl Storage) -> Result<()> {
             NoteCommands::Show { id } => commands::note::show::execute(storage, id),
             NoteCommands::Preview { id } => commands::note::preview::execute(storage, id),
             NoteCommands::Edit(args) => commands::note::edit::execute(storage, args),
-            NoteCommands::Remove { id, yes } => commands::note::remove::execute(storage, id, yes),
-            NoteCommands::Clear { yes } => commands::note::clear::execute(storage, yes),
+            NoteCommands::Remove { id, yes } => {
+                commands::note::remove::execute(storage, id, yes || skip_confirm)
+            }
+            NoteCommands::Clear { yes } => {
+                commands::note::clear::execute(storage, yes || skip_confirm)
+            }
         },
 
         Commands::Resource(sub) => match sub {
@@ -135,39 +490,157 @@ fn run(cli: Cli, storage: &impl Storage) -> Result<()> {
             ResourceCommands::Show { id } => commands::resource::show::execute(storage, id),
             ResourceCommands::Edit(args) => commands::resource::edit::execute(storage, args),
             ResourceCommands::Remove { id, yes } => {
-                commands::resource::remove::execute(storage, id, yes)
+                commands::resource::remove::execute(storage, id, yes || skip_confirm)
+            }
+            ResourceCommands::Clear { yes } => {
+                commands::resource::clear::execute(storage, yes || skip_confirm)
             }
-            ResourceCommands::Clear { yes } => commands::resource::clear::execute(storage, yes),
         },
 
         Commands::Context { id } => commands::context::execute(storage, id),
 
-        Commands::Deps { id } => commands::task::deps::execute(storage, id),
+        Commands::Deps {
+            action: Some(DepsCommands::Why { from, to }),
+            ..
+        } => commands::task::deps::execute_why(storage, from, to, cli.format),
+
+        Commands::Deps { id, .. } => match id {
+            Some(id) => commands::task::deps::execute_as(storage, id, cli.format),
+            None => unreachable!("clap requires ID without a subcommand"),
+        },
+
+        Commands::Annotate { id, file } => commands::task::annotate::execute(storage, id, file),
+
+        Commands::Open { id, attachment } => {
+            commands::task::annotate::execute_open(storage, id, attachment)
+        }
+
+        Commands::Blame { id } => commands::task::blame::execute(storage, id),
+
+        Commands::Similar { id, limit } => commands::task::similar::execute(storage, id, limit),
 
         Commands::Info => commands::task::info::execute(),
 
-        Commands::Recur { id, pattern } => commands::task::recur::execute(storage, id, pattern),
+        Commands::Recur {
+            action: Some(RecurCommands::Preview { id, count }),
+            ..
+        } => commands::task::recur::execute_preview(storage, id, count),
+
+        Commands::Recur {
+            action: Some(RecurCommands::History { id }),
+            ..
+        } => commands::task::recur::execute_history(storage, id),
+
+        Commands::Recur {
+            id,
+            pattern,
+            month_end,
+            catch_up,
+            recur_chain_deps,
+            ..
+        } => match (id, pattern) {
+            (Some(id), Some(pattern)) => commands::task::recur::execute_with(
+                storage,
+                id,
+                pattern,
+                month_end,
+                catch_up,
+                recur_chain_deps,
+            ),
+            _ => unreachable!("clap requires ID and PATTERN without a subcommand"),
+        },
 
         Commands::ClearRecur { id } => commands::task::clear_recur::execute(storage, id),
 
         Commands::Purge { days, dry_run, yes } => {
-            commands::purge::execute(storage, days, dry_run, yes)
+            commands::purge::execute(storage, days, dry_run, yes || skip_confirm)
         }
 
-        Commands::Export { file } => commands::portability::execute_export(storage, file),
+        Commands::Export { file, to, filter } => match to {
+            ExportFormat::Json => commands::portability::execute_export(storage, file, &filter),
+            ExportFormat::Csv => commands::csv_io::execute_export(storage, file, &filter),
+        },
 
-        Commands::Import { file, replace, yes } => {
-            commands::portability::execute_import(storage, file, replace, yes)
-        }
+        Commands::Publish {
+            file,
+            to,
+            title,
+            filter,
+        } => commands::publish::execute(storage, file, to, title, &filter),
+
+        Commands::Import {
+            file,
+            from,
+            replace,
+            yes,
+        } => match from {
+            ImportFormat::Json => {
+                commands::portability::execute_import(storage, file, replace, yes || skip_confirm)
+            }
+            ImportFormat::Csv if replace => Err(anyhow::anyhow!(
+                "--replace is only supported for JSON imports"
+            )),
+            ImportFormat::Csv => {
+                commands::csv_io::execute_import(storage, file, yes || skip_confirm)
+            }
+            ImportFormat::Eml => {
+                commands::mail_import::execute(storage, file, MailSource::Eml, yes || skip_confirm)
+            }
+            ImportFormat::Maildir => commands::mail_import::execute(
+                storage,
+                file,
+                MailSource::Maildir,
+                yes || skip_confirm,
+            ),
+        },
+
+        Commands::Demo => commands::demo::execute(),
+
+        Commands::Diff { before, after } => commands::diff::execute(storage, before, after),
+
+        Commands::Completions {
+            shell,
+            install,
+            yes,
+        } => commands::completions::execute(shell, install, yes),
+
+        Commands::External(_) => unreachable!("plugins are dispatched before storage opens"),
+
+        Commands::Prompt { template } => commands::prompt::execute(storage, template),
+
+        Commands::Doctor { fix } => commands::doctor::execute(storage, fix),
+
+        Commands::OpenData { file, print } => commands::open_data::execute(file, print),
 
         Commands::Backup => commands::backup::execute_backup(),
 
-        Commands::Restore { file, yes } => commands::backup::execute_restore(file, yes),
+        Commands::Restore { .. } => unreachable!("restore runs before storage opens"),
 
         Commands::BackupList => commands::backup::execute_list(),
 
         Commands::Holidays(sub) => match sub {
             HolidaysCommands::Refresh => commands::holidays_cmd::execute_refresh(),
         },
+
+        Commands::Jira(sub) => match sub {
+            JiraCommands::Pull { project } => commands::jira::execute_pull(storage, &project),
+        },
+
+        #[cfg(feature = "todoist")]
+        Commands::Todoist(sub) => match sub {
+            TodoistCommands::Pull => commands::todoist::execute_pull(storage),
+            TodoistCommands::Push => commands::todoist::execute_push(storage),
+        },
+
+        Commands::Undo => commands::undo::execute_undo(storage),
+
+        Commands::Redo => commands::undo::execute_redo(storage),
+    };
+
+    if result.is_ok()
+        && let (Some(op), Some(before)) = (journal_op, before)
+    {
+        commands::undo::record(storage, op, before)?;
     }
+    result
 }
@@ -0,0 +1,109 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+/// How much work a task is expected to take, set with `--estimate`.
+///
+/// Written as hours and minutes — `45m`, `2h`, `1h30m`, `1.5h` — both on the
+/// command line and in storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Estimate {
+    minutes: i64,
+}
+
+impl Estimate {
+    pub fn from_minutes(minutes: i64) -> Self {
+        Estimate { minutes }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::minutes(self.minutes)
+    }
+}
+
+impl fmt::Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (h, m) = (self.minutes / 60, self.minutes % 60);
+        match (h, m) {
+            (0, m) => write!(f, "{}m", m),
+            (h, 0) => write!(f, "{}h", h),
+            (h, m) => write!(f, "{}h{:02}m", h, m),
+        }
+    }
+}
+
+impl FromStr for Estimate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let err = || format!("invalid estimate '{}': use e.g. 45m, 2h, 1h30m or 1.5h", s);
+
+        let mut minutes = 0.0;
+        let mut number = String::new();
+        for c in s.chars().filter(|c| !c.is_whitespace()) {
+            let per_unit = match c {
+                'm' => 1.0,
+                'h' => 60.0,
+                _ if c.is_ascii_digit() || c == '.' => {
+                    number.push(c);
+                    continue;
+                }
+                _ => return Err(err()),
+            };
+            let count: f64 = number.parse().map_err(|_| err())?;
+            minutes += count * per_unit;
+            number.clear();
+        }
+        if !number.is_empty() || minutes <= 0.0 {
+            return Err(err());
+        }
+        Ok(Estimate {
+            minutes: minutes.round() as i64,
+        })
+    }
+}
+
+impl From<Estimate> for String {
+    fn from(estimate: Estimate) -> Self {
+        estimate.to_string()
+    }
+}
+
+impl TryFrom<String> for Estimate {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        for (input, minutes, shown) in [
+            ("45m", 45, "45m"),
+            ("2h", 120, "2h"),
+            ("1h30m", 90, "1h30m"),
+            ("1.5h", 90, "1h30m"),
+            ("90m", 90, "1h30m"),
+        ] {
+            let estimate: Estimate = input.parse().unwrap();
+            assert_eq!(estimate, Estimate::from_minutes(minutes));
+            assert_eq!(estimate.to_string(), shown);
+        }
+    }
+
+    #[test]
+    fn test_rejects_bad_estimates() {
+        for bad in ["", "0h", "2", "2d", "h", "1.2.3h"] {
+            assert!(bad.parse::<Estimate>().is_err(), "{} should fail", bad);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
 
 /// Filters tasks by completion status.
 ///
@@ -9,6 +10,8 @@ pub enum StatusFilter {
     Pending,
     /// Show only completed tasks.
     Done,
+    /// Show only cancelled tasks.
+    Cancelled,
     /// Show all tasks (default).
     All,
 }
@@ -26,6 +29,12 @@ pub enum DueFilter {
     WithDue,
     /// Tasks that have no due date.
     NoDue,
+    /// Tasks due exactly today.
+    Today,
+    /// Tasks due exactly tomorrow.
+    Tomorrow,
+    /// Tasks due in the current calendar week (Monday to Sunday).
+    ThisWeek,
 }
 
 /// Filters tasks by recurrence pattern.
@@ -47,8 +56,9 @@ pub enum RecurrenceFilter {
 
 /// Sort order for `todo list`.
 ///
-/// Used by `todo list --sort`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+/// Used by `todo list --sort` and the `default_sort` setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SortBy {
     /// Sort by priority: High → Medium → Low.
     Priority,
@@ -58,4 +68,155 @@ pub enum SortBy {
     Created,
     /// Sort by calculated urgency score (highest first).
     Urgency,
+    /// Sort by age: oldest pending tasks first, completed tasks last.
+    Age,
+}
+
+/// Explicit direction for one key of a [`SortSpec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDir {
+    Asc,
+    Desc,
+}
+
+/// One `key[:asc|desc]` entry of a [`SortSpec`].
+///
+/// Without a direction the key keeps its natural order, as documented on
+/// [`SortBy`]. Ascending means lowest value first: Low priority, earliest
+/// due date, oldest creation date, lowest urgency score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    pub by: SortBy,
+    pub dir: Option<SortDir>,
+}
+
+/// Ordered sort keys for `todo list`, e.g. `due:asc,priority:desc`.
+///
+/// Later keys break ties left by earlier ones. Used by `todo list --sort`
+/// and the `default_sort` setting, which still accept a single key name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct SortSpec(pub Vec<SortKey>);
+
+impl From<SortBy> for SortSpec {
+    fn from(by: SortBy) -> Self {
+        SortSpec(vec![SortKey { by, dir: None }])
+    }
+}
+
+impl std::str::FromStr for SortSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let keys = s
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let (name, dir) = match part.split_once(':') {
+                    Some((name, dir)) => (name.trim(), Some(dir.trim())),
+                    None => (part, None),
+                };
+                let by = SortBy::from_str(name, true).map_err(|_| {
+                    format!(
+                        "unknown sort key '{}' (expected priority, due, created, urgency or age)",
+                        name
+                    )
+                })?;
+                let dir = match dir.map(str::to_lowercase).as_deref() {
+                    None => None,
+                    Some("asc") => Some(SortDir::Asc),
+                    Some("desc") => Some(SortDir::Desc),
+                    Some(other) => {
+                        return Err(format!(
+                            "unknown sort direction '{}' for '{}' (expected asc or desc)",
+                            other, name
+                        ));
+                    }
+                };
+                Ok(SortKey { by, dir })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        if keys.is_empty() {
+            return Err("expected at least one sort key".to_string());
+        }
+        Ok(SortSpec(keys))
+    }
+}
+
+impl TryFrom<String> for SortSpec {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::fmt::Display for SortSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = self
+            .0
+            .iter()
+            .map(|key| {
+                let name = key
+                    .by
+                    .to_possible_value()
+                    .map(|v| v.get_name().to_string())
+                    .unwrap_or_default();
+                match key.dir {
+                    None => name,
+                    Some(SortDir::Asc) => format!("{}:asc", name),
+                    Some(SortDir::Desc) => format!("{}:desc", name),
+                }
+            })
+            .collect();
+        write!(f, "{}", parts.join(","))
+    }
+}
+
+impl From<SortSpec> for String {
+    fn from(spec: SortSpec) -> Self {
+        spec.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_spec_parses_keys_and_directions() {
+        let spec: SortSpec = "due:asc, priority:DESC,urgency".parse().unwrap();
+        assert_eq!(
+            spec.0,
+            vec![
+                SortKey {
+                    by: SortBy::Due,
+                    dir: Some(SortDir::Asc)
+                },
+                SortKey {
+                    by: SortBy::Priority,
+                    dir: Some(SortDir::Desc)
+                },
+                SortKey {
+                    by: SortBy::Urgency,
+                    dir: None
+                },
+            ]
+        );
+        assert_eq!(spec.to_string(), "due:asc,priority:desc,urgency");
+        assert_eq!("age".parse::<SortSpec>().unwrap(), SortBy::Age.into());
+    }
+
+    #[test]
+    fn test_sort_spec_rejects_bad_input() {
+        assert!("size".parse::<SortSpec>().unwrap_err().contains("size"));
+        assert!(
+            "due:up"
+                .parse::<SortSpec>()
+                .unwrap_err()
+                .contains("asc or desc")
+        );
+        assert!(" , ".parse::<SortSpec>().is_err());
+    }
 }
@@ -0,0 +1,49 @@
+use clap::ValueEnum;
+use colored::{ColoredString, Colorize};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Color label for visual grouping, independent of priority.
+///
+/// Shown as a colored bullet before the task text in tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Label {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    Gray,
+}
+
+impl Label {
+    /// Returns a `●` in this label's color.
+    pub fn bullet(&self) -> ColoredString {
+        match self {
+            Label::Red => "●".red(),
+            Label::Orange => "●".truecolor(255, 165, 0),
+            Label::Yellow => "●".yellow(),
+            Label::Green => "●".green(),
+            Label::Blue => "●".blue(),
+            Label::Purple => "●".magenta(),
+            Label::Gray => "●".truecolor(150, 150, 150),
+        }
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Label::Red => "red",
+            Label::Orange => "orange",
+            Label::Yellow => "yellow",
+            Label::Green => "green",
+            Label::Blue => "blue",
+            Label::Purple => "purple",
+            Label::Gray => "gray",
+        };
+        write!(f, "{}", s)
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Duration, NaiveDate};
+
+/// A window of whole days ending today, as in `todo time report --last 7d`.
+///
+/// Written as a number of days (`7`, `7d`) or weeks (`2w`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lookback {
+    days: u32,
+}
+
+impl Lookback {
+    pub const WEEK: Lookback = Lookback { days: 7 };
+
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// First day inside the window that ends on `today`.
+    pub fn since(&self, today: NaiveDate) -> NaiveDate {
+        today - Duration::days(i64::from(self.days) - 1)
+    }
+}
+
+impl fmt::Display for Lookback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.days.is_multiple_of(7) {
+            write!(f, "{}w", self.days / 7)
+        } else {
+            write!(f, "{}d", self.days)
+        }
+    }
+}
+
+impl FromStr for Lookback {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let err = || format!("invalid period '{}': use e.g. 7d or 2w", s);
+        let (count, per_unit) = match s.strip_suffix('w') {
+            Some(weeks) => (weeks, 7),
+            None => (s.strip_suffix('d').unwrap_or(s), 1),
+        };
+        let count: u32 = count.parse().map_err(|_| err())?;
+        match count.checked_mul(per_unit) {
+            Some(days) if days > 0 => Ok(Lookback { days }),
+            _ => Err(err()),
+        }
+    }
+}
@@ -7,25 +7,46 @@
 //! | [`Project`]  | A project entity that groups tasks and notes |
 //! | [`ResourceType`] | Docs / Article / Video / Repo / Crate / Book / Spec / Tool |
 //! | [`Priority`]         | High / Medium / Low priority levels |
+//! | [`Label`]            | Red / Orange / … color labels shown as bullets |
 //! | [`Recurrence`]       | Daily / Weekly / Monthly repeat patterns |
+//! | [`MonthEndPolicy`]   | Same-day / last-day handling of month-end monthly dates |
+//! | [`Timeliness`]       | On time / late / missed, for closed occurrences |
+//! | [`ReminderLead`]     | Lead time before a deadline for `todo remind` |
+//! | [`Estimate`]         | Expected effort, e.g. `2h` or `1h30m` |
+//! | [`Lookback`]         | Window of days ending today, e.g. `--last 7d` |
+//! | [`TaskSource`]       | Manual / Recurrence / Import / Restored provenance |
+//! | [`TimeEntry`]        | A start/stop interval from `todo start` / `todo stop` |
 //! | [`StatusFilter`]     | Filter tasks by completion status |
 //! | [`DueFilter`]        | Filter tasks by due-date window |
 //! | [`RecurrenceFilter`] | Filter tasks by recurrence pattern |
 //! | [`SortBy`]           | Sort order options for task lists |
+//! | [`SortSpec`]         | Multi-key sort with directions    |
 
+mod estimate;
 mod filters;
+mod label;
+mod lookback;
 mod note;
 mod priority;
 mod project;
 mod recurrence;
+mod reminder;
 mod resource;
+mod source;
 mod task;
+mod time_entry;
 
-pub use filters::{DueFilter, RecurrenceFilter, SortBy, StatusFilter};
+pub use estimate::Estimate;
+pub use filters::{DueFilter, RecurrenceFilter, SortBy, SortDir, SortKey, SortSpec, StatusFilter};
+pub use label::Label;
+pub use lookback::Lookback;
 pub use note::{Note, NoteFormat};
 pub use priority::Priority;
 pub use project::{Difficulty, Project};
-pub use recurrence::Recurrence;
+pub use recurrence::{CatchUpPolicy, MonthEndPolicy, Recurrence, Timeliness};
+pub use reminder::ReminderLead;
 pub use resource::{Resource, ResourceType};
-pub(crate) use task::detect_cycle;
-pub use task::{Task, count_by_project};
+pub use source::TaskSource;
+pub use task::{MISSED_REASON, Task, count_by_project};
+pub(crate) use task::{detect_cycle, local_midnight};
+pub use time_entry::TimeEntry;
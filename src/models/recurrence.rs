@@ -1,4 +1,4 @@
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -28,6 +28,85 @@ impl fmt::Display for Recurrence {
     }
 }
 
+/// What a monthly recurrence does when its due date is the last day of the
+/// month.
+///
+/// Set per task with `todo recur <ID> monthly --month-end <POLICY>`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum MonthEndPolicy {
+    /// Keep the day of month, clamped to shorter months: Jan 31 → Feb 28 →
+    /// Mar 28
+    #[default]
+    SameDay,
+    /// A due date on the last day of its month moves to the last day of the
+    /// next month: Jan 31 → Feb 28 → Mar 31
+    LastDay,
+}
+
+impl fmt::Display for MonthEndPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            MonthEndPolicy::SameDay => "same-day",
+            MonthEndPolicy::LastDay => "last-day",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// What completing a recurring task does when occurrences after it are
+/// already overdue, e.g. a daily task finished a week late.
+///
+/// Set per task with `todo recur <ID> daily --catch-up <POLICY>`. Either way
+/// the next occurrence records how many dates were skipped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum CatchUpPolicy {
+    /// Create only the occurrence right after the completed one, even if it
+    /// is already overdue
+    #[default]
+    Next,
+    /// Also create every skipped occurrence, closed as missed, so the
+    /// history has no gaps
+    Backfill,
+    /// Skip straight to the first occurrence due today or later
+    FastForward,
+}
+
+impl fmt::Display for CatchUpPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            CatchUpPolicy::Next => "next",
+            CatchUpPolicy::Backfill => "backfill",
+            CatchUpPolicy::FastForward => "fast-forward",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// How a past occurrence of a recurring task was closed, judged from
+/// `completed_at` against its due date and time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timeliness {
+    OnTime,
+    /// Completed this many days after the due date; 0 when only the due
+    /// time had passed
+    Late(i64),
+    /// Closed as missed by [`CatchUpPolicy::Backfill`]
+    Missed,
+}
+
+impl fmt::Display for Timeliness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Timeliness::OnTime => write!(f, "on time"),
+            Timeliness::Late(0) => write!(f, "late"),
+            Timeliness::Late(days) => write!(f, "late {}d", days),
+            Timeliness::Missed => write!(f, "missed"),
+        }
+    }
+}
+
 impl Recurrence {
     /// Calculates the next occurrence date based on the pattern.
     ///
@@ -52,14 +131,107 @@ impl Recurrence {
     ///            NaiveDate::from_ymd_opt(2025, 2, 11).unwrap());
     /// ```
     pub fn next_date(&self, from_date: NaiveDate) -> NaiveDate {
-        use chrono::Duration;
+        self.next_date_with(from_date, MonthEndPolicy::SameDay)
+    }
+
+    /// Like [`next_date`](Self::next_date), applying `policy` to monthly
+    /// dates that fall on the last day of their month.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use rustodo::models::{MonthEndPolicy, Recurrence};
+    ///
+    /// let feb_end = NaiveDate::from_ymd_opt(2026, 2, 28).unwrap();
+    /// assert_eq!(
+    ///     Recurrence::Monthly.next_date_with(feb_end, MonthEndPolicy::LastDay),
+    ///     NaiveDate::from_ymd_opt(2026, 3, 31).unwrap()
+    /// );
+    /// ```
+    pub fn next_date_with(&self, from_date: NaiveDate, policy: MonthEndPolicy) -> NaiveDate {
+        use chrono::{Duration, Months};
 
         match self {
             Recurrence::Daily => from_date + Duration::days(1),
             Recurrence::Weekly => from_date + Duration::days(7),
+            Recurrence::Monthly
+                if policy == MonthEndPolicy::LastDay && is_last_day_of_month(from_date) =>
+            {
+                // First of the month after next, minus one day
+                from_date
+                    .with_day(1)
+                    .and_then(|d| d.checked_add_months(Months::new(2)))
+                    .and_then(|d| d.pred_opt())
+                    .unwrap_or(from_date)
+            }
             Recurrence::Monthly => from_date
-                .checked_add_months(chrono::Months::new(1))
+                .checked_add_months(Months::new(1))
                 .unwrap_or(from_date),
         }
     }
+
+    /// The next `count` occurrence dates after `from_date`, each computed
+    /// from the previous one exactly as completing the task would.
+    pub fn upcoming(
+        &self,
+        from_date: NaiveDate,
+        count: usize,
+        policy: MonthEndPolicy,
+    ) -> Vec<NaiveDate> {
+        std::iter::successors(Some(from_date), |d| Some(self.next_date_with(*d, policy)))
+            .skip(1)
+            .take(count)
+            .collect()
+    }
+}
+
+fn is_last_day_of_month(date: NaiveDate) -> bool {
+    date.succ_opt()
+        .is_none_or(|next| next.month() != date.month())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_upcoming_weekly() {
+        assert_eq!(
+            Recurrence::Weekly.upcoming(date(2026, 2, 10), 3, MonthEndPolicy::SameDay),
+            vec![date(2026, 2, 17), date(2026, 2, 24), date(2026, 3, 3)]
+        );
+    }
+
+    #[test]
+    fn test_upcoming_monthly_chains_from_previous_date() {
+        // Jan 31 clamps to Feb 28, and later months follow from the 28th
+        assert_eq!(
+            Recurrence::Monthly.upcoming(date(2026, 1, 31), 3, MonthEndPolicy::SameDay),
+            vec![date(2026, 2, 28), date(2026, 3, 28), date(2026, 4, 28)]
+        );
+    }
+
+    #[test]
+    fn test_upcoming_monthly_last_day_snaps_to_month_end() {
+        assert_eq!(
+            Recurrence::Monthly.upcoming(date(2026, 1, 31), 4, MonthEndPolicy::LastDay),
+            vec![
+                date(2026, 2, 28),
+                date(2026, 3, 31),
+                date(2026, 4, 30),
+                date(2026, 5, 31)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_last_day_policy_leaves_mid_month_dates_alone() {
+        assert_eq!(
+            Recurrence::Monthly.next_date_with(date(2026, 1, 15), MonthEndPolicy::LastDay),
+            date(2026, 2, 15)
+        );
+    }
 }
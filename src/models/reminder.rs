@@ -0,0 +1,83 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+/// How long before a task's deadline `todo remind` notifies about it.
+///
+/// Written as a number with a unit — `30m`, `2h`, `1d`, `1w` — both on the
+/// command line and in storage. `0` turns the early reminder off, leaving
+/// only the overdue one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct ReminderLead {
+    minutes: i64,
+}
+
+impl ReminderLead {
+    /// One day, the lead used when neither the task nor the config sets one.
+    pub const DEFAULT: ReminderLead = ReminderLead { minutes: 24 * 60 };
+
+    pub fn duration(&self) -> Duration {
+        Duration::minutes(self.minutes)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.minutes == 0
+    }
+}
+
+impl fmt::Display for ReminderLead {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let m = self.minutes;
+        match m {
+            0 => write!(f, "0"),
+            _ if m % (7 * 24 * 60) == 0 => write!(f, "{}w", m / (7 * 24 * 60)),
+            _ if m % (24 * 60) == 0 => write!(f, "{}d", m / (24 * 60)),
+            _ if m % 60 == 0 => write!(f, "{}h", m / 60),
+            _ => write!(f, "{}m", m),
+        }
+    }
+}
+
+impl FromStr for ReminderLead {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "0" {
+            return Ok(ReminderLead { minutes: 0 });
+        }
+        let err = || format!("invalid reminder lead '{}': use e.g. 30m, 2h, 1d or 1w", s);
+        let unit = s.chars().last().ok_or_else(err)?;
+        let count: i64 = s[..s.len() - unit.len_utf8()].parse().map_err(|_| err())?;
+        let per_unit = match unit {
+            'm' => 1,
+            'h' => 60,
+            'd' => 24 * 60,
+            'w' => 7 * 24 * 60,
+            _ => return Err(err()),
+        };
+        if count < 0 {
+            return Err(err());
+        }
+        Ok(ReminderLead {
+            minutes: count * per_unit,
+        })
+    }
+}
+
+impl From<ReminderLead> for String {
+    fn from(lead: ReminderLead) -> Self {
+        lead.to_string()
+    }
+}
+
+impl TryFrom<String> for ReminderLead {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
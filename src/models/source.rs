@@ -0,0 +1,72 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Where a task came from, recorded once when it enters the database.
+///
+/// Stored as a compact string (`manual`, `recurrence`, `import:<from>`,
+/// `restored:<from>`) both in SQLite and in JSON exports.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum TaskSource {
+    /// Created by hand with `todo add` or from the TUI
+    Manual,
+    /// Generated when its parent recurring task was completed
+    Recurrence,
+    /// Brought in by an importer (`json`, `eml`, `maildir`, `jira`, …)
+    Import(String),
+    /// Brought back from a backup or snapshot after being lost
+    Restored(String),
+}
+
+impl TaskSource {
+    /// Human-readable description, as shown by `todo blame`.
+    pub fn describe(&self) -> String {
+        match self {
+            TaskSource::Manual => "created manually".to_string(),
+            TaskSource::Recurrence => "generated from recurrence".to_string(),
+            TaskSource::Import(from) => format!("imported from {}", from),
+            TaskSource::Restored(from) => format!("restored from {}", from),
+        }
+    }
+}
+
+impl fmt::Display for TaskSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskSource::Manual => write!(f, "manual"),
+            TaskSource::Recurrence => write!(f, "recurrence"),
+            TaskSource::Import(from) => write!(f, "import:{}", from),
+            TaskSource::Restored(from) => write!(f, "restored:{}", from),
+        }
+    }
+}
+
+impl FromStr for TaskSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            None if s == "manual" => Ok(TaskSource::Manual),
+            None if s == "recurrence" => Ok(TaskSource::Recurrence),
+            Some(("import", from)) => Ok(TaskSource::Import(from.to_string())),
+            Some(("restored", from)) => Ok(TaskSource::Restored(from.to_string())),
+            _ => Err(format!("unknown task source: {}", s)),
+        }
+    }
+}
+
+impl From<TaskSource> for String {
+    fn from(source: TaskSource) -> Self {
+        source.to_string()
+    }
+}
+
+impl TryFrom<String> for TaskSource {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
@@ -1,10 +1,19 @@
-use chrono::{DateTime, Local, NaiveDate, Utc};
-use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use chrono::{
+    DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
+use serde::{Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
+use super::estimate::Estimate;
 use super::filters::{DueFilter, StatusFilter};
+use super::label::Label;
 use super::priority::Priority;
-use super::recurrence::Recurrence;
+use super::recurrence::{CatchUpPolicy, MonthEndPolicy, Recurrence, Timeliness};
+use super::reminder::ReminderLead;
+use super::source::TaskSource;
+use super::time_entry::TimeEntry;
 
 /// Represents a single task in the todo list.
 ///
@@ -67,6 +76,10 @@ pub struct Task {
     pub project_name_legacy: Option<String>,
     /// Optional due date for deadline tracking
     pub due_date: Option<NaiveDate>,
+    /// Time of day the task is due on `due_date`, in local time. `None`
+    /// means any time that day, as before times were supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_time: Option<NaiveTime>,
     /// Timestamp when the task was created (UTC).
     pub created_at: DateTime<Utc>,
     /// Optional recurrence pattern (daily, weekly, monthly)
@@ -82,9 +95,12 @@ pub struct Task {
     /// IDs (1- based) of tasks that must be completed before this one
     #[serde(default)]
     pub depends_on: Vec<Uuid>,
-    /// Date when the task was marked as completed.
-    #[serde(default)]
-    pub completed_at: Option<NaiveDate>,
+    /// Timestamp when the task was marked as completed (UTC).
+    ///
+    /// Older exports stored a bare local date (`"2026-02-10"`); those are read
+    /// as local midnight of that day.
+    #[serde(default, deserialize_with = "date_or_datetime")]
+    pub completed_at: Option<DateTime<Utc>>,
     /// Timestamp of the last modification.
     ///
     /// Used by sync to determine which version of a task is more recent
@@ -105,8 +121,83 @@ pub struct Task {
     /// `None` means the task is not deleted.
     #[serde(default)]
     pub deleted_at: Option<DateTime<Utc>>,
+    /// Key of the linked Jira issue (e.g. `ABC-123`).
+    ///
+    /// Set by `todo jira pull`; used to deduplicate repeated pulls and to
+    /// transition the issue when the task is marked done.
+    #[serde(default)]
+    pub jira_key: Option<String>,
+    /// How the task entered the database; shown by `todo blame`.
+    ///
+    /// `None` for tasks created before provenance was tracked.
+    #[serde(default)]
+    pub source: Option<TaskSource>,
+    /// Date before which the task cannot be started.
+    ///
+    /// Until it passes the task counts as blocked, exactly like a pending
+    /// dependency.
+    #[serde(default)]
+    pub wait_until: Option<NaiveDate>,
+    /// How monthly recurrence treats a due date on the last day of the month.
+    #[serde(default)]
+    pub month_end: MonthEndPolicy,
+    /// Absolute paths of files attached with `todo annotate --file`.
+    #[serde(default)]
+    pub attachments: Vec<PathBuf>,
+    /// Optional color label, shown as a bullet before the text in tables.
+    #[serde(default)]
+    pub label: Option<Label>,
+    /// Intervals recorded by `todo start` / `todo stop`, oldest first; at most
+    /// the last one is still open.
+    #[serde(default)]
+    pub time_entries: Vec<TimeEntry>,
+    /// Reminder lead time for this task; `None` uses the configured default.
+    #[serde(default)]
+    pub remind_before: Option<ReminderLead>,
+    /// Expected effort, set with `--estimate`.
+    #[serde(default)]
+    pub estimate: Option<Estimate>,
+    /// Timestamp of cancellation via `todo cancel`.
+    ///
+    /// A cancelled task is closed like a completed one (`completed` is
+    /// `true`, so it leaves every pending view) but has no `completed_at`
+    /// and does not count toward completion rates.
+    #[serde(default)]
+    pub cancelled_at: Option<DateTime<Utc>>,
+    /// Why the task was cancelled, if a reason was given.
+    #[serde(default)]
+    pub cancel_reason: Option<String>,
+    /// Whether time tracked on this task can be invoiced.
+    #[serde(default)]
+    pub billable: bool,
+    /// Hourly rate for billable time; `None` uses the `hourly_rate` setting.
+    #[serde(default)]
+    pub hourly_rate: Option<f64>,
+    /// What completing this recurring task does about overdue occurrences.
+    #[serde(default)]
+    pub catch_up: CatchUpPolicy,
+    /// Occurrences of this task's series whose due dates passed unfinished
+    /// right before this one was created.
+    #[serde(default)]
+    pub missed: u32,
+    /// The other task this one shared a UUID with before one of them was
+    /// given a new UUID on load. Set on both tasks until `todo doctor --fix`
+    /// marks them reviewed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duplicate_of: Option<Uuid>,
+    /// Whether each new occurrence of this recurring task depends on the
+    /// one before it, so the series cannot be completed out of order.
+    #[serde(default)]
+    pub recur_chain_deps: bool,
+    /// Date the task becomes relevant. Unlike `wait_until` it does not
+    /// block anything; `todo list --started` just hides the task until then.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<NaiveDate>,
 }
 
+/// Cancel reason of occurrences created by [`CatchUpPolicy::Backfill`].
+pub const MISSED_REASON: &str = "missed";
+
 impl Task {
     /// Creates a new pending task with a unique UUID.
     ///
@@ -163,6 +254,7 @@ impl Task {
             project_id,
             project_name_legacy: None,
             due_date,
+            due_time: None,
             created_at: Utc::now(),
             recurrence,
             parent_id: None,
@@ -170,6 +262,24 @@ impl Task {
             completed_at: None,
             updated_at: Some(Utc::now()),
             deleted_at: None,
+            jira_key: None,
+            source: None,
+            wait_until: None,
+            month_end: MonthEndPolicy::default(),
+            attachments: Vec::new(),
+            time_entries: Vec::new(),
+            remind_before: None,
+            estimate: None,
+            cancelled_at: None,
+            cancel_reason: None,
+            billable: false,
+            hourly_rate: None,
+            catch_up: CatchUpPolicy::default(),
+            missed: 0,
+            duplicate_of: None,
+            recur_chain_deps: false,
+            start_date: None,
+            label: None,
         }
     }
 
@@ -184,6 +294,12 @@ impl Task {
         self.updated_at = Some(Utc::now());
     }
 
+    /// When the task last changed: `updated_at`, or `created_at` for tasks
+    /// from before modification times were tracked.
+    pub fn last_modified(&self) -> DateTime<Utc> {
+        self.updated_at.unwrap_or(self.created_at)
+    }
+
     /// Marks this task as soft-deleted.
     ///
     /// Sets `deleted_at` to the current UTC timestamp and calls [`touch`]
@@ -204,17 +320,102 @@ impl Task {
         self.deleted_at.is_some()
     }
 
-    /// Marks this task as completed.
+    /// Marks this task as completed, stopping its clock if it is running.
     pub fn mark_done(&mut self) {
+        let now = Utc::now();
+        self.stop_timer(now);
         self.completed = true;
-        self.completed_at = Some(Local::now().naive_local().date());
+        self.completed_at = Some(now);
+        self.cancelled_at = None;
+        self.cancel_reason = None;
         self.touch();
     }
 
-    /// Marks this task as pending (not completed).
+    /// Closes this task without completing it, stopping its clock if it is
+    /// running.
+    pub fn cancel(&mut self, reason: Option<String>) {
+        let now = Utc::now();
+        self.stop_timer(now);
+        self.completed = true;
+        self.completed_at = None;
+        self.cancelled_at = Some(now);
+        self.cancel_reason = reason;
+        self.touch();
+    }
+
+    /// Returns `true` if this task was closed with [`cancel`](Task::cancel).
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled_at.is_some()
+    }
+
+    /// When the running time entry began, if the clock is running.
+    pub fn started_at(&self) -> Option<DateTime<Utc>> {
+        self.time_entries
+            .last()
+            .filter(|e| e.end.is_none())
+            .map(|e| e.start)
+    }
+
+    /// Opens a time entry at `now`. Returns `false` if one is already open.
+    pub fn start_timer(&mut self, now: DateTime<Utc>) -> bool {
+        if self.started_at().is_some() {
+            return false;
+        }
+        self.time_entries.push(TimeEntry {
+            start: now,
+            end: None,
+            pomodoro: false,
+        });
+        self.touch();
+        true
+    }
+
+    /// Closes the open time entry at `now`. Returns `false` if none was open.
+    pub fn stop_timer(&mut self, now: DateTime<Utc>) -> bool {
+        match self.time_entries.last_mut() {
+            Some(entry) if entry.end.is_none() => {
+                entry.end = Some(now);
+                self.touch();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The moment the task becomes overdue: its due time, or the start of
+    /// the day after its due date, local time.
+    pub fn deadline(&self) -> Option<DateTime<Utc>> {
+        if let Some(at) = self.due_at() {
+            return Some(local_to_utc(at));
+        }
+        self.due_date.and_then(|d| d.succ_opt()).map(local_midnight)
+    }
+
+    /// Total time tracked, counting a running entry up to `now`.
+    pub fn time_tracked(&self, now: DateTime<Utc>) -> Duration {
+        self.time_entries
+            .iter()
+            .map(|e| e.duration(now))
+            .fold(Duration::zero(), |total, d| total + d)
+    }
+
+    /// Local calendar day the task was created on.
+    pub fn created_on(&self) -> NaiveDate {
+        self.created_at.with_timezone(&Local).date_naive()
+    }
+
+    /// Local calendar day the task was completed on, if it has been.
+    pub fn completed_on(&self) -> Option<NaiveDate> {
+        self.completed_at
+            .map(|at| at.with_timezone(&Local).date_naive())
+    }
+
+    /// Marks this task as pending (neither completed nor cancelled).
     pub fn mark_undone(&mut self) {
         self.completed = false;
         self.completed_at = None;
+        self.cancelled_at = None;
+        self.cancel_reason = None;
         self.touch();
     }
 
@@ -223,7 +424,9 @@ impl Task {
     /// A task is considered overdue if it has a due date in the past
     /// and is not yet completed.
     pub fn is_overdue(&self) -> bool {
-        if let Some(due) = self.due_date {
+        if let Some(due_at) = self.due_at() {
+            due_at < Local::now().naive_local() && !self.completed
+        } else if let Some(due) = self.due_date {
             let today = Local::now().naive_local().date();
             due < today && !self.completed
         } else {
@@ -231,6 +434,30 @@ impl Task {
         }
     }
 
+    /// How this occurrence of a recurring task was closed. `None` for
+    /// pending occurrences, tasks cancelled for another reason, and tasks
+    /// that do not recur.
+    pub fn timeliness(&self) -> Option<Timeliness> {
+        self.recurrence?;
+        let due = self.due_date?;
+        if self.is_cancelled() {
+            return (self.cancel_reason.as_deref() == Some(MISSED_REASON))
+                .then_some(Timeliness::Missed);
+        }
+        let done_at = self.completed_at.filter(|_| self.completed)?;
+        if done_at <= self.deadline()? {
+            Some(Timeliness::OnTime)
+        } else {
+            let done_on = done_at.with_timezone(&Local).date_naive();
+            Some(Timeliness::Late((done_on - due).num_days()))
+        }
+    }
+
+    /// The due date and time, for tasks due at a time of day.
+    pub fn due_at(&self) -> Option<NaiveDateTime> {
+        Some(self.due_date?.and_time(self.due_time?))
+    }
+
     /// Checks if this task is due soon (within the specified number of days).
     ///
     /// # Arguments
@@ -255,32 +482,62 @@ impl Task {
     pub fn matches_status(&self, status: StatusFilter) -> bool {
         match status {
             StatusFilter::Pending => !self.completed,
-            StatusFilter::Done => self.completed,
+            StatusFilter::Done => self.completed && !self.is_cancelled(),
+            StatusFilter::Cancelled => self.is_cancelled(),
             StatusFilter::All => true,
         }
     }
 
-    /// Checks if this task matches the given due date filter.
-    pub fn matches_due_filter(&self, filter: DueFilter) -> bool {
+    /// Checks if this task matches the given due date filter; `soon_days` is
+    /// the window for [`DueFilter::Soon`].
+    pub fn matches_due_filter(&self, filter: DueFilter, soon_days: i64) -> bool {
+        self.matches_due_filter_on(filter, Local::now().date_naive(), soon_days)
+    }
+
+    /// Like [`matches_due_filter`](Self::matches_due_filter), relative to `today`.
+    pub fn matches_due_filter_on(
+        &self,
+        filter: DueFilter,
+        today: NaiveDate,
+        soon_days: i64,
+    ) -> bool {
         match filter {
             DueFilter::Overdue => self.is_overdue(),
-            DueFilter::Soon => self.is_due_soon(7),
+            DueFilter::Soon => self.is_due_soon(soon_days),
             DueFilter::WithDue => self.due_date.is_some(),
             DueFilter::NoDue => self.due_date.is_none(),
+            DueFilter::Today => self.due_date == Some(today),
+            DueFilter::Tomorrow => self.due_date == today.succ_opt(),
+            DueFilter::ThisWeek => self
+                .due_date
+                .is_some_and(|due| due.iso_week() == today.iso_week()),
         }
     }
 
-    /// Returns true if any dependency task is still pending.
+    /// Returns true unless the start date is after `today`.
+    pub fn is_started(&self, today: NaiveDate) -> bool {
+        self.start_date.is_none_or(|date| date <= today)
+    }
+
+    /// Returns true if `wait_until` is still in the future.
+    pub fn is_waiting(&self) -> bool {
+        self.wait_until
+            .is_some_and(|date| date > Local::now().date_naive())
+    }
+
+    /// Returns true if the task is waiting for a date or any dependency task
+    /// is still pending.
     ///
     /// `all_tasks` is the full 0-indexed task list; IDs in `depends_on` are 1-based.
     pub fn is_blocked(&self, all_tasks: &[Task]) -> bool {
-        self.depends_on.iter().any(|dep_uuid| {
-            all_tasks
-                .iter()
-                .find(|t| t.uuid == *dep_uuid)
-                .map(|t| !t.completed)
-                .unwrap_or(false)
-        })
+        self.is_waiting()
+            || self.depends_on.iter().any(|dep_uuid| {
+                all_tasks
+                    .iter()
+                    .find(|t| t.uuid == *dep_uuid)
+                    .map(|t| !t.completed)
+                    .unwrap_or(false)
+            })
     }
 
     /// Returns the IDs of blocking (still-pending) dependencies.
@@ -298,6 +555,21 @@ impl Task {
             .collect()
     }
 
+    /// Returns dependency UUIDs that point at missing or deleted tasks.
+    ///
+    /// Such references never block and are pruned by `todo doctor --fix`.
+    pub fn dangling_deps(&self, all_tasks: &[Task]) -> Vec<Uuid> {
+        self.depends_on
+            .iter()
+            .copied()
+            .filter(|dep_uuid| {
+                !all_tasks
+                    .iter()
+                    .any(|t| t.uuid == *dep_uuid && !t.is_deleted())
+            })
+            .collect()
+    }
+
     /// Calculates an urgency score combining priority, due date, dependencies,
     /// blocking status, age, and tags — following the Taskwarrior model.
     ///
@@ -361,7 +633,7 @@ impl Task {
     ///
     /// # Behavior
     ///
-    /// - Preserves: text, priority, tags, recurrence pattern
+    /// - Preserves: text, priority, tags, recurrence pattern, month-end policy
     /// - Resets: completed = false
     /// - Updates: due_date (calculated from recurrence), created_at (now), updated_at (now)
     /// - Generates: New UUID for the next occurrence
@@ -395,7 +667,7 @@ impl Task {
     pub fn create_next_recurrence(&self, parent_uuid: Uuid) -> Option<Task> {
         let recurrence = self.recurrence?;
         let current_due = self.due_date?;
-        let next_due = recurrence.next_date(current_due);
+        let next_due = recurrence.next_date_with(current_due, self.month_end);
 
         let mut next_task = Task::new(
             self.text.clone(),
@@ -407,18 +679,101 @@ impl Task {
         );
 
         next_task.parent_id = Some(parent_uuid);
-        // Dependencies are NOT propagated to recurrences — each occurrence stands alone.
+        next_task.due_time = self.due_time;
+        next_task.estimate = self.estimate;
+        next_task.month_end = self.month_end;
+        next_task.catch_up = self.catch_up;
+        next_task.source = Some(TaskSource::Recurrence);
+        // The start date keeps its distance to the due date
+        next_task.start_date = self
+            .start_date
+            .map(|start| start + (next_due - current_due));
+        // Other dependencies are not propagated — only the optional link to
+        // the previous occurrence
+        next_task.recur_chain_deps = self.recur_chain_deps;
+        if self.recur_chain_deps {
+            next_task.depends_on = vec![self.uuid];
+        }
         Some(next_task)
     }
 
+    /// The occurrences that follow this one when it is completed on `today`,
+    /// according to its [`CatchUpPolicy`]: the skipped ones already closed
+    /// as missed (only with `Backfill`), then the next one to do.
+    ///
+    /// Returns `None` for tasks that are not recurring or have no due date.
+    pub fn catch_up_recurrence(
+        &self,
+        parent_uuid: Uuid,
+        today: NaiveDate,
+    ) -> Option<(Vec<Task>, Task)> {
+        let mut next = self.create_next_recurrence(parent_uuid)?;
+        let mut skipped = Vec::new();
+        let mut missed = 0;
+
+        if self.catch_up != CatchUpPolicy::Next {
+            while next.due_date.is_some_and(|due| due < today) {
+                missed += 1;
+                if self.catch_up == CatchUpPolicy::Backfill {
+                    let following = next.create_next_recurrence(next.uuid)?;
+                    next.cancel(Some(MISSED_REASON.to_owned()));
+                    skipped.push(next);
+                    next = following;
+                } else {
+                    next = next.create_next_recurrence(parent_uuid)?;
+                }
+            }
+        }
+        // Skipped occurrences are never saved, so chain to this one instead
+        if self.recur_chain_deps && skipped.is_empty() {
+            next.depends_on = vec![self.uuid];
+        }
+        next.missed = missed;
+        Some((skipped, next))
+    }
+
     #[allow(dead_code)]
     pub fn is_recurring(&self) -> bool {
         self.recurrence.is_some()
     }
 }
 
+/// Local midnight of `date` as a UTC timestamp.
+///
+/// Used to upgrade legacy date-only `completed_at` values.
+pub(crate) fn local_midnight(date: NaiveDate) -> DateTime<Utc> {
+    local_to_utc(date.and_hms_opt(0, 0, 0).unwrap())
+}
+
+/// `local` as a UTC instant, taking the earlier moment of a repeated hour.
+fn local_to_utc(local: NaiveDateTime) -> DateTime<Utc> {
+    local
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&local))
+}
+
+/// Accepts either an RFC 3339 timestamp or a legacy `YYYY-MM-DD` date.
+fn date_or_datetime<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(raw) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    if let Ok(dt) = DateTime::parse_from_rfc3339(&raw) {
+        return Ok(Some(dt.with_timezone(&Utc)));
+    }
+    NaiveDate::parse_from_str(&raw, "%Y-%m-%d")
+        .map(|d| Some(local_midnight(d)))
+        .map_err(serde::de::Error::custom)
+}
+
 /// Counts the tasks of a project by UUID, returning (total, completed).
 ///
+/// Cancelled tasks are left out of both numbers.
+///
 /// # Example
 ///
 /// ```
@@ -436,7 +791,7 @@ impl Task {
 pub fn count_by_project(tasks: &[Task], project_uuid: uuid::Uuid) -> (usize, usize) {
     let matching: Vec<_> = tasks
         .iter()
-        .filter(|t| !t.is_deleted() && t.project_id == Some(project_uuid))
+        .filter(|t| !t.is_deleted() && !t.is_cancelled() && t.project_id == Some(project_uuid))
         .collect();
 
     let total = matching.len();
@@ -527,6 +882,32 @@ mod tests {
         assert!(!task.is_blocked(&[dep]));
     }
 
+    #[test]
+    fn test_is_blocked_while_waiting() {
+        let today = Local::now().date_naive();
+        let mut task = make_task("Sign contract");
+        task.wait_until = today.succ_opt();
+        assert!(task.is_waiting());
+        assert!(task.is_blocked(&[]));
+
+        task.wait_until = Some(today);
+        assert!(!task.is_waiting());
+        assert!(!task.is_blocked(&[]));
+    }
+
+    #[test]
+    fn test_dangling_deps_missing_and_deleted() {
+        let live = make_task("Live");
+        let mut deleted = make_task("Deleted");
+        deleted.soft_delete();
+        let missing = Uuid::new_v4();
+        let mut task = make_task("Task");
+        task.depends_on = vec![live.uuid, deleted.uuid, missing];
+
+        let dangling = task.dangling_deps(&[live, deleted.clone()]);
+        assert_eq!(dangling, vec![deleted.uuid, missing]);
+    }
+
     #[test]
     fn test_detect_cycle_direct() {
         let mut tasks = vec![make_task("A"), make_task("B")];
@@ -625,6 +1006,20 @@ mod tests {
         assert!(task.updated_at > before);
     }
 
+    #[test]
+    fn test_completed_at_reads_legacy_date() {
+        let mut task = make_task("A");
+        task.mark_done();
+        let mut json = serde_json::to_value(&task).unwrap();
+        json["completed_at"] = "2026-02-10".into();
+
+        let legacy: Task = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.completed_on(), NaiveDate::from_ymd_opt(2026, 2, 10));
+
+        let roundtrip: Task = serde_json::from_str(&serde_json::to_string(&task).unwrap()).unwrap();
+        assert_eq!(roundtrip.completed_at, task.completed_at);
+    }
+
     #[test]
     fn test_mark_undone_updates_timestamp() {
         let mut task = make_task("A");
@@ -709,6 +1104,56 @@ mod tests {
         assert!(task.create_next_recurrence(parent_uuid).is_none());
     }
 
+    #[test]
+    fn test_catch_up_policies_on_late_completion() {
+        let date = |d: u32| NaiveDate::from_ymd_opt(2026, 2, d).unwrap();
+        let mut task = make_recurring(Some(Recurrence::Daily), Some(date(10)));
+        let today = date(14);
+
+        let (missed, next) = task.catch_up_recurrence(task.uuid, today).unwrap();
+        assert!(missed.is_empty());
+        assert_eq!((next.due_date, next.missed), (Some(date(11)), 0));
+
+        task.catch_up = CatchUpPolicy::FastForward;
+        let (missed, next) = task.catch_up_recurrence(task.uuid, today).unwrap();
+        assert!(missed.is_empty());
+        assert_eq!((next.due_date, next.missed), (Some(date(14)), 3));
+        assert_eq!(next.parent_id, Some(task.uuid));
+
+        task.catch_up = CatchUpPolicy::Backfill;
+        let (missed, next) = task.catch_up_recurrence(task.uuid, today).unwrap();
+        let dates: Vec<_> = missed.iter().map(|t| t.due_date.unwrap()).collect();
+        assert_eq!(dates, vec![date(11), date(12), date(13)]);
+        assert!(missed.iter().all(|t| t.is_cancelled()));
+        assert_eq!(missed[0].cancel_reason.as_deref(), Some(MISSED_REASON));
+        assert_eq!(missed[1].parent_id, Some(missed[0].uuid));
+        assert_eq!((next.due_date, next.missed), (Some(date(14)), 3));
+        assert_eq!(next.parent_id, Some(missed[2].uuid));
+        assert_eq!(next.catch_up, CatchUpPolicy::Backfill);
+    }
+
+    #[test]
+    fn test_timeliness_of_closed_occurrences() {
+        let today = Local::now().date_naive();
+        let mut task = make_recurring(Some(Recurrence::Daily), Some(today));
+        assert_eq!(task.timeliness(), None);
+
+        task.mark_done();
+        assert_eq!(task.timeliness(), Some(Timeliness::OnTime));
+
+        task.due_date = Some(today - Duration::days(2));
+        assert_eq!(task.timeliness(), Some(Timeliness::Late(2)));
+
+        task.recurrence = None;
+        assert_eq!(task.timeliness(), None);
+
+        let mut missed = make_recurring(Some(Recurrence::Daily), Some(today));
+        missed.cancel(Some(MISSED_REASON.to_string()));
+        assert_eq!(missed.timeliness(), Some(Timeliness::Missed));
+        missed.cancel(Some("not needed".to_string()));
+        assert_eq!(missed.timeliness(), None);
+    }
+
     #[test]
     fn test_recurrence_next_is_not_deleted() {
         let date = NaiveDate::from_ymd_opt(2026, 2, 10).unwrap();
@@ -722,6 +1167,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_due_filter_calendar_windows() {
+        // Wednesday
+        let today = NaiveDate::from_ymd_opt(2026, 2, 11).unwrap();
+        let due = |d: u32| make_recurring(None, NaiveDate::from_ymd_opt(2026, 2, d));
+
+        assert!(due(11).matches_due_filter_on(DueFilter::Today, today, 7));
+        assert!(!due(12).matches_due_filter_on(DueFilter::Today, today, 7));
+        assert!(due(12).matches_due_filter_on(DueFilter::Tomorrow, today, 7));
+        assert!(!due(11).matches_due_filter_on(DueFilter::Tomorrow, today, 7));
+
+        // Monday 9th through Sunday 15th
+        assert!(due(9).matches_due_filter_on(DueFilter::ThisWeek, today, 7));
+        assert!(due(15).matches_due_filter_on(DueFilter::ThisWeek, today, 7));
+        assert!(!due(16).matches_due_filter_on(DueFilter::ThisWeek, today, 7));
+        assert!(!due(8).matches_due_filter_on(DueFilter::ThisWeek, today, 7));
+    }
+
     #[test]
     fn test_project_preserved_in_recurrence() {
         let date = NaiveDate::from_ymd_opt(2026, 2, 10).unwrap();
@@ -825,4 +1288,25 @@ mod tests {
         let (total, _) = count_by_project(&tasks, project_uuid);
         assert_eq!(total, 1);
     }
+
+    #[test]
+    fn test_time_tracking() {
+        let mut task = Task::new("A".into(), Priority::Medium, vec![], None, None, None);
+        let t0 = Utc::now() - Duration::hours(2);
+
+        assert!(task.start_timer(t0));
+        assert!(!task.start_timer(t0));
+        assert!(task.stop_timer(t0 + Duration::minutes(30)));
+        assert!(!task.stop_timer(t0 + Duration::minutes(40)));
+        assert!(task.start_timer(t0 + Duration::hours(1)));
+        assert_eq!(task.started_at(), Some(t0 + Duration::hours(1)));
+        assert_eq!(
+            task.time_tracked(t0 + Duration::minutes(75)),
+            Duration::minutes(45)
+        );
+
+        task.mark_done();
+        assert!(task.started_at().is_none());
+        assert_eq!(task.time_entries.len(), 2);
+    }
 }
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// One stretch of work on a task, from `todo start` to `todo stop`.
+///
+/// `end` is `None` while the clock is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub start: DateTime<Utc>,
+    pub end: Option<DateTime<Utc>>,
+    /// A work interval from `todo pomodoro` that ran its full length.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pomodoro: bool,
+}
+
+impl TimeEntry {
+    /// Length of the entry; a running entry counts up to `now`.
+    pub fn duration(&self, now: DateTime<Utc>) -> Duration {
+        (self.end.unwrap_or(now) - self.start).max(Duration::zero())
+    }
+}
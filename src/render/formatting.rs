@@ -1,8 +1,13 @@
-use chrono::Local;
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, Utc, Weekday};
 use colored::{ColoredString, Colorize};
 use uuid::Uuid;
 
-use crate::models::{Note, Project, Task};
+use crate::config::{Config, DueDisplay, PriorityDisplay};
+use crate::models::{Note, Priority, Project, Task, Timeliness};
 
 /// Resolves a `project_id` to its display name.
 ///
@@ -26,14 +31,207 @@ pub fn project_colored(name: &str) -> ColoredString {
     }
 }
 
-/// Converts a `NaiveDate` into a full date string (YYYY-MM-DD).
+/// Compact age of a timestamp: `45m`, `5h`, `3d`, `2w`, `4mo`, `1y`.
+pub fn format_age(since: DateTime<Utc>) -> String {
+    let age_secs = (Utc::now() - since).num_seconds();
+    if age_secs < 3600 {
+        format!("{}m", age_secs / 60)
+    } else if age_secs < 86400 {
+        format!("{}h", age_secs / 3600)
+    } else if age_secs < 7 * 86400 {
+        format!("{}d", age_secs / 86400)
+    } else if age_secs < 30 * 86400 {
+        format!("{}w", age_secs / (7 * 86400))
+    } else if age_secs < 365 * 86400 {
+        format!("{}mo", age_secs / (30 * 86400))
+    } else {
+        format!("{}y", age_secs / (365 * 86400))
+    }
+}
+
+/// `width`-cell bar of `done` out of `total`: green when complete, yellow
+/// once started, dimmed when empty.
+pub fn progress_bar(done: usize, total: usize, width: usize) -> String {
+    let filled = (done * width).checked_div(total).unwrap_or(0);
+    let empty = width - filled;
+    let bar = format!(
+        "{}{}",
+        glyph("█", "#").repeat(filled),
+        glyph("░", ".").repeat(empty)
+    );
+    if done == total && total > 0 {
+        bar.green().to_string()
+    } else if filled > 0 {
+        bar.yellow().to_string()
+    } else {
+        bar.dimmed().to_string()
+    }
+}
+
+/// Compact tracked-time text: `0m`, `25m`, `1h 05m`.
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.num_minutes().max(0);
+    if minutes < 60 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    }
+}
+
+/// Colored priority in the configured `priority_display` style.
+pub fn priority_label(priority: Priority) -> ColoredString {
+    let text = format_priority(priority, priority_display());
+    match priority {
+        Priority::High => text.red(),
+        Priority::Medium => text.yellow(),
+        Priority::Low => text.green(),
+    }
+}
+
+/// Column width that fits every priority in the configured style.
+pub fn priority_width() -> usize {
+    [Priority::High, Priority::Medium, Priority::Low]
+        .iter()
+        .map(|&p| format_priority(p, priority_display()).len())
+        .max()
+        .unwrap_or(1)
+}
+
+/// The configured priority style, read from the config once per process.
+fn priority_display() -> PriorityDisplay {
+    static MODE: OnceLock<PriorityDisplay> = OnceLock::new();
+    *MODE.get_or_init(|| {
+        Config::load()
+            .map(|c| c.priority_display)
+            .unwrap_or_default()
+    })
+}
+
+/// Uncolored priority text in the given style.
+pub fn format_priority(priority: Priority, mode: PriorityDisplay) -> &'static str {
+    match (mode, priority) {
+        (PriorityDisplay::Letter, Priority::High) => "H",
+        (PriorityDisplay::Letter, Priority::Medium) => "M",
+        (PriorityDisplay::Letter, Priority::Low) => "L",
+        (PriorityDisplay::Word, Priority::High) => "high",
+        (PriorityDisplay::Word, Priority::Medium) => "medium",
+        (PriorityDisplay::Word, Priority::Low) => "low",
+        (PriorityDisplay::Bang, Priority::High) => "!!!",
+        (PriorityDisplay::Bang, Priority::Medium) => "!!",
+        (PriorityDisplay::Bang, Priority::Low) => "!",
+    }
+}
+
+/// Tracked time for the task table: `—` when none, `▶ ` prefix while running.
+pub fn time_text(task: &Task) -> String {
+    if task.time_entries.is_empty() {
+        return "—".to_string();
+    }
+    let total = format_duration(task.time_tracked(Utc::now()));
+    if task.started_at().is_some() {
+        format!("▶ {}", total)
+    } else {
+        total
+    }
+}
+
+/// Renders a due date according to the `due_display` setting.
 /// Shared by task list, project list, and any other due-date display.
-pub fn due_relative_text(due: chrono::NaiveDate) -> String {
-    due.format("%Y-%m-%d").to_string()
+pub fn due_relative_text(due: NaiveDate) -> String {
+    format_due(due, Local::now().date_naive(), due_display())
 }
 
+/// Like [`due_relative_text`], for a task's due date and time.
+pub fn due_at_relative_text(task: &Task) -> Option<String> {
+    match task.due_at() {
+        Some(at) => Some(format_due_at(at, Local::now().naive_local(), due_display())),
+        None => task.due_date.map(due_relative_text),
+    }
+}
+
+/// Formats a due date with a time of day. Within a day of `now` the
+/// relative and weekday styles read "in 3 hours" or "20 min ago"; otherwise
+/// it is the date as [`format_due`] shows it, followed by the time.
+pub fn format_due_at(due: NaiveDateTime, now: NaiveDateTime, mode: DueDisplay) -> String {
+    let minutes = (due - now).num_minutes();
+    if mode != DueDisplay::Absolute && minutes.abs() < 24 * 60 {
+        let amount = match minutes.abs() {
+            0 => return "now".to_string(),
+            m if m < 60 => format!("{} min", m),
+            m if m < 120 => "1 hour".to_string(),
+            m => format!("{} hours", m / 60),
+        };
+        return if minutes > 0 {
+            format!("in {}", amount)
+        } else {
+            format!("{} ago", amount)
+        };
+    }
+    format!(
+        "{} {}",
+        format_due(due.date(), now.date(), mode),
+        due.format("%H:%M")
+    )
+}
+
+/// Beyond this many days away, relative and weekday styles show the date.
+const SMART_DUE_RANGE_DAYS: i64 = 14;
+
+/// The configured due-date style, read from the config once per process.
+fn due_display() -> DueDisplay {
+    static MODE: OnceLock<DueDisplay> = OnceLock::new();
+    *MODE.get_or_init(|| Config::load().map(|c| c.due_display).unwrap_or_default())
+}
+
+/// Formats `due` relative to `today` in the given style.
+pub fn format_due(due: NaiveDate, today: NaiveDate, mode: DueDisplay) -> String {
+    let days = (due - today).num_days();
+    let absolute = || due.format("%Y-%m-%d").to_string();
+
+    if mode == DueDisplay::Absolute || days.abs() > SMART_DUE_RANGE_DAYS {
+        return absolute();
+    }
+    match days {
+        0 => return "today".to_string(),
+        1 => return "tomorrow".to_string(),
+        -1 => return "yesterday".to_string(),
+        _ => {}
+    }
+
+    match mode {
+        DueDisplay::Relative if days > 0 => format!("in {} days", days),
+        DueDisplay::Relative => format!("{} days ago", -days),
+        _ => {
+            let weekday = due.format("%a").to_string();
+            if days < 0 {
+                return if days > -7 {
+                    format!("last {}", weekday)
+                } else {
+                    absolute()
+                };
+            }
+            // Later this calendar week (Mon–Sun) → bare weekday name
+            let week_offset = (due.week(Weekday::Mon).first_day()
+                - today.week(Weekday::Mon).first_day())
+            .num_days()
+                / 7;
+            match week_offset {
+                0 => weekday,
+                1 => format!("next {}", weekday),
+                _ => absolute(),
+            }
+        }
+    }
+}
+
+/// Due text for tables; a pending overdue task gets a trailing ` !` so the
+/// red coloring is not the only sign.
 pub fn get_due_text(task: &Task) -> String {
-    task.due_date.map(due_relative_text).unwrap_or_default()
+    match due_at_relative_text(task) {
+        Some(text) if task.is_overdue() => format!("{} !", text),
+        Some(text) => text,
+        None => String::new(),
+    }
 }
 
 /// Returns a colored version of the due date text based on urgency.
@@ -52,6 +250,9 @@ pub fn get_due_colored(task: &Task, text: &str) -> ColoredString {
     if task.completed {
         return text.dimmed();
     }
+    if task.is_overdue() {
+        return text.red().bold();
+    }
 
     if let Some(due) = task.due_date {
         let today = Local::now().naive_local().date();
@@ -71,16 +272,199 @@ pub fn get_due_colored(task: &Task, text: &str) -> ColoredString {
     }
 }
 
+/// How a recurring occurrence was closed: green on time, yellow late, red
+/// missed, and a dimmed `—` when it does not apply.
+pub fn timeliness_colored(timeliness: Option<Timeliness>) -> ColoredString {
+    match timeliness {
+        Some(Timeliness::OnTime) => "on time".green(),
+        Some(late @ Timeliness::Late(_)) => late.to_string().yellow(),
+        Some(Timeliness::Missed) => "missed".red(),
+        None => "—".dimmed(),
+    }
+}
+
 /// Truncates text to `max` characters, appending `...` if cut.
 ///
 /// Uses `.chars()` to avoid splitting multi-byte UTF-8 sequences.
 pub fn truncate(text: &str, max: usize) -> String {
-    if text.chars().count() <= max {
-        text.to_string()
-    } else {
-        let truncated: String = text.chars().take(max.saturating_sub(3)).collect();
-        format!("{}...", truncated)
+    if display_width(text) <= max {
+        return text.to_string();
     }
+    let budget = max.saturating_sub(3);
+    let mut out = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = char_width(c);
+        // Zero-width marks stay with the character before them
+        if w > 0 && used + w > budget {
+            break;
+        }
+        used += w;
+        out.push(c);
+    }
+    // A trailing joiner would glue the next emoji onto the "..."
+    while out.ends_with(ZWJ) {
+        out.pop();
+    }
+    format!("{}...", out)
+}
+
+/// Pads `text` with spaces to `width` terminal columns; `{:<w$}` counts
+/// characters instead, which misaligns wide text.
+pub fn pad(text: &str, width: usize) -> String {
+    let fill = width.saturating_sub(display_width(text));
+    format!("{}{}", text, " ".repeat(fill))
+}
+
+const ZWJ: char = '\u{200D}';
+
+/// Terminal columns taken by `text`.
+/// Spaces that pad `text` to `width` terminal columns. Unlike `{:<w$}` this
+/// counts wide characters (CJK, emoji) as two columns.
+pub fn fill(text: &str, width: usize) -> String {
+    " ".repeat(width.saturating_sub(display_width(text)))
+}
+
+pub fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+/// Terminal columns taken by `c`: 0 for combining marks, joiners, and
+/// variation selectors, 2 for East Asian wide characters and emoji.
+pub fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x0000..=0x001F | 0x007F..=0x009F => 0,
+        0x0300..=0x036F | 0x1AB0..=0x1AFF | 0x1DC0..=0x1DFF | 0x20D0..=0x20FF => 0,
+        0x200B..=0x200F | 0xFE00..=0xFE0F | 0xFE20..=0xFE2F | 0xE0100..=0xE01EF => 0,
+        0x1F3FB..=0x1F3FF => 0, // skin-tone modifiers join the emoji before them
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F1E6..=0x1F1FF
+        | 0x1F300..=0x1F64F
+        | 0x1F680..=0x1F6FF
+        | 0x1F900..=0x1F9FF
+        | 0x1FA70..=0x1FAFF
+        | 0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+/// Set by the global `--no-wrap` flag.
+static NO_WRAP: AtomicBool = AtomicBool::new(false);
+
+/// Turns wrapping of long table cells off for this process (`--no-wrap`).
+pub fn disable_wrap() {
+    NO_WRAP.store(true, Ordering::Relaxed);
+}
+
+/// Whether tables fit the terminal and wrap long text instead of
+/// truncating it. Off with `--no-wrap`, and when stdout is not a terminal so
+/// piped output keeps content-sized columns.
+pub fn wrap_enabled() -> bool {
+    !NO_WRAP.load(Ordering::Relaxed) && std::io::stdout().is_terminal()
+}
+
+/// Set by `--plain` or `TERM=dumb`.
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+/// Applies `--no-color` / `--plain` for this process. `NO_COLOR` (when
+/// non-empty) also turns color off, and `TERM=dumb` implies `--plain`, which
+/// drops color and swaps box-drawing and block characters for ASCII.
+pub fn configure_output(no_color: bool, plain: bool) {
+    let dumb = std::env::var("TERM").is_ok_and(|term| term == "dumb");
+    let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    if plain || dumb {
+        PLAIN.store(true, Ordering::Relaxed);
+    }
+    if no_color || no_color_env || plain || dumb {
+        colored::control::set_override(false);
+    }
+}
+
+/// Whether output is limited to ASCII decoration (`--plain`).
+pub fn plain() -> bool {
+    PLAIN.load(Ordering::Relaxed)
+}
+
+/// `fancy` normally, `ascii` under `--plain`.
+pub fn glyph(fancy: &'static str, ascii: &'static str) -> &'static str {
+    if plain() { ascii } else { fancy }
+}
+
+/// A horizontal rule `width` columns wide.
+pub fn rule(width: usize) -> String {
+    glyph("─", "-").repeat(width)
+}
+
+/// Width tables are fitted to: `$COLUMNS`, else the terminal's width when
+/// stdout is one, else 80.
+pub fn terminal_width() -> usize {
+    static WIDTH: OnceLock<usize> = OnceLock::new();
+    *WIDTH.get_or_init(|| {
+        std::env::var("COLUMNS")
+            .ok()
+            .and_then(|c| c.trim().parse().ok())
+            .filter(|&c: &usize| c > 0)
+            .or_else(|| {
+                std::io::stdout()
+                    .is_terminal()
+                    .then(ratatui::crossterm::terminal::size)
+                    .and_then(|size| size.ok())
+                    .map(|(cols, _)| cols as usize)
+            })
+            .unwrap_or(80)
+    })
+}
+
+/// Splits `text` into lines of at most `width` columns, breaking at
+/// spaces where possible and splitting words longer than a whole line.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(2);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let used = display_width(&line);
+        let word_width = display_width(word);
+        if used > 0 && used + 1 + word_width <= width {
+            line.push(' ');
+            line.push_str(word);
+            continue;
+        }
+        if used > 0 {
+            lines.push(std::mem::take(&mut line));
+        }
+        // Split words wider than a line between characters
+        let mut used = 0;
+        for c in word.chars() {
+            let w = char_width(c);
+            if w > 0 && used + w > width {
+                lines.push(std::mem::take(&mut line));
+                used = 0;
+            }
+            used += w;
+            line.push(c);
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Returns the first line of multi-line text (e.g. a task read from stdin).
+///
+/// Tables show only this line; `todo show` prints the rest.
+pub fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or("")
 }
 
 /// Returns a single-line preview of a note.
@@ -100,3 +484,121 @@ pub fn note_preview(note: &Note) -> String {
         .map(|l| l.trim_start_matches('#').trim().to_string())
         .unwrap_or_default()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    /// A Wednesday
+    fn today() -> NaiveDate {
+        d(2026, 10, 14)
+    }
+
+    #[test]
+    fn test_format_due_absolute() {
+        assert_eq!(
+            format_due(d(2026, 10, 15), today(), DueDisplay::Absolute),
+            "2026-10-15"
+        );
+    }
+
+    #[test]
+    fn test_format_due_relative() {
+        let mode = DueDisplay::Relative;
+        assert_eq!(format_due(today(), today(), mode), "today");
+        assert_eq!(format_due(d(2026, 10, 15), today(), mode), "tomorrow");
+        assert_eq!(format_due(d(2026, 10, 19), today(), mode), "in 5 days");
+        assert_eq!(format_due(d(2026, 10, 11), today(), mode), "3 days ago");
+        assert_eq!(format_due(d(2026, 12, 1), today(), mode), "2026-12-01");
+    }
+
+    #[test]
+    fn test_format_due_weekday() {
+        let mode = DueDisplay::Weekday;
+        assert_eq!(format_due(d(2026, 10, 16), today(), mode), "Fri");
+        assert_eq!(format_due(d(2026, 10, 20), today(), mode), "next Tue");
+        assert_eq!(format_due(d(2026, 10, 12), today(), mode), "last Mon");
+        assert_eq!(format_due(d(2026, 10, 7), today(), mode), "2026-10-07");
+        assert_eq!(format_due(d(2026, 10, 27), today(), mode), "2026-10-27");
+    }
+
+    #[test]
+    fn test_format_due_at_within_a_day_is_relative() {
+        let now = today().and_hms_opt(14, 0, 0).unwrap();
+        let at = |day: NaiveDate, h, m| day.and_hms_opt(h, m, 0).unwrap();
+        let mode = DueDisplay::Relative;
+        assert_eq!(format_due_at(at(today(), 17, 0), now, mode), "in 3 hours");
+        assert_eq!(format_due_at(at(today(), 15, 30), now, mode), "in 1 hour");
+        assert_eq!(format_due_at(at(today(), 13, 40), now, mode), "20 min ago");
+        assert_eq!(format_due_at(now, now, mode), "now");
+        assert_eq!(
+            format_due_at(at(d(2026, 10, 16), 9, 0), now, DueDisplay::Weekday),
+            "Fri 09:00"
+        );
+        assert_eq!(
+            format_due_at(at(today(), 17, 0), now, DueDisplay::Absolute),
+            "2026-10-14 17:00"
+        );
+    }
+
+    #[test]
+    fn test_format_priority_styles() {
+        assert_eq!(
+            format_priority(Priority::High, PriorityDisplay::Letter),
+            "H"
+        );
+        assert_eq!(
+            format_priority(Priority::Medium, PriorityDisplay::Word),
+            "medium"
+        );
+        assert_eq!(format_priority(Priority::Low, PriorityDisplay::Bang), "!");
+        assert_eq!(
+            format_priority(Priority::High, PriorityDisplay::Bang),
+            "!!!"
+        );
+    }
+
+    #[test]
+    fn test_wrap_breaks_at_spaces_and_splits_long_words() {
+        assert_eq!(
+            wrap("write the quarterly report", 12),
+            ["write the", "quarterly", "report"]
+        );
+        assert_eq!(wrap("abcdefghij k", 4), ["abcd", "efgh", "ij k"]);
+        assert_eq!(wrap("short", 40), ["short"]);
+        assert_eq!(wrap("", 10), [""]);
+    }
+
+    #[test]
+    fn test_display_width_of_cjk_and_emoji() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("🎉 ok"), 5);
+        // Combining accent, ZWJ family, and flag
+        assert_eq!(display_width("e\u{301}"), 1);
+        assert_eq!(display_width("👩\u{200D}👧"), 4);
+        assert_eq!(display_width("🇧🇷"), 4);
+    }
+
+    #[test]
+    fn test_truncate_by_display_width() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate("会議の議事録を送る", 10), "会議の...");
+        assert_eq!(display_width(&truncate("会議の議事録を送る", 10)), 9);
+        assert_eq!(truncate("🎉🎉🎉🎉🎉", 8), "🎉🎉...");
+        // Never ends on a joiner or splits an accent from its letter
+        assert_eq!(truncate("ab👩\u{200D}👧cdefgh", 7), "ab👩...");
+        assert_eq!(truncate("cafe\u{301} au lait", 7), "cafe\u{301}...");
+    }
+
+    #[test]
+    fn test_wrap_and_pad_wide_text() {
+        assert_eq!(wrap("日本語のテキスト", 6), ["日本語", "のテキ", "スト"]);
+        assert_eq!(pad("日本", 6), "日本  ");
+        assert_eq!(display_width(&pad("🎉", 4)), 4);
+    }
+}
@@ -0,0 +1,127 @@
+//! Static HTML rendering for `todo publish`.
+//!
+//! A single self-contained page (inline CSS, no scripts) with one table
+//! row per task, meant to be opened in a browser or served as-is.
+
+use chrono::NaiveDate;
+
+use crate::config::PriorityDisplay;
+use crate::models::{Project, Task};
+use crate::render::formatting::{first_line, format_priority, project_name};
+use crate::render::markdown::ReportStatus;
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;margin:2rem;color:#222}\
+table{border-collapse:collapse;width:100%}\
+th,td{text-align:left;padding:.4rem .6rem;border-bottom:1px solid #ddd}\
+th{background:#f4f4f4}\
+tr.closed td{color:#888}tr.closed .task{text-decoration:line-through}\
+tr.overdue .due{color:#c00;font-weight:bold}\
+.tag{background:#eef;border-radius:3px;padding:0 .3rem;margin-right:.2rem}\
+footer{margin-top:1rem;color:#888;font-size:.9rem}";
+
+/// Renders `tasks` (with their display IDs) as an HTML page.
+///
+/// `all_tasks` is used to decide which tasks are blocked.
+pub fn task_page(
+    title: &str,
+    tasks: &[(usize, &Task)],
+    all_tasks: &[Task],
+    projects: &[Project],
+    today: NaiveDate,
+) -> String {
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{title}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n",
+        title = escape(title),
+    );
+    if tasks.is_empty() {
+        out.push_str("<p>No tasks.</p>\n");
+    } else {
+        out.push_str(
+            "<table>\n<thead><tr><th>#</th><th>Task</th><th>Priority</th><th>Due</th>\
+             <th>Tags</th><th>Project</th><th>Status</th></tr></thead>\n<tbody>\n",
+        );
+        for &(id, task) in tasks {
+            let status = ReportStatus::of(task, all_tasks);
+            let class = if task.completed {
+                " class=\"closed\""
+            } else if task.due_date.is_some_and(|d| d < today) {
+                " class=\"overdue\""
+            } else {
+                ""
+            };
+            let tags: String = task
+                .tags
+                .iter()
+                .map(|t| format!("<span class=\"tag\">#{}</span>", escape(t)))
+                .collect();
+            out.push_str(&format!(
+                "<tr{}><td>{}</td><td class=\"task\">{}</td><td>{}</td><td class=\"due\">{}</td>\
+                 <td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                class,
+                id,
+                escape(first_line(&task.text)),
+                format_priority(task.priority, PriorityDisplay::Word),
+                task.due_date.map(|d| d.to_string()).unwrap_or_default(),
+                tags,
+                escape(project_name(task.project_id, projects)),
+                status.heading(),
+            ));
+        }
+        out.push_str("</tbody>\n</table>\n");
+    }
+    out.push_str(&format!(
+        "<footer>{} task{} · generated {} · read-only</footer>\n</body>\n</html>\n",
+        tasks.len(),
+        if tasks.len() == 1 { "" } else { "s" },
+        today
+    ));
+    out
+}
+
+/// Escapes the characters HTML treats specially.
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+
+    #[test]
+    fn test_task_page_escapes_and_marks_rows() {
+        let today = NaiveDate::from_ymd_opt(2030, 1, 10).unwrap();
+        let late = Task::new(
+            "Fix <script> & stuff".into(),
+            Priority::High,
+            vec!["a&b".into()],
+            None,
+            NaiveDate::from_ymd_opt(2030, 1, 9),
+            None,
+        );
+        let mut done = Task::new("Done".into(), Priority::Low, vec![], None, None, None);
+        done.mark_done();
+        let all = vec![late, done];
+        let rows: Vec<_> = all.iter().enumerate().map(|(i, t)| (i + 1, t)).collect();
+
+        let page = task_page("Family <list>", &rows, &all, &[], today);
+        assert!(page.contains("<title>Family &lt;list&gt;</title>"));
+        assert!(page.contains("<tr class=\"overdue\"><td>1</td><td class=\"task\">Fix &lt;script&gt; &amp; stuff</td><td>high</td>"));
+        assert!(page.contains("<span class=\"tag\">#a&amp;b</span>"));
+        assert!(page.contains("<tr class=\"closed\"><td>2</td>"));
+        assert!(!page.contains("<script>"));
+    }
+}
@@ -0,0 +1,303 @@
+//! Markdown rendering for `todo report`, `todo digest`, and `todo publish`.
+//!
+//! Produces a document grouped by project, then by status, meant to be
+//! pasted into team updates, wikis, or issue comments:
+//!
+//! ```text
+//! # Task report — 2026-03-06
+//!
+//! ## Website
+//!
+//! ### Done (1)
+//!
+//! - [x] Ship landing page — done 2026-03-04 `#release` <sub>#3</sub>
+//! ```
+//!
+//! Projects are listed alphabetically with tasks outside any project last.
+//! Empty status sections are left out.
+
+use chrono::NaiveDate;
+
+use crate::config::PriorityDisplay;
+use crate::models::{Priority, Project, Task};
+use crate::render::formatting::{first_line, format_priority, project_name};
+
+/// Status sections, in the order they appear under each project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReportStatus {
+    Done,
+    InProgress,
+    Open,
+    Blocked,
+    Cancelled,
+}
+
+impl ReportStatus {
+    pub fn of(task: &Task, all_tasks: &[Task]) -> Self {
+        if task.is_cancelled() {
+            Self::Cancelled
+        } else if task.completed {
+            Self::Done
+        } else if task.started_at().is_some() {
+            Self::InProgress
+        } else if task.is_blocked(all_tasks) {
+            Self::Blocked
+        } else {
+            Self::Open
+        }
+    }
+
+    pub fn heading(self) -> &'static str {
+        match self {
+            Self::Done => "Done",
+            Self::InProgress => "In progress",
+            Self::Open => "Open",
+            Self::Blocked => "Blocked",
+            Self::Cancelled => "Cancelled",
+        }
+    }
+}
+
+/// A task in the report with its status and display ID.
+type Row<'a> = (ReportStatus, usize, &'a Task);
+
+/// Renders `tasks` (with their display IDs) as a Markdown report.
+///
+/// `all_tasks` is used to decide which tasks are blocked; `since` is only
+/// shown in the subtitle — filtering is up to the caller.
+pub fn report(
+    tasks: &[(usize, &Task)],
+    all_tasks: &[Task],
+    projects: &[Project],
+    today: NaiveDate,
+    since: Option<NaiveDate>,
+) -> String {
+    let mut out = format!("# Task report — {}\n\n", today);
+    if let Some(since) = since {
+        out.push_str(&format!("_Activity since {}_\n\n", since));
+    }
+    if tasks.is_empty() {
+        out.push_str("_No tasks._\n");
+        return out;
+    }
+
+    let mut groups: Vec<(Option<&Project>, Vec<Row>)> = Vec::new();
+    for &(id, task) in tasks {
+        let project = task
+            .project_id
+            .and_then(|pid| projects.iter().find(|p| p.uuid == pid && !p.is_deleted()));
+        let entry = (ReportStatus::of(task, all_tasks), id, task);
+        match groups
+            .iter_mut()
+            .find(|(p, _)| p.map(|p| p.uuid) == project.map(|p| p.uuid))
+        {
+            Some((_, rows)) => rows.push(entry),
+            None => groups.push((project, vec![entry])),
+        }
+    }
+    groups.sort_by(|(a, _), (b, _)| match (a, b) {
+        (Some(a), Some(b)) => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        (a, b) => b.is_some().cmp(&a.is_some()),
+    });
+
+    for (project, mut rows) in groups {
+        let name = project.map_or("No project", |p| p.name.as_str());
+        out.push_str(&format!("## {}\n\n", escape(name)));
+        rows.sort_by_key(|&(status, id, _)| (status, id));
+
+        let mut current = None;
+        for &(status, id, task) in &rows {
+            if current != Some(status) {
+                if current.is_some() {
+                    out.push('\n');
+                }
+                let count = rows.iter().filter(|(s, _, _)| *s == status).count();
+                out.push_str(&format!("### {} ({})\n\n", status.heading(), count));
+                current = Some(status);
+            }
+            out.push_str(&task_line(id, task, status));
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+    out.truncate(out.trim_end().len());
+    out.push('\n');
+    out
+}
+
+/// A section of a digest: its heading and tasks with their display IDs.
+pub struct DigestSection<'a> {
+    pub heading: &'static str,
+    pub tasks: Vec<(usize, &'a Task)>,
+}
+
+/// Renders a `todo digest` document covering `from` to `to`.
+///
+/// Sections are kept even when empty, so consecutive digests line up.
+pub fn digest(
+    sections: &[DigestSection],
+    all_tasks: &[Task],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> String {
+    let mut out = format!("# Digest — {} to {}\n", from, to);
+    for section in sections {
+        out.push_str(&format!(
+            "\n## {} ({})\n\n",
+            section.heading,
+            section.tasks.len()
+        ));
+        if section.tasks.is_empty() {
+            out.push_str("_None._\n");
+        }
+        for &(id, task) in &section.tasks {
+            out.push_str(&task_line(id, task, ReportStatus::of(task, all_tasks)));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Renders `tasks` (with their display IDs) as a titled Markdown table,
+/// for `todo publish`.
+pub fn task_table(
+    title: &str,
+    tasks: &[(usize, &Task)],
+    all_tasks: &[Task],
+    projects: &[Project],
+    today: NaiveDate,
+) -> String {
+    let mut out = format!("# {}\n\n", escape(title));
+    if tasks.is_empty() {
+        out.push_str("_No tasks._\n");
+    } else {
+        out.push_str("| # | Task | Priority | Due | Tags | Project | Status |\n");
+        out.push_str("|---|------|----------|-----|------|---------|--------|\n");
+        for &(id, task) in tasks {
+            let text = escape(first_line(&task.text));
+            let text = if task.completed {
+                format!("~~{}~~", text)
+            } else {
+                text
+            };
+            let tags: Vec<String> = task.tags.iter().map(|t| format!("`#{}`", t)).collect();
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} | {} |\n",
+                id,
+                text,
+                format_priority(task.priority, PriorityDisplay::Word),
+                task.due_date.map(|d| d.to_string()).unwrap_or_default(),
+                tags.join(" "),
+                escape(project_name(task.project_id, projects)),
+                ReportStatus::of(task, all_tasks).heading(),
+            ));
+        }
+    }
+    out.push_str(&format!("\n_Generated {} · read-only_\n", today));
+    out
+}
+
+/// One list item: checkbox, text, then dates, priority, and tags.
+fn task_line(id: usize, task: &Task, status: ReportStatus) -> String {
+    let text = escape(first_line(&task.text));
+    let mut line = match status {
+        ReportStatus::Done => format!("- [x] {}", text),
+        ReportStatus::Cancelled => format!("- ~~{}~~", text),
+        _ => format!("- [ ] {}", text),
+    };
+
+    let mut details = Vec::new();
+    match status {
+        ReportStatus::Done => {
+            if let Some(on) = task.completed_on() {
+                details.push(format!("done {}", on));
+            }
+        }
+        ReportStatus::Cancelled => {
+            if let Some(ref reason) = task.cancel_reason {
+                details.push(escape(reason));
+            }
+        }
+        _ => {
+            if task.priority == Priority::High {
+                details.push("**high**".to_string());
+            }
+            if let Some(due) = task.due_date {
+                details.push(format!("due {}", due));
+            }
+        }
+    }
+    if !details.is_empty() {
+        line.push_str(" — ");
+        line.push_str(&details.join(", "));
+    }
+    for tag in &task.tags {
+        line.push_str(&format!(" `#{}`", tag));
+    }
+    line.push_str(&format!(" <sub>#{}</sub>", id));
+    line
+}
+
+/// Backslash-escapes characters Markdown would otherwise interpret.
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~'
+        ) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_groups_by_project_then_status() {
+        let web = Project::new("Website".into());
+        let mut shipped = Task::new(
+            "Ship *landing* page".into(),
+            Priority::Medium,
+            vec!["release".into()],
+            Some(web.uuid),
+            None,
+            None,
+        );
+        shipped.mark_done();
+        let copy = Task::new(
+            "Write copy".into(),
+            Priority::High,
+            vec![],
+            Some(web.uuid),
+            NaiveDate::from_ymd_opt(2030, 1, 2),
+            None,
+        );
+        let errand = Task::new("Buy milk".into(), Priority::Low, vec![], None, None, None);
+        let all = vec![errand, copy, shipped];
+        let rows: Vec<_> = all.iter().enumerate().map(|(i, t)| (i + 1, t)).collect();
+
+        let md = report(
+            &rows,
+            &all,
+            &[web],
+            NaiveDate::from_ymd_opt(2030, 1, 1).unwrap(),
+            None,
+        );
+
+        let website = md.find("## Website").unwrap();
+        let none = md.find("## No project").unwrap();
+        assert!(website < none);
+        let done = md.find("### Done (1)").unwrap();
+        let open = md.find("### Open (1)").unwrap();
+        assert!(website < done && done < open && open < none);
+        assert!(md.contains("- [x] Ship \\*landing\\* page — done "));
+        assert!(md.contains("`#release` <sub>#3</sub>"));
+        assert!(md.contains("- [ ] Write copy — **high**, due 2030-01-02 <sub>#2</sub>"));
+        assert!(md.ends_with("- [ ] Buy milk <sub>#1</sub>\n"));
+    }
+}
@@ -7,19 +7,25 @@
 //! - [`project_table`]  — `todo project list`
 //! - [`resource_table`] — `todo resource list`
 //! - [`formatting`]     — shared helpers (truncate, due text, colors)
+//! - [`structured`]     — `--format json|plain` output
+//! - [`markdown`]       — `todo report`, `todo digest`, `todo publish`
+//! - [`html`]           — `todo publish --to html`
 //! - [`next_table`]
 //! - [`calendar`]
 
 pub mod calendar;
 pub mod formatting;
+pub mod html;
+pub mod markdown;
 pub mod next_table;
 pub mod note_table;
 pub mod project_table;
 pub mod resource_table;
+pub mod structured;
 pub mod task_table;
 
 pub use next_table::display_next;
 pub use note_table::display_notes;
 pub use project_table::display_projects;
 pub use resource_table::display_resources;
-pub use task_table::display_lists;
+pub use task_table::{Columns, display_lists};
@@ -3,16 +3,17 @@
 //! Column order (Taskwarrior-style): ID  Age  P  Tags  Project  Due  Task  Urg
 //! Fixed context columns on the left, content (Task) and score (Urg) on the right.
 
-use chrono::Utc;
 use colored::Colorize;
 
 use crate::models::{Project, Task};
 
-use super::formatting::{get_due_colored, get_due_text, project_colored, project_name, truncate};
+use super::formatting::{
+    display_width, fill, first_line, format_age, get_due_colored, get_due_text, priority_label,
+    priority_width, project_colored, project_name, rule, truncate,
+};
 
 const ID_WIDTH: usize = 4;
 const AGE_WIDTH: usize = 5;
-const PRIORITY_WIDTH: usize = 1;
 const SCORE_WIDTH: usize = 5;
 
 pub struct NextTableLayout {
@@ -33,16 +34,16 @@ impl NextTableLayout {
         let mut max_due = 3usize;
 
         for task in tasks {
-            max_task = max_task.max(task.text.len());
+            max_task = max_task.max(display_width(first_line(&task.text)));
 
             if !task.tags.is_empty() {
-                max_tags = max_tags.max(task.tags.join(", ").len());
+                max_tags = max_tags.max(display_width(&task.tags.join(", ")));
             }
 
             if let Some(pid) = task.project_id
                 && let Some(p) = projects.iter().find(|p| p.uuid == pid && !p.is_deleted())
             {
-                max_project = max_project.max(p.name.len());
+                max_project = max_project.max(display_width(&p.name));
             }
 
             let due_text = get_due_text(task);
@@ -73,7 +74,7 @@ impl NextTableLayout {
     pub fn total_width(&self) -> usize {
         // ID(4) + 2 + Age(5) + 2 + P(1) + 2 + optional cols + 2 + Task + 2 + Urg(5)
         let mut width =
-            ID_WIDTH + 2 + AGE_WIDTH + 2 + PRIORITY_WIDTH + 2 + self.task + 2 + SCORE_WIDTH;
+            ID_WIDTH + 2 + AGE_WIDTH + 2 + priority_width() + 2 + self.task + 2 + SCORE_WIDTH;
         if self.show_tags {
             width += 2 + self.tags;
         }
@@ -89,7 +90,7 @@ impl NextTableLayout {
     pub fn display_header(&self) {
         print!("{:>id$}  ", "ID".dimmed(), id = ID_WIDTH);
         print!("{:<age$}  ", "Age".dimmed(), age = AGE_WIDTH);
-        print!("{:<p$}  ", "P".dimmed(), p = PRIORITY_WIDTH);
+        print!("{:<p$}  ", "P".dimmed(), p = priority_width());
         if self.show_tags {
             print!("{:<t$}  ", "Tags".dimmed(), t = self.tags);
         }
@@ -105,7 +106,7 @@ impl NextTableLayout {
     }
 
     pub fn display_separator(&self) {
-        println!("{}", "─".repeat(self.total_width()).dimmed());
+        println!("{}", rule(self.total_width()).dimmed());
     }
 
     pub fn display_row(&self, idx: usize, task: &Task, all_tasks: &[Task], projects: &[Project]) {
@@ -119,20 +120,7 @@ impl NextTableLayout {
             score_str.normal()
         };
 
-        let age_secs = (Utc::now() - task.created_at).num_seconds();
-        let age_str = if age_secs < 3600 {
-            format!("{}m", age_secs / 60)
-        } else if age_secs < 86400 {
-            format!("{}h", age_secs / 3600)
-        } else if age_secs < 7 * 86400 {
-            format!("{}d", age_secs / 86400)
-        } else if age_secs < 30 * 86400 {
-            format!("{}w", age_secs / (7 * 86400))
-        } else if age_secs < 365 * 86400 {
-            format!("{}mo", age_secs / (30 * 86400))
-        } else {
-            format!("{}y", age_secs / (365 * 86400))
-        };
+        let age_str = format_age(task.created_at);
 
         let tags_str = if task.tags.is_empty() {
             "—".to_string()
@@ -154,21 +142,22 @@ impl NextTableLayout {
 
         print!("{:>id$}  ", format!("#{}", idx).dimmed(), id = ID_WIDTH);
         print!("{:<age$}  ", age_str.dimmed(), age = AGE_WIDTH);
-        print!("{:<p$}  ", task.priority.letter(), p = PRIORITY_WIDTH);
+        print!(
+            "{:<p$}  ",
+            priority_label(task.priority),
+            p = priority_width()
+        );
         if self.show_tags {
-            print!("{:<t$}  ", tags_colored, t = self.tags);
+            print!("{}{}  ", tags_colored, fill(&tags_str, self.tags));
         }
         if self.show_project {
-            print!("{:<p$}  ", proj_colored, p = self.project);
+            print!("{}{}  ", proj_colored, fill(&proj_str, self.project));
         }
         if self.show_due {
             print!("{:<d$}  ", due_colored, d = self.due);
         }
-        print!(
-            "{:<t$}  ",
-            truncate(&task.text, self.task).bright_white(),
-            t = self.task
-        );
+        let text = truncate(first_line(&task.text), self.task);
+        print!("{}{}  ", text.bright_white(), fill(&text, self.task));
         print!("{:>score$}", score_colored, score = SCORE_WIDTH);
         println!();
     }
@@ -198,7 +187,7 @@ pub fn display_next(
 
     let footer = if blocked_count > 0 {
         format!(
-            "Showing {} of {} ready · {} blocked by deps",
+            "Showing {} of {} ready · {} blocked",
             tasks.len(),
             ready_count,
             blocked_count,
@@ -3,7 +3,7 @@
 use colored::Colorize;
 
 use crate::models::{Note, NoteFormat, Project};
-use crate::render::formatting::{project_colored, project_name, truncate};
+use crate::render::formatting::{project_colored, project_name, rule, truncate};
 
 pub struct NoteTableLayout {
     pub body_w: usize,
@@ -135,7 +135,7 @@ impl NoteTableLayout {
         }
         print!("{:<body_w$}", "Note".dimmed(), body_w = self.body_w);
         println!();
-        println!("{}", "─".repeat(self.total_w).dimmed());
+        println!("{}", rule(self.total_w).dimmed());
     }
 
     pub fn display_row(
@@ -203,7 +203,7 @@ impl NoteTableLayout {
     }
 
     pub fn display_separator(&self) {
-        println!("{}", "─".repeat(self.total_w).dimmed());
+        println!("{}", rule(self.total_w).dimmed());
     }
 }
 
@@ -1,10 +1,15 @@
 //! Terminal rendering for project lists.
 
-use chrono::Local;
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Local, Utc};
 use colored::Colorize;
+use uuid::Uuid;
 
 use crate::models::{Difficulty, Note, Project, Task, count_by_project};
-use crate::render::formatting::{due_relative_text, truncate};
+use crate::render::formatting::{
+    due_relative_text, format_age, glyph, progress_bar, rule, truncate,
+};
 
 pub struct ProjectTableLayout {
     pub name_w: usize,
@@ -95,7 +100,7 @@ impl ProjectTableLayout {
             print!("  {:<10}", "Due".dimmed());
         }
         println!();
-        println!("{}", "─".repeat(self.total_w).dimmed());
+        println!("{}", rule(self.total_w).dimmed());
     }
 
     pub fn display_row(&self, id: usize, project: &Project, tasks: &[Task], notes: &[Note]) {
@@ -202,7 +207,7 @@ impl ProjectTableLayout {
     }
 
     pub fn display_separator(&self) {
-        println!("{}", "─".repeat(self.total_w).dimmed());
+        println!("{}", rule(self.total_w).dimmed());
     }
 }
 
@@ -216,3 +221,294 @@ pub fn display_projects(projects: &[&Project], tasks: &[Task], notes: &[Note]) {
     layout.display_separator();
     println!();
 }
+
+// ── Dashboard ─────────────────────────────────────────────────────────────────
+
+/// Separates levels in a project name: `Work/Client A` nests under `Work`.
+pub const PATH_SEPARATOR: char = '/';
+
+/// Views selected by `todo project list --tree / --progress`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DashboardOptions {
+    /// Nest projects by their `/`-separated names, rolling counts up
+    pub tree: bool,
+    /// Show a progress bar instead of a bare `done/total`
+    pub progress: bool,
+}
+
+/// Task counts and latest activity for one or more projects.
+#[derive(Debug, Default, PartialEq)]
+pub struct ProjectSummary {
+    pub total: usize,
+    pub done: usize,
+    pub overdue: usize,
+    /// Most recent change to the projects, their tasks, or their notes
+    pub last_activity: Option<DateTime<Utc>>,
+}
+
+impl ProjectSummary {
+    pub fn of(projects: &[&Project], tasks: &[Task], notes: &[Note]) -> Self {
+        let uuids: Vec<Uuid> = projects.iter().map(|p| p.uuid).collect();
+        let in_set = |id: Option<Uuid>| id.is_some_and(|id| uuids.contains(&id));
+
+        let mut summary = Self::default();
+        for &uuid in &uuids {
+            let (total, done) = count_by_project(tasks, uuid);
+            summary.total += total;
+            summary.done += done;
+        }
+
+        let live_tasks = tasks
+            .iter()
+            .filter(|t| !t.is_deleted() && in_set(t.project_id));
+        let live_notes = notes
+            .iter()
+            .filter(|n| !n.is_deleted() && in_set(n.project_id));
+        summary.overdue = live_tasks.clone().filter(|t| t.is_overdue()).count();
+        summary.last_activity = projects
+            .iter()
+            .map(|p| p.updated_at.unwrap_or(p.created_at))
+            .chain(live_tasks.map(|t| t.updated_at.unwrap_or(t.created_at)))
+            .chain(live_notes.map(|n| n.updated_at.unwrap_or(n.created_at)))
+            .max();
+        summary
+    }
+}
+
+/// One dashboard line: a project, or in tree view a parent level that only
+/// exists as a name prefix.
+struct DashboardRow<'a> {
+    id: Option<usize>,
+    label: String,
+    project: Option<&'a Project>,
+    summary: ProjectSummary,
+}
+
+/// Renders the `--tree` / `--progress` dashboard. `projects` must be in ID
+/// order, as in [`display_projects`].
+pub fn display_project_dashboard(
+    projects: &[&Project],
+    tasks: &[Task],
+    notes: &[Note],
+    options: DashboardOptions,
+) {
+    let rows = if options.tree {
+        tree_rows(projects, tasks, notes)
+    } else {
+        projects
+            .iter()
+            .enumerate()
+            .map(|(i, p)| DashboardRow {
+                id: Some(i + 1),
+                label: p.name.clone(),
+                project: Some(p),
+                summary: ProjectSummary::of(&[p], tasks, notes),
+            })
+            .collect()
+    };
+
+    let name_w = rows
+        .iter()
+        .map(|r| r.label.chars().count())
+        .max()
+        .unwrap_or(7)
+        .clamp(7, 40);
+    let progress_w = if options.progress { 16 } else { 7 };
+    let total_w = 4 + 2 + 1 + 2 + name_w + 2 + progress_w + 2 + 7 + 2 + 8;
+
+    println!("\nProjects:\n");
+    print!("{:>4}  {:<1}  ", "ID".dimmed(), "S".dimmed());
+    print!("{:<name_w$}  ", "Project".dimmed(), name_w = name_w);
+    print!(
+        "{:<progress_w$}  ",
+        if options.progress {
+            "Progress"
+        } else {
+            "Tasks"
+        }
+        .dimmed(),
+        progress_w = progress_w
+    );
+    println!("{:>7}  {:<8}", "Overdue".dimmed(), "Activity".dimmed());
+    println!("{}", rule(total_w).dimmed());
+
+    for row in &rows {
+        let id = row
+            .id
+            .map(|id| format!("#{}", id))
+            .unwrap_or_default()
+            .dimmed();
+        let status = match row.project {
+            Some(p) if p.completed => "D".green(),
+            Some(_) => "P".yellow(),
+            None => "·".dimmed(),
+        };
+        let name = format!("{:<name_w$}", truncate(&row.label, name_w), name_w = name_w);
+        let name = match row.project {
+            Some(p) if p.completed => name.dimmed(),
+            Some(_) => name.magenta(),
+            None => name.bright_white(),
+        };
+
+        let ProjectSummary {
+            total,
+            done,
+            overdue,
+            last_activity,
+        } = row.summary;
+        let counts = format!("{}/{}", done, total);
+        let progress = if total == 0 {
+            format!("{:<progress_w$}", "—", progress_w = progress_w)
+                .dimmed()
+                .to_string()
+        } else if options.progress {
+            format!(
+                "{} {:>4}",
+                progress_bar(done, total, 10),
+                format!("{}%", done * 100 / total)
+            )
+        } else {
+            format!("{:<progress_w$}", counts, progress_w = progress_w)
+        };
+        let overdue = if overdue > 0 {
+            format!("{:>7}", overdue).red().to_string()
+        } else {
+            format!("{:>7}", "—").dimmed().to_string()
+        };
+        let activity = last_activity
+            .map(|at| format!("{} ago", format_age(at)))
+            .unwrap_or_else(|| "—".to_string());
+
+        println!(
+            "{:>4}  {:<1}  {}  {}  {}  {}",
+            id,
+            status,
+            name,
+            progress,
+            overdue,
+            activity.dimmed()
+        );
+    }
+    println!("{}", rule(total_w).dimmed());
+    println!();
+}
+
+/// Rows for the tree view, depth-first, with parents rolled up from their
+/// descendants.
+fn tree_rows<'a>(
+    projects: &[&'a Project],
+    tasks: &[Task],
+    notes: &[Note],
+) -> Vec<DashboardRow<'a>> {
+    let paths: Vec<Vec<&str>> = projects.iter().map(|p| project_path(&p.name)).collect();
+    let nodes: BTreeSet<Vec<&str>> = paths
+        .iter()
+        .flat_map(|path| (1..=path.len()).map(|len| path[..len].to_vec()))
+        .collect();
+    let is_last = |node: &[&str]| {
+        let (parent, name) = node.split_at(node.len() - 1);
+        !nodes.iter().any(|other| {
+            other.len() == node.len()
+                && other.starts_with(parent)
+                && other[node.len() - 1] > name[0]
+        })
+    };
+
+    nodes
+        .iter()
+        .map(|node| {
+            let mut label = String::new();
+            for depth in 1..node.len() {
+                if depth > 1 {
+                    label.push_str(if is_last(&node[..depth]) {
+                        "   "
+                    } else {
+                        glyph("│  ", "|  ")
+                    });
+                }
+            }
+            if node.len() > 1 {
+                label.push_str(if is_last(node) {
+                    glyph("└─ ", "`- ")
+                } else {
+                    glyph("├─ ", "|- ")
+                });
+            }
+            label.push_str(node[node.len() - 1]);
+
+            let own = paths.iter().position(|p| p == node);
+            let subtree: Vec<&Project> = projects
+                .iter()
+                .zip(&paths)
+                .filter(|(_, path)| path.starts_with(node))
+                .map(|(p, _)| *p)
+                .collect();
+            DashboardRow {
+                id: own.map(|i| i + 1),
+                label,
+                project: own.map(|i| projects[i]),
+                summary: ProjectSummary::of(&subtree, tasks, notes),
+            }
+        })
+        .collect()
+}
+
+/// Levels of a project name, e.g. `["Work", "Client A"]`.
+fn project_path(name: &str) -> Vec<&str> {
+    let path: Vec<&str> = name
+        .split(PATH_SEPARATOR)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+    if path.is_empty() { vec![name] } else { path }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+
+    #[test]
+    fn test_tree_rows_nest_and_roll_up() {
+        let web = Project::new("Work/Web".into());
+        let api = Project::new("Work/API".into());
+        let home = Project::new("Home".into());
+        let mut done = Task::new(
+            "a".into(),
+            Priority::Medium,
+            vec![],
+            Some(web.uuid),
+            None,
+            None,
+        );
+        done.mark_done();
+        let tasks = vec![
+            done,
+            Task::new(
+                "b".into(),
+                Priority::Medium,
+                vec![],
+                Some(api.uuid),
+                None,
+                None,
+            ),
+        ];
+        let projects = [&home, &api, &web];
+
+        let rows = tree_rows(&projects, &tasks, &[]);
+        let labels: Vec<_> = rows.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(labels, ["Home", "Work", "├─ API", "└─ Web"]);
+
+        let ids: Vec<_> = rows.iter().map(|r| r.id).collect();
+        assert_eq!(ids, [Some(1), None, Some(2), Some(3)]);
+        assert_eq!((rows[1].summary.total, rows[1].summary.done), (2, 1));
+        assert_eq!((rows[3].summary.total, rows[3].summary.done), (1, 1));
+    }
+
+    #[test]
+    fn test_project_path() {
+        assert_eq!(project_path("Work / Client A"), ["Work", "Client A"]);
+        assert_eq!(project_path("Solo"), ["Solo"]);
+        assert_eq!(project_path("/"), ["/"]);
+    }
+}
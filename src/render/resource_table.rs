@@ -3,7 +3,7 @@
 use colored::Colorize;
 
 use crate::models::Resource;
-use crate::render::formatting::truncate;
+use crate::render::formatting::{rule, truncate};
 
 pub struct ResourceTableLayout {
     pub title_w: usize,
@@ -122,7 +122,7 @@ impl ResourceTableLayout {
             );
         }
         println!();
-        println!("{}", "─".repeat(self.total_w).dimmed());
+        println!("{}", rule(self.total_w).dimmed());
     }
 
     pub fn display_row(&self, id: usize, resource: &Resource, notes: &[crate::models::Note]) {
@@ -177,7 +177,7 @@ impl ResourceTableLayout {
     }
 
     pub fn display_separator(&self) {
-        println!("{}", "─".repeat(self.total_w).dimmed());
+        println!("{}", rule(self.total_w).dimmed());
     }
 }
 
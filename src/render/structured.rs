@@ -0,0 +1,152 @@
+//! Machine-readable output for the global `--format json|plain` option.
+//!
+//! - `json` prints one pretty-printed JSON document per command. Entities are
+//!   serialized in full (the same shape as `todo export`) with an added `id`
+//!   field holding the number shown in tables.
+//! - `plain` prints one tab-separated record per line, without colors,
+//!   headers, or truncation — suitable for `cut`, `awk`, and `grep`.
+//!
+//! `todo list --flat-uuid` prints [`task_flat_uuid`] records. Unlike the
+//! formats above, that layout is a stable contract for scripts: its columns
+//! and status words will not change between releases.
+
+use std::io::Write;
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::models::{Priority, Project, Task};
+use crate::render::formatting::{first_line, project_name};
+
+/// Serializes `item` and adds its display `id`.
+pub fn with_id<T: Serialize>(id: usize, item: &T) -> Value {
+    let mut value = serde_json::to_value(item).unwrap_or(Value::Null);
+    if let Value::Object(map) = &mut value {
+        map.insert("id".to_string(), Value::from(id));
+    }
+    value
+}
+
+/// Prints `value` as pretty JSON on stdout.
+pub fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<()> {
+    let mut out = std::io::stdout().lock();
+    serde_json::to_writer_pretty(&mut out, value)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+/// Tab-separated task record: `id status priority due project tags text`.
+///
+/// Status is `done`, `cancelled`, `pending`, or `blocked`; empty fields are
+/// `-`; only the first line of multi-line text is included.
+pub fn task_plain(id: usize, task: &Task, all_tasks: &[Task], projects: &[Project]) -> String {
+    let status = if task.is_cancelled() {
+        "cancelled"
+    } else if task.completed {
+        "done"
+    } else if task.is_blocked(all_tasks) {
+        "blocked"
+    } else {
+        "pending"
+    };
+    let due = task
+        .due_date
+        .map(|d| d.to_string())
+        .unwrap_or_else(|| "-".to_string());
+    let project = match project_name(task.project_id, projects) {
+        "—" => "-",
+        name => name,
+    };
+    let tags = if task.tags.is_empty() {
+        "-".to_string()
+    } else {
+        task.tags.join(",")
+    };
+    [
+        id.to_string(),
+        status.to_string(),
+        match task.priority {
+            Priority::High => "H",
+            Priority::Medium => "M",
+            Priority::Low => "L",
+        }
+        .to_string(),
+        due,
+        project.to_string(),
+        tags,
+        first_line(&task.text).to_string(),
+    ]
+    .join("\t")
+}
+
+/// Stable scripting record: `uuid status text`, tab-separated.
+///
+/// Status is `pending`, `done`, or `cancelled`. The full text is included,
+/// with backslashes, tabs, and line breaks escaped as `\\`, `\t`, `\n`, and
+/// `\r` so that every task stays on one line.
+pub fn task_flat_uuid(task: &Task) -> String {
+    let status = if task.is_cancelled() {
+        "cancelled"
+    } else if task.completed {
+        "done"
+    } else {
+        "pending"
+    };
+    let mut text = String::with_capacity(task.text.len());
+    for c in task.text.chars() {
+        match c {
+            '\\' => text.push_str("\\\\"),
+            '\t' => text.push_str("\\t"),
+            '\n' => text.push_str("\\n"),
+            '\r' => text.push_str("\\r"),
+            c => text.push(c),
+        }
+    }
+    format!("{}\t{}\t{}", task.uuid, status, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_id_adds_display_id() {
+        let task = Task::new("a".into(), Priority::Low, vec![], None, None, None);
+        let value = with_id(7, &task);
+        assert_eq!(value["id"], 7);
+        assert_eq!(value["text"], "a");
+    }
+
+    #[test]
+    fn test_task_plain_fields() {
+        let mut task = Task::new(
+            "Write\nmore".into(),
+            Priority::High,
+            vec!["work".into(), "docs".into()],
+            None,
+            chrono::NaiveDate::from_ymd_opt(2026, 5, 1),
+            None,
+        );
+        task.mark_done();
+        let line = task_plain(3, &task, std::slice::from_ref(&task), &[]);
+        assert_eq!(line, "3\tdone\tH\t2026-05-01\t-\twork,docs\tWrite");
+    }
+
+    #[test]
+    fn test_task_flat_uuid_keeps_one_line() {
+        let mut task = Task::new(
+            "Fix\tC:\\temp\nsecond".into(),
+            Priority::Low,
+            vec![],
+            None,
+            None,
+            None,
+        );
+        task.cancel(None);
+        assert_eq!(
+            task_flat_uuid(&task),
+            format!("{}\tcancelled\tFix\\tC:\\\\temp\\nsecond", task.uuid)
+        );
+    }
+}
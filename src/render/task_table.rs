@@ -1,34 +1,69 @@
 //! Terminal rendering for task lists.
 //!
-//! Column order (Taskwarrior-style): ID  P  S  R  Tags  Project  Due  Task
+//! Column order (Taskwarrior-style): ID  P  S  R  Tags  Project  Due  Time  Est  Task
 //! Fixed context columns on the left, content (Task) on the right.
+//! Optional columns (Age, Urg, Closed) are switched on through [`Columns`].
 
 use colored::Colorize;
 
+use crate::i18n::{Msg, t, tf};
 use crate::models::{Project, Recurrence, Task};
 
-use super::formatting::{get_due_colored, get_due_text, project_colored, project_name, truncate};
+use super::formatting::{
+    display_width, fill, first_line, format_age, get_due_colored, get_due_text, priority_label,
+    priority_width, project_colored, project_name, rule, terminal_width, time_text,
+    timeliness_colored, truncate, wrap, wrap_enabled,
+};
 
 const ID_WIDTH: usize = 4;
-const PRIORITY_WIDTH: usize = 1;
 const STATUS_WIDTH: usize = 1;
 const RECUR_WIDTH: usize = 1;
+const AGE_WIDTH: usize = 6;
+const URGENCY_WIDTH: usize = 4;
+/// Fits `late 12d`.
+const TIMELINESS_WIDTH: usize = 8;
+/// Narrowest the Task column gets when fitting the table to the terminal.
+const MIN_TASK_WIDTH: usize = 20;
+
+/// Width of a header cell: at least `min`, and never narrower than `title`.
+fn header_width(title: Msg, min: usize) -> usize {
+    t(title).chars().count().max(min)
+}
+
+/// Optional columns the caller asks for.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Columns {
+    /// Age column threshold in days; `None` hides the column.
+    pub age_warning_days: Option<u32>,
+    /// Show whether closed recurring occurrences were on time, late, or missed.
+    pub timeliness: bool,
+    /// Show each task's urgency score.
+    pub urgency: bool,
+}
 
 pub struct TableLayout<'a> {
     id: usize,
     priority: usize,
     status: usize,
     recur: usize,
+    age: usize,
+    urgency: usize,
+    timeliness: usize,
     task: usize,
     project: usize,
     tags: usize,
     due: usize,
+    time: usize,
+    estimate: usize,
     show_recur: bool,
     show_project: bool,
     show_tags: bool,
     show_due: bool,
+    show_time: bool,
+    show_estimate: bool,
     show_notes: bool,
     show_resources: bool,
+    columns: Columns,
     all_tasks: &'a [Task],
     projects: &'a [Project],
     notes: &'a [crate::models::Note],
@@ -42,6 +77,7 @@ impl<'a> TableLayout<'a> {
         projects: &'a [Project],
         notes: &'a [crate::models::Note],
         resources: &'a [crate::models::Resource],
+        columns: Columns,
     ) -> Self {
         let (task_w, project_w, tags_w, due_w) = calculate_column_widths(tasks, projects);
         let show_recur = tasks.iter().any(|(_, t)| t.recurrence.is_some());
@@ -52,6 +88,17 @@ impl<'a> TableLayout<'a> {
         });
         let show_tags = tasks.iter().any(|(_, t)| !t.tags.is_empty());
         let show_due = tasks.iter().any(|(_, t)| t.due_date.is_some());
+        let show_time = tasks.iter().any(|(_, t)| !t.time_entries.is_empty());
+        let time_w = tasks
+            .iter()
+            .map(|(_, t)| time_text(t).chars().count())
+            .fold(header_width(Msg::ColTime, 4), usize::max);
+        let show_estimate = tasks.iter().any(|(_, t)| t.estimate.is_some());
+        let estimate_w = tasks
+            .iter()
+            .filter_map(|(_, t)| t.estimate)
+            .map(|e| e.to_string().len())
+            .fold(header_width(Msg::ColEstimate, 3), usize::max);
         let show_notes = tasks.iter().any(|(_, t)| {
             notes
                 .iter()
@@ -67,26 +114,54 @@ impl<'a> TableLayout<'a> {
             })
         });
 
-        Self {
+        let mut layout = Self {
             id: ID_WIDTH,
-            priority: PRIORITY_WIDTH,
+            priority: priority_width(),
             status: STATUS_WIDTH,
             recur: RECUR_WIDTH,
+            age: header_width(Msg::ColAge, AGE_WIDTH),
+            urgency: header_width(Msg::ColUrgency, URGENCY_WIDTH),
+            timeliness: header_width(Msg::ColTimeliness, TIMELINESS_WIDTH),
             task: task_w,
             project: project_w,
             tags: tags_w,
             due: due_w,
+            time: time_w,
+            estimate: estimate_w,
             show_recur,
             show_project,
             show_tags,
             show_due,
+            show_time,
+            show_estimate,
             show_notes,
             show_resources,
+            columns,
             all_tasks,
             projects,
             notes,
             resources,
+        };
+        // Wrapped text gets whatever the other columns leave
+        if wrap_enabled() {
+            let available = terminal_width()
+                .saturating_sub(layout.total_width() - layout.task)
+                .max(MIN_TASK_WIDTH);
+            layout.task = layout.task.min(available);
+        }
+        layout
+    }
+
+    /// Width of the columns printed before the Task column.
+    fn task_offset(&self) -> usize {
+        let mut width = self.total_width() - self.task;
+        if self.show_notes {
+            width -= 5 + 2;
+        }
+        if self.show_resources {
+            width -= 3 + 2;
         }
+        width
     }
 
     pub fn total_width(&self) -> usize {
@@ -95,6 +170,12 @@ impl<'a> TableLayout<'a> {
         if self.show_recur {
             width += self.recur + 2;
         }
+        if self.columns.age_warning_days.is_some() {
+            width += self.age + 2;
+        }
+        if self.columns.urgency {
+            width += self.urgency + 2;
+        }
         if self.show_tags {
             width += self.tags + 2;
         }
@@ -104,6 +185,15 @@ impl<'a> TableLayout<'a> {
         if self.show_due {
             width += self.due + 2;
         }
+        if self.columns.timeliness {
+            width += self.timeliness + 2;
+        }
+        if self.show_time {
+            width += self.time + 2;
+        }
+        if self.show_estimate {
+            width += self.estimate + 2;
+        }
         if self.show_notes {
             width += 5 + 2;
         }
@@ -120,27 +210,43 @@ impl<'a> TableLayout<'a> {
         if self.show_recur {
             print!("{:<r$}  ", "R".dimmed(), r = self.recur);
         }
+        if self.columns.age_warning_days.is_some() {
+            print!("{:<a$}  ", t(Msg::ColAge).dimmed(), a = self.age);
+        }
+        if self.columns.urgency {
+            print!("{:<u$}  ", t(Msg::ColUrgency).dimmed(), u = self.urgency);
+        }
         if self.show_tags {
-            print!("{:<t$}  ", "Tags".dimmed(), t = self.tags);
+            print!("{:<t$}  ", t(Msg::ColTags).dimmed(), t = self.tags);
         }
         if self.show_project {
-            print!("{:<p$}  ", "Project".dimmed(), p = self.project);
+            print!("{:<p$}  ", t(Msg::ColProject).dimmed(), p = self.project);
         }
         if self.show_due {
-            print!("{:<d$}  ", "Due".dimmed(), d = self.due);
+            print!("{:<d$}  ", t(Msg::ColDue).dimmed(), d = self.due);
+        }
+        if self.columns.timeliness {
+            let title = t(Msg::ColTimeliness);
+            print!("{:<w$}  ", title.dimmed(), w = self.timeliness);
+        }
+        if self.show_time {
+            print!("{:<w$}  ", t(Msg::ColTime).dimmed(), w = self.time);
         }
-        print!("{:<t$}", "Task".dimmed(), t = self.task);
+        if self.show_estimate {
+            print!("{:<w$}  ", t(Msg::ColEstimate).dimmed(), w = self.estimate);
+        }
+        print!("{:<t$}", t(Msg::ColTask).dimmed(), t = self.task);
         if self.show_notes {
-            print!("  {:^5}", "Notes".dimmed());
+            print!("  {:^5}", t(Msg::ColNotes).dimmed());
         }
         if self.show_resources {
-            print!("  {:^3}", "Res".dimmed());
+            print!("  {:^3}", t(Msg::ColResources).dimmed());
         }
         println!();
     }
 
     pub fn display_separator(&self) {
-        println!("{}", "─".repeat(self.total_width()).dimmed());
+        println!("{}", rule(self.total_width()).dimmed());
     }
 
     pub fn display_task(&self, number: usize, task: &Task) {
@@ -148,14 +254,36 @@ impl<'a> TableLayout<'a> {
 
         let status_letter = if blocked {
             "B".red()
+        } else if task.is_cancelled() {
+            "C".dimmed()
         } else if task.completed {
             "D".green()
         } else {
             "P".yellow()
         };
 
-        let letter = task.priority.letter();
-        let task_text = truncate(&task.text, self.task);
+        let letter = priority_label(task.priority);
+        // A label bullet takes two columns out of the text
+        let text_width = match task.label {
+            Some(_) => self.task.saturating_sub(2),
+            None => self.task,
+        };
+        let lines = if wrap_enabled() {
+            wrap(first_line(&task.text), text_width)
+        } else {
+            vec![truncate(first_line(&task.text), text_width)]
+        };
+        let paint = |text: &str| {
+            if task.is_cancelled() {
+                text.dimmed().strikethrough()
+            } else if task.completed {
+                text.green()
+            } else if blocked {
+                text.truecolor(150, 150, 150)
+            } else {
+                text.bright_white()
+            }
+        };
 
         let name = project_name(task.project_id, self.projects);
         let project_str = truncate(name, self.project);
@@ -175,25 +303,12 @@ impl<'a> TableLayout<'a> {
             None => " ".normal(),
         };
 
-        let (text_colored, tags_colored, proj_colored) = if task.completed {
-            (task_text.green(), tags_str.dimmed(), project_str.dimmed())
-        } else if blocked {
-            (
-                task_text.truecolor(150, 150, 150),
-                tags_str.dimmed(),
-                project_str.dimmed(),
-            )
+        let (tags_colored, proj_colored) = if task.completed || blocked {
+            (tags_str.dimmed(), project_str.dimmed())
+        } else if task.tags.is_empty() {
+            (tags_str.dimmed(), project_colored(&project_str))
         } else {
-            let tags_c = if task.tags.is_empty() {
-                tags_str.dimmed()
-            } else {
-                tags_str.cyan()
-            };
-            (
-                task_text.bright_white(),
-                tags_c,
-                project_colored(&project_str),
-            )
+            (tags_str.cyan(), project_colored(&project_str))
         };
 
         print!(
@@ -206,16 +321,47 @@ impl<'a> TableLayout<'a> {
         if self.show_recur {
             print!("{:<r$}  ", recur_indicator, r = self.recur);
         }
+        if let Some(threshold) = self.columns.age_warning_days {
+            print!("{:<a$}  ", age_colored(task, threshold), a = self.age);
+        }
+        if self.columns.urgency {
+            let colored = urgency_colored(task, self.all_tasks);
+            print!("{:<u$}  ", colored, u = self.urgency);
+        }
         if self.show_tags {
-            print!("{:<t$}  ", tags_colored, t = self.tags);
+            print!("{}{}  ", tags_colored, fill(&tags_str, self.tags));
         }
         if self.show_project {
-            print!("{:<p$}  ", proj_colored, p = self.project);
+            print!("{}{}  ", proj_colored, fill(&project_str, self.project));
         }
         if self.show_due {
             print!("{:<d$}  ", due_colored, d = self.due);
         }
-        print!("{:<t$}", text_colored, t = self.task);
+        if self.columns.timeliness {
+            let colored = timeliness_colored(task.timeliness());
+            print!("{:<w$}  ", colored, w = self.timeliness);
+        }
+        if self.show_time {
+            let text = time_text(task);
+            let colored = if task.started_at().is_some() {
+                text.green()
+            } else {
+                text.dimmed()
+            };
+            print!("{:<w$}  ", colored, w = self.time);
+        }
+        if self.show_estimate {
+            let colored = match task.estimate {
+                Some(estimate) if task.completed => estimate.to_string().dimmed(),
+                Some(estimate) => estimate.to_string().normal(),
+                None => "—".dimmed(),
+            };
+            print!("{:<w$}  ", colored, w = self.estimate);
+        }
+        if let Some(label) = task.label {
+            print!("{} ", label.bullet());
+        }
+        print!("{}{}", paint(&lines[0]), fill(&lines[0], text_width));
         if self.show_notes {
             let count = self
                 .notes
@@ -249,6 +395,46 @@ impl<'a> TableLayout<'a> {
             print!("  {}", res_str);
         }
         println!();
+
+        // Continuation lines line up under the text, after any label bullet
+        let indent = self.task_offset() + (self.task - text_width);
+        for line in &lines[1..] {
+            println!("{}{}", " ".repeat(indent), paint(line));
+        }
+    }
+}
+
+/// Urgency score of a pending task: red from 10, yellow from 5, dimmed
+/// below. Closed tasks show `—`.
+fn urgency_colored(task: &Task, all_tasks: &[Task]) -> colored::ColoredString {
+    if task.completed {
+        return "—".dimmed();
+    }
+    let score = task.urgency_score(all_tasks);
+    let text = format!("{:.1}", score);
+    if score >= 10.0 {
+        text.red()
+    } else if score >= 5.0 {
+        text.yellow()
+    } else {
+        text.dimmed()
+    }
+}
+
+/// Age of a pending task: dimmed normally, yellow with `!` past `threshold`
+/// days, red with `!!` past twice that. Completed tasks show `—`.
+fn age_colored(task: &Task, threshold: u32) -> colored::ColoredString {
+    if task.completed {
+        return "—".dimmed();
+    }
+    let days = (chrono::Utc::now() - task.created_at).num_days();
+    let text = format_age(task.created_at);
+    if days >= 2 * i64::from(threshold) {
+        format!("{}!!", text).red()
+    } else if days >= i64::from(threshold) {
+        format!("{}!", text).yellow()
+    } else {
+        text.dimmed()
     }
 }
 
@@ -256,22 +442,23 @@ fn calculate_column_widths(
     tasks: &[(usize, &Task)],
     projects: &[Project],
 ) -> (usize, usize, usize, usize) {
-    let mut max_task = 10;
-    let mut max_project = 7;
-    let mut max_tags = 4;
-    let mut max_due = 3;
+    let mut max_task = header_width(Msg::ColTask, 10);
+    let mut max_project = header_width(Msg::ColProject, 7);
+    let mut max_tags = header_width(Msg::ColTags, 4);
+    let mut max_due = header_width(Msg::ColDue, 3);
 
     for (_, task) in tasks {
-        max_task = max_task.max(task.text.len());
+        let bullet = if task.label.is_some() { 2 } else { 0 };
+        max_task = max_task.max(display_width(first_line(&task.text)) + bullet);
 
         if let Some(pid) = task.project_id
             && let Some(p) = projects.iter().find(|p| p.uuid == pid && !p.is_deleted())
         {
-            max_project = max_project.max(p.name.len());
+            max_project = max_project.max(display_width(&p.name));
         }
 
         if !task.tags.is_empty() {
-            max_tags = max_tags.max(task.tags.join(", ").len());
+            max_tags = max_tags.max(display_width(&task.tags.join(", ")));
         }
         let due_text = get_due_text(task);
         if !due_text.is_empty() {
@@ -279,8 +466,14 @@ fn calculate_column_widths(
         }
     }
 
+    // Wrapping tables fit the Task column to the terminal instead
+    let max_task = if wrap_enabled() {
+        max_task
+    } else {
+        max_task.min(40)
+    };
     (
-        max_task.min(40),
+        max_task,
         max_project.min(24),
         max_tags.min(20),
         max_due.min(20),
@@ -295,18 +488,24 @@ pub fn display_lists(
     projects: &[Project],
     notes: &[crate::models::Note],
     resources: &[crate::models::Resource],
+    columns: Columns,
 ) {
     println!("\n{}:\n", title);
 
-    let layout = TableLayout::new(tasks, all_tasks, projects, notes, resources);
+    let layout = TableLayout::new(tasks, all_tasks, projects, notes, resources, columns);
     layout.display_header();
     layout.display_separator();
 
+    // Cancelled tasks are listed but do not count toward the completion rate
     let mut completed = 0;
-    let total = tasks.len();
+    let mut total = 0;
 
     for (number, task) in tasks {
         layout.display_task(*number, task);
+        if task.is_cancelled() {
+            continue;
+        }
+        total += 1;
         if task.completed {
             completed += 1;
         }
@@ -319,7 +518,7 @@ pub fn display_lists(
     } else {
         0
     };
-    let stats = format!("{} of {} completed ({}%)", completed, total, percentage);
+    let stats = tf(Msg::ListSummary, &[&completed, &total, &percentage]);
 
     if percentage == 100 {
         println!("{}", stats.green().bold());
@@ -1,7 +1,13 @@
-//! Holiday data fetched from holidata.net and cached locally.
+//! Holiday data fetched from holidata.net and cached locally, plus an
+//! optional user-maintained holidays file.
 //!
 //! Cache location: `~/.config/rustodo/holidays/<locale>/<year>.json`
 //!
+//! The holidays file (`holidays_file` in config.toml, relative paths resolved
+//! against the config directory) lists one day per line, `YYYY-MM-DD Name`;
+//! blank lines and `#` comments are skipped. Holidata NDJSON lines are also
+//! accepted, so a downloaded table can be used offline as-is.
+//!
 //! Usage:
 //! ```no_run
 //! use rustodo::services::holidays::HolidayCache;
@@ -16,9 +22,11 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::{Context, Result};
-use chrono::NaiveDate;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
 
+use crate::config::Config;
+
 // ── Types ─────────────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -60,6 +68,41 @@ impl HolidayCache {
         Ok(Self { map })
     }
 
+    /// Holidays for `years` as configured for this workspace: the
+    /// `holidays_locale` table (when not `none`) merged with `holidays_file`.
+    ///
+    /// Sources that cannot be loaded are skipped, so this never fails — a
+    /// missing table only means fewer days are treated as holidays.
+    pub fn configured(years: &[i32]) -> Self {
+        let cfg = Config::load().unwrap_or_default();
+        let mut map = HashMap::new();
+
+        if cfg.holidays_locale != "none" && !cfg.holidays_locale.is_empty() {
+            for &year in years {
+                if let Ok(cache) = Self::load(&cfg.holidays_locale, year) {
+                    map.extend(cache.map);
+                }
+            }
+        }
+        if let Some(file) = &cfg.holidays_file
+            && let Ok(path) = resolve_holidays_file(file)
+            && let Ok(cache) = Self::from_file(&path)
+        {
+            map.extend(cache.map);
+        }
+
+        Self { map }
+    }
+
+    /// Load holidays from a local file (see the module docs for the format).
+    pub fn from_file(path: &std::path::Path) -> Result<Self> {
+        let raw = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read holidays file {}", path.display()))?;
+        Ok(Self {
+            map: parse_holidays_file(&raw),
+        })
+    }
+
     /// Returns the holiday name for a date, if any.
     pub fn for_date(&self, date: NaiveDate) -> Option<&str> {
         self.map.get(&date).map(String::as_str)
@@ -69,6 +112,24 @@ impl HolidayCache {
     pub fn is_holiday(&self, date: NaiveDate) -> bool {
         self.map.contains_key(&date)
     }
+
+    /// Returns true if the date is neither a weekend nor a holiday.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.is_holiday(date)
+    }
+
+    /// The date `days` business days after `from` (`from` itself when 0).
+    pub fn add_business_days(&self, from: NaiveDate, days: u32) -> NaiveDate {
+        let mut date = from;
+        let mut left = days;
+        while left > 0 {
+            date += Duration::days(1);
+            if self.is_business_day(date) {
+                left -= 1;
+            }
+        }
+        date
+    }
 }
 
 /// Empty cache — used when holidays are disabled.
@@ -132,6 +193,22 @@ fn parse_ndjson(raw: &str) -> HashMap<NaiveDate, String> {
     map
 }
 
+/// Parse a holidays file: `YYYY-MM-DD Name` lines or holidata NDJSON lines.
+fn parse_holidays_file(raw: &str) -> HashMap<NaiveDate, String> {
+    let mut map = parse_ndjson(raw);
+    for line in raw.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('{') {
+            continue;
+        }
+        let (date, name) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        if let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+            map.insert(date, name.trim().to_string());
+        }
+    }
+    map
+}
+
 // ── Cache path ────────────────────────────────────────────────────────────────
 
 fn cache_path(locale: &str, year: i32) -> Result<PathBuf> {
@@ -148,6 +225,19 @@ fn cache_path(locale: &str, year: i32) -> Result<PathBuf> {
         .join(format!("{}.json", year)))
 }
 
+/// Resolves `holidays_file` from config.toml; relative paths are taken from
+/// the config directory so each workspace can carry its own calendar.
+fn resolve_holidays_file(file: &std::path::Path) -> Result<PathBuf> {
+    if file.is_absolute() {
+        return Ok(file.to_path_buf());
+    }
+    let config_file = Config::path()?;
+    let config_dir = config_file
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?;
+    Ok(config_dir.join(file))
+}
+
 // ── Refresh command ───────────────────────────────────────────────────────────
 
 /// Force re-download of holiday data for `locale` and `year`.
@@ -167,3 +257,47 @@ pub fn refresh(locale: &str, year: i32) -> Result<()> {
     );
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_parse_holidays_file() {
+        let raw = "\
+# company calendar
+2026-12-24 Christmas Eve
+2026-12-31
+
+{\"date\": \"2026-12-25\", \"description\": \"Christmas Day\", \"region\": \"\"}
+not-a-date Whatever
+";
+        let map = parse_holidays_file(raw);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&date(2026, 12, 24)], "Christmas Eve");
+        assert_eq!(map[&date(2026, 12, 25)], "Christmas Day");
+        assert_eq!(map[&date(2026, 12, 31)], "");
+    }
+
+    #[test]
+    fn test_add_business_days_skips_weekends_and_holidays() {
+        let cache = HolidayCache {
+            map: parse_holidays_file("2026-12-24 Christmas Eve\n2026-12-25 Christmas Day"),
+        };
+        // Wed 2026-12-23 → Thu/Fri are holidays, then the weekend
+        assert_eq!(
+            cache.add_business_days(date(2026, 12, 23), 1),
+            date(2026, 12, 28)
+        );
+        assert_eq!(
+            cache.add_business_days(date(2026, 12, 23), 0),
+            date(2026, 12, 23)
+        );
+        assert!(!cache.is_business_day(date(2026, 12, 26)));
+        assert!(cache.is_business_day(date(2026, 12, 28)));
+    }
+}
@@ -0,0 +1,257 @@
+//! Minimal Jira REST client used by `todo jira pull` and the `done` hook.
+//!
+//! Only two endpoints are needed:
+//! - `GET  /rest/api/2/search` — issues assigned to the current user
+//! - `GET/POST /rest/api/2/issue/<KEY>/transitions` — move an issue to a new status
+//!
+//! Authentication follows the two schemes Jira supports for API access:
+//! basic auth with `email:token` (Jira Cloud) or a bearer personal access
+//! token (Jira Server / Data Center).
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::config::JiraConfig;
+use crate::models::Priority;
+use crate::utils::base64;
+
+/// HTTP timeout for Jira requests.
+const HTTP_TIMEOUT_SECS: u64 = 15;
+
+/// Upper bound on issues fetched by a single pull.
+const MAX_RESULTS: usize = 200;
+
+// ── Types ─────────────────────────────────────────────────────────────────────
+
+/// A Jira issue reduced to the fields rustodo maps onto a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraIssue {
+    pub key: String,
+    pub summary: String,
+    pub priority: Priority,
+    pub due_date: Option<NaiveDate>,
+}
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    issues: Vec<RawIssue>,
+}
+
+#[derive(Deserialize)]
+struct RawIssue {
+    key: String,
+    fields: RawFields,
+}
+
+#[derive(Deserialize)]
+struct RawFields {
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    duedate: Option<String>,
+    #[serde(default)]
+    priority: Option<RawNamed>,
+}
+
+#[derive(Deserialize)]
+struct RawNamed {
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct TransitionsResponse {
+    #[serde(default)]
+    transitions: Vec<RawTransition>,
+}
+
+#[derive(Deserialize)]
+struct RawTransition {
+    id: String,
+    name: String,
+}
+
+// ── Client ────────────────────────────────────────────────────────────────────
+
+pub struct JiraClient<'a> {
+    config: &'a JiraConfig,
+    agent: ureq::Agent,
+}
+
+impl<'a> JiraClient<'a> {
+    /// Builds a client, rejecting configs without a base URL or token.
+    pub fn new(config: &'a JiraConfig) -> Result<Self> {
+        if config.base_url.trim().is_empty() || config.token.trim().is_empty() {
+            bail!("Jira is not configured. Set base_url and token under [jira] in config.toml");
+        }
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(Some(std::time::Duration::from_secs(HTTP_TIMEOUT_SECS)))
+            .build()
+            .into();
+        Ok(Self { config, agent })
+    }
+
+    /// Fetches unresolved issues in `project` assigned to the authenticated user.
+    pub fn assigned_issues(&self, project: &str) -> Result<Vec<JiraIssue>> {
+        let jql = format!(
+            "project = {} AND assignee = currentUser() AND statusCategory != Done ORDER BY created ASC",
+            jql_string(project)
+        );
+        let mut response = self
+            .agent
+            .get(self.url("/rest/api/2/search"))
+            .header("Authorization", self.auth_header())
+            .header("Accept", "application/json")
+            .query("jql", &jql)
+            .query("fields", "summary,duedate,priority")
+            .query("maxResults", MAX_RESULTS.to_string())
+            .call()
+            .with_context(|| format!("Failed to search Jira issues for project {}", project))?;
+
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .context("Failed to read Jira search response")?;
+        parse_search_response(&body)
+    }
+
+    /// Applies the transition named `name` (case-insensitive) to `issue_key`.
+    pub fn transition(&self, issue_key: &str, name: &str) -> Result<()> {
+        let path = format!("/rest/api/2/issue/{}/transitions", issue_key);
+
+        let mut response = self
+            .agent
+            .get(self.url(&path))
+            .header("Authorization", self.auth_header())
+            .header("Accept", "application/json")
+            .call()
+            .with_context(|| format!("Failed to fetch transitions for {}", issue_key))?;
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .context("Failed to read Jira transitions response")?;
+        let transitions: TransitionsResponse =
+            serde_json::from_str(&body).context("Unexpected Jira transitions response")?;
+
+        let Some(transition) = transitions
+            .transitions
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(name))
+        else {
+            let available = transitions
+                .transitions
+                .iter()
+                .map(|t| t.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            bail!(
+                "Transition '{}' is not available for {} (available: {})",
+                name,
+                issue_key,
+                available
+            );
+        };
+
+        let payload = serde_json::json!({ "transition": { "id": transition.id } });
+        self.agent
+            .post(self.url(&path))
+            .header("Authorization", self.auth_header())
+            .header("Content-Type", "application/json")
+            .send(payload.to_string())
+            .with_context(|| format!("Failed to transition {}", issue_key))?;
+        Ok(())
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.config.base_url.trim_end_matches('/'), path)
+    }
+
+    fn auth_header(&self) -> String {
+        match self.config.email.as_deref() {
+            Some(email) if !email.is_empty() => format!(
+                "Basic {}",
+                base64::encode(format!("{}:{}", email, self.config.token).as_bytes())
+            ),
+            _ => format!("Bearer {}", self.config.token),
+        }
+    }
+}
+
+// ── Parsing ───────────────────────────────────────────────────────────────────
+
+/// Parses a `/search` response body into issues.
+pub fn parse_search_response(body: &str) -> Result<Vec<JiraIssue>> {
+    let response: SearchResponse =
+        serde_json::from_str(body).context("Unexpected Jira search response")?;
+    Ok(response
+        .issues
+        .into_iter()
+        .map(|raw| JiraIssue {
+            key: raw.key,
+            summary: raw.fields.summary,
+            priority: map_priority(raw.fields.priority.as_ref().map(|p| p.name.as_str())),
+            due_date: raw
+                .fields
+                .duedate
+                .as_deref()
+                .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok()),
+        })
+        .collect())
+}
+
+/// Maps Jira's default priority scheme onto rustodo's three levels.
+fn map_priority(name: Option<&str>) -> Priority {
+    match name.map(|n| n.to_lowercase()).as_deref() {
+        Some("highest") | Some("high") | Some("blocker") | Some("critical") => Priority::High,
+        Some("low") | Some("lowest") | Some("trivial") | Some("minor") => Priority::Low,
+        _ => Priority::Medium,
+    }
+}
+
+/// Quotes `value` as a JQL string literal, so a project key cannot close the
+/// quotes and extend the query.
+fn jql_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_search_response() {
+        let body = r#"{"issues":[
+            {"key":"ABC-1","fields":{"summary":"Fix login","duedate":"2030-01-15","priority":{"name":"High"}}},
+            {"key":"ABC-2","fields":{"summary":"Docs","duedate":null,"priority":null}}
+        ]}"#;
+        let issues = parse_search_response(body).unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].key, "ABC-1");
+        assert_eq!(issues[0].priority, Priority::High);
+        assert_eq!(
+            issues[0].due_date,
+            Some(NaiveDate::from_ymd_opt(2030, 1, 15).unwrap())
+        );
+        assert_eq!(issues[1].priority, Priority::Medium);
+        assert!(issues[1].due_date.is_none());
+    }
+
+    #[test]
+    fn test_map_priority() {
+        assert_eq!(map_priority(Some("Highest")), Priority::High);
+        assert_eq!(map_priority(Some("lowest")), Priority::Low);
+        assert_eq!(map_priority(Some("Medium")), Priority::Medium);
+        assert_eq!(map_priority(None), Priority::Medium);
+    }
+
+    #[test]
+    fn test_jql_string_escapes_quotes() {
+        assert_eq!(jql_string("PROJ"), "\"PROJ\"");
+        assert_eq!(
+            jql_string(r#"X" OR project != "Y\"#),
+            r#""X\" OR project != \"Y\\""#
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! Minimal RFC 5322 message parsing for `todo import --from eml|maildir`.
+//!
+//! Only what is needed to turn a forwarded email into a task is handled:
+//! unfolded headers, RFC 2047 encoded-word subjects, `multipart/*` bodies
+//! (first `text/plain` part wins), and quoted-printable / base64 transfer
+//! encodings. Anything else is passed through as-is.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+
+use crate::utils::{base64, date_parser};
+
+// ── Types ─────────────────────────────────────────────────────────────────────
+
+/// An email reduced to the parts that map onto a task and its note.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MailMessage {
+    /// Subject with `Re:`/`Fwd:` prefixes removed.
+    pub subject: String,
+    /// Plain-text body, trimmed.
+    pub body: String,
+}
+
+impl MailMessage {
+    /// Parses a raw message.
+    pub fn parse(raw: &str) -> Self {
+        let raw = raw.replace("\r\n", "\n");
+        let (header_block, body) = raw.split_once("\n\n").unwrap_or((raw.as_str(), ""));
+        let headers = parse_headers(header_block);
+
+        let subject = header(&headers, "subject")
+            .map(decode_encoded_words)
+            .map(|s| strip_reply_prefixes(&s))
+            .unwrap_or_default();
+
+        let content_type = header(&headers, "content-type").unwrap_or("text/plain");
+        let encoding = header(&headers, "content-transfer-encoding").unwrap_or("7bit");
+        let body = extract_text(content_type, encoding, body);
+
+        Self {
+            subject,
+            body: body.trim().to_string(),
+        }
+    }
+
+    /// Reads and parses a message file.
+    pub fn read(path: &Path) -> Result<Self> {
+        let bytes =
+            std::fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        Ok(Self::parse(&String::from_utf8_lossy(&bytes)))
+    }
+
+    /// Looks for a trailing date hint in the subject (`... by friday`,
+    /// `... due 2026-05-01`) and returns the subject without it plus the date.
+    ///
+    /// Everything after the last `by`/`due`/`on`/`before` (at most four words)
+    /// must parse as a date, so both `by next friday` and `due tomorrow` resolve
+    /// while `notes on the architecture` is left alone.
+    pub fn split_due_hint(&self) -> (String, Option<NaiveDate>) {
+        let words: Vec<&str> = self.subject.split_whitespace().collect();
+        let keyword_pos = words.iter().rposition(|w| {
+            matches!(
+                w.to_lowercase().trim_end_matches(':'),
+                "by" | "due" | "on" | "before"
+            )
+        });
+
+        if let Some(pos) = keyword_pos {
+            let tail = &words[pos + 1..];
+            if !tail.is_empty() && tail.len() <= 4 {
+                let phrase = tail.join(" ");
+                let phrase = phrase.trim_end_matches(['.', '!', '?', ')']);
+                if let Ok(date) = date_parser::parse_date(phrase) {
+                    let text = words[..pos].join(" ");
+                    if !text.is_empty() {
+                        return (text, Some(date));
+                    }
+                }
+            }
+        }
+        (self.subject.clone(), None)
+    }
+}
+
+/// Collects message files from a maildir (`new/` and `cur/`) or, when the
+/// directory has neither, every regular file directly inside it.
+pub fn maildir_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut roots: Vec<PathBuf> = ["new", "cur"]
+        .iter()
+        .map(|sub| dir.join(sub))
+        .filter(|p| p.is_dir())
+        .collect();
+    if roots.is_empty() {
+        roots.push(dir.to_path_buf());
+    }
+
+    let mut files = Vec::new();
+    for root in roots {
+        for entry in std::fs::read_dir(&root)
+            .with_context(|| format!("Failed to read directory {}", root.display()))?
+        {
+            let path = entry?.path();
+            if path.is_file()
+                && !path
+                    .file_name()
+                    .is_some_and(|n| n.to_string_lossy().starts_with('.'))
+            {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// ── Headers ───────────────────────────────────────────────────────────────────
+
+/// Parses and unfolds headers into `(lowercase-name, value)` pairs.
+fn parse_headers(block: &str) -> Vec<(String, String)> {
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in block.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+        }
+    }
+    headers
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, v)| v.as_str())
+}
+
+/// Extracts a parameter (e.g. `boundary`) from a structured header value.
+fn header_param(value: &str, param: &str) -> Option<String> {
+    value.split(';').skip(1).find_map(|part| {
+        let (k, v) = part.trim().split_once('=')?;
+        k.trim()
+            .eq_ignore_ascii_case(param)
+            .then(|| v.trim().trim_matches('"').to_string())
+    })
+}
+
+fn strip_reply_prefixes(subject: &str) -> String {
+    let mut s = subject.trim();
+    loop {
+        let lower = s.to_lowercase();
+        let prefix = ["re:", "fwd:", "fw:", "tr:"]
+            .iter()
+            .find(|p| lower.starts_with(*p));
+        match prefix {
+            Some(p) => s = s[p.len()..].trim_start(),
+            None => return s.to_string(),
+        }
+    }
+}
+
+/// Decodes RFC 2047 encoded words (`=?utf-8?B?...?=` / `=?utf-8?Q?...?=`).
+fn decode_encoded_words(value: &str) -> String {
+    let mut out = String::new();
+    let mut rest = value;
+    let mut last_was_encoded = false;
+
+    while let Some(start) = rest.find("=?") {
+        let before = &rest[..start];
+        // Whitespace between adjacent encoded words is not significant.
+        if !(last_was_encoded && before.trim().is_empty()) {
+            out.push_str(before);
+        }
+        let after = &rest[start + 2..];
+        let parts: Vec<&str> = after.splitn(3, '?').collect();
+        if parts.len() < 3 {
+            out.push_str(&rest[start..]);
+            return out;
+        }
+        let (encoding, tail) = (parts[1], parts[2]);
+        let Some(end) = tail.find("?=") else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let text = &tail[..end];
+        let bytes = match encoding.to_ascii_uppercase().as_str() {
+            "B" => base64::decode(text),
+            _ => quoted_printable_decode(&text.replace('_', " ")),
+        };
+        out.push_str(&String::from_utf8_lossy(&bytes));
+        rest = &tail[end + 2..];
+        last_was_encoded = true;
+    }
+    out.push_str(rest);
+    out
+}
+
+// ── Body ──────────────────────────────────────────────────────────────────────
+
+fn extract_text(content_type: &str, encoding: &str, body: &str) -> String {
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_lowercase();
+
+    if mime.starts_with("multipart/") {
+        let Some(boundary) = header_param(content_type, "boundary") else {
+            return body.to_string();
+        };
+        let delimiter = format!("--{}", boundary);
+        for part in body.split(&delimiter).skip(1) {
+            if part.starts_with("--") {
+                break;
+            }
+            let part = part.trim_start_matches('\n');
+            let (part_headers, part_body) = part.split_once("\n\n").unwrap_or((part, ""));
+            let headers = parse_headers(part_headers);
+            let ct = header(&headers, "content-type").unwrap_or("text/plain");
+            let enc = header(&headers, "content-transfer-encoding").unwrap_or("7bit");
+            let text = extract_text(ct, enc, part_body);
+            let is_text =
+                ct.to_lowercase().starts_with("text/plain") || ct.starts_with("multipart/");
+            if is_text && !text.trim().is_empty() {
+                return text;
+            }
+        }
+        return String::new();
+    }
+
+    let decoded = match encoding.trim().to_lowercase().as_str() {
+        "quoted-printable" => quoted_printable_decode(body),
+        "base64" => base64::decode(body),
+        _ => return body.to_string(),
+    };
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn quoted_printable_decode(input: &str) -> Vec<u8> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'=' {
+            // Soft line break
+            if bytes.get(i + 1) == Some(&b'\n') {
+                i += 2;
+                continue;
+            }
+            if let Some(hex) = input.get(i + 1..i + 3)
+                && let Ok(b) = u8::from_str_radix(hex, 16)
+            {
+                out.push(b);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple_message() {
+        let raw = "From: a@b.c\r\nSubject: Fwd: Renew passport\r\n\r\nBring two photos.\r\n";
+        let msg = MailMessage::parse(raw);
+        assert_eq!(msg.subject, "Renew passport");
+        assert_eq!(msg.body, "Bring two photos.");
+    }
+
+    #[test]
+    fn test_parse_encoded_subject_and_folding() {
+        let raw = "Subject: =?utf-8?B?UmV2aXNhcg==?=\n =?utf-8?Q?_contrato?=\n\nbody";
+        let msg = MailMessage::parse(raw);
+        assert_eq!(msg.subject, "Revisar contrato");
+    }
+
+    #[test]
+    fn test_parse_multipart_prefers_text_plain() {
+        let raw = "Subject: Report\n\
+Content-Type: multipart/alternative; boundary=\"XYZ\"\n\
+\n\
+--XYZ\n\
+Content-Type: text/html\n\
+\n\
+<p>html</p>\n\
+--XYZ\n\
+Content-Type: text/plain; charset=utf-8\n\
+Content-Transfer-Encoding: quoted-printable\n\
+\n\
+Line one =\n\
+continued =3D ok\n\
+--XYZ--\n";
+        let msg = MailMessage::parse(raw);
+        assert_eq!(msg.body, "Line one continued = ok");
+    }
+
+    #[test]
+    fn test_split_due_hint() {
+        let msg = MailMessage {
+            subject: "Send invoice by 2030-01-31".into(),
+            body: String::new(),
+        };
+        let (text, due) = msg.split_due_hint();
+        assert_eq!(text, "Send invoice");
+        assert_eq!(due, NaiveDate::from_ymd_opt(2030, 1, 31));
+    }
+
+    #[test]
+    fn test_split_due_hint_ignores_non_dates() {
+        let msg = MailMessage {
+            subject: "Notes on the architecture".into(),
+            body: String::new(),
+        };
+        let (text, due) = msg.split_due_hint();
+        assert_eq!(text, "Notes on the architecture");
+        assert!(due.is_none());
+    }
+}
@@ -1,2 +1,7 @@
 pub mod holidays;
+pub mod jira;
+pub mod mail;
 pub mod tag_service;
+pub mod task_diff;
+#[cfg(feature = "todoist")]
+pub mod todoist;
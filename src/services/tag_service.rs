@@ -5,12 +5,13 @@
 //! [`Task`], [`Note`], and [`Resource`].
 
 use crate::models::{Note, Resource, Task};
+use serde::Serialize;
 use std::collections::HashMap;
 
 // ── TagStat ───────────────────────────────────────────────────────────────────
 
 /// Aggregated tag statistics across all entity types.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct TagStat {
     /// The tag name.
     pub name: String,
@@ -0,0 +1,213 @@
+//! Semantic diff of two task lists.
+//!
+//! Pure domain logic — no CLI, no storage, no I/O.
+//! Tasks are matched by UUID, so reordering and renumbering are not
+//! changes. A task that is soft-deleted on one side counts as absent there.
+//! Field-level changes are found by comparing each task's serialized form,
+//! which keeps the diff in step with [`Task`] as fields are added.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::models::Task;
+
+/// Fields that change on every write and would drown out real edits.
+const IGNORED_FIELDS: &[&str] = &["updated_at"];
+
+// ── Types ─────────────────────────────────────────────────────────────────────
+
+/// One field that differs between two versions of a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// Serialized field name, e.g. `due_date`.
+    pub field: String,
+    /// Old value rendered for display (`—` when unset).
+    pub before: String,
+    /// New value rendered for display (`—` when unset).
+    pub after: String,
+}
+
+/// A task present on both sides with at least one changed field.
+#[derive(Debug)]
+pub struct TaskChange<'a> {
+    pub before: &'a Task,
+    pub after: &'a Task,
+    pub fields: Vec<FieldChange>,
+}
+
+/// Result of [`diff_tasks`].
+#[derive(Debug, Default)]
+pub struct TaskDiff<'a> {
+    /// Tasks only on the `after` side, in `after` order.
+    pub added: Vec<&'a Task>,
+    /// Tasks only on the `before` side, in `before` order.
+    pub removed: Vec<&'a Task>,
+    /// Tasks on both sides that differ, in `after` order.
+    pub changed: Vec<TaskChange<'a>>,
+}
+
+impl TaskDiff<'_> {
+    /// True when both sides hold the same tasks with the same fields.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// One-line count summary, e.g. `2 added, 1 removed, 3 changed`.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} added, {} removed, {} changed",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        )
+    }
+}
+
+// ── Diff ──────────────────────────────────────────────────────────────────────
+
+/// Compares two task lists by UUID.
+pub fn diff_tasks<'a>(before: &'a [Task], after: &'a [Task]) -> TaskDiff<'a> {
+    let live = |tasks: &'a [Task]| -> HashMap<Uuid, &'a Task> {
+        tasks
+            .iter()
+            .filter(|t| !t.is_deleted())
+            .map(|t| (t.uuid, t))
+            .collect()
+    };
+    let old = live(before);
+    let new = live(after);
+
+    let mut diff = TaskDiff::default();
+    for task in after.iter().filter(|t| !t.is_deleted()) {
+        match old.get(&task.uuid) {
+            None => diff.added.push(task),
+            Some(prev) => {
+                let fields = field_changes(prev, task);
+                if !fields.is_empty() {
+                    diff.changed.push(TaskChange {
+                        before: prev,
+                        after: task,
+                        fields,
+                    });
+                }
+            }
+        }
+    }
+    diff.removed = before
+        .iter()
+        .filter(|t| !t.is_deleted() && !new.contains_key(&t.uuid))
+        .collect();
+    diff
+}
+
+/// Lists the serialized fields that differ between two versions of a task.
+pub fn field_changes(before: &Task, after: &Task) -> Vec<FieldChange> {
+    let (Ok(Value::Object(old)), Ok(Value::Object(new))) =
+        (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return Vec::new();
+    };
+
+    let mut fields: Vec<&String> = old.keys().chain(new.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .filter(|f| !IGNORED_FIELDS.contains(&f.as_str()))
+        .filter_map(|field| {
+            let a = old.get(field).unwrap_or(&Value::Null);
+            let b = new.get(field).unwrap_or(&Value::Null);
+            (a != b).then(|| FieldChange {
+                field: field.clone(),
+                before: display_value(a),
+                after: display_value(b),
+            })
+        })
+        .collect()
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => "—".to_string(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) if items.is_empty() => "—".to_string(),
+        Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+
+    fn task(text: &str) -> Task {
+        Task::new(text.to_string(), Priority::Medium, vec![], None, None, None)
+    }
+
+    #[test]
+    fn test_identical_lists_have_no_diff() {
+        let tasks = vec![task("a"), task("b")];
+        let diff = diff_tasks(&tasks, &tasks);
+        assert!(diff.is_empty());
+        assert_eq!(diff.summary(), "0 added, 0 removed, 0 changed");
+    }
+
+    #[test]
+    fn test_added_removed_and_changed() {
+        let kept = task("kept");
+        let gone = task("gone");
+        let before = vec![kept.clone(), gone];
+
+        let mut edited = kept.clone();
+        edited.priority = Priority::High;
+        edited.tags = vec!["work".into()];
+        edited.touch();
+        let after = vec![edited, task("new")];
+
+        let diff = diff_tasks(&before, &after);
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].text, "new");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].text, "gone");
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(
+            diff.changed[0].fields,
+            vec![
+                FieldChange {
+                    field: "priority".into(),
+                    before: "medium".into(),
+                    after: "high".into(),
+                },
+                FieldChange {
+                    field: "tags".into(),
+                    before: "—".into(),
+                    after: "work".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_soft_deleted_task_counts_as_removed() {
+        let t = task("a");
+        let mut deleted = t.clone();
+        deleted.soft_delete();
+
+        let before = [t];
+        let after = [deleted];
+        let diff = diff_tasks(&before, &after);
+        assert_eq!(diff.removed.len(), 1);
+        assert!(diff.changed.is_empty());
+    }
+}
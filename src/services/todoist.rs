@@ -0,0 +1,283 @@
+//! Minimal Todoist REST client used by `todo todoist pull` and `push`.
+//!
+//! Endpoints (REST API v2, authenticated with a bearer API token):
+//! - `GET  /tasks`, `GET /projects` — active tasks and all projects
+//! - `POST /tasks`, `POST /tasks/<ID>` — create or update a task
+//! - `POST /tasks/<ID>/close` — complete a task
+//! - `POST /projects` — create a project
+//!
+//! Todoist has four priorities (API value 4 is the highest, "p1") while
+//! rustodo has three; see [`priority_from_todoist`] for the mapping.
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::config::TodoistConfig;
+use crate::models::Priority;
+
+/// HTTP timeout for Todoist requests.
+const HTTP_TIMEOUT_SECS: u64 = 15;
+
+/// API root used when `api_url` is not configured.
+pub const DEFAULT_API_URL: &str = "https://api.todoist.com/rest/v2";
+
+// ── Types ─────────────────────────────────────────────────────────────────────
+
+/// An active Todoist task reduced to the fields rustodo maps onto a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TodoistTask {
+    pub id: String,
+    pub content: String,
+    /// 1 (normal) to 4 (urgent)
+    pub priority: u8,
+    pub labels: Vec<String>,
+    pub project_id: String,
+    pub due: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TodoistProject {
+    pub id: String,
+    pub name: String,
+}
+
+/// Fields sent when creating or updating a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskPayload {
+    pub content: String,
+    pub priority: u8,
+    pub labels: Vec<String>,
+    pub project_id: Option<String>,
+    pub due: Option<NaiveDate>,
+}
+
+impl TaskPayload {
+    fn to_json(&self) -> serde_json::Value {
+        let mut body = serde_json::json!({
+            "content": self.content,
+            "priority": self.priority,
+            "labels": self.labels,
+        });
+        if let Some(ref project_id) = self.project_id {
+            body["project_id"] = project_id.clone().into();
+        }
+        match self.due {
+            Some(date) => body["due_date"] = date.format("%Y-%m-%d").to_string().into(),
+            None => body["due_string"] = "no date".into(),
+        }
+        body
+    }
+}
+
+#[derive(Deserialize)]
+struct RawTask {
+    id: String,
+    #[serde(default)]
+    content: String,
+    #[serde(default = "normal_priority")]
+    priority: u8,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    project_id: String,
+    #[serde(default)]
+    due: Option<RawDue>,
+}
+
+#[derive(Deserialize)]
+struct RawDue {
+    date: String,
+}
+
+fn normal_priority() -> u8 {
+    1
+}
+
+// ── Client ────────────────────────────────────────────────────────────────────
+
+pub struct TodoistClient<'a> {
+    config: &'a TodoistConfig,
+    agent: ureq::Agent,
+}
+
+impl<'a> TodoistClient<'a> {
+    /// Builds a client, rejecting configs without a token.
+    pub fn new(config: &'a TodoistConfig) -> Result<Self> {
+        if config.token.trim().is_empty() {
+            bail!("Todoist is not configured. Set token under [todoist] in config.toml");
+        }
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(Some(std::time::Duration::from_secs(HTTP_TIMEOUT_SECS)))
+            .build()
+            .into();
+        Ok(Self { config, agent })
+    }
+
+    /// All active (not completed) tasks.
+    pub fn tasks(&self) -> Result<Vec<TodoistTask>> {
+        parse_tasks(&self.get("/tasks")?)
+    }
+
+    pub fn projects(&self) -> Result<Vec<TodoistProject>> {
+        serde_json::from_str(&self.get("/projects")?)
+            .context("Unexpected Todoist projects response")
+    }
+
+    pub fn create_project(&self, name: &str) -> Result<TodoistProject> {
+        let body = self.post("/projects", serde_json::json!({ "name": name }))?;
+        serde_json::from_str(&body).context("Unexpected Todoist project response")
+    }
+
+    /// Creates a task and returns its Todoist ID.
+    pub fn create_task(&self, payload: &TaskPayload) -> Result<String> {
+        let body = self.post("/tasks", payload.to_json())?;
+        let created = parse_task(&body)?;
+        Ok(created.id)
+    }
+
+    pub fn update_task(&self, id: &str, payload: &TaskPayload) -> Result<()> {
+        let mut body = payload.to_json();
+        // A task's project cannot be changed through the update endpoint
+        if let Some(fields) = body.as_object_mut() {
+            fields.remove("project_id");
+        }
+        self.post(&format!("/tasks/{}", id), body)?;
+        Ok(())
+    }
+
+    pub fn close_task(&self, id: &str) -> Result<()> {
+        self.post(&format!("/tasks/{}/close", id), serde_json::json!({}))?;
+        Ok(())
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let mut response = self
+            .agent
+            .get(self.url(path))
+            .header("Authorization", self.auth_header())
+            .call()
+            .with_context(|| format!("Todoist request failed: GET {}", path))?;
+        response
+            .body_mut()
+            .read_to_string()
+            .context("Failed to read Todoist response")
+    }
+
+    fn post(&self, path: &str, body: serde_json::Value) -> Result<String> {
+        let mut response = self
+            .agent
+            .post(self.url(path))
+            .header("Authorization", self.auth_header())
+            .header("Content-Type", "application/json")
+            .send(body.to_string())
+            .with_context(|| format!("Todoist request failed: POST {}", path))?;
+        response
+            .body_mut()
+            .read_to_string()
+            .context("Failed to read Todoist response")
+    }
+
+    fn url(&self, path: &str) -> String {
+        let base = self.config.api_url.as_deref().unwrap_or(DEFAULT_API_URL);
+        format!("{}{}", base.trim_end_matches('/'), path)
+    }
+
+    fn auth_header(&self) -> String {
+        format!("Bearer {}", self.config.token.trim())
+    }
+}
+
+// ── Parsing & mapping ─────────────────────────────────────────────────────────
+
+/// Parses a `GET /tasks` response body.
+pub fn parse_tasks(body: &str) -> Result<Vec<TodoistTask>> {
+    let raw: Vec<RawTask> =
+        serde_json::from_str(body).context("Unexpected Todoist tasks response")?;
+    Ok(raw.into_iter().map(from_raw).collect())
+}
+
+fn parse_task(body: &str) -> Result<TodoistTask> {
+    let raw: RawTask = serde_json::from_str(body).context("Unexpected Todoist task response")?;
+    Ok(from_raw(raw))
+}
+
+fn from_raw(raw: RawTask) -> TodoistTask {
+    TodoistTask {
+        id: raw.id,
+        content: raw.content,
+        priority: raw.priority,
+        labels: raw.labels,
+        project_id: raw.project_id,
+        // `date` is `YYYY-MM-DD`, or a full timestamp for tasks with a time
+        due: raw
+            .due
+            .and_then(|d| NaiveDate::parse_from_str(d.date.get(..10)?, "%Y-%m-%d").ok()),
+    }
+}
+
+/// Maps Todoist's four priorities onto rustodo's three: p1 and p2 are high,
+/// p3 is medium, and p4 ("normal", the default) is low.
+pub fn priority_from_todoist(priority: u8) -> Priority {
+    match priority {
+        4 | 3 => Priority::High,
+        2 => Priority::Medium,
+        _ => Priority::Low,
+    }
+}
+
+pub fn priority_to_todoist(priority: Priority) -> u8 {
+    match priority {
+        Priority::High => 4,
+        Priority::Medium => 2,
+        Priority::Low => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tasks() {
+        let body = r#"[
+            {"id":"101","content":"Call bank","priority":4,"labels":["finance"],
+             "project_id":"9","due":{"date":"2030-01-15","string":"Jan 15"}},
+            {"id":"102","content":"Read","project_id":"9",
+             "due":{"date":"2030-02-01T09:00:00","string":"Feb 1 9am"}},
+            {"id":"103","content":"Someday","priority":1,"labels":[],"project_id":"9","due":null}
+        ]"#;
+        let tasks = parse_tasks(body).unwrap();
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0].labels, vec!["finance"]);
+        assert_eq!(priority_from_todoist(tasks[0].priority), Priority::High);
+        assert_eq!(tasks[0].due, NaiveDate::from_ymd_opt(2030, 1, 15));
+        assert_eq!(tasks[1].priority, 1);
+        assert_eq!(tasks[1].due, NaiveDate::from_ymd_opt(2030, 2, 1));
+        assert!(tasks[2].due.is_none());
+    }
+
+    #[test]
+    fn test_priority_round_trip() {
+        for priority in [Priority::High, Priority::Medium, Priority::Low] {
+            assert_eq!(
+                priority_from_todoist(priority_to_todoist(priority)),
+                priority
+            );
+        }
+    }
+
+    #[test]
+    fn test_payload_clears_due_date() {
+        let payload = TaskPayload {
+            content: "Read".into(),
+            priority: 1,
+            labels: vec![],
+            project_id: None,
+            due: None,
+        };
+        let json = payload.to_json();
+        assert_eq!(json["due_string"], "no date");
+        assert!(json.get("project_id").is_none());
+    }
+}
@@ -13,7 +13,12 @@
 //! (e.g. `/home/d'artagnan/.local/...`).
 
 use anyhow::{Context, Result};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+use super::{SqliteStorage, Storage};
+use crate::models::TaskSource;
 
 /// Checks whether a backup is needed and creates one if so.
 ///
@@ -99,3 +104,37 @@ fn last_backup_time(backup_dir: &Path) -> Option<std::time::SystemTime> {
         .filter_map(|e| e.metadata().ok()?.modified().ok())
         .max()
 }
+
+/// Task UUIDs currently in the database at `db_path`, or an empty set if it
+/// cannot be read. Taken before a restore so [`mark_restored`] can tell which
+/// tasks the backup brought back.
+pub fn live_task_uuids(db_path: &Path) -> HashSet<Uuid> {
+    SqliteStorage::open_at(db_path.to_path_buf())
+        .and_then(|s| s.load())
+        .map(|tasks| tasks.iter().map(|t| t.uuid).collect())
+        .unwrap_or_default()
+}
+
+/// Records `backup` as the source of every restored task that was not in
+/// `live_before`. Returns the number of tasks marked.
+pub fn mark_restored(db_path: &Path, backup: &Path, live_before: &HashSet<Uuid>) -> Result<usize> {
+    let from = format!(
+        "backup {}",
+        backup
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| backup.display().to_string())
+    );
+
+    let storage = SqliteStorage::open_at(db_path.to_path_buf())?;
+    let mut tasks = storage.load()?;
+    let mut marked = 0;
+    for task in tasks.iter_mut().filter(|t| !live_before.contains(&t.uuid)) {
+        task.source = Some(TaskSource::Restored(from.clone()));
+        marked += 1;
+    }
+    if marked > 0 {
+        storage.save(&tasks)?;
+    }
+    Ok(marked)
+}
@@ -0,0 +1,123 @@
+//! Healing of tasks that share a UUID.
+//!
+//! Two tasks can end up with the same UUID when data files are copied or
+//! merged by hand. The first task keeps the UUID; each later copy gets a
+//! new one derived from the shared UUID and its position among the copies,
+//! so loading the same data twice heals it the same way. Both tasks are
+//! flagged through [`Task::duplicate_of`] for review with `todo doctor`.
+//!
+//! SQLite's primary key keeps duplicates out of the database itself, so
+//! this runs on data that bypasses it: [`InMemoryStorage`] loads and JSON
+//! imports.
+//!
+//! [`InMemoryStorage`]: super::InMemoryStorage
+
+use std::collections::{HashMap, HashSet};
+
+use colored::Colorize;
+use uuid::{Builder, Uuid};
+
+use crate::models::Task;
+
+/// A task that was given a new UUID because another task already had it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealedUuid {
+    pub old: Uuid,
+    pub new: Uuid,
+    pub text: String,
+}
+
+/// Gives every task after the first with a given UUID a new one, flags
+/// both tasks, and returns what was changed.
+pub fn heal_duplicate_uuids(tasks: &mut [Task]) -> Vec<HealedUuid> {
+    let mut taken: HashSet<Uuid> = tasks.iter().map(|t| t.uuid).collect();
+    let mut first: HashMap<Uuid, usize> = HashMap::new();
+    let mut copies: HashMap<Uuid, u64> = HashMap::new();
+    let mut healed = Vec::new();
+
+    for i in 0..tasks.len() {
+        let old = tasks[i].uuid;
+        let Some(&original) = first.get(&old) else {
+            first.insert(old, i);
+            continue;
+        };
+        let copy = copies.entry(old).or_insert(0);
+        let new = loop {
+            *copy += 1;
+            let candidate = derive_uuid(old, *copy);
+            if taken.insert(candidate) {
+                break candidate;
+            }
+        };
+
+        tasks[i].uuid = new;
+        tasks[i].duplicate_of = Some(old);
+        tasks[i].touch();
+        tasks[original].duplicate_of = Some(new);
+        healed.push(HealedUuid {
+            old,
+            new,
+            text: tasks[i].text.clone(),
+        });
+    }
+    healed
+}
+
+/// Prints one warning per healed task to stderr.
+pub fn report(healed: &[HealedUuid]) {
+    for h in healed {
+        eprintln!(
+            "{} Task \"{}\" shared UUID {} with another task — given {}; run {}",
+            "⚠".yellow(),
+            h.text,
+            h.old,
+            h.new,
+            "todo doctor".cyan()
+        );
+    }
+}
+
+/// A version-4-shaped UUID derived from `uuid` and `copy` (FNV-1a, 128-bit).
+fn derive_uuid(uuid: Uuid, copy: u64) -> Uuid {
+    const OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+    let mut hash = OFFSET;
+    for byte in uuid.as_bytes().iter().chain(&copy.to_le_bytes()) {
+        hash ^= u128::from(*byte);
+        hash = hash.wrapping_mul(PRIME);
+    }
+    Builder::from_random_bytes(hash.to_be_bytes()).into_uuid()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+
+    fn make_task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    #[test]
+    fn test_heal_is_deterministic_and_flags_both() {
+        let original = make_task("Original");
+        let mut copy = make_task("Copy");
+        copy.uuid = original.uuid;
+        let tasks = vec![original, make_task("Other"), copy];
+
+        let mut first = tasks.clone();
+        let healed = heal_duplicate_uuids(&mut first);
+        assert_eq!(healed.len(), 1);
+        assert_eq!(healed[0].old, tasks[0].uuid);
+        assert_eq!(first[0].uuid, tasks[0].uuid);
+        assert_eq!(first[2].uuid, healed[0].new);
+        assert_eq!(first[0].duplicate_of, Some(healed[0].new));
+        assert_eq!(first[2].duplicate_of, Some(tasks[0].uuid));
+        assert_eq!(first[1].duplicate_of, None);
+
+        let mut second = tasks.clone();
+        heal_duplicate_uuids(&mut second);
+        assert_eq!(second[2].uuid, first[2].uuid);
+        assert!(heal_duplicate_uuids(&mut first).is_empty());
+    }
+}
@@ -5,7 +5,7 @@ use chrono::TimeZone;
 use std::cell::RefCell;
 use uuid::Uuid;
 
-use super::{EntityType, EventStat, EventType, Storage};
+use super::{EntityType, EventStat, EventType, Storage, dedupe};
 use crate::models::{Note, Project, Resource, Task};
 
 #[derive(Debug, Default)]
@@ -25,6 +25,7 @@ pub struct InMemoryStorage {
     notes: RefCell<Vec<Note>>,
     resources: RefCell<Vec<Resource>>,
     events: RefCell<Vec<EventRow>>,
+    snapshots: RefCell<std::collections::HashMap<String, String>>,
 }
 
 #[allow(dead_code)]
@@ -46,7 +47,10 @@ impl InMemoryStorage {
 
 impl Storage for InMemoryStorage {
     fn load(&self) -> Result<Vec<Task>> {
-        Ok(self.tasks.borrow().clone())
+        let mut tasks = self.tasks.borrow_mut();
+        let healed = dedupe::heal_duplicate_uuids(&mut tasks);
+        dedupe::report(&healed);
+        Ok(tasks.clone())
     }
 
     fn upsert_task(&self, task: &Task) -> Result<()> {
@@ -235,6 +239,22 @@ impl Storage for InMemoryStorage {
         Ok(map.into_values().collect())
     }
 
+    fn save_snapshot(&self, name: &str, payload: &str) -> Result<()> {
+        self.snapshots
+            .borrow_mut()
+            .insert(name.to_string(), payload.to_string());
+        Ok(())
+    }
+
+    fn load_snapshot(&self, name: &str) -> Result<Option<String>> {
+        Ok(self.snapshots.borrow().get(name).cloned())
+    }
+
+    fn delete_snapshot(&self, name: &str) -> Result<()> {
+        self.snapshots.borrow_mut().remove(name);
+        Ok(())
+    }
+
     fn location(&self) -> String {
         "memory".to_string()
     }
@@ -4,10 +4,17 @@
 //! |---|---|
 //! | [`SqliteStorage`]   | Persists to a SQLite database in the OS data directory |
 //! | [`InMemoryStorage`] | Stores in memory — ideal for tests |
+//! | [`ReadOnlyStorage`] | Wraps another backend and rejects every write |
+//! | [`TimedStorage`]    | Wraps another backend and warns when it is slow |
+//! | [`SocketStorage`]   | Forwards every call to a running `todo remind --daemon` |
+//!
+//! Backends can also be selected by name through [`registry::StorageRegistry`],
+//! which is how third-party implementations plug in.
 
 use crate::models::StatusFilter;
 use crate::models::{Note, Project, Resource, Task};
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 // ── EntityType / EventType ────────────────────────────────────────────────────
@@ -39,6 +46,7 @@ pub enum EventType {
     Edited,
     Deleted,
     Purged,
+    Cancelled,
 }
 
 impl EventType {
@@ -50,6 +58,7 @@ impl EventType {
             EventType::Edited => "edited",
             EventType::Deleted => "deleted",
             EventType::Purged => "purged",
+            EventType::Cancelled => "cancelled",
         }
     }
 }
@@ -57,7 +66,7 @@ impl EventType {
 // ── EventStat ─────────────────────────────────────────────────────────────────
 
 /// Aggregated event count for a single month.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct EventStat {
     pub year: i32,
     pub month: u32,
@@ -139,6 +148,17 @@ pub trait Storage {
     /// `EventStat` per month, oldest first, covering only `task` events.
     fn load_event_stats(&self, months: usize) -> Result<Vec<EventStat>>;
 
+    // ── snapshots ─────────────────────────────────────────────────────────────
+
+    /// Store a named snapshot payload, replacing any previous one with that name.
+    fn save_snapshot(&self, name: &str, payload: &str) -> Result<()>;
+
+    /// Load a named snapshot payload, if one exists.
+    fn load_snapshot(&self, name: &str) -> Result<Option<String>>;
+
+    /// Remove a named snapshot. Missing snapshots are not an error.
+    fn delete_snapshot(&self, name: &str) -> Result<()>;
+
     // ── search ────────────────────────────────────────────────────────────────
 
     /// Search tasks by substring query with optional tag and project filters.
@@ -286,8 +306,19 @@ pub trait Storage {
 }
 
 pub mod backup;
+pub mod dedupe;
 pub mod memory;
+pub mod read_only;
+pub mod recovery;
+pub mod registry;
+#[cfg(unix)]
+pub mod shared;
 pub mod sqlite;
+pub mod timed;
 
 pub use memory::InMemoryStorage;
+pub use read_only::ReadOnlyStorage;
+#[cfg(unix)]
+pub use shared::SocketStorage;
 pub use sqlite::{SqliteStorage, get_db_path};
+pub use timed::TimedStorage;
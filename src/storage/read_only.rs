@@ -0,0 +1,156 @@
+//! Read-only wrapper used by `--read-only`.
+//!
+//! Reads are forwarded to the inner backend; every write fails immediately
+//! with the same error, so mutating commands stop before touching anything
+//! while `list`, `search`, and `stats` behave normally.
+
+use anyhow::{Result, bail};
+use uuid::Uuid;
+
+use super::{EntityType, EventStat, EventType, Storage};
+use crate::models::{Note, Project, Resource, StatusFilter, Task};
+
+pub struct ReadOnlyStorage<S: Storage> {
+    inner: S,
+}
+
+impl<S: Storage> ReadOnlyStorage<S> {
+    pub fn new(inner: S) -> Self {
+        Self { inner }
+    }
+}
+
+fn refuse<T>() -> Result<T> {
+    bail!("Storage is read-only (started with --read-only); this command would modify data")
+}
+
+impl<S: Storage> Storage for ReadOnlyStorage<S> {
+    fn load(&self) -> Result<Vec<Task>> {
+        self.inner.load()
+    }
+    fn save(&self, _tasks: &[Task]) -> Result<()> {
+        refuse()
+    }
+    fn delete_tasks(&self, _uuids: &[Uuid]) -> Result<()> {
+        refuse()
+    }
+    fn load_projects(&self) -> Result<Vec<Project>> {
+        self.inner.load_projects()
+    }
+    fn save_projects(&self, _projects: &[Project]) -> Result<()> {
+        refuse()
+    }
+    fn delete_projects(&self, _uuids: &[Uuid]) -> Result<()> {
+        refuse()
+    }
+    fn load_notes(&self) -> Result<Vec<Note>> {
+        self.inner.load_notes()
+    }
+    fn save_notes(&self, _notes: &[Note]) -> Result<()> {
+        refuse()
+    }
+    fn delete_notes(&self, _uuids: &[Uuid]) -> Result<()> {
+        refuse()
+    }
+    fn load_resources(&self) -> Result<Vec<Resource>> {
+        self.inner.load_resources()
+    }
+    fn save_resources(&self, _resources: &[Resource]) -> Result<()> {
+        refuse()
+    }
+    fn delete_resources(&self, _uuids: &[Uuid]) -> Result<()> {
+        refuse()
+    }
+    fn record_event(
+        &self,
+        _entity_type: EntityType,
+        _entity_uuid: Uuid,
+        _event_type: EventType,
+    ) -> Result<()> {
+        refuse()
+    }
+    fn clear_events(&self, _older_than_days: Option<u32>) -> Result<usize> {
+        refuse()
+    }
+    fn load_event_stats(&self, months: usize) -> Result<Vec<EventStat>> {
+        self.inner.load_event_stats(months)
+    }
+    fn save_snapshot(&self, _name: &str, _payload: &str) -> Result<()> {
+        refuse()
+    }
+    fn load_snapshot(&self, name: &str) -> Result<Option<String>> {
+        self.inner.load_snapshot(name)
+    }
+    fn delete_snapshot(&self, _name: &str) -> Result<()> {
+        refuse()
+    }
+    fn search_tasks(
+        &self,
+        q: &str,
+        tags: &[String],
+        project_id: Option<Uuid>,
+        status: StatusFilter,
+    ) -> Result<Vec<Task>> {
+        self.inner.search_tasks(q, tags, project_id, status)
+    }
+    fn search_notes(
+        &self,
+        q: &str,
+        tags: &[String],
+        project_id: Option<Uuid>,
+    ) -> Result<Vec<Note>> {
+        self.inner.search_notes(q, tags, project_id)
+    }
+    fn search_projects(&self, q: &str) -> Result<Vec<Project>> {
+        self.inner.search_projects(q)
+    }
+    fn search_resources(&self, q: &str, tags: &[String]) -> Result<Vec<Resource>> {
+        self.inner.search_resources(q, tags)
+    }
+    fn upsert_task(&self, _task: &Task) -> Result<()> {
+        refuse()
+    }
+    fn upsert_project(&self, _project: &Project) -> Result<()> {
+        refuse()
+    }
+    fn upsert_note(&self, _note: &Note) -> Result<()> {
+        refuse()
+    }
+    fn upsert_resource(&self, _resource: &Resource) -> Result<()> {
+        refuse()
+    }
+    fn location(&self) -> String {
+        format!("{} (read-only)", self.inner.location())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::InMemoryStorage;
+
+    #[test]
+    fn test_reads_pass_through_and_writes_fail() {
+        let task = Task::new("T".into(), Priority::Medium, vec![], None, None, None);
+        let storage = ReadOnlyStorage::new(InMemoryStorage::with_tasks(vec![task.clone()]));
+
+        assert_eq!(storage.load().unwrap().len(), 1);
+        assert!(storage.upsert_task(&task).is_err());
+        assert!(storage.save_all(&[task], &[], &[]).is_err());
+        assert!(
+            storage
+                .record_event(EntityType::Task, Uuid::new_v4(), EventType::Created)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_mutating_command_fails_without_changes() {
+        let task = Task::new("T".into(), Priority::Medium, vec![], None, None, None);
+        let storage = ReadOnlyStorage::new(InMemoryStorage::with_tasks(vec![task]));
+
+        assert!(crate::commands::task::done::execute(&storage, 1).is_err());
+        assert!(!storage.load().unwrap()[0].completed);
+    }
+}
@@ -0,0 +1,212 @@
+//! Recovery flow for a database that can no longer be opened.
+//!
+//! The damaged file is always moved aside as `rustodo.db.corrupt` (with its
+//! `-wal`/`-shm` companions) for inspection. Then, in order:
+//!
+//! 1. Salvage every readable row from the damaged file into a fresh database.
+//! 2. Otherwise, copy the most recent file from `backups/` into place.
+//! 3. Otherwise, leave the path empty so a new database is created.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use rusqlite::{Connection, OpenFlags};
+use uuid::Uuid;
+
+use super::sqlite::{Salvaged, SqliteStorage};
+use super::{Storage, backup};
+use crate::models::Task;
+
+/// What [`recover`] put in place of the damaged database.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Recovery {
+    /// Rows were read back from the damaged file.
+    Salvaged { tasks: usize, notes: usize },
+    /// The latest automatic or manual backup was restored.
+    Backup(PathBuf),
+    /// Nothing could be recovered; a fresh database will be created.
+    Empty,
+}
+
+/// Returns true if `db_path` exists but is not a readable SQLite database.
+pub fn looks_corrupt(db_path: &Path) -> bool {
+    if !db_path.exists() {
+        return false;
+    }
+    let Ok(conn) = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY) else {
+        return true;
+    };
+    conn.query_row("PRAGMA quick_check", [], |row| row.get::<_, String>(0))
+        .map(|r| r != "ok")
+        .unwrap_or(true)
+}
+
+/// Path the damaged database is moved to.
+pub fn corrupt_path(db_path: &Path) -> PathBuf {
+    let mut name = db_path.file_name().unwrap_or_default().to_os_string();
+    name.push(".corrupt");
+    db_path.with_file_name(name)
+}
+
+/// Moves the damaged database aside and rebuilds `db_path` from whatever
+/// can be recovered.
+pub fn recover(db_path: &Path) -> Result<Recovery> {
+    let aside = corrupt_path(db_path);
+    move_aside(db_path, &aside)?;
+
+    let salvaged = SqliteStorage::salvage(&aside);
+    if !salvaged.is_empty() {
+        let (tasks, notes) = (salvaged.tasks.len(), salvaged.notes.len());
+        write_salvaged(db_path, salvaged)?;
+        return Ok(Recovery::Salvaged { tasks, notes });
+    }
+
+    let backup_dir = db_path.parent().unwrap_or(Path::new(".")).join("backups");
+    if let Some(latest) = latest_backup(&backup_dir) {
+        std::fs::copy(&latest, db_path).context("Failed to restore backup")?;
+        backup::mark_restored(db_path, &latest, &HashSet::new())?;
+        return Ok(Recovery::Backup(latest));
+    }
+
+    Ok(Recovery::Empty)
+}
+
+/// Writes salvaged rows into a fresh database, dropping references to rows
+/// that were lost so foreign keys hold.
+fn write_salvaged(db_path: &Path, mut salvaged: Salvaged) -> Result<()> {
+    let task_ids: HashSet<Uuid> = salvaged.tasks.iter().map(|t| t.uuid).collect();
+    let project_ids: HashSet<Uuid> = salvaged.projects.iter().map(|p| p.uuid).collect();
+    let resource_ids: HashSet<Uuid> = salvaged.resources.iter().map(|r| r.uuid).collect();
+
+    for task in &mut salvaged.tasks {
+        task.project_id = task.project_id.filter(|id| project_ids.contains(id));
+        task.parent_id = task.parent_id.filter(|id| task_ids.contains(id));
+        task.depends_on.retain(|id| task_ids.contains(id));
+    }
+    for note in &mut salvaged.notes {
+        note.project_id = note.project_id.filter(|id| project_ids.contains(id));
+        note.task_id = note.task_id.filter(|id| task_ids.contains(id));
+        note.resource_ids.retain(|id| resource_ids.contains(id));
+    }
+
+    // Tasks may reference each other in any order, so insert them bare first.
+    let bare: Vec<Task> = salvaged
+        .tasks
+        .iter()
+        .cloned()
+        .map(|mut t| {
+            t.parent_id = None;
+            t.depends_on.clear();
+            t
+        })
+        .collect();
+
+    let storage = SqliteStorage::open_at(db_path.to_path_buf())?;
+    storage.save_projects(&salvaged.projects)?;
+    storage.save_resources(&salvaged.resources)?;
+    storage.save(&bare)?;
+    storage.save(&salvaged.tasks)?;
+    storage.save_notes(&salvaged.notes)
+}
+
+fn move_aside(db_path: &Path, aside: &Path) -> Result<()> {
+    std::fs::rename(db_path, aside)
+        .with_context(|| format!("Failed to move {} aside", db_path.display()))?;
+    for suffix in ["-wal", "-shm"] {
+        let mut from = db_path.as_os_str().to_os_string();
+        from.push(suffix);
+        let mut to = aside.as_os_str().to_os_string();
+        to.push(suffix);
+        if Path::new(&from).exists() {
+            std::fs::rename(&from, &to)
+                .with_context(|| format!("Failed to move {} aside", Path::new(&from).display()))?;
+        }
+    }
+    Ok(())
+}
+
+fn latest_backup(backup_dir: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(backup_dir)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "db"))
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, TaskSource};
+    use tempfile::TempDir;
+
+    fn write_db(path: &Path, texts: &[&str]) {
+        let storage = SqliteStorage::open_at(path.to_path_buf()).unwrap();
+        let tasks: Vec<Task> = texts
+            .iter()
+            .map(|t| Task::new(t.to_string(), Priority::Medium, vec![], None, None, None))
+            .collect();
+        storage.save(&tasks).unwrap();
+    }
+
+    #[test]
+    fn test_looks_corrupt() {
+        let tmp = TempDir::new().unwrap();
+        let db = tmp.path().join("rustodo.db");
+        assert!(!looks_corrupt(&db));
+        write_db(&db, &["A"]);
+        assert!(!looks_corrupt(&db));
+        std::fs::write(&db, b"definitely not sqlite").unwrap();
+        assert!(looks_corrupt(&db));
+    }
+
+    #[test]
+    fn test_recover_falls_back_to_latest_backup() {
+        let tmp = TempDir::new().unwrap();
+        let backups = tmp.path().join("backups");
+        std::fs::create_dir_all(&backups).unwrap();
+        write_db(&backups.join("2020-01-01_00-00-00.db"), &["Old"]);
+        write_db(&backups.join("2021-01-01_00-00-00.db"), &["New"]);
+
+        let db = tmp.path().join("rustodo.db");
+        std::fs::write(&db, b"garbage").unwrap();
+
+        let outcome = recover(&db).unwrap();
+        assert_eq!(
+            outcome,
+            Recovery::Backup(backups.join("2021-01-01_00-00-00.db"))
+        );
+        assert!(corrupt_path(&db).exists());
+        let storage = SqliteStorage::open_at(db).unwrap();
+        let task = &storage.load().unwrap()[0];
+        assert_eq!(task.text, "New");
+        assert_eq!(
+            task.source,
+            Some(TaskSource::Restored("backup 2021-01-01_00-00-00.db".into()))
+        );
+    }
+
+    #[test]
+    fn test_recover_without_backups_leaves_fresh_path() {
+        let tmp = TempDir::new().unwrap();
+        let db = tmp.path().join("rustodo.db");
+        std::fs::write(&db, b"garbage").unwrap();
+
+        assert_eq!(recover(&db).unwrap(), Recovery::Empty);
+        assert!(!db.exists());
+        assert_eq!(
+            std::fs::read(corrupt_path(&db)).unwrap(),
+            b"garbage".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_salvage_reads_all_rows_of_readable_db() {
+        let tmp = TempDir::new().unwrap();
+        let db = tmp.path().join("rustodo.db");
+        write_db(&db, &["A", "B"]);
+        let salvaged = SqliteStorage::salvage(&db);
+        assert_eq!(salvaged.tasks.len(), 2);
+    }
+}
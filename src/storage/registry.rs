@@ -0,0 +1,240 @@
+//! Named storage backends selected by the `storage` config key.
+//!
+//! A backend is a factory that turns an option string into a boxed
+//! [`Storage`]. The config value `storage = "name:opts"` picks the factory
+//! registered under `name` and passes it everything after the first `:`
+//! (or an empty string).
+//!
+//! | Built-in   | Options                                   |
+//! |------------|-------------------------------------------|
+//! | `sqlite`   | Database path (default: the data directory) |
+//! | `memory`   | None — data is lost on exit               |
+//!
+//! Third-party backends are added by building a custom binary on top of the
+//! `rustodo` library:
+//!
+//! ```no_run
+//! use rustodo::storage::{InMemoryStorage, registry::StorageRegistry};
+//!
+//! let mut registry = StorageRegistry::with_builtins();
+//! registry.register("scratch", |_opts| Ok(Box::new(InMemoryStorage::default())));
+//! let storage = registry.open("scratch:").unwrap();
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::{Result, bail};
+use uuid::Uuid;
+
+use super::{EntityType, EventStat, EventType, InMemoryStorage, SqliteStorage, Storage};
+use crate::models::{Note, Project, Resource, StatusFilter, Task};
+
+/// Builds a backend from the option part of a `name:opts` spec.
+pub type StorageFactory = fn(opts: &str) -> Result<Box<dyn Storage>>;
+
+/// Maps backend names to their factories.
+#[derive(Default)]
+pub struct StorageRegistry {
+    factories: BTreeMap<String, StorageFactory>,
+}
+
+impl StorageRegistry {
+    /// A registry containing the `sqlite` and `memory` backends.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::default();
+        registry.register("sqlite", |opts| {
+            let storage = if opts.is_empty() {
+                SqliteStorage::new()?
+            } else {
+                SqliteStorage::open_at(PathBuf::from(opts))?
+            };
+            Ok(Box::new(storage))
+        });
+        registry.register("memory", |_| Ok(Box::new(InMemoryStorage::default())));
+        registry
+    }
+
+    /// Registers (or replaces) the backend called `name`.
+    pub fn register(&mut self, name: &str, factory: StorageFactory) {
+        self.factories.insert(name.to_string(), factory);
+    }
+
+    /// Registered backend names, sorted.
+    pub fn names(&self) -> Vec<&str> {
+        self.factories.keys().map(String::as_str).collect()
+    }
+
+    /// Opens the backend described by `spec` (`name` or `name:opts`).
+    pub fn open(&self, spec: &str) -> Result<Box<dyn Storage>> {
+        let (name, opts) = spec.split_once(':').unwrap_or((spec, ""));
+        let Some(factory) = self.factories.get(name.trim()) else {
+            bail!(
+                "Unknown storage backend '{}' (available: {})",
+                name,
+                self.names().join(", ")
+            );
+        };
+        factory(opts.trim())
+    }
+}
+
+// ── Box<dyn Storage> ──────────────────────────────────────────────────────────
+
+/// Lets a boxed backend be passed wherever `&impl Storage` is expected.
+/// Every method forwards, so backend overrides of default methods are kept.
+impl<S: Storage + ?Sized> Storage for Box<S> {
+    fn load(&self) -> Result<Vec<Task>> {
+        (**self).load()
+    }
+    fn save(&self, tasks: &[Task]) -> Result<()> {
+        (**self).save(tasks)
+    }
+    fn delete_tasks(&self, uuids: &[Uuid]) -> Result<()> {
+        (**self).delete_tasks(uuids)
+    }
+    fn load_projects(&self) -> Result<Vec<Project>> {
+        (**self).load_projects()
+    }
+    fn save_projects(&self, projects: &[Project]) -> Result<()> {
+        (**self).save_projects(projects)
+    }
+    fn delete_projects(&self, uuids: &[Uuid]) -> Result<()> {
+        (**self).delete_projects(uuids)
+    }
+    fn load_notes(&self) -> Result<Vec<Note>> {
+        (**self).load_notes()
+    }
+    fn save_notes(&self, notes: &[Note]) -> Result<()> {
+        (**self).save_notes(notes)
+    }
+    fn delete_notes(&self, uuids: &[Uuid]) -> Result<()> {
+        (**self).delete_notes(uuids)
+    }
+    fn load_resources(&self) -> Result<Vec<Resource>> {
+        (**self).load_resources()
+    }
+    fn save_resources(&self, resources: &[Resource]) -> Result<()> {
+        (**self).save_resources(resources)
+    }
+    fn delete_resources(&self, uuids: &[Uuid]) -> Result<()> {
+        (**self).delete_resources(uuids)
+    }
+    fn record_event(
+        &self,
+        entity_type: EntityType,
+        entity_uuid: Uuid,
+        event_type: EventType,
+    ) -> Result<()> {
+        (**self).record_event(entity_type, entity_uuid, event_type)
+    }
+    fn clear_events(&self, older_than_days: Option<u32>) -> Result<usize> {
+        (**self).clear_events(older_than_days)
+    }
+    fn load_event_stats(&self, months: usize) -> Result<Vec<EventStat>> {
+        (**self).load_event_stats(months)
+    }
+    fn save_snapshot(&self, name: &str, payload: &str) -> Result<()> {
+        (**self).save_snapshot(name, payload)
+    }
+    fn load_snapshot(&self, name: &str) -> Result<Option<String>> {
+        (**self).load_snapshot(name)
+    }
+    fn delete_snapshot(&self, name: &str) -> Result<()> {
+        (**self).delete_snapshot(name)
+    }
+    fn search_tasks(
+        &self,
+        q: &str,
+        tags: &[String],
+        project_id: Option<Uuid>,
+        status: StatusFilter,
+    ) -> Result<Vec<Task>> {
+        (**self).search_tasks(q, tags, project_id, status)
+    }
+    fn search_notes(
+        &self,
+        q: &str,
+        tags: &[String],
+        project_id: Option<Uuid>,
+    ) -> Result<Vec<Note>> {
+        (**self).search_notes(q, tags, project_id)
+    }
+    fn search_projects(&self, q: &str) -> Result<Vec<Project>> {
+        (**self).search_projects(q)
+    }
+    fn search_resources(&self, q: &str, tags: &[String]) -> Result<Vec<Resource>> {
+        (**self).search_resources(q, tags)
+    }
+    fn upsert_task(&self, task: &Task) -> Result<()> {
+        (**self).upsert_task(task)
+    }
+    fn upsert_project(&self, project: &Project) -> Result<()> {
+        (**self).upsert_project(project)
+    }
+    fn upsert_note(&self, note: &Note) -> Result<()> {
+        (**self).upsert_note(note)
+    }
+    fn upsert_resource(&self, resource: &Resource) -> Result<()> {
+        (**self).upsert_resource(resource)
+    }
+    fn load_all(&self) -> Result<(Vec<Task>, Vec<Project>, Vec<Note>)> {
+        (**self).load_all()
+    }
+    fn load_all_with_resources(
+        &self,
+    ) -> Result<(Vec<Task>, Vec<Project>, Vec<Note>, Vec<Resource>)> {
+        (**self).load_all_with_resources()
+    }
+    fn save_all(&self, tasks: &[Task], projects: &[Project], notes: &[Note]) -> Result<()> {
+        (**self).save_all(tasks, projects, notes)
+    }
+    fn location(&self) -> String {
+        (**self).location()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+
+    #[test]
+    fn test_open_builtin_memory() {
+        let storage = StorageRegistry::with_builtins().open("memory").unwrap();
+        assert_eq!(storage.location(), "memory");
+    }
+
+    #[test]
+    fn test_open_sqlite_with_path_option() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("custom.db");
+        let spec = format!("sqlite:{}", path.display());
+        let storage = StorageRegistry::with_builtins().open(&spec).unwrap();
+        assert_eq!(storage.location(), path.display().to_string());
+    }
+
+    #[test]
+    fn test_register_custom_backend_and_use_boxed_storage() {
+        let mut registry = StorageRegistry::with_builtins();
+        registry.register("custom", |opts| {
+            assert_eq!(opts, "a=1");
+            Ok(Box::new(InMemoryStorage::default()))
+        });
+        let storage = registry.open("custom:a=1").unwrap();
+        let task = Task::new("T".into(), Priority::Medium, vec![], None, None, None);
+        storage.upsert_task(&task).unwrap();
+        assert_eq!(storage.load().unwrap().len(), 1);
+        assert_eq!(registry.names(), vec!["custom", "memory", "sqlite"]);
+    }
+
+    #[test]
+    fn test_unknown_backend_lists_available() {
+        let err = StorageRegistry::with_builtins()
+            .open("redis:localhost")
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("redis") && err.contains("sqlite"));
+    }
+}
@@ -0,0 +1,586 @@
+//! One writer shared by every command while `todo remind --daemon` runs.
+//!
+//! The daemon listens on `rustodo.sock` next to the database and answers
+//! storage calls on its own connection. Commands that can connect to the
+//! socket use [`SocketStorage`] instead of opening the file, so the daemon
+//! is the only process holding the SQLite write lock; when no daemon is
+//! running they fall back to opening the database directly.
+//!
+//! Each call is one connection carrying one JSON request line and one JSON
+//! response line. Answers carry the daemon's [`Revision`] before and after
+//! the call. A client remembers the one from its last load, moved forward
+//! past its own writes, and bulk saves send it back, so a save still fails
+//! instead of overwriting what another command wrote in between — just as
+//! it does with a direct connection.
+
+use std::cell::Cell;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result, bail};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::sqlite::{Revision, SqliteStorage};
+use super::{EntityType, EventStat, EventType, Storage};
+use crate::models::{Note, Project, Resource, Task};
+
+/// How long either side waits on a silent peer before giving up.
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Socket the daemon serves for the database at `db_path`.
+pub fn socket_path(db_path: &Path) -> PathBuf {
+    db_path.with_file_name("rustodo.sock")
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum Request {
+    Load,
+    Save {
+        tasks: Vec<Task>,
+        seen: Option<Revision>,
+    },
+    DeleteTasks {
+        uuids: Vec<Uuid>,
+    },
+    UpsertTask {
+        task: Box<Task>,
+    },
+    LoadProjects,
+    SaveProjects {
+        projects: Vec<Project>,
+        seen: Option<Revision>,
+    },
+    DeleteProjects {
+        uuids: Vec<Uuid>,
+    },
+    UpsertProject {
+        project: Project,
+    },
+    LoadNotes,
+    SaveNotes {
+        notes: Vec<Note>,
+        seen: Option<Revision>,
+    },
+    DeleteNotes {
+        uuids: Vec<Uuid>,
+    },
+    UpsertNote {
+        note: Note,
+    },
+    LoadResources,
+    SaveResources {
+        resources: Vec<Resource>,
+        seen: Option<Revision>,
+    },
+    DeleteResources {
+        uuids: Vec<Uuid>,
+    },
+    UpsertResource {
+        resource: Resource,
+    },
+    RecordEvent {
+        entity_type: String,
+        entity_uuid: Uuid,
+        event_type: String,
+    },
+    ClearEvents {
+        older_than_days: Option<u32>,
+    },
+    LoadEventStats {
+        months: usize,
+    },
+    SaveSnapshot {
+        name: String,
+        payload: String,
+    },
+    LoadSnapshot {
+        name: String,
+    },
+    DeleteSnapshot {
+        name: String,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Response {
+    Ok {
+        value: Value,
+        before: Revision,
+        after: Revision,
+    },
+    Err {
+        message: String,
+    },
+}
+
+// ── Server ────────────────────────────────────────────────────────────────────
+
+/// The daemon's end: owns the socket and answers calls on `storage`.
+pub struct Server<'a> {
+    storage: &'a SqliteStorage,
+    path: PathBuf,
+    clients: Receiver<UnixStream>,
+}
+
+impl<'a> Server<'a> {
+    /// Starts listening on `path`. Fails if another daemon already serves it;
+    /// a socket file left behind by one that was killed is replaced.
+    pub fn bind(storage: &'a SqliteStorage, path: &Path) -> Result<Self> {
+        if path.exists() {
+            if UnixStream::connect(path).is_ok() {
+                bail!(
+                    "Another `todo remind --daemon` is already running ({})",
+                    path.display()
+                );
+            }
+            std::fs::remove_file(path)
+                .with_context(|| format!("Failed to remove stale socket {}", path.display()))?;
+        }
+        let listener = UnixListener::bind(path)
+            .with_context(|| format!("Failed to listen on {}", path.display()))?;
+
+        // Accepting blocks, so it runs on its own thread; the storage stays
+        // on this one and calls are answered in order of arrival.
+        let (tx, clients) = mpsc::channel();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                if tx.send(stream).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            storage,
+            path: path.to_path_buf(),
+            clients,
+        })
+    }
+
+    /// Answers calls until `duration` has passed.
+    pub fn serve_for(&self, duration: Duration) {
+        let deadline = Instant::now() + duration;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.clients.recv_timeout(remaining) {
+                Ok(stream) => {
+                    // One client failing (bad request, dropped connection) must not stop the daemon
+                    if let Err(e) = self.serve(stream) {
+                        eprintln!("shared storage: {:#}", e);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => return,
+                Err(RecvTimeoutError::Disconnected) => {
+                    std::thread::sleep(remaining);
+                    return;
+                }
+            }
+        }
+    }
+
+    fn serve(&self, stream: UnixStream) -> Result<()> {
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+        let mut writer = stream.try_clone()?;
+        let mut line = String::new();
+        // `SocketStorage::connect` probes with an empty connection
+        if BufReader::new(stream).read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        let request: Request = serde_json::from_str(&line).context("Invalid request")?;
+
+        let before = self.storage.revision()?;
+        let response = match self.handle(request) {
+            Ok(value) => Response::Ok {
+                value,
+                before,
+                after: self.storage.revision()?,
+            },
+            Err(e) => Response::Err {
+                message: format!("{:#}", e),
+            },
+        };
+        serde_json::to_writer(&mut writer, &response)?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    fn handle(&self, request: Request) -> Result<Value> {
+        let storage = self.storage;
+        let value = match request {
+            Request::Load => serde_json::to_value(storage.load()?)?,
+            Request::Save { tasks, seen } => {
+                storage.expect_revision(seen)?;
+                unit(storage.save(&tasks)?)
+            }
+            Request::DeleteTasks { uuids } => unit(storage.delete_tasks(&uuids)?),
+            Request::UpsertTask { task } => unit(storage.upsert_task(&task)?),
+            Request::LoadProjects => serde_json::to_value(storage.load_projects()?)?,
+            Request::SaveProjects { projects, seen } => {
+                storage.expect_revision(seen)?;
+                unit(storage.save_projects(&projects)?)
+            }
+            Request::DeleteProjects { uuids } => unit(storage.delete_projects(&uuids)?),
+            Request::UpsertProject { project } => unit(storage.upsert_project(&project)?),
+            Request::LoadNotes => serde_json::to_value(storage.load_notes()?)?,
+            Request::SaveNotes { notes, seen } => {
+                storage.expect_revision(seen)?;
+                unit(storage.save_notes(&notes)?)
+            }
+            Request::DeleteNotes { uuids } => unit(storage.delete_notes(&uuids)?),
+            Request::UpsertNote { note } => unit(storage.upsert_note(&note)?),
+            Request::LoadResources => serde_json::to_value(storage.load_resources()?)?,
+            Request::SaveResources { resources, seen } => {
+                storage.expect_revision(seen)?;
+                unit(storage.save_resources(&resources)?)
+            }
+            Request::DeleteResources { uuids } => unit(storage.delete_resources(&uuids)?),
+            Request::UpsertResource { resource } => unit(storage.upsert_resource(&resource)?),
+            Request::RecordEvent {
+                entity_type,
+                entity_uuid,
+                event_type,
+            } => unit(storage.record_event(
+                entity_type_from(&entity_type)?,
+                entity_uuid,
+                event_type_from(&event_type)?,
+            )?),
+            Request::ClearEvents { older_than_days } => {
+                serde_json::to_value(storage.clear_events(older_than_days)?)?
+            }
+            Request::LoadEventStats { months } => {
+                serde_json::to_value(storage.load_event_stats(months)?)?
+            }
+            Request::SaveSnapshot { name, payload } => {
+                unit(storage.save_snapshot(&name, &payload)?)
+            }
+            Request::LoadSnapshot { name } => serde_json::to_value(storage.load_snapshot(&name)?)?,
+            Request::DeleteSnapshot { name } => unit(storage.delete_snapshot(&name)?),
+        };
+        Ok(value)
+    }
+}
+
+impl Drop for Server<'_> {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+fn unit(_: ()) -> Value {
+    Value::Null
+}
+
+fn entity_type_from(name: &str) -> Result<EntityType> {
+    Ok(match name {
+        "task" => EntityType::Task,
+        "project" => EntityType::Project,
+        "note" => EntityType::Note,
+        "resource" => EntityType::Resource,
+        _ => bail!("Unknown entity type '{}'", name),
+    })
+}
+
+fn event_type_from(name: &str) -> Result<EventType> {
+    Ok(match name {
+        "created" => EventType::Created,
+        "completed" => EventType::Completed,
+        "uncompleted" => EventType::Uncompleted,
+        "edited" => EventType::Edited,
+        "deleted" => EventType::Deleted,
+        "purged" => EventType::Purged,
+        "cancelled" => EventType::Cancelled,
+        _ => bail!("Unknown event type '{}'", name),
+    })
+}
+
+// ── Client ────────────────────────────────────────────────────────────────────
+
+/// A command's end: forwards every storage call to the daemon.
+pub struct SocketStorage {
+    path: PathBuf,
+    /// Revision of the most recent load, moved past this client's own writes.
+    seen: Cell<Option<Revision>>,
+}
+
+impl SocketStorage {
+    /// Connects to the daemon serving `path`, or `None` if none is running.
+    pub fn connect(path: &Path) -> Option<Self> {
+        UnixStream::connect(path).ok()?;
+        Some(Self {
+            path: path.to_path_buf(),
+            seen: Cell::new(None),
+        })
+    }
+
+    fn call<T: DeserializeOwned>(&self, request: &Request) -> Result<T> {
+        let (value, before, after) = self.send(request)?;
+        let load = matches!(
+            request,
+            Request::Load | Request::LoadProjects | Request::LoadNotes | Request::LoadResources
+        );
+        // A direct connection does not see its own commits as changes either
+        if load || self.seen.get() == Some(before) {
+            self.seen.set(Some(after));
+        }
+        serde_json::from_value(value).context("Invalid response from the reminder daemon")
+    }
+
+    fn send(&self, request: &Request) -> Result<(Value, Revision, Revision)> {
+        let lost = || {
+            format!(
+                "Lost connection to the reminder daemon ({})",
+                self.path.display()
+            )
+        };
+        let mut stream = UnixStream::connect(&self.path).with_context(lost)?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+        serde_json::to_writer(&mut stream, request).with_context(lost)?;
+        stream.write_all(b"\n").with_context(lost)?;
+        let mut line = String::new();
+        BufReader::new(stream)
+            .read_line(&mut line)
+            .with_context(lost)?;
+
+        match serde_json::from_str(&line).with_context(lost)? {
+            Response::Ok {
+                value,
+                before,
+                after,
+            } => Ok((value, before, after)),
+            Response::Err { message } => bail!(message),
+        }
+    }
+}
+
+impl Storage for SocketStorage {
+    fn load(&self) -> Result<Vec<Task>> {
+        self.call(&Request::Load)
+    }
+    fn save(&self, tasks: &[Task]) -> Result<()> {
+        self.call(&Request::Save {
+            tasks: tasks.to_vec(),
+            seen: self.seen.get(),
+        })
+    }
+    fn delete_tasks(&self, uuids: &[Uuid]) -> Result<()> {
+        self.call(&Request::DeleteTasks {
+            uuids: uuids.to_vec(),
+        })
+    }
+    fn upsert_task(&self, task: &Task) -> Result<()> {
+        self.call(&Request::UpsertTask {
+            task: Box::new(task.clone()),
+        })
+    }
+    fn load_projects(&self) -> Result<Vec<Project>> {
+        self.call(&Request::LoadProjects)
+    }
+    fn save_projects(&self, projects: &[Project]) -> Result<()> {
+        self.call(&Request::SaveProjects {
+            projects: projects.to_vec(),
+            seen: self.seen.get(),
+        })
+    }
+    fn delete_projects(&self, uuids: &[Uuid]) -> Result<()> {
+        self.call(&Request::DeleteProjects {
+            uuids: uuids.to_vec(),
+        })
+    }
+    fn upsert_project(&self, project: &Project) -> Result<()> {
+        self.call(&Request::UpsertProject {
+            project: project.clone(),
+        })
+    }
+    fn load_notes(&self) -> Result<Vec<Note>> {
+        self.call(&Request::LoadNotes)
+    }
+    fn save_notes(&self, notes: &[Note]) -> Result<()> {
+        self.call(&Request::SaveNotes {
+            notes: notes.to_vec(),
+            seen: self.seen.get(),
+        })
+    }
+    fn delete_notes(&self, uuids: &[Uuid]) -> Result<()> {
+        self.call(&Request::DeleteNotes {
+            uuids: uuids.to_vec(),
+        })
+    }
+    fn upsert_note(&self, note: &Note) -> Result<()> {
+        self.call(&Request::UpsertNote { note: note.clone() })
+    }
+    fn load_resources(&self) -> Result<Vec<Resource>> {
+        self.call(&Request::LoadResources)
+    }
+    fn save_resources(&self, resources: &[Resource]) -> Result<()> {
+        self.call(&Request::SaveResources {
+            resources: resources.to_vec(),
+            seen: self.seen.get(),
+        })
+    }
+    fn delete_resources(&self, uuids: &[Uuid]) -> Result<()> {
+        self.call(&Request::DeleteResources {
+            uuids: uuids.to_vec(),
+        })
+    }
+    fn upsert_resource(&self, resource: &Resource) -> Result<()> {
+        self.call(&Request::UpsertResource {
+            resource: resource.clone(),
+        })
+    }
+    fn record_event(
+        &self,
+        entity_type: EntityType,
+        entity_uuid: Uuid,
+        event_type: EventType,
+    ) -> Result<()> {
+        self.call(&Request::RecordEvent {
+            entity_type: entity_type.as_str().to_string(),
+            entity_uuid,
+            event_type: event_type.as_str().to_string(),
+        })
+    }
+    fn clear_events(&self, older_than_days: Option<u32>) -> Result<usize> {
+        self.call(&Request::ClearEvents { older_than_days })
+    }
+    fn load_event_stats(&self, months: usize) -> Result<Vec<EventStat>> {
+        self.call(&Request::LoadEventStats { months })
+    }
+    fn save_snapshot(&self, name: &str, payload: &str) -> Result<()> {
+        self.call(&Request::SaveSnapshot {
+            name: name.to_string(),
+            payload: payload.to_string(),
+        })
+    }
+    fn load_snapshot(&self, name: &str) -> Result<Option<String>> {
+        self.call(&Request::LoadSnapshot {
+            name: name.to_string(),
+        })
+    }
+    fn delete_snapshot(&self, name: &str) -> Result<()> {
+        self.call(&Request::DeleteSnapshot {
+            name: name.to_string(),
+        })
+    }
+    fn location(&self) -> String {
+        format!("reminder daemon at {}", self.path.display())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tempfile::TempDir;
+
+    /// Runs a daemon-side server on its own thread until the guard drops.
+    struct Daemon {
+        stop: Arc<AtomicBool>,
+        thread: Option<std::thread::JoinHandle<()>>,
+    }
+
+    impl Drop for Daemon {
+        fn drop(&mut self) {
+            self.stop.store(true, Ordering::Relaxed);
+            self.thread.take().unwrap().join().unwrap();
+        }
+    }
+
+    fn start(tmp: &TempDir) -> (Daemon, PathBuf) {
+        let db = tmp.path().join("rustodo.db");
+        let socket = socket_path(&db);
+        let stop = Arc::new(AtomicBool::new(false));
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let flag = stop.clone();
+        let path = socket.clone();
+        let thread = std::thread::spawn(move || {
+            let storage = SqliteStorage::with_path(db).unwrap();
+            let server = Server::bind(&storage, &path).unwrap();
+            ready_tx.send(()).unwrap();
+            while !flag.load(Ordering::Relaxed) {
+                server.serve_for(Duration::from_millis(20));
+            }
+        });
+        ready_rx.recv().unwrap();
+        let daemon = Daemon {
+            stop,
+            thread: Some(thread),
+        };
+        (daemon, socket)
+    }
+
+    fn make_task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    #[test]
+    fn test_calls_go_through_the_daemon() {
+        let tmp = TempDir::new().unwrap();
+        let (_daemon, socket) = start(&tmp);
+        let client = SocketStorage::connect(&socket).unwrap();
+
+        client.save(&[make_task("Shared")]).unwrap();
+        client
+            .record_event(EntityType::Task, Uuid::new_v4(), EventType::Created)
+            .unwrap();
+        client.save_snapshot("s", "payload").unwrap();
+
+        assert_eq!(client.load().unwrap()[0].text, "Shared");
+        assert_eq!(
+            client.load_snapshot("s").unwrap().as_deref(),
+            Some("payload")
+        );
+        assert_eq!(client.clear_events(None).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_save_after_another_clients_write_fails() {
+        let tmp = TempDir::new().unwrap();
+        let (_daemon, socket) = start(&tmp);
+        let first = SocketStorage::connect(&socket).unwrap();
+        let second = SocketStorage::connect(&socket).unwrap();
+        first.save(&[make_task("A")]).unwrap();
+
+        let mut mine = first.load().unwrap();
+        let mut theirs = second.load().unwrap();
+        theirs[0].text = "Theirs".into();
+        second.save(&theirs).unwrap();
+
+        mine[0].text = "Mine".into();
+        let err = first.save(&mine).unwrap_err();
+        assert!(err.to_string().contains("modified by another process"));
+        assert_eq!(first.load().unwrap()[0].text, "Theirs");
+    }
+
+    #[test]
+    fn test_own_writes_do_not_trip_detection() {
+        let tmp = TempDir::new().unwrap();
+        let (_daemon, socket) = start(&tmp);
+        let client = SocketStorage::connect(&socket).unwrap();
+        client.save(&[make_task("A")]).unwrap();
+
+        let mut tasks = client.load().unwrap();
+        tasks[0].text = "B".into();
+        client.upsert_task(&tasks[0]).unwrap();
+        client.save_snapshot("s", "payload").unwrap();
+        client.save(&tasks).unwrap();
+    }
+
+    #[test]
+    fn test_connect_without_daemon_is_none() {
+        let tmp = TempDir::new().unwrap();
+        assert!(SocketStorage::connect(&tmp.path().join("rustodo.sock")).is_none());
+    }
+}
@@ -15,6 +15,20 @@
 //! `tx.commit()`. This guarantees atomicity: either all rows are written or
 //! none are, preventing partial updates that could corrupt relational integrity.
 //!
+//! # Concurrent modification
+//!
+//! Commands load, mutate in memory, then save. Each load remembers SQLite's
+//! `PRAGMA data_version`, which only changes when *another* connection
+//! commits. The bulk `save*` methods re-read it inside an immediate
+//! transaction and refuse to write if it moved, so a second process's changes
+//! are never silently overwritten. Single-row upserts are not checked — they
+//! cannot clobber unrelated rows.
+//!
+//! While `todo remind --daemon` runs, other commands send their storage
+//! calls to it instead of opening the file, so it is the only writer. It
+//! applies the same check on their behalf through [`SqliteStorage::revision`];
+//! see [`super::shared`].
+//!
 //! # Event log
 //!
 //! Every domain action (create, complete, delete, etc.) records a row in the
@@ -22,23 +36,24 @@
 //! physically removes tombstones — the event log is append-only and never
 //! cleaned up automatically.
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::path::PathBuf;
 
-use anyhow::{Context, Result};
-use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeZone, Utc};
 use directories::ProjectDirs;
 use rusqlite::{
-    Connection, Row, params,
+    Connection, Row, TransactionBehavior, params,
     types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef},
 };
-use serde::{Serialize, de::DeserializeOwned};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use uuid::Uuid;
 
 use super::{EntityType, EventStat, EventType, Storage};
 use crate::models::StatusFilter;
 use crate::models::{
-    Difficulty, Note, NoteFormat, Priority, Project, Recurrence, Resource, ResourceType, Task,
+    CatchUpPolicy, Difficulty, Label, MonthEndPolicy, Note, NoteFormat, Priority, Project,
+    Recurrence, Resource, ResourceType, Task, TimeEntry,
 };
 
 // ── JsonVec<T> ────────────────────────────────────────────────────────────────
@@ -88,6 +103,8 @@ fn opt_from_unix(ts: Option<i64>) -> Option<DateTime<Utc>> {
 pub struct SqliteStorage {
     conn: RefCell<Connection>,
     path: PathBuf,
+    /// `PRAGMA data_version` observed by the most recent load.
+    loaded_version: Cell<Option<i64>>,
 }
 
 impl SqliteStorage {
@@ -101,13 +118,39 @@ impl SqliteStorage {
         Self::open_at(path)
     }
 
-    fn open_at(path: PathBuf) -> Result<Self> {
+    /// Opens an existing database without creating, migrating, or writing
+    /// anything — for data files on read-only mounts. Fails if the database
+    /// still needs a migration, since the current code cannot read it as is.
+    pub fn open_read_only() -> Result<Self> {
+        Self::open_read_only_at(get_db_path()?)
+    }
+
+    fn open_read_only_at(path: PathBuf) -> Result<Self> {
+        let conn = Connection::open_with_flags(&path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .with_context(|| format!("Failed to open {} read-only", path.display()))?;
+        if needs_migration(&conn)? {
+            bail!(
+                "{} was written by an older version and must be migrated. \
+                 Run any todo command once without --read-only first.",
+                path.display()
+            );
+        }
+        Ok(Self {
+            conn: RefCell::new(conn),
+            path,
+            loaded_version: Cell::new(None),
+        })
+    }
+
+    /// Opens (creating if needed) the database at `path`.
+    pub fn open_at(path: PathBuf) -> Result<Self> {
         let conn = Connection::open(&path).context("Failed to open SQLite database")?;
         conn.execute_batch("PRAGMA foreign_keys = ON; PRAGMA journal_mode = WAL;")
             .context("Failed to set SQLite pragmas")?;
         let storage = Self {
             conn: RefCell::new(conn),
             path,
+            loaded_version: Cell::new(None),
         };
         storage.initialize()?;
         Ok(storage)
@@ -118,8 +161,175 @@ impl SqliteStorage {
             .borrow()
             .execute_batch(SCHEMA)
             .context("Failed to initialize schema")?;
+        self.migrate()
+    }
+
+    /// Adds columns introduced after the initial schema to existing databases,
+    /// then runs any [`DATA_MIGRATIONS`] newer than the stored `user_version`.
+    fn migrate(&self) -> Result<()> {
+        for (table, column, decl) in MIGRATIONS {
+            self.ensure_column(table, column, decl)?;
+        }
+
+        let mut conn = self.conn.borrow_mut();
+        let current: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        for (version, migration) in DATA_MIGRATIONS.iter().enumerate() {
+            let version = version as i64 + 1;
+            if version <= current {
+                continue;
+            }
+            let tx = conn.transaction()?;
+            migration(&tx).with_context(|| format!("Failed to run data migration {}", version))?;
+            tx.pragma_update(None, "user_version", version)?;
+            tx.commit()?;
+        }
+        Ok(())
+    }
+
+    fn ensure_column(&self, table: &str, column: &str, decl: &str) -> Result<()> {
+        let conn = self.conn.borrow();
+        if !has_column(&conn, table, column)? {
+            conn.execute_batch(&format!(
+                "ALTER TABLE {} ADD COLUMN {} {}",
+                table, column, decl
+            ))
+            .with_context(|| format!("Failed to add column {}.{}", table, column))?;
+        }
         Ok(())
     }
+
+    fn remember_version(&self, conn: &Connection) -> Result<()> {
+        self.loaded_version.set(Some(data_version(conn)?));
+        Ok(())
+    }
+
+    /// Fails if another process committed since the last load.
+    fn ensure_unchanged(&self, conn: &Connection) -> Result<()> {
+        if let Some(seen) = self.loaded_version.get()
+            && data_version(conn)? != seen
+        {
+            bail!(MODIFIED_ELSEWHERE);
+        }
+        Ok(())
+    }
+
+    /// The database state as this connection sees it. Unlike `data_version`
+    /// alone, it also moves when this connection commits.
+    pub fn revision(&self) -> Result<Revision> {
+        let conn = self.conn.borrow();
+        Ok(Revision {
+            data_version: data_version(&conn)?,
+            own_changes: conn.total_changes(),
+        })
+    }
+
+    /// Makes the next bulk `save*` check against `seen` instead of this
+    /// connection's last load, for a caller that loaded through it earlier.
+    /// Fails at once if this connection has written since; `None` skips the
+    /// check, as for a caller that never loaded.
+    pub fn expect_revision(&self, seen: Option<Revision>) -> Result<()> {
+        if let Some(seen) = seen
+            && self.conn.borrow().total_changes() != seen.own_changes
+        {
+            bail!(MODIFIED_ELSEWHERE);
+        }
+        self.loaded_version.set(seen.map(|r| r.data_version));
+        Ok(())
+    }
+}
+
+const MODIFIED_ELSEWHERE: &str = "The database was modified by another process since it was loaded. \
+     Reload and retry the command.";
+
+/// A point in the database's history, from [`SqliteStorage::revision`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Revision {
+    data_version: i64,
+    own_changes: u64,
+}
+
+fn has_column(conn: &Connection, table: &str, column: &str) -> Result<bool> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>("name"))?
+        .filter_map(|r| r.ok())
+        .any(|name| name == column);
+    Ok(exists)
+}
+
+/// Whether [`SqliteStorage::migrate`] still has columns to add or data
+/// migrations to run on this database.
+fn needs_migration(conn: &Connection) -> Result<bool> {
+    let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    if version < DATA_MIGRATIONS.len() as i64 {
+        return Ok(true);
+    }
+    for (table, column, _) in MIGRATIONS {
+        if !has_column(conn, table, column)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Rows that could still be read from a damaged database.
+#[derive(Debug, Default)]
+pub struct Salvaged {
+    pub tasks: Vec<Task>,
+    pub projects: Vec<Project>,
+    pub notes: Vec<Note>,
+    pub resources: Vec<Resource>,
+}
+
+impl Salvaged {
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+            && self.projects.is_empty()
+            && self.notes.is_empty()
+            && self.resources.is_empty()
+    }
+}
+
+impl SqliteStorage {
+    /// Reads every row it can from a possibly corrupted database, in rowid
+    /// order, stopping at the first unreadable row of each table.
+    pub fn salvage(path: &std::path::Path) -> Salvaged {
+        let Ok(conn) =
+            Connection::open_with_flags(path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+        else {
+            return Salvaged::default();
+        };
+
+        fn rows<T>(
+            conn: &Connection,
+            table: &str,
+            map: impl FnMut(&Row) -> rusqlite::Result<T>,
+        ) -> Vec<T> {
+            let Ok(mut stmt) = conn.prepare(&format!("SELECT * FROM {} ORDER BY rowid", table))
+            else {
+                return Vec::new();
+            };
+            let Ok(iter) = stmt.query_map([], map) else {
+                return Vec::new();
+            };
+            iter.map_while(|r| r.ok()).collect()
+        }
+
+        Salvaged {
+            tasks: rows(&conn, "tasks", |row| {
+                let uuid_str: String = row.get("uuid")?;
+                row_to_task(row, &conn, &uuid_str)
+            }),
+            projects: rows(&conn, "projects", row_to_project),
+            notes: rows(&conn, "notes", |row| row_to_note(row, &conn)),
+            resources: rows(&conn, "resources", row_to_resource),
+        }
+    }
+}
+
+fn data_version(conn: &Connection) -> Result<i64> {
+    conn.query_row("PRAGMA data_version", [], |row| row.get(0))
+        .context("Failed to read database version")
 }
 
 // ── schema ────────────────────────────────────────────────────────────────────
@@ -200,10 +410,17 @@ CREATE TABLE IF NOT EXISTS events (
     id          INTEGER PRIMARY KEY AUTOINCREMENT,
     entity_type TEXT NOT NULL CHECK(entity_type IN ('task','project','note','resource')),
     entity_uuid TEXT NOT NULL,
-    event_type  TEXT NOT NULL CHECK(event_type IN ('created','completed','uncompleted','edited','deleted','purged')),
+    event_type  TEXT NOT NULL CHECK(event_type IN ('created','completed','uncompleted','edited','deleted','purged','cancelled')),
     occurred_at INTEGER NOT NULL
 );
 
+-- Named recovery snapshots (e.g. the task list removed by 'todo clear').
+CREATE TABLE IF NOT EXISTS snapshots (
+    name        TEXT PRIMARY KEY NOT NULL,
+    payload     TEXT NOT NULL,
+    created_at  INTEGER NOT NULL
+);
+
 CREATE INDEX IF NOT EXISTS idx_tasks_active
     ON tasks(created_at) WHERE deleted_at IS NULL;
 CREATE INDEX IF NOT EXISTS idx_tasks_project_active
@@ -228,6 +445,81 @@ CREATE INDEX IF NOT EXISTS idx_events_entity
     ON events(entity_uuid);
 ";
 
+/// Columns added after the initial schema: `(table, column, declaration)`.
+///
+/// Applied on every open by [`SqliteStorage::migrate`]; each entry is a no-op
+/// once the column exists.
+const MIGRATIONS: &[(&str, &str, &str)] = &[
+    ("tasks", "jira_key", "TEXT"),
+    ("tasks", "source", "TEXT"),
+    ("tasks", "wait_until", "TEXT"),
+    ("tasks", "month_end", "TEXT"),
+    ("tasks", "attachments", "TEXT NOT NULL DEFAULT '[]'"),
+    ("tasks", "label", "TEXT"),
+    ("tasks", "time_entries", "TEXT NOT NULL DEFAULT '[]'"),
+    ("tasks", "remind_before", "TEXT"),
+    ("tasks", "cancelled_at", "INTEGER"),
+    ("tasks", "cancel_reason", "TEXT"),
+    ("tasks", "billable", "INTEGER NOT NULL DEFAULT 0"),
+    ("tasks", "hourly_rate", "REAL"),
+    ("tasks", "catch_up", "TEXT"),
+    ("tasks", "missed", "INTEGER NOT NULL DEFAULT 0"),
+    ("tasks", "duplicate_of", "TEXT"),
+    ("tasks", "recur_chain_deps", "INTEGER NOT NULL DEFAULT 0"),
+    ("tasks", "start_date", "TEXT"),
+    ("tasks", "due_time", "TEXT"),
+    ("tasks", "estimate", "TEXT"),
+];
+
+/// Data rewrites, applied in order; entry `n` brings the database to
+/// `PRAGMA user_version = n + 1`. Fresh databases run them all (harmlessly,
+/// since they have no rows yet).
+const DATA_MIGRATIONS: &[fn(&Connection) -> Result<()>] =
+    &[completed_at_to_local_instant, events_allow_cancelled];
+
+/// v1: `completed_at` used to hold a bare date, written as midnight UTC of
+/// the local completion day. Re-anchor those values to local midnight so
+/// they display on the same day in the user's timezone.
+fn completed_at_to_local_instant(conn: &Connection) -> Result<()> {
+    let mut stmt =
+        conn.prepare("SELECT uuid, completed_at FROM tasks WHERE completed_at IS NOT NULL")?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for (uuid, ts) in rows {
+        let instant = crate::models::local_midnight(from_unix(ts).date_naive());
+        conn.execute(
+            "UPDATE tasks SET completed_at = ?1 WHERE uuid = ?2",
+            params![to_unix(instant), uuid],
+        )?;
+    }
+    Ok(())
+}
+
+/// v2: widen the `events.event_type` CHECK to accept `cancelled`. SQLite
+/// cannot alter a constraint, so the table is rebuilt with the current
+/// definition and its rows copied over.
+fn events_allow_cancelled(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "ALTER TABLE events RENAME TO events_old;
+         CREATE TABLE events (
+             id          INTEGER PRIMARY KEY AUTOINCREMENT,
+             entity_type TEXT NOT NULL CHECK(entity_type IN ('task','project','note','resource')),
+             entity_uuid TEXT NOT NULL,
+             event_type  TEXT NOT NULL CHECK(event_type IN ('created','completed','uncompleted','edited','deleted','purged','cancelled')),
+             occurred_at INTEGER NOT NULL
+         );
+         INSERT INTO events (id, entity_type, entity_uuid, event_type, occurred_at)
+             SELECT id, entity_type, entity_uuid, event_type, occurred_at FROM events_old;
+         DROP TABLE events_old;
+         CREATE INDEX IF NOT EXISTS idx_events_occurred ON events(occurred_at);
+         CREATE INDEX IF NOT EXISTS idx_events_entity ON events(entity_uuid);",
+    )?;
+    Ok(())
+}
+
 // ── row mappers ───────────────────────────────────────────────────────────────
 
 fn row_to_task(row: &Row, conn: &Connection, uuid_str: &str) -> rusqlite::Result<Task> {
@@ -264,10 +556,7 @@ fn row_to_task(row: &Row, conn: &Connection, uuid_str: &str) -> rusqlite::Result
     let created_at = from_unix(row.get("created_at")?);
     let updated_at = opt_from_unix(row.get("updated_at")?);
     let deleted_at = opt_from_unix(row.get("deleted_at")?);
-    let completed_at_ts: Option<i64> = row.get("completed_at")?;
-    let completed_at = completed_at_ts
-        .map(from_unix)
-        .map(|dt| dt.naive_local().date());
+    let completed_at = opt_from_unix(row.get("completed_at")?);
 
     let mut dep_stmt =
         conn.prepare_cached("SELECT depends_on_uuid FROM task_dependencies WHERE task_uuid = ?1")?;
@@ -298,6 +587,48 @@ fn row_to_task(row: &Row, conn: &Connection, uuid_str: &str) -> rusqlite::Result
         updated_at,
         deleted_at,
         completed_at,
+        jira_key: row.get("jira_key")?,
+        source: row
+            .get::<_, Option<String>>("source")?
+            .and_then(|s| s.parse().ok()),
+        wait_until: row
+            .get::<_, Option<String>>("wait_until")?
+            .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
+        month_end: match row.get::<_, Option<String>>("month_end")?.as_deref() {
+            Some("last-day") => MonthEndPolicy::LastDay,
+            _ => MonthEndPolicy::SameDay,
+        },
+        attachments: row.get::<_, JsonVec<PathBuf>>("attachments")?.0,
+        label: row
+            .get::<_, Option<String>>("label")?
+            .and_then(|s| <Label as clap::ValueEnum>::from_str(&s, true).ok()),
+        time_entries: row.get::<_, JsonVec<TimeEntry>>("time_entries")?.0,
+        remind_before: row
+            .get::<_, Option<String>>("remind_before")?
+            .and_then(|s| s.parse().ok()),
+        cancelled_at: opt_from_unix(row.get("cancelled_at")?),
+        cancel_reason: row.get("cancel_reason")?,
+        billable: row.get::<_, i64>("billable")? != 0,
+        hourly_rate: row.get("hourly_rate")?,
+        catch_up: match row.get::<_, Option<String>>("catch_up")?.as_deref() {
+            Some("backfill") => CatchUpPolicy::Backfill,
+            Some("fast-forward") => CatchUpPolicy::FastForward,
+            _ => CatchUpPolicy::Next,
+        },
+        missed: row.get("missed")?,
+        duplicate_of: row
+            .get::<_, Option<String>>("duplicate_of")?
+            .and_then(|s| Uuid::parse_str(&s).ok()),
+        recur_chain_deps: row.get::<_, i64>("recur_chain_deps")? != 0,
+        start_date: row
+            .get::<_, Option<String>>("start_date")?
+            .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
+        due_time: row
+            .get::<_, Option<String>>("due_time")?
+            .and_then(|s| NaiveTime::parse_from_str(&s, "%H:%M").ok()),
+        estimate: row
+            .get::<_, Option<String>>("estimate")?
+            .and_then(|s| s.parse().ok()),
     })
 }
 
@@ -416,6 +747,89 @@ fn row_to_resource(row: &Row) -> rusqlite::Result<Resource> {
     })
 }
 
+// ── row writers ───────────────────────────────────────────────────────────────
+
+/// Upserts one task row and replaces its dependency edges.
+///
+/// Callers own the surrounding transaction.
+fn write_task(conn: &Connection, task: &Task) -> rusqlite::Result<()> {
+    let uuid_str = task.uuid.to_string();
+    conn.execute(
+        "INSERT INTO tasks (uuid, text, completed, priority, due_date, recurrence,
+                  project_id, parent_id, tags, completed_at, created_at,
+                  updated_at, deleted_at, jira_key, source, wait_until, month_end,
+                  attachments, label, time_entries, remind_before, cancelled_at,
+                  cancel_reason, billable, hourly_rate, catch_up, missed, duplicate_of,
+                  recur_chain_deps, start_date, due_time, estimate)
+         VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13,?14,?15,?16,?17,?18,?19,?20,?21,
+                 ?22,?23,?24,?25,?26,?27,?28,?29,?30,?31,?32)
+         ON CONFLICT(uuid) DO UPDATE SET
+           text=excluded.text, completed=excluded.completed,
+           priority=excluded.priority, due_date=excluded.due_date,
+           recurrence=excluded.recurrence, project_id=excluded.project_id,
+           parent_id=excluded.parent_id, tags=excluded.tags,
+           completed_at=excluded.completed_at, created_at=excluded.created_at,
+           updated_at=excluded.updated_at, deleted_at=excluded.deleted_at,
+           jira_key=excluded.jira_key, source=excluded.source,
+           wait_until=excluded.wait_until, month_end=excluded.month_end,
+           attachments=excluded.attachments, label=excluded.label,
+           time_entries=excluded.time_entries, remind_before=excluded.remind_before,
+           cancelled_at=excluded.cancelled_at, cancel_reason=excluded.cancel_reason,
+           billable=excluded.billable, hourly_rate=excluded.hourly_rate,
+           catch_up=excluded.catch_up, missed=excluded.missed,
+           duplicate_of=excluded.duplicate_of, recur_chain_deps=excluded.recur_chain_deps,
+           start_date=excluded.start_date, due_time=excluded.due_time,
+           estimate=excluded.estimate",
+        params![
+            uuid_str,
+            task.text,
+            task.completed as i64,
+            priority_to_str(task.priority),
+            task.due_date.map(|d| d.format("%Y-%m-%d").to_string()),
+            task.recurrence.map(recurrence_to_str),
+            task.project_id.map(|u| u.to_string()),
+            task.parent_id.map(|u| u.to_string()),
+            JsonVec(task.tags.clone()),
+            opt_to_unix(task.completed_at),
+            to_unix(task.created_at),
+            opt_to_unix(task.updated_at),
+            opt_to_unix(task.deleted_at),
+            task.jira_key,
+            task.source.as_ref().map(|s| s.to_string()),
+            task.wait_until.map(|d| d.format("%Y-%m-%d").to_string()),
+            task.month_end.to_string(),
+            JsonVec(task.attachments.clone()),
+            task.label.map(|l| l.to_string()),
+            JsonVec(task.time_entries.clone()),
+            task.remind_before.map(|l| l.to_string()),
+            opt_to_unix(task.cancelled_at),
+            task.cancel_reason,
+            task.billable as i64,
+            task.hourly_rate,
+            task.catch_up.to_string(),
+            task.missed,
+            task.duplicate_of.map(|u| u.to_string()),
+            task.recur_chain_deps as i64,
+            task.start_date.map(|d| d.format("%Y-%m-%d").to_string()),
+            task.due_time.map(|t| t.format("%H:%M").to_string()),
+            task.estimate.map(|e| e.to_string()),
+        ],
+    )?;
+
+    conn.execute(
+        "DELETE FROM task_dependencies WHERE task_uuid = ?1",
+        params![uuid_str],
+    )?;
+    for dep_uuid in &task.depends_on {
+        conn.execute(
+            "INSERT OR IGNORE INTO task_dependencies
+             (task_uuid, depends_on_uuid) VALUES (?1, ?2)",
+            params![uuid_str, dep_uuid.to_string()],
+        )?;
+    }
+    Ok(())
+}
+
 // ── Storage impl ──────────────────────────────────────────────────────────────
 
 impl Storage for SqliteStorage {
@@ -429,6 +843,7 @@ impl Storage for SqliteStorage {
             })?
             .collect::<rusqlite::Result<Vec<_>>>()
             .context("Failed to load tasks")?;
+        self.remember_version(&conn)?;
         Ok(tasks)
     }
 
@@ -437,51 +852,7 @@ impl Storage for SqliteStorage {
         let tx = conn
             .transaction()
             .context("Failed to begin upsert_task transaction")?;
-        let uuid_str = task.uuid.to_string();
-        tx.execute(
-            "INSERT INTO tasks (uuid, text, completed, priority, due_date, recurrence,
-                      project_id, parent_id, tags, completed_at, created_at,
-                      updated_at, deleted_at)
-             VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13)
-             ON CONFLICT(uuid) DO UPDATE SET
-               text=excluded.text, completed=excluded.completed,
-               priority=excluded.priority, due_date=excluded.due_date,
-               recurrence=excluded.recurrence, project_id=excluded.project_id,
-               parent_id=excluded.parent_id, tags=excluded.tags,
-               completed_at=excluded.completed_at, updated_at=excluded.updated_at,
-               deleted_at=excluded.deleted_at",
-            params![
-                uuid_str,
-                task.text,
-                task.completed as i64,
-                priority_to_str(task.priority),
-                task.due_date.map(|d| d.format("%Y-%m-%d").to_string()),
-                task.recurrence.map(recurrence_to_str),
-                task.project_id.map(|u| u.to_string()),
-                task.parent_id.map(|u| u.to_string()),
-                JsonVec(task.tags.clone()),
-                task.completed_at.map(|d| {
-                    let dt: DateTime<Utc> = Utc.from_utc_datetime(&d.and_hms_opt(0, 0, 0).unwrap());
-                    to_unix(dt)
-                }),
-                to_unix(task.created_at),
-                opt_to_unix(task.updated_at),
-                opt_to_unix(task.deleted_at),
-            ],
-        )
-        .context("Failed to upsert task")?;
-
-        tx.execute(
-            "DELETE FROM task_dependencies WHERE task_uuid = ?1",
-            params![uuid_str],
-        )?;
-        for dep_uuid in &task.depends_on {
-            tx.execute(
-                "INSERT OR IGNORE INTO task_dependencies
-                 (task_uuid, depends_on_uuid) VALUES (?1, ?2)",
-                params![uuid_str, dep_uuid.to_string()],
-            )?;
-        }
+        write_task(&tx, task).context("Failed to upsert task")?;
         tx.commit()
             .context("Failed to commit upsert_task transaction")?;
         Ok(())
@@ -604,7 +975,8 @@ impl Storage for SqliteStorage {
 
         let status_clause = match status {
             StatusFilter::Pending => " AND completed = 0",
-            StatusFilter::Done => " AND completed = 1",
+            StatusFilter::Done => " AND completed = 1 AND cancelled_at IS NULL",
+            StatusFilter::Cancelled => " AND cancelled_at IS NOT NULL",
             StatusFilter::All => "",
         };
         let proj_clause = if project_id.is_some() {
@@ -727,54 +1099,13 @@ impl Storage for SqliteStorage {
 
     fn save(&self, tasks: &[Task]) -> Result<()> {
         let mut conn = self.conn.borrow_mut();
-        let tx = conn.transaction().context("Failed to begin transaction")?;
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .context("Failed to begin transaction")?;
+        self.ensure_unchanged(&tx)?;
 
         for task in tasks {
-            let uuid_str = task.uuid.to_string();
-            tx.execute(
-                "INSERT INTO tasks (uuid, text, completed, priority, due_date, recurrence,
-                          project_id, parent_id, tags, completed_at, created_at,
-                          updated_at, deleted_at)
-                 VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13)
-                 ON CONFLICT(uuid) DO UPDATE SET
-                   text=excluded.text, completed=excluded.completed,
-                   priority=excluded.priority, due_date=excluded.due_date,
-                   recurrence=excluded.recurrence, project_id=excluded.project_id,
-                   parent_id=excluded.parent_id, tags=excluded.tags,
-                   completed_at=excluded.completed_at, updated_at=excluded.updated_at,
-                   deleted_at=excluded.deleted_at",
-                params![
-                    uuid_str,
-                    task.text,
-                    task.completed as i64,
-                    priority_to_str(task.priority),
-                    task.due_date.map(|d| d.format("%Y-%m-%d").to_string()),
-                    task.recurrence.map(recurrence_to_str),
-                    task.project_id.map(|u| u.to_string()),
-                    task.parent_id.map(|u| u.to_string()),
-                    JsonVec(task.tags.clone()),
-                    task.completed_at.map(|d| {
-                        let dt: DateTime<Utc> =
-                            Utc.from_utc_datetime(&d.and_hms_opt(0, 0, 0).unwrap());
-                        to_unix(dt)
-                    }),
-                    to_unix(task.created_at),
-                    opt_to_unix(task.updated_at),
-                    opt_to_unix(task.deleted_at),
-                ],
-            )?;
-
-            tx.execute(
-                "DELETE FROM task_dependencies WHERE task_uuid = ?1",
-                params![uuid_str],
-            )?;
-            for dep_uuid in &task.depends_on {
-                tx.execute(
-                    "INSERT OR IGNORE INTO task_dependencies
-                     (task_uuid, depends_on_uuid) VALUES (?1, ?2)",
-                    params![uuid_str, dep_uuid.to_string()],
-                )?;
-            }
+            write_task(&tx, task)?;
         }
 
         tx.commit().context("Failed to commit tasks transaction")?;
@@ -788,12 +1119,16 @@ impl Storage for SqliteStorage {
             .query_map([], row_to_project)?
             .collect::<rusqlite::Result<Vec<_>>>()
             .context("Failed to load projects")?;
+        self.remember_version(&conn)?;
         Ok(projects)
     }
 
     fn save_projects(&self, projects: &[Project]) -> Result<()> {
         let mut conn = self.conn.borrow_mut();
-        let tx = conn.transaction().context("Failed to begin transaction")?;
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .context("Failed to begin transaction")?;
+        self.ensure_unchanged(&tx)?;
 
         for project in projects {
             tx.execute(
@@ -834,12 +1169,16 @@ impl Storage for SqliteStorage {
             .query_map([], |row| row_to_note(row, &conn))?
             .collect::<rusqlite::Result<Vec<_>>>()
             .context("Failed to load notes")?;
+        self.remember_version(&conn)?;
         Ok(notes)
     }
 
     fn save_notes(&self, notes: &[Note]) -> Result<()> {
         let mut conn = self.conn.borrow_mut();
-        let tx = conn.transaction().context("Failed to begin transaction")?;
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .context("Failed to begin transaction")?;
+        self.ensure_unchanged(&tx)?;
 
         for note in notes {
             let uuid_str = note.uuid.to_string();
@@ -891,12 +1230,16 @@ impl Storage for SqliteStorage {
             .query_map([], row_to_resource)?
             .collect::<rusqlite::Result<Vec<_>>>()
             .context("Failed to load resources")?;
+        self.remember_version(&conn)?;
         Ok(resources)
     }
 
     fn save_resources(&self, resources: &[Resource]) -> Result<()> {
         let mut conn = self.conn.borrow_mut();
-        let tx = conn.transaction().context("Failed to begin transaction")?;
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .context("Failed to begin transaction")?;
+        self.ensure_unchanged(&tx)?;
 
         for resource in resources {
             tx.execute(
@@ -910,6 +1253,7 @@ impl Storage for SqliteStorage {
                    deleted_at=excluded.deleted_at",
                 params![
                     resource.uuid.to_string(),
+                    resource.title,
                     resource.resource_type.map(resource_type_to_str),
                     resource.url,
                     resource.description,
@@ -1037,6 +1381,34 @@ impl Storage for SqliteStorage {
         Ok(map.into_values().collect())
     }
 
+    fn save_snapshot(&self, name: &str, payload: &str) -> Result<()> {
+        self.conn
+            .borrow()
+            .execute(
+                "INSERT INTO snapshots (name, payload, created_at) VALUES (?1, ?2, ?3)
+                 ON CONFLICT(name) DO UPDATE SET
+                   payload=excluded.payload, created_at=excluded.created_at",
+                params![name, payload, Utc::now().timestamp()],
+            )
+            .context("Failed to save snapshot")?;
+        Ok(())
+    }
+
+    fn load_snapshot(&self, name: &str) -> Result<Option<String>> {
+        let conn = self.conn.borrow();
+        let mut stmt = conn.prepare("SELECT payload FROM snapshots WHERE name = ?1")?;
+        let mut rows = stmt.query_map(params![name], |row| row.get::<_, String>(0))?;
+        Ok(rows.next().transpose()?)
+    }
+
+    fn delete_snapshot(&self, name: &str) -> Result<()> {
+        self.conn
+            .borrow()
+            .execute("DELETE FROM snapshots WHERE name = ?1", params![name])
+            .context("Failed to delete snapshot")?;
+        Ok(())
+    }
+
     fn location(&self) -> String {
         self.path.display().to_string()
     }
@@ -1155,6 +1527,8 @@ fn resource_type_to_str(rt: ResourceType) -> &'static str {
 pub fn get_db_path() -> Result<PathBuf> {
     let data_dir = if let Ok(dir) = std::env::var("RUSTODO_DATA_DIR") {
         PathBuf::from(dir)
+    } else if let Some(dir) = &crate::config::Config::defaults().data_dir {
+        dir.clone()
     } else {
         let proj_dirs =
             ProjectDirs::from("", "", "rustodo").context("Could not determine data directory")?;
@@ -1257,4 +1631,140 @@ mod tests {
         storage.save(&[task]).unwrap();
         assert!(storage.load().unwrap()[0].is_deleted());
     }
+
+    #[test]
+    fn test_snapshot_roundtrip_and_replace() {
+        let (storage, _tmp) = make_storage();
+        assert!(storage.load_snapshot("clear").unwrap().is_none());
+        storage.save_snapshot("clear", "first").unwrap();
+        storage.save_snapshot("clear", "second").unwrap();
+        assert_eq!(
+            storage.load_snapshot("clear").unwrap().as_deref(),
+            Some("second")
+        );
+        storage.delete_snapshot("clear").unwrap();
+        assert!(storage.load_snapshot("clear").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_save_refuses_after_concurrent_write() {
+        let (first, tmp) = make_storage();
+        let second = SqliteStorage::with_path(tmp.path().join("test.db")).unwrap();
+        let task = Task::new("T".into(), Priority::Medium, vec![], None, None, None);
+
+        let mut tasks = first.load().unwrap();
+        second.save(std::slice::from_ref(&task)).unwrap();
+        tasks.push(Task::new(
+            "Mine".into(),
+            Priority::Medium,
+            vec![],
+            None,
+            None,
+            None,
+        ));
+        assert!(first.save(&tasks).is_err());
+
+        // Reloading picks up the other write and allows saving again
+        let mut tasks = first.load().unwrap();
+        tasks[0].text = "Edited".into();
+        first.save(&tasks).unwrap();
+        assert_eq!(second.load().unwrap()[0].text, "Edited");
+    }
+
+    #[test]
+    fn test_own_writes_do_not_trip_detection() {
+        let (storage, _tmp) = make_storage();
+        let task = Task::new("T".into(), Priority::Medium, vec![], None, None, None);
+        storage.load().unwrap();
+        storage.upsert_task(&task).unwrap();
+        storage.save(&[task]).unwrap();
+    }
+
+    #[test]
+    fn test_save_resources_roundtrip() {
+        let (storage, _tmp) = make_storage();
+        storage
+            .save_resources(&[Resource::new("Rust book".into())])
+            .unwrap();
+        assert_eq!(storage.load_resources().unwrap()[0].title, "Rust book");
+    }
+
+    #[test]
+    fn test_migration_reanchors_legacy_completed_at() {
+        let (storage, tmp) = make_storage();
+        let mut task = Task::new("T".into(), Priority::Medium, vec![], None, None, None);
+        task.mark_done();
+        storage.save(&[task]).unwrap();
+
+        // Simulate a pre-v1 database: midnight UTC of the completion day
+        let legacy = NaiveDate::from_ymd_opt(2026, 2, 10).unwrap();
+        {
+            let conn = storage.conn.borrow();
+            conn.execute(
+                "UPDATE tasks SET completed_at = ?1",
+                params![to_unix(
+                    Utc.from_utc_datetime(&legacy.and_hms_opt(0, 0, 0).unwrap())
+                )],
+            )
+            .unwrap();
+            conn.pragma_update(None, "user_version", 0).unwrap();
+        }
+        drop(storage);
+
+        let path = tmp.path().join("test.db");
+        let err = SqliteStorage::open_read_only_at(path.clone())
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("without --read-only"));
+
+        let reopened = SqliteStorage::with_path(path.clone()).unwrap();
+        assert_eq!(reopened.load().unwrap()[0].completed_on(), Some(legacy));
+        drop(reopened);
+        let read_only = SqliteStorage::open_read_only_at(path).unwrap();
+        assert_eq!(read_only.load().unwrap()[0].completed_on(), Some(legacy));
+    }
+
+    #[test]
+    fn test_migration_allows_cancelled_events() {
+        let (storage, tmp) = make_storage();
+        let uuid = Uuid::new_v4();
+        storage
+            .record_event(EntityType::Task, uuid, EventType::Created)
+            .unwrap();
+
+        // Simulate a v1 database whose CHECK predates `cancelled`
+        {
+            let conn = storage.conn.borrow();
+            conn.execute_batch(
+                "DROP TABLE events;
+                 CREATE TABLE events (
+                     id          INTEGER PRIMARY KEY AUTOINCREMENT,
+                     entity_type TEXT NOT NULL,
+                     entity_uuid TEXT NOT NULL,
+                     event_type  TEXT NOT NULL CHECK(event_type IN ('created','completed')),
+                     occurred_at INTEGER NOT NULL
+                 );",
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO events (entity_type, entity_uuid, event_type, occurred_at)
+                 VALUES ('task', ?1, 'created', 0)",
+                params![uuid.to_string()],
+            )
+            .unwrap();
+            conn.pragma_update(None, "user_version", 1).unwrap();
+        }
+        drop(storage);
+
+        let reopened = SqliteStorage::with_path(tmp.path().join("test.db")).unwrap();
+        reopened
+            .record_event(EntityType::Task, uuid, EventType::Cancelled)
+            .unwrap();
+        let count: i64 = reopened
+            .conn
+            .borrow()
+            .query_row("SELECT COUNT(*) FROM events", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(count, 2);
+    }
 }
@@ -0,0 +1,258 @@
+//! Timing wrapper that warns once when storage is slow.
+//!
+//! Every call is forwarded to the inner backend and timed. The first call
+//! that takes longer than the threshold prints a one-line hint to stderr —
+//! typically a large data file on a network drive — and the total time
+//! spent in storage is reported by `--timing`.
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use colored::Colorize;
+use uuid::Uuid;
+
+use super::{EntityType, EventStat, EventType, Storage};
+use crate::models::{Note, Project, Resource, StatusFilter, Task};
+
+/// Default for the `slow_storage_ms` setting.
+pub const DEFAULT_SLOW_STORAGE: Duration = Duration::from_millis(500);
+
+/// What the slow-storage hint suggests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlowHint {
+    /// Already on SQLite: trim what it has to read.
+    Archive,
+    /// A custom backend: switch to the built-in SQLite one.
+    Sqlite,
+}
+
+impl SlowHint {
+    /// The hint for a configured `storage` spec; unset means SQLite.
+    pub fn for_backend(spec: Option<&str>) -> Self {
+        match spec.map(str::trim) {
+            None | Some("") | Some("sqlite") => SlowHint::Archive,
+            Some(_) => SlowHint::Sqlite,
+        }
+    }
+
+    fn advice(self) -> &'static str {
+        match self {
+            SlowHint::Archive => {
+                "archive finished projects (`todo archive`) and `todo purge` tombstones to keep it small"
+            }
+            SlowHint::Sqlite => {
+                "the SQLite backend (`storage = \"sqlite\"`) handles large lists faster"
+            }
+        }
+    }
+}
+
+pub struct TimedStorage<'a, S: Storage + ?Sized> {
+    inner: &'a S,
+    threshold: Option<Duration>,
+    hint: SlowHint,
+    spent: Cell<Duration>,
+    warned: Cell<bool>,
+}
+
+impl<'a, S: Storage + ?Sized> TimedStorage<'a, S> {
+    /// Wraps `inner`; a `threshold` of `None` never warns.
+    pub fn new(inner: &'a S, threshold: Option<Duration>, hint: SlowHint) -> Self {
+        Self {
+            inner,
+            threshold,
+            hint,
+            spent: Cell::new(Duration::ZERO),
+            warned: Cell::new(false),
+        }
+    }
+
+    /// Total time spent in storage calls so far.
+    pub fn spent(&self) -> Duration {
+        self.spent.get()
+    }
+
+    /// Whether the slow-storage hint has been printed.
+    pub fn warned(&self) -> bool {
+        self.warned.get()
+    }
+
+    fn timed<T>(&self, action: &str, call: impl FnOnce() -> Result<T>) -> Result<T> {
+        let start = Instant::now();
+        let result = call();
+        let took = start.elapsed();
+        self.spent.set(self.spent.get() + took);
+
+        if self.threshold.is_some_and(|limit| took > limit) && !self.warned.replace(true) {
+            let took = if took < Duration::from_secs(1) {
+                format!("{} ms", took.as_millis())
+            } else {
+                format!("{:.1} s", took.as_secs_f64())
+            };
+            eprintln!(
+                "{} {} took {}; {}",
+                "hint:".yellow(),
+                action,
+                took,
+                self.hint.advice()
+            );
+        }
+        result
+    }
+}
+
+impl<S: Storage + ?Sized> Storage for TimedStorage<'_, S> {
+    fn load(&self) -> Result<Vec<Task>> {
+        self.timed("loading tasks", || self.inner.load())
+    }
+    fn save(&self, tasks: &[Task]) -> Result<()> {
+        self.timed("saving tasks", || self.inner.save(tasks))
+    }
+    fn delete_tasks(&self, uuids: &[Uuid]) -> Result<()> {
+        self.timed("deleting tasks", || self.inner.delete_tasks(uuids))
+    }
+    fn load_projects(&self) -> Result<Vec<Project>> {
+        self.timed("loading projects", || self.inner.load_projects())
+    }
+    fn save_projects(&self, projects: &[Project]) -> Result<()> {
+        self.timed("saving projects", || self.inner.save_projects(projects))
+    }
+    fn delete_projects(&self, uuids: &[Uuid]) -> Result<()> {
+        self.timed("deleting projects", || self.inner.delete_projects(uuids))
+    }
+    fn load_notes(&self) -> Result<Vec<Note>> {
+        self.timed("loading notes", || self.inner.load_notes())
+    }
+    fn save_notes(&self, notes: &[Note]) -> Result<()> {
+        self.timed("saving notes", || self.inner.save_notes(notes))
+    }
+    fn delete_notes(&self, uuids: &[Uuid]) -> Result<()> {
+        self.timed("deleting notes", || self.inner.delete_notes(uuids))
+    }
+    fn load_resources(&self) -> Result<Vec<Resource>> {
+        self.timed("loading resources", || self.inner.load_resources())
+    }
+    fn save_resources(&self, resources: &[Resource]) -> Result<()> {
+        self.timed("saving resources", || self.inner.save_resources(resources))
+    }
+    fn delete_resources(&self, uuids: &[Uuid]) -> Result<()> {
+        self.timed("deleting resources", || self.inner.delete_resources(uuids))
+    }
+    fn record_event(
+        &self,
+        entity_type: EntityType,
+        entity_uuid: Uuid,
+        event_type: EventType,
+    ) -> Result<()> {
+        self.timed("recording history", || {
+            self.inner
+                .record_event(entity_type, entity_uuid, event_type)
+        })
+    }
+    fn clear_events(&self, older_than_days: Option<u32>) -> Result<usize> {
+        self.timed("clearing history", || {
+            self.inner.clear_events(older_than_days)
+        })
+    }
+    fn load_event_stats(&self, months: usize) -> Result<Vec<EventStat>> {
+        self.timed("loading history", || self.inner.load_event_stats(months))
+    }
+    fn save_snapshot(&self, name: &str, payload: &str) -> Result<()> {
+        self.timed("saving a snapshot", || {
+            self.inner.save_snapshot(name, payload)
+        })
+    }
+    fn load_snapshot(&self, name: &str) -> Result<Option<String>> {
+        self.timed("loading a snapshot", || self.inner.load_snapshot(name))
+    }
+    fn delete_snapshot(&self, name: &str) -> Result<()> {
+        self.timed("deleting a snapshot", || self.inner.delete_snapshot(name))
+    }
+    fn search_tasks(
+        &self,
+        q: &str,
+        tags: &[String],
+        project_id: Option<Uuid>,
+        status: StatusFilter,
+    ) -> Result<Vec<Task>> {
+        self.timed("searching tasks", || {
+            self.inner.search_tasks(q, tags, project_id, status)
+        })
+    }
+    fn search_notes(
+        &self,
+        q: &str,
+        tags: &[String],
+        project_id: Option<Uuid>,
+    ) -> Result<Vec<Note>> {
+        self.timed("searching notes", || {
+            self.inner.search_notes(q, tags, project_id)
+        })
+    }
+    fn search_projects(&self, q: &str) -> Result<Vec<Project>> {
+        self.timed("searching projects", || self.inner.search_projects(q))
+    }
+    fn search_resources(&self, q: &str, tags: &[String]) -> Result<Vec<Resource>> {
+        self.timed("searching resources", || {
+            self.inner.search_resources(q, tags)
+        })
+    }
+    fn upsert_task(&self, task: &Task) -> Result<()> {
+        self.timed("saving a task", || self.inner.upsert_task(task))
+    }
+    fn upsert_project(&self, project: &Project) -> Result<()> {
+        self.timed("saving a project", || self.inner.upsert_project(project))
+    }
+    fn upsert_note(&self, note: &Note) -> Result<()> {
+        self.timed("saving a note", || self.inner.upsert_note(note))
+    }
+    fn upsert_resource(&self, resource: &Resource) -> Result<()> {
+        self.timed("saving a resource", || self.inner.upsert_resource(resource))
+    }
+    fn load_all(&self) -> Result<(Vec<Task>, Vec<Project>, Vec<Note>)> {
+        self.timed("loading data", || self.inner.load_all())
+    }
+    fn load_all_with_resources(
+        &self,
+    ) -> Result<(Vec<Task>, Vec<Project>, Vec<Note>, Vec<Resource>)> {
+        self.timed("loading data", || self.inner.load_all_with_resources())
+    }
+    fn save_all(&self, tasks: &[Task], projects: &[Project], notes: &[Note]) -> Result<()> {
+        self.timed("saving data", || {
+            self.inner.save_all(tasks, projects, notes)
+        })
+    }
+    fn location(&self) -> String {
+        self.inner.location()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::InMemoryStorage;
+
+    #[test]
+    fn test_forwards_calls_and_warns_once_past_threshold() {
+        let task = Task::new("T".into(), Priority::Medium, vec![], None, None, None);
+        let inner = InMemoryStorage::with_tasks(vec![task]);
+
+        let quiet = TimedStorage::new(&inner, None, SlowHint::Archive);
+        assert_eq!(quiet.load().unwrap().len(), 1);
+        assert!(!quiet.warned());
+
+        let slow = TimedStorage::new(&inner, Some(Duration::ZERO), SlowHint::Sqlite);
+        assert_eq!(slow.load_all().unwrap().0.len(), 1);
+        assert!(slow.warned());
+        assert!(slow.spent() > Duration::ZERO);
+    }
+
+    #[test]
+    fn test_hint_follows_backend() {
+        assert_eq!(SlowHint::for_backend(None), SlowHint::Archive);
+        assert_eq!(SlowHint::for_backend(Some(" sqlite ")), SlowHint::Archive);
+        assert_eq!(SlowHint::for_backend(Some("json:/tmp/x")), SlowHint::Sqlite);
+    }
+}
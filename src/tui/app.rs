@@ -2,6 +2,9 @@
 
 use std::collections::BTreeSet;
 
+use uuid::Uuid;
+
+use crate::commands::task::deps::{self, Direction};
 use crate::models::{Priority, Project, Recurrence, StatusFilter, Task};
 use crate::storage::Storage;
 use anyhow::Result;
@@ -201,10 +204,11 @@ impl EditFormState {
             focused: EditField::Text,
             text: task.text.clone(),
             priority: task.priority,
-            due: task
-                .due_date
-                .map(|d| d.format("%Y-%m-%d").to_string())
-                .unwrap_or_default(),
+            due: match (task.due_date, task.due_at()) {
+                (_, Some(at)) => at.format("%Y-%m-%d %H:%M").to_string(),
+                (Some(d), None) => d.format("%Y-%m-%d").to_string(),
+                (None, None) => String::new(),
+            },
             recurrence: task.recurrence,
             project: project_name,
             tags: task.tags.join(", "),
@@ -361,6 +365,22 @@ impl TreeItem {
     }
 }
 
+// ── DepRow ────────────────────────────────────────────────────────────────────
+
+/// One line of the dependency pane: a task reached from the selected task
+/// along `direction`, `depth` edges below the section header.
+#[derive(Debug, Clone)]
+pub struct DepRow {
+    pub direction: Direction,
+    pub depth: usize,
+    /// Index into `App::tasks`; `None` for a dependency that no longer exists
+    pub task_idx: Option<usize>,
+    pub has_children: bool,
+    pub expanded: bool,
+    /// Already on the path from the root, so not expanded again
+    pub cycle: bool,
+}
+
 // ── App ───────────────────────────────────────────────────────────────────────
 
 pub struct App {
@@ -382,6 +402,10 @@ pub struct App {
     pub focused_panel: FocusedPanel,
     pub project_tree: Vec<TreeItem>,
     pub tree_selected: usize,
+    /// Selected row in the dependency pane
+    pub deps_selected: usize,
+    /// Tasks whose subtree is folded in the dependency pane
+    pub deps_collapsed: BTreeSet<Uuid>,
 }
 
 impl App {
@@ -413,6 +437,8 @@ impl App {
             focused_panel: FocusedPanel::Left,
             project_tree: vec![],
             tree_selected: 0,
+            deps_selected: 0,
+            deps_collapsed: BTreeSet::new(),
         };
         app.build_project_tree();
         Ok(app)
@@ -625,6 +651,111 @@ impl App {
             .map(|p| p + 1)
     }
 
+    // ── dependency pane ───────────────────────────────────────────────────────
+
+    /// Flattened dependency graph of the selected task: everything it
+    /// depends on, then everything that depends on it, skipping folded
+    /// subtrees. Built from the same traversal as `todo deps`.
+    pub fn deps_rows(&self) -> Vec<DepRow> {
+        let Some(root) = self.selected_task() else {
+            return vec![];
+        };
+        let mut rows = Vec::new();
+        for direction in [Direction::Upstream, Direction::Downstream] {
+            let mut path = vec![root.uuid];
+            self.push_dep_rows(root, direction, 0, &mut path, &mut rows);
+        }
+        rows
+    }
+
+    fn push_dep_rows(
+        &self,
+        task: &Task,
+        direction: Direction,
+        depth: usize,
+        path: &mut Vec<Uuid>,
+        rows: &mut Vec<DepRow>,
+    ) {
+        for child in deps::children(&self.tasks, task, direction) {
+            let Some(child) = child else {
+                rows.push(DepRow {
+                    direction,
+                    depth,
+                    task_idx: None,
+                    has_children: false,
+                    expanded: false,
+                    cycle: false,
+                });
+                continue;
+            };
+            let cycle = path.contains(&child.uuid);
+            let has_children = !cycle && !deps::children(&self.tasks, child, direction).is_empty();
+            let expanded = has_children && !self.deps_collapsed.contains(&child.uuid);
+            rows.push(DepRow {
+                direction,
+                depth,
+                task_idx: self.tasks.iter().position(|t| t.uuid == child.uuid),
+                has_children,
+                expanded,
+                cycle,
+            });
+            if expanded {
+                path.push(child.uuid);
+                self.push_dep_rows(child, direction, depth + 1, path, rows);
+                path.pop();
+            }
+        }
+    }
+
+    pub fn deps_move_down(&mut self) {
+        let len = self.deps_rows().len();
+        if len > 0 {
+            self.deps_selected = (self.deps_selected + 1).min(len - 1);
+        }
+    }
+
+    pub fn deps_move_up(&mut self) {
+        self.deps_selected = self.deps_selected.saturating_sub(1);
+    }
+
+    /// Folds (`Some(false)`), unfolds (`Some(true)`) or toggles (`None`) the
+    /// subtree under the selected row.
+    pub fn deps_set_expanded(&mut self, expand: Option<bool>) {
+        let rows = self.deps_rows();
+        let Some(row) = rows.get(self.deps_selected) else {
+            return;
+        };
+        let (Some(idx), true) = (row.task_idx, row.has_children) else {
+            return;
+        };
+        let uuid = self.tasks[idx].uuid;
+        if expand.unwrap_or(!row.expanded) {
+            self.deps_collapsed.remove(&uuid);
+        } else {
+            self.deps_collapsed.insert(uuid);
+        }
+    }
+
+    /// Selects the task under the cursor in the task list, so the pane
+    /// re-centers on it. Filters are reset if they hide it.
+    pub fn deps_jump(&mut self) {
+        let rows = self.deps_rows();
+        let Some(idx) = rows.get(self.deps_selected).and_then(|r| r.task_idx) else {
+            return;
+        };
+        if !self.filtered_indices.contains(&idx) {
+            self.list_filter = ListFilter::All;
+            self.priority_filter = PriorityFilter::All;
+            self.refilter();
+        }
+        if let Some(pos) = self.filtered_indices.iter().position(|&i| i == idx) {
+            self.selected = pos;
+            self.deps_selected = 0;
+            self.details_scroll = 0;
+            self.status_msg = Some(format!("Jumped to #{}", idx + 1));
+        }
+    }
+
     // ── form helpers ──────────────────────────────────────────────────────────
 
     pub fn open_edit_form(&mut self) {
@@ -666,12 +797,14 @@ impl App {
         if !self.filtered_indices.is_empty() {
             self.selected = (self.selected + 1).min(self.filtered_indices.len() - 1);
             self.details_scroll = 0;
+            self.deps_selected = 0;
         }
     }
 
     pub fn move_up(&mut self) {
         self.selected = self.selected.saturating_sub(1);
         self.details_scroll = 0;
+        self.deps_selected = 0;
     }
 
     pub fn scroll_details_down(&mut self) {
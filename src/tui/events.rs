@@ -14,6 +14,7 @@
 //! | `p`      | Cycle priority filter                   |
 //! | `x`      | Delete task (Tasks tab only)            |
 //! | `X`      | Clear all visible tasks                 |
+//! | `D`      | Show the dependency pane and focus it (Tasks tab only) |
 //! | `?`      | Help popup                              |
 //! | `Tab`    | Toggle panel focus                      |
 //! | `q`/Esc  | Quit                                    |
@@ -25,6 +26,15 @@
 //! | `?`      | Help popup                              |
 //! | `q`/Esc  | Quit                                    |
 //!
+//! # Keybinds — Dependency pane (Right panel focused)
+//! | Key      | Action                                  |
+//! |----------|-----------------------------------------|
+//! | `j/k`/↑↓ | Move between dependencies               |
+//! | `Space`  | Expand / collapse the selected subtree  |
+//! | `h/l`/←→ | Collapse / expand                       |
+//! | `Enter`  | Jump to the selected task               |
+//! | `D`      | Back to task details                    |
+//!
 //! # Keybinds — EditForm / AddForm mode
 //! | Key         | Action                               |
 //! |-------------|--------------------------------------|
@@ -43,7 +53,7 @@ use crate::models::Task;
 use crate::storage::Storage;
 use crate::utils::tag_normalizer::{collect_existing_tags, normalize_tags};
 
-use super::app::{App, EditField, FocusedPanel, LeftPanel, Mode};
+use super::app::{App, EditField, FocusedPanel, LeftPanel, Mode, RightPanel};
 
 pub fn handle(app: &mut App, storage: &impl Storage) -> Result<bool> {
     let ev = event::read()?;
@@ -135,6 +145,16 @@ fn handle_left(
         }
 
         KeyCode::Char('d') if app.left_panel == LeftPanel::Tasks => toggle_done(app, storage)?,
+        KeyCode::Char('D') if app.left_panel == LeftPanel::Tasks => {
+            if app.right_panel == RightPanel::Deps {
+                app.right_panel = RightPanel::Details;
+            } else {
+                app.right_panel = RightPanel::Deps;
+                app.focused_panel = FocusedPanel::Right;
+            }
+            app.deps_selected = 0;
+            app.status_msg = None;
+        }
         KeyCode::Char('e') if app.left_panel == LeftPanel::Tasks => app.open_edit_form(),
         KeyCode::Char('a') => app.open_add_form(),
 
@@ -174,15 +194,13 @@ fn handle_left(
                 app.status_msg = Some(format!("Clear all {} tasks? [y/n]", count));
             }
         }
-        KeyCode::Char('x') if app.left_panel == LeftPanel::Tasks => {
-            if !app.tasks.is_empty() {
-                app.mode = Mode::ConfirmDelete;
-                let preview = app
-                    .selected_task()
-                    .map(|t| truncate_str(&t.text, 30))
-                    .unwrap_or_default();
-                app.status_msg = Some(format!("Delete \"{}\"? [y/n]", preview));
-            }
+        KeyCode::Char('x') if app.left_panel == LeftPanel::Tasks && !app.tasks.is_empty() => {
+            app.mode = Mode::ConfirmDelete;
+            let preview = app
+                .selected_task()
+                .map(|t| truncate_str(&t.text, 30))
+                .unwrap_or_default();
+            app.status_msg = Some(format!("Delete \"{}\"? [y/n]", preview));
         }
 
         KeyCode::Char('q') | KeyCode::Esc => return Ok(true),
@@ -194,6 +212,9 @@ fn handle_left(
 // ── right panel (focused) ─────────────────────────────────────────────────────
 
 fn handle_right(app: &mut App, key: KeyCode) -> Result<bool> {
+    if app.left_panel == LeftPanel::Tasks && app.right_panel == RightPanel::Deps {
+        return handle_deps(app, key);
+    }
     match key {
         KeyCode::Char('j') | KeyCode::Down => app.scroll_details_down(),
         KeyCode::Char('k') | KeyCode::Up => app.scroll_details_up(),
@@ -210,6 +231,33 @@ fn handle_right(app: &mut App, key: KeyCode) -> Result<bool> {
     Ok(false)
 }
 
+// ── dependency pane (focused) ─────────────────────────────────────────────────
+
+fn handle_deps(app: &mut App, key: KeyCode) -> Result<bool> {
+    match key {
+        KeyCode::Char('j') | KeyCode::Down => app.deps_move_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.deps_move_up(),
+        KeyCode::Char(' ') => app.deps_set_expanded(None),
+        KeyCode::Char('h') | KeyCode::Left => app.deps_set_expanded(Some(false)),
+        KeyCode::Char('l') | KeyCode::Right => app.deps_set_expanded(Some(true)),
+        KeyCode::Enter => app.deps_jump(),
+        KeyCode::Char('D') => {
+            app.right_panel = RightPanel::Details;
+            app.status_msg = None;
+        }
+
+        KeyCode::Char('?') => {
+            app.help_selected = 0;
+            app.mode = Mode::Help;
+            app.status_msg = None;
+        }
+
+        KeyCode::Char('q') | KeyCode::Esc => return Ok(true),
+        _ => {}
+    }
+    Ok(false)
+}
+
 // ── confirm-delete mode ───────────────────────────────────────────────────────
 
 fn handle_confirm(app: &mut App, storage: &impl Storage, key: KeyCode) -> Result<bool> {
@@ -299,13 +347,13 @@ fn handle_search(app: &mut App, key: KeyCode) -> Result<bool> {
 // ── help mode ─────────────────────────────────────────────────────────────────
 
 fn handle_help(app: &mut App, key: KeyCode) -> Result<bool> {
-    const SELECTABLE: usize = 14;
+    let selectable = super::ui::help_selectable_count();
     match key {
         KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => {
             app.mode = Mode::Normal;
         }
         KeyCode::Char('j') | KeyCode::Down => {
-            app.help_selected = (app.help_selected + 1).min(SELECTABLE - 1);
+            app.help_selected = (app.help_selected + 1).min(selectable - 1);
         }
         KeyCode::Char('k') | KeyCode::Up => {
             app.help_selected = app.help_selected.saturating_sub(1);
@@ -314,7 +362,7 @@ fn handle_help(app: &mut App, key: KeyCode) -> Result<bool> {
             app.help_selected = 0;
         }
         KeyCode::Char('G') => {
-            app.help_selected = SELECTABLE - 1;
+            app.help_selected = selectable - 1;
         }
         _ => {}
     }
@@ -427,12 +475,17 @@ fn commit_add_form(app: &mut App, storage: &impl Storage) -> Result<()> {
 
     let args = crate::cli::AddArgs {
         text: form.text.trim().to_string(),
-        priority: form.priority,
+        priority: Some(form.priority),
         tag: tags,
         project,
         due,
         recurrence: form.recurrence,
         depends_on: deps,
+        extra: vec![],
+        wait_until: None,
+        start: None,
+        estimate: None,
+        label: None,
     };
 
     match crate::commands::task::add::execute_silent(storage, args) {
@@ -622,6 +675,8 @@ fn commit_edit_form(app: &mut App, storage: &impl Storage) -> Result<()> {
 
     let args = crate::cli::EditArgs {
         id: vis_id,
+        more_ids: vec![],
+        replace_text: None,
         text: Some(form.text.trim().to_string()),
         priority: Some(form.priority),
         due: due_str,
@@ -634,6 +689,20 @@ fn commit_edit_form(app: &mut App, storage: &impl Storage) -> Result<()> {
         add_dep,
         remove_dep,
         clear_deps,
+        wait_until: None,
+        clear_wait: false,
+        start: None,
+        clear_start: false,
+        estimate: None,
+        clear_estimate: false,
+        label: None,
+        clear_label: false,
+        billable: false,
+        rate: None,
+        clear_billable: false,
+        created: None,
+        completed: None,
+        force_metadata: false,
     };
 
     match crate::commands::task::edit::execute_silent(storage, args) {
@@ -4,7 +4,9 @@
 //!   `[`/`]` cycles tabs, `j`/`k` navigates within the active tab.
 //!
 //! Right panel `[0]`: single full-height box, content is contextual:
-//!   • Tasks active   → rich task details (metadata + deps + history)
+//!   • Tasks active   → rich task details (metadata + deps + history), or
+//!     the dependency pane (`D`): a foldable upstream/downstream tree
+//!     whose rows can be jumped to
 //!   • Projects active → list of tasks for selected project
 //!   • Tags active    → list of tasks for selected tag
 //!
@@ -22,9 +24,13 @@ use ratatui::{
     },
 };
 
+use crate::commands::task::deps::Direction as DepDirection;
 use crate::models::Task;
+use crate::render::formatting::first_line;
 
-use super::app::{App, EditField, FocusedPanel, LeftPanel, Mode, PriorityFilter, TreeItem};
+use super::app::{
+    App, EditField, FocusedPanel, LeftPanel, Mode, PriorityFilter, RightPanel, TreeItem,
+};
 
 use super::style::ResolvedTheme;
 
@@ -150,7 +156,9 @@ fn draw_tasks_tab(f: &mut Frame, app: &mut App, block: Block, area: Rect, theme:
 fn task_line<'a>(task: &'a Task, all_tasks: &'a [Task], theme: &ResolvedTheme) -> Line<'a> {
     let blocked = !task.completed && task.is_blocked(all_tasks);
 
-    let (status_text, status_color) = if task.completed {
+    let (status_text, status_color) = if task.is_cancelled() {
+        ("C", Color::DarkGray)
+    } else if task.completed {
         ("D", Color::Green)
     } else if blocked {
         ("B", Color::Red)
@@ -170,7 +178,7 @@ fn task_line<'a>(task: &'a Task, all_tasks: &'a [Task], theme: &ResolvedTheme) -
         Span::raw(" "),
         Span::styled(status_text, Style::default().fg(status_color)),
         Span::raw("  "),
-        Span::styled(task.text.clone(), text_style),
+        Span::styled(first_line(&task.text).to_string(), text_style),
     ])
 }
 
@@ -233,7 +241,7 @@ fn draw_projects_tree(f: &mut Frame, app: &App, block: Block, area: Rect, theme:
                     };
                     Line::from(vec![
                         Span::raw("    "),
-                        Span::styled(task.text.clone(), text_style),
+                        Span::styled(first_line(&task.text).to_string(), text_style),
                     ])
                 }
             }
@@ -337,6 +345,9 @@ fn draw_right_panel(f: &mut Frame, app: &mut App, area: Rect, theme: &ResolvedTh
     };
 
     match app.left_panel {
+        LeftPanel::Tasks if app.right_panel == RightPanel::Deps => {
+            draw_deps_pane(f, app, area, border_style, theme)
+        }
         LeftPanel::Tasks => draw_task_details(f, app, area, border_style, theme),
         LeftPanel::Projects => draw_tree_details(f, app, area, border_style, theme),
         LeftPanel::Tags => draw_context_panel(f, app, area, border_style, true, theme),
@@ -369,7 +380,7 @@ fn draw_task_details(
                 ),
                 Span::styled(": ", Style::default().fg(Color::DarkGray)),
                 Span::styled(
-                    truncate(&task.text, max_text),
+                    truncate(first_line(&task.text), max_text),
                     Style::default()
                         .fg(Color::White)
                         .add_modifier(Modifier::BOLD),
@@ -414,6 +425,166 @@ fn draw_task_details(
     }
 }
 
+// ── [0] dependency pane ──────────────────────────────────────────────────────
+
+fn draw_deps_pane(
+    f: &mut Frame,
+    app: &App,
+    area: Rect,
+    border_style: Style,
+    theme: &ResolvedTheme,
+) {
+    let Some(task) = app.selected_task() else {
+        let para = Paragraph::new(Line::from(Span::styled(
+            "No tasks",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(right_title("Dependencies"))
+                .border_style(border_style),
+        );
+        f.render_widget(para, area);
+        return;
+    };
+
+    let id = app.selected_visible_id().unwrap_or(0);
+    let prefix_len = format!("[0]─ Deps #{}: ", id).len();
+    let max_text = (area.width as usize).saturating_sub(prefix_len + 2);
+    let title = Line::from(vec![
+        Span::styled("[0]", Style::default().fg(Color::DarkGray)),
+        Span::styled("─ Deps #", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!("{}", id),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(": ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            truncate(first_line(&task.text), max_text),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+
+    let rows = app.deps_rows();
+    let focused = app.focused_panel == FocusedPanel::Right;
+    let mut lines: Vec<Line> = Vec::new();
+    let mut selected_line = 0;
+
+    for (direction, heading, empty) in [
+        (DepDirection::Upstream, "Depends on", "No dependencies"),
+        (
+            DepDirection::Downstream,
+            "Required by",
+            "No tasks depend on this one",
+        ),
+    ] {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(
+            heading,
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )));
+        let mut any = false;
+        for (i, row) in rows.iter().enumerate() {
+            if row.direction != direction {
+                continue;
+            }
+            any = true;
+            let selected = i == app.deps_selected;
+            if selected {
+                selected_line = lines.len();
+            }
+            let mut spans = vec![Span::raw("  ".repeat(row.depth + 1))];
+            spans.push(Span::styled(
+                match (row.has_children, row.expanded) {
+                    (true, true) => "▾ ",
+                    (true, false) => "▸ ",
+                    _ => "  ",
+                },
+                Style::default().fg(Color::DarkGray),
+            ));
+            match row.task_idx.map(|idx| (idx, &app.tasks[idx])) {
+                None => spans.push(Span::styled(
+                    "? (task not found)",
+                    Style::default().fg(Color::Yellow),
+                )),
+                Some((idx, dep)) => {
+                    let checkbox = if dep.completed {
+                        Span::styled("[x] ", Style::default().fg(theme.done))
+                    } else if direction == DepDirection::Upstream {
+                        Span::styled("[ ] ", Style::default().fg(Color::Red))
+                    } else {
+                        Span::styled("[ ] ", Style::default().fg(Color::Yellow))
+                    };
+                    spans.push(checkbox);
+                    spans.push(Span::styled(
+                        format!("#{} ", idx + 1),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                    spans.push(Span::styled(
+                        first_line(&dep.text).to_string(),
+                        if dep.completed {
+                            Style::default().fg(theme.done)
+                        } else {
+                            Style::default().fg(Color::White)
+                        },
+                    ));
+                    if row.cycle {
+                        spans.push(Span::styled(" (cycle)", Style::default().fg(Color::Red)));
+                    }
+                }
+            }
+            let line = Line::from(spans);
+            lines.push(if selected && focused {
+                line.style(Style::default().bg(theme.selected_bg))
+            } else {
+                line
+            });
+        }
+        if !any {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", empty),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    let blocking = task.blocking_deps(&app.tasks);
+    if !blocking.is_empty() {
+        let ids = blocking
+            .iter()
+            .filter_map(|uuid| app.tasks.iter().position(|t| t.uuid == *uuid))
+            .map(|idx| format!("#{}", idx + 1))
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Blocked by ", Style::default().fg(Color::DarkGray)),
+            Span::styled(ids, Style::default().fg(Color::Red)),
+        ]));
+    }
+
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let scroll = (selected_line + 1).saturating_sub(inner_height);
+    let para = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(border_style),
+        )
+        .scroll((scroll as u16, 0));
+    f.render_widget(para, area);
+}
+
 fn sep() -> Line<'static> {
     Line::from(Span::styled(
         "─────────────────────────────────────",
@@ -448,7 +619,9 @@ fn build_task_details(
             Span::styled(format!("{:<14}", "Low"), Style::default().fg(theme.low))
         }
     };
-    let status_span = if task.completed {
+    let status_span = if task.is_cancelled() {
+        Span::styled("Cancelled", Style::default().fg(Color::DarkGray))
+    } else if task.completed {
         Span::styled("Done", Style::default().fg(theme.done))
     } else if task.is_blocked(all_tasks) {
         Span::styled(
@@ -486,7 +659,11 @@ fn build_task_details(
                     d if d <= 7 => format!(" ({}d)", d),
                     _ => String::new(),
                 };
-                format!("{}{}", d.format("%Y-%m-%d"), suffix)
+                let time = task
+                    .due_time
+                    .map(|t| t.format(" %H:%M").to_string())
+                    .unwrap_or_default();
+                format!("{}{}{}", d.format("%Y-%m-%d"), time, suffix)
             })
             .unwrap_or_default();
         let due_color = task
@@ -527,7 +704,7 @@ fn build_task_details(
         ),
         lbl("Created"),
         Span::styled(
-            task.created_at.format("%Y-%m-%d").to_string(),
+            task.created_on().format("%Y-%m-%d").to_string(),
             Style::default().fg(Color::White),
         ),
     ]));
@@ -547,11 +724,33 @@ fn build_task_details(
         lines.push(Line::from(vec![
             lbl("Completed"),
             Span::styled(
-                done_at.format("%Y-%m-%d").to_string(),
+                done_at
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
                 Style::default().fg(theme.done),
             ),
         ]));
     }
+    if let Some(cancelled_at) = task.cancelled_at {
+        let mut spans = vec![
+            lbl("Cancelled"),
+            Span::styled(
+                cancelled_at
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ];
+        if let Some(reason) = &task.cancel_reason {
+            spans.push(Span::styled(
+                format!("  {}", reason),
+                Style::default().fg(Color::Gray),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
 
     // ── Dependencies ─────────────────────────────────────────────────────────
     let visible: Vec<&Task> = all_tasks.iter().filter(|t| !t.is_deleted()).collect();
@@ -692,7 +891,10 @@ fn draw_tree_details(
         .filter(|t| proj_uuid.is_some() && t.project_id == proj_uuid)
         .collect();
     let pending = tasks.iter().filter(|t| !t.completed).count();
-    let done = tasks.iter().filter(|t| t.completed).count();
+    let done = tasks
+        .iter()
+        .filter(|t| t.completed && !t.is_cancelled())
+        .count();
     let blocked = tasks
         .iter()
         .filter(|t| {
@@ -754,7 +956,7 @@ fn draw_tree_details(
         };
         lines.push(Line::from(vec![
             pri,
-            Span::styled(task.text.clone(), text_style),
+            Span::styled(first_line(&task.text).to_string(), text_style),
         ]));
     }
 
@@ -819,7 +1021,10 @@ fn draw_context_panel(
     };
 
     let pending = tasks.iter().filter(|t| !t.completed).count();
-    let done = tasks.iter().filter(|t| t.completed).count();
+    let done = tasks
+        .iter()
+        .filter(|t| t.completed && !t.is_cancelled())
+        .count();
     let blocked = tasks
         .iter()
         .filter(|t| {
@@ -868,7 +1073,9 @@ fn draw_context_panel(
                     .iter()
                     .any(|t2| t2.uuid == *dep_uuid && !t2.completed)
             });
-        let (s, s_color) = if task.completed {
+        let (s, s_color) = if task.is_cancelled() {
+            ("C", Color::DarkGray)
+        } else if task.completed {
             ("D", Color::Green)
         } else if is_blocked {
             ("B", Color::Red)
@@ -882,7 +1089,10 @@ fn draw_context_panel(
         };
         lines.push(Line::from(vec![
             Span::styled(format!("{} ", s), Style::default().fg(s_color)),
-            Span::styled(truncate(&task.text, 28), Style::default().fg(Color::White)),
+            Span::styled(
+                truncate(first_line(&task.text), 28),
+                Style::default().fg(Color::White),
+            ),
             Span::raw("  "),
             Span::styled(
                 match task.priority {
@@ -1148,6 +1358,25 @@ fn draw_footer(f: &mut Frame, app: &App, area: Rect, theme: &ResolvedTheme) {
                 Span::styled("[Esc]", Style::default().fg(Color::Red)),
                 Span::raw(" cancel"),
             ]),
+            _ if app.focused_panel == FocusedPanel::Right
+                && app.left_panel == LeftPanel::Tasks
+                && app.right_panel == RightPanel::Deps =>
+            {
+                Line::from(vec![
+                    Span::styled("j/k", Style::default().fg(theme.accent)),
+                    Span::raw(" nav  "),
+                    Span::styled("Space", Style::default().fg(theme.accent)),
+                    Span::raw(" fold  "),
+                    Span::styled("Enter", Style::default().fg(Color::Green)),
+                    Span::raw(" jump  "),
+                    Span::styled("D", Style::default().fg(theme.accent)),
+                    Span::raw(" details  "),
+                    Span::styled("Tab", Style::default().fg(theme.accent)),
+                    Span::raw(" focus  "),
+                    Span::styled("?", Style::default().fg(theme.accent)),
+                    Span::raw(" help"),
+                ])
+            }
             _ => Line::from(vec![
                 Span::styled("j/k", Style::default().fg(theme.accent)),
                 Span::raw(" nav  "),
@@ -1218,6 +1447,13 @@ fn help_entries() -> Vec<HelpEntry> {
             action: "Cycle left panel tabs",
             description: Some("Switches the left panel [1] between Tasks, Projects, and Tags."),
         },
+        HelpEntry {
+            key: "D",
+            action: "Dependency pane",
+            description: Some(
+                "Shows what the task depends on and what depends on it. j/k move, Space folds, Enter jumps to the task, D returns to details.",
+            ),
+        },
         HelpEntry {
             key: "──── Actions",
             action: "",
@@ -1291,6 +1527,14 @@ fn help_entries() -> Vec<HelpEntry> {
     ]
 }
 
+/// Number of help entries that can be selected (section headers excluded).
+pub fn help_selectable_count() -> usize {
+    help_entries()
+        .iter()
+        .filter(|e| !e.action.is_empty())
+        .count()
+}
+
 fn draw_help_popup(f: &mut Frame, app: &App, area: Rect, theme: &ResolvedTheme) {
     let entries = help_entries();
     let selectable: Vec<usize> = entries
@@ -1,14 +1,17 @@
 //! Yes/no confirmation prompt for destructive operations.
 //!
 //! Used by [`commands::task_remove`] and [`commands::task_clear`] before
-//! irreversible actions.
+//! irreversible actions. [`read_key`] and [`prompt`] serve the interactive
+//! `todo triage` loop.
 //!
 //! [`commands::task_remove`]: crate::commands::task_remove
 //! [`commands::task_clear`]: crate::commands::task_clear
 
 use anyhow::Result;
 use colored::Colorize;
-use std::io::{self, Write};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use std::io::{self, IsTerminal, Write};
 
 /// Prompts the user for confirmation.
 ///
@@ -29,3 +32,51 @@ pub fn confirm(message: &str) -> Result<bool> {
     let response = input.trim().to_lowercase();
     Ok(matches!(response.as_str(), "y" | "yes"))
 }
+
+/// Prints `message` and reads one line of input, trimmed.
+pub fn prompt(message: &str) -> Result<String> {
+    print!("{} ", message.yellow());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+/// Reads a single keypress without waiting for Enter.
+///
+/// Esc and Ctrl-C come back as `'q'`, Enter as `' '`. When stdin is not a
+/// terminal, a whole line is read and its first character used instead
+/// (an empty line counts as `' '`; end of input as `'q'`).
+pub fn read_key() -> Result<char> {
+    if !io::stdin().is_terminal() {
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input)? == 0 {
+            return Ok('q');
+        }
+        return Ok(input
+            .trim_end_matches(['\n', '\r'])
+            .chars()
+            .next()
+            .unwrap_or(' '));
+    }
+
+    enable_raw_mode()?;
+    let key = loop {
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    break Ok('q');
+                }
+                KeyCode::Char(c) => break Ok(c),
+                KeyCode::Enter => break Ok(' '),
+                KeyCode::Esc => break Ok('q'),
+                _ => {}
+            },
+            Ok(_) => {}
+            Err(e) => break Err(e),
+        }
+    };
+    disable_raw_mode()?;
+    Ok(key?)
+}
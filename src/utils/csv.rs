@@ -0,0 +1,106 @@
+//! Minimal RFC 4180 CSV reading and writing.
+//!
+//! Fields are separated by commas; a field containing a comma, quote, or
+//! line break is wrapped in double quotes, with inner quotes doubled.
+
+use anyhow::{Result, bail};
+
+/// Quotes a CSV field when it contains a delimiter, quote, or line break.
+pub fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Joins fields into one CSV line, without the line break.
+pub fn line<S: AsRef<str>>(fields: &[S]) -> String {
+    fields
+        .iter()
+        .map(|f| field(f.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Splits CSV text into records of fields.
+///
+/// Accepts `\n` and `\r\n` line endings, quoted fields spanning lines, and a
+/// leading byte-order mark (as written by spreadsheet programs). Blank
+/// lines are skipped.
+pub fn parse(input: &str) -> Result<Vec<Vec<String>>> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut line_no = 1;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    current.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' {
+                        line_no += 1;
+                    }
+                    current.push(c);
+                }
+            }
+            continue;
+        }
+        match c {
+            '"' if current.is_empty() => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut current)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut current));
+                if record.iter().any(|f| !f.is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+                line_no += 1;
+            }
+            _ => current.push(c),
+        }
+    }
+    if in_quotes {
+        bail!("unterminated quoted field on line {}", line_no);
+    }
+    record.push(current);
+    if record.iter().any(|f| !f.is_empty()) {
+        records.push(record);
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_quoting() {
+        assert_eq!(field("plain"), "plain");
+        assert_eq!(field("a, b"), "\"a, b\"");
+        assert_eq!(field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_parse_round_trips_quoted_fields() {
+        let text = "two\nlines, \"quoted\"";
+        let csv = format!(
+            "{}\r\n\n{}\n",
+            line(&["text", "tags"]),
+            line(&[text, "a b"])
+        );
+
+        let records = parse(&format!("\u{feff}{}", csv)).unwrap();
+        assert_eq!(records, vec![vec!["text", "tags"], vec![text, "a b"]]);
+        assert!(parse("\"open").is_err());
+    }
+}
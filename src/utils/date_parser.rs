@@ -1,24 +1,35 @@
 //! Natural language date parsing
 //!
 //! Parses dates from both natural language (e.g., "tomorrow", "next friday")
-//! and strict format (YYYY-MM-DD).
+//! and strict format (YYYY-MM-DD). Due dates may end in a time of day
+//! (`tomorrow 17:00`, `friday at 5pm`); see [`parse_due`].
 
 use anyhow::{Context, Result, bail};
-use chrono::{Duration, Local, NaiveDate};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveTime, Utc};
 use chrono_english::{Dialect, parse_date_string};
 use std::sync::LazyLock;
 
+use crate::services::holidays::HolidayCache;
+
 // Regex compiled once via LazyLock, avoiding recompilation on every call
 // to try_parse_custom_patterns.
 static RE_IN_N_DAYS: LazyLock<regex::Regex> =
     LazyLock::new(|| regex::Regex::new(r"in (\d+) days?").unwrap());
 
+static RE_IN_N_BUSINESS_DAYS: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"in (\d+) (?:business|work) ?days?").unwrap());
+
 static RE_IN_N_WEEKS: LazyLock<regex::Regex> =
     LazyLock::new(|| regex::Regex::new(r"in (\d+) weeks?").unwrap());
 
 static RE_IN_N_MONTHS: LazyLock<regex::Regex> =
     LazyLock::new(|| regex::Regex::new(r"in (\d+) months?").unwrap());
 
+/// A trailing time of day: `17:00`, `at 9:30`, `5pm`, `5:15 pm`.
+static RE_TIME_SUFFIX: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(r"^(?:(.*?)\s+)?(?:at\s+)?(\d{1,2})(?::(\d{2}))?\s*(am|pm)?$").unwrap()
+});
+
 /// Parses a date from either natural language or YYYY-MM-DD format.
 ///
 /// # Supported formats
@@ -26,6 +37,8 @@ static RE_IN_N_MONTHS: LazyLock<regex::Regex> =
 /// **Natural language:**
 /// - `today`, `tomorrow`, `yesterday`
 /// - `in N days` (e.g., `in 3 days`)
+/// - `in N business days` / `in N workdays`, skipping weekends and the
+///   configured holidays
 /// - `in N weeks` (e.g., `in 2 weeks`)
 /// - `in N months` (e.g., `in 1 month`)
 /// - `monday`, `next friday`, `next monday`
@@ -72,6 +85,7 @@ pub fn parse_date(input: &str) -> Result<NaiveDate> {
                 "Could not parse date: '{}'\n\n\
                 Accepted formats:\n  \
                 * Natural language: tomorrow, next friday, in 3 days, in 2 weeks, in 1 month\n  \
+                * Business days:    in 5 business days, in 2 workdays\n  \
                 * Weekdays:         monday, tuesday, next wednesday\n  \
                 * Month and day:    jan 15, march 20, december 25\n  \
                 * Strict format:    YYYY-MM-DD (e.g. 2026-02-20)",
@@ -109,11 +123,109 @@ pub fn parse_date_not_in_past(input: &str) -> Result<NaiveDate> {
     Ok(date)
 }
 
+/// Parses a due date with an optional trailing time of day.
+///
+/// The date part accepts everything [`parse_date`] does; a bare time means
+/// today. A time needs minutes or `am`/`pm`, so `in 3 days` and `jan 15`
+/// stay plain dates.
+///
+/// # Examples
+///
+/// ```
+/// use rustodo::utils::date_parser::parse_due;
+///
+/// let (date, time) = parse_due("tomorrow 17:00").unwrap();
+/// assert_eq!(time.unwrap().to_string(), "17:00:00");
+/// let (date, time) = parse_due("friday at 5pm").unwrap();
+/// let (date, time) = parse_due("2026-02-20").unwrap();
+/// assert!(time.is_none());
+/// ```
+pub fn parse_due(input: &str) -> Result<(NaiveDate, Option<NaiveTime>)> {
+    let trimmed = input.trim().to_lowercase();
+    if let Some((date_part, time)) = split_time(&trimmed) {
+        let date = match date_part {
+            Some(part) => parse_date(part)?,
+            None => Local::now().date_naive(),
+        };
+        return Ok((date, Some(time)));
+    }
+    Ok((parse_date(input)?, None))
+}
+
+/// Like [`parse_due`], rejecting dates before today as
+/// [`parse_date_not_in_past`] does.
+pub fn parse_due_not_in_past(input: &str) -> Result<(NaiveDate, Option<NaiveTime>)> {
+    let (date, time) = parse_due(input)?;
+    if date < Local::now().date_naive() {
+        // Reuse the past-date message, which names the input
+        parse_date_not_in_past(input.trim())?;
+    }
+    Ok((date, time))
+}
+
+/// Parses a point in time: an RFC 3339 timestamp, or anything
+/// [`parse_due`] accepts, read as local time (midnight without a time).
+///
+/// Used by `todo list --modified-since`, so scripts can pass back the
+/// timestamp of their previous run.
+pub fn parse_instant(input: &str) -> Result<DateTime<Utc>> {
+    if let Ok(instant) = DateTime::parse_from_rfc3339(input.trim()) {
+        return Ok(instant.with_timezone(&Utc));
+    }
+    let (date, time) = parse_due(input)?;
+    let local = date.and_time(time.unwrap_or(NaiveTime::MIN));
+    Ok(local
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| local.and_utc()))
+}
+
+/// Splits a trailing time of day off `input`, returning the date part (if
+/// any) and the time.
+fn split_time(input: &str) -> Option<(Option<&str>, NaiveTime)> {
+    // `2026-02-20T17:00` is the ISO spelling of `2026-02-20 17:00`
+    if let Some((date, time)) = input.split_once('t')
+        && NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok()
+    {
+        let time = NaiveTime::parse_from_str(time, "%H:%M").ok()?;
+        return Some((Some(date), time));
+    }
+
+    let caps = RE_TIME_SUFFIX.captures(input)?;
+    let minutes = caps.get(3);
+    let meridiem = caps.get(4).map(|m| m.as_str());
+    if minutes.is_none() && meridiem.is_none() {
+        return None;
+    }
+    let mut hour: u32 = caps[2].parse().ok()?;
+    let minute: u32 = minutes.map_or(Some(0), |m| m.as_str().parse().ok())?;
+    match meridiem {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some("am") if hour == 12 => hour = 0,
+        Some("pm") if hour < 12 => hour += 12,
+        _ => {}
+    }
+    let time = NaiveTime::from_hms_opt(hour, minute, 0)?;
+    let date = caps.get(1).map(|m| m.as_str()).filter(|d| !d.is_empty());
+    Some((date, time))
+}
+
 /// Tries custom date patterns that chrono-english does not handle well.
 fn try_parse_custom_patterns(input: &str) -> Option<NaiveDate> {
+    // Every pattern starts with "in "; skip compiling the regexes otherwise
+    if !input.contains("in ") {
+        return None;
+    }
     let today = Local::now().date_naive();
 
     // Uses static regexes instead of compiling on every call
+    if let Some(caps) = RE_IN_N_BUSINESS_DAYS.captures(input) {
+        let days: u32 = caps[1].parse().ok()?;
+        let holidays = HolidayCache::configured(&[today.year(), today.year() + 1]);
+        return Some(holidays.add_business_days(today, days));
+    }
+
     if let Some(caps) = RE_IN_N_DAYS.captures(input) {
         let days: i64 = caps[1].parse().ok()?;
         return Some(today + Duration::days(days));
@@ -136,6 +248,54 @@ fn try_parse_custom_patterns(input: &str) -> Option<NaiveDate> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_due_with_time() {
+        let tomorrow = Local::now().date_naive() + Duration::days(1);
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0);
+
+        assert_eq!(parse_due("tomorrow 17:00").unwrap(), (tomorrow, at(17, 0)));
+        assert_eq!(parse_due("Tomorrow at 5pm").unwrap(), (tomorrow, at(17, 0)));
+        assert_eq!(parse_due("tomorrow 12am").unwrap(), (tomorrow, at(0, 0)));
+        assert_eq!(
+            parse_due("tomorrow 9:30 am").unwrap(),
+            (tomorrow, at(9, 30))
+        );
+        let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
+        assert_eq!(parse_due("2026-02-20 08:15").unwrap(), (date, at(8, 15)));
+        assert_eq!(parse_due("2026-02-20T08:15").unwrap(), (date, at(8, 15)));
+        assert_eq!(
+            parse_due("18:00").unwrap(),
+            (Local::now().date_naive(), at(18, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_due_without_time() {
+        let date = NaiveDate::from_ymd_opt(2026, 2, 20).unwrap();
+        assert_eq!(parse_due("2026-02-20").unwrap(), (date, None));
+        assert_eq!(parse_due("in 3 days").unwrap().1, None);
+        assert_eq!(parse_due("jan 15").unwrap().1, None);
+        assert!(parse_due("tomorrow 25:00").is_err());
+        assert!(parse_due("tomorrow 13pm").is_err());
+    }
+
+    #[test]
+    fn test_parse_instant() {
+        assert_eq!(
+            parse_instant("2026-02-20T08:15:00Z").unwrap().to_rfc3339(),
+            "2026-02-20T08:15:00+00:00"
+        );
+        let midnight = Local::now().date_naive().and_time(NaiveTime::MIN);
+        assert_eq!(
+            parse_instant("today")
+                .unwrap()
+                .with_timezone(&Local)
+                .naive_local(),
+            midnight
+        );
+        assert!(parse_instant("not a date").is_err());
+    }
+
     #[test]
     fn test_parse_strict_format() {
         let date = parse_date("2026-02-20").unwrap();
@@ -0,0 +1,71 @@
+//! Multi-ID arguments for `todo done`, `undone`, `remove`, and
+//! `edit --replace-text`.
+//!
+//! Each argument is a single ID (`3`) or an inclusive range (`2-6`);
+//! [`expand`] flattens them in the order given, dropping repeats.
+
+use std::str::FromStr;
+
+/// One `ID` argument: a single visible ID or an inclusive `START-END` range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl FromStr for IdRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |part: &str| {
+            part.trim()
+                .parse::<usize>()
+                .map_err(|_| format!("invalid ID '{}': expected a number or a range like 2-6", s))
+        };
+        let (start, end) = match s.split_once('-') {
+            Some((start, end)) => (parse(start)?, parse(end)?),
+            None => {
+                let id = parse(s)?;
+                (id, id)
+            }
+        };
+        if start > end {
+            return Err(format!("invalid range '{}': start is after end", s));
+        }
+        Ok(Self { start, end })
+    }
+}
+
+/// All IDs covered by `ranges`, in order of first appearance.
+pub fn expand(ranges: &[IdRange]) -> Vec<usize> {
+    let mut ids = Vec::new();
+    for id in ranges.iter().flat_map(|r| r.start..=r.end) {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(args: &[&str]) -> Vec<IdRange> {
+        args.iter().map(|a| a.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_expand_ids_and_ranges() {
+        assert_eq!(expand(&ranges(&["3", "5", "7"])), vec![3, 5, 7]);
+        assert_eq!(expand(&ranges(&["2-4", "9"])), vec![2, 3, 4, 9]);
+        assert_eq!(expand(&ranges(&["4", "3-5"])), vec![4, 3, 5]);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_input() {
+        assert!("abc".parse::<IdRange>().is_err());
+        assert!("6-2".parse::<IdRange>().is_err());
+        assert!("2-".parse::<IdRange>().is_err());
+    }
+}
@@ -2,14 +2,32 @@
 //!
 //! | Module | Purpose |
 //! |---|---|
-//! | [`confirm`] | Yes/no prompt for destructive operations |
+//! | [`base64`] | RFC 4648 base64 encoding and decoding |
+//! | [`confirm`] | Yes/no, line and single-key prompts |
+//! | [`csv`] | CSV field quoting and parsing |
+//! | [`timing`] | Per-phase durations for `--timing` |
 //! | [`tag_normalizer`] | Fuzzy tag normalization with Levenshtein distance |
 //! | [`date_parser`] |
+//! | [`id_list`] | `3 5 7` / `2-6` multi-ID arguments |
+//! | [`query`] | Filter expressions for `todo list --query` |
+//! | [`quick_capture`] | Inline `!priority #tag @project date` parsing for `todo add` |
+//! | [`stdin_arg`] | Reads `-` text arguments from stdin |
+//! | [`substitution`] | sed-style `s/old/new/` expressions for `todo edit --replace-text` |
+//! | [`suggest`] | "Did you mean …?" suggestions for mistyped project names |
 //! | [`validation`] | Input validation for task fields |
 
+pub mod base64;
 pub mod confirm;
+pub mod csv;
 pub mod date_parser;
+pub mod id_list;
+pub mod query;
+pub mod quick_capture;
+pub mod stdin_arg;
+pub mod substitution;
+pub mod suggest;
 pub mod tag_normalizer;
+pub mod timing;
 pub mod validation;
 
 pub use confirm::confirm;
@@ -0,0 +1,438 @@
+//! Filter expressions for `todo list --query`.
+//!
+//! ```text
+//! status:pending and (tag:work or priority:high) and due<friday
+//! ```
+//!
+//! A query is made of `field<op>value` terms combined with `and`, `or`,
+//! `not` (or a leading `-`) and parentheses. `and` binds tighter than `or`,
+//! and terms placed side by side are joined with `and`. A word without an
+//! operator matches the task text.
+//!
+//! | Field        | Values                                                        |
+//! |--------------|---------------------------------------------------------------|
+//! | `status`     | `pending`, `done`, `cancelled`, `blocked`, `waiting`, `all`   |
+//! | `priority`   | `high`, `medium`, `low` (or `h`/`m`/`l`); `>`/`<` compare rank |
+//! | `tag`        | a tag name                                                    |
+//! | `project`    | a project name (case-insensitive), or `none`                  |
+//! | `due`        | `overdue`, `today`, `tomorrow`, `soon`, `week`, `none`, `any`, or a date |
+//! | `created`    | a date                                                        |
+//! | `completed`  | a date                                                        |
+//! | `recur`      | `daily`, `weekly`, `monthly`, `any`, `none`                   |
+//! | `text`       | a substring (case-insensitive)                                |
+//!
+//! Operators are `:` and `=` (equal), `<`, `<=`, `>`, `>=`. Dates accept
+//! everything `--due` does (`2026-05-01`, `friday`, `in 3 days`); quote
+//! values that contain spaces: `due<"next friday"`. A date comparison never
+//! matches a task without that date.
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+
+use crate::models::{DueFilter, Priority, Project, Recurrence, StatusFilter, Task};
+use crate::utils::date_parser;
+
+/// A parsed query expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Query {
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Not(Box<Query>),
+    Status(StatusTerm),
+    Priority(Cmp, Priority),
+    Tag(String),
+    /// Project name, or `None` for tasks without a project
+    Project(Option<String>),
+    Due(DueFilter),
+    DueDate(Cmp, NaiveDate),
+    Created(Cmp, NaiveDate),
+    Completed(Cmp, NaiveDate),
+    Recur(Option<Option<Recurrence>>),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusTerm {
+    Is(StatusFilter),
+    Blocked,
+    Waiting,
+}
+
+/// Comparison operator of a term.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cmp {
+    Eq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Cmp {
+    fn test<T: Ord>(self, left: T, right: T) -> bool {
+        match self {
+            Cmp::Eq => left == right,
+            Cmp::Lt => left < right,
+            Cmp::Le => left <= right,
+            Cmp::Gt => left > right,
+            Cmp::Ge => left >= right,
+        }
+    }
+}
+
+/// What a query needs besides the task itself.
+pub struct QueryContext<'a> {
+    pub all_tasks: &'a [Task],
+    pub projects: &'a [Project],
+    pub today: NaiveDate,
+    pub soon_days: i64,
+}
+
+impl Query {
+    pub fn matches(&self, task: &Task, ctx: &QueryContext) -> bool {
+        let local_date = |at: DateTime<Utc>| at.with_timezone(&Local).date_naive();
+        match self {
+            Query::And(a, b) => a.matches(task, ctx) && b.matches(task, ctx),
+            Query::Or(a, b) => a.matches(task, ctx) || b.matches(task, ctx),
+            Query::Not(q) => !q.matches(task, ctx),
+            Query::Status(StatusTerm::Is(status)) => task.matches_status(*status),
+            Query::Status(StatusTerm::Blocked) => !task.completed && task.is_blocked(ctx.all_tasks),
+            Query::Status(StatusTerm::Waiting) => !task.completed && task.is_waiting(),
+            // Rank so that `priority>medium` means "more urgent than medium"
+            Query::Priority(cmp, p) => cmp.test(2 - task.priority.order(), 2 - p.order()),
+            Query::Tag(tag) => task.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)),
+            Query::Project(None) => task.project_id.is_none(),
+            Query::Project(Some(name)) => task.project_id.is_some_and(|pid| {
+                ctx.projects
+                    .iter()
+                    .any(|p| p.uuid == pid && !p.is_deleted() && p.name.eq_ignore_ascii_case(name))
+            }),
+            Query::Due(filter) => task.matches_due_filter_on(*filter, ctx.today, ctx.soon_days),
+            Query::DueDate(cmp, date) => task.due_date.is_some_and(|d| cmp.test(d, *date)),
+            Query::Created(cmp, date) => cmp.test(local_date(task.created_at), *date),
+            Query::Completed(cmp, date) => task
+                .completed_at
+                .is_some_and(|at| cmp.test(local_date(at), *date)),
+            Query::Recur(None) => task.recurrence.is_some(),
+            Query::Recur(Some(recurrence)) => task.recurrence == *recurrence,
+            Query::Text(text) => task.text.to_lowercase().contains(&text.to_lowercase()),
+        }
+    }
+}
+
+// ── Parsing ───────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Open,
+    Close,
+    /// A term or keyword; `quoted` words are never keywords
+    Word {
+        text: String,
+        quoted: bool,
+    },
+}
+
+/// Parses a query expression.
+pub fn parse(input: &str) -> Result<Query> {
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        bail!("empty query");
+    }
+    let mut parser = Parser { tokens, pos: 0 };
+    let query = parser.or()?;
+    if let Some(token) = parser.tokens.get(parser.pos) {
+        bail!("unexpected {} in query", describe(token));
+    }
+    Ok(query)
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            _ => {
+                let mut text = String::new();
+                let mut quoted = false;
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' {
+                        break;
+                    }
+                    chars.next();
+                    if c != '"' {
+                        text.push(c);
+                        continue;
+                    }
+                    quoted = true;
+                    loop {
+                        match chars.next() {
+                            Some('"') => break,
+                            Some(c) => text.push(c),
+                            None => bail!("unterminated quote in query"),
+                        }
+                    }
+                }
+                tokens.push(Token::Word { text, quoted });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Open => "'('".to_string(),
+        Token::Close => "')'".to_string(),
+        Token::Word { text, .. } => format!("'{}'", text),
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn keyword(&self, name: &str) -> bool {
+        matches!(
+            self.tokens.get(self.pos),
+            Some(Token::Word { text, quoted: false }) if text.eq_ignore_ascii_case(name)
+        )
+    }
+
+    fn or(&mut self) -> Result<Query> {
+        let mut left = self.and()?;
+        while self.keyword("or") {
+            self.pos += 1;
+            left = Query::Or(Box::new(left), Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Query> {
+        let mut left = self.unary()?;
+        loop {
+            if self.keyword("and") {
+                self.pos += 1;
+            } else if self.keyword("or")
+                || matches!(self.tokens.get(self.pos), None | Some(Token::Close))
+            {
+                return Ok(left);
+            }
+            left = Query::And(Box::new(left), Box::new(self.unary()?));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Query> {
+        if self.keyword("not") {
+            self.pos += 1;
+            return Ok(Query::Not(Box::new(self.unary()?)));
+        }
+        match self.tokens.get(self.pos).cloned() {
+            None => bail!("query ends where a term was expected"),
+            Some(Token::Open) => {
+                self.pos += 1;
+                let inner = self.or()?;
+                if self.tokens.get(self.pos) != Some(&Token::Close) {
+                    bail!("missing ')' in query");
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            Some(Token::Close) => bail!("unexpected ')' in query"),
+            Some(Token::Word { text, quoted }) => {
+                self.pos += 1;
+                if quoted {
+                    return term(&text);
+                }
+                match text.strip_prefix('-') {
+                    Some(rest) if !rest.is_empty() => Ok(Query::Not(Box::new(term(rest)?))),
+                    _ => term(&text),
+                }
+            }
+        }
+    }
+}
+
+/// Parses one `field<op>value` term, or a bare text word.
+fn term(word: &str) -> Result<Query> {
+    let Some(at) = word.find([':', '<', '>', '=']) else {
+        return Ok(Query::Text(word.to_string()));
+    };
+    let field = word[..at].to_lowercase();
+    let rest = &word[at..];
+    let (cmp, value) = if let Some(v) = rest.strip_prefix("<=") {
+        (Cmp::Le, v)
+    } else if let Some(v) = rest.strip_prefix(">=") {
+        (Cmp::Ge, v)
+    } else if let Some(v) = rest.strip_prefix('<') {
+        (Cmp::Lt, v)
+    } else if let Some(v) = rest.strip_prefix('>') {
+        (Cmp::Gt, v)
+    } else {
+        (Cmp::Eq, &rest[1..])
+    };
+    if value.is_empty() {
+        bail!("missing value in query term '{}'", word);
+    }
+    let lower = value.to_lowercase();
+    let equality_only = |query: Query| {
+        if cmp == Cmp::Eq {
+            Ok(query)
+        } else {
+            bail!("'{}' only supports ':' in query term '{}'", field, word)
+        }
+    };
+
+    match field.as_str() {
+        "status" | "is" => equality_only(Query::Status(match lower.as_str() {
+            "pending" | "open" => StatusTerm::Is(StatusFilter::Pending),
+            "done" | "completed" => StatusTerm::Is(StatusFilter::Done),
+            "cancelled" | "canceled" => StatusTerm::Is(StatusFilter::Cancelled),
+            "all" => StatusTerm::Is(StatusFilter::All),
+            "blocked" => StatusTerm::Blocked,
+            "waiting" => StatusTerm::Waiting,
+            _ => bail!("unknown status '{}' in query", value),
+        })),
+        "priority" | "pri" => Ok(Query::Priority(
+            cmp,
+            match lower.as_str() {
+                "high" | "h" => Priority::High,
+                "medium" | "m" => Priority::Medium,
+                "low" | "l" => Priority::Low,
+                _ => bail!("unknown priority '{}' in query", value),
+            },
+        )),
+        "tag" => equality_only(Query::Tag(value.trim_start_matches('#').to_string())),
+        "project" => equality_only(Query::Project(
+            Some(value.to_string()).filter(|_| lower != "none"),
+        )),
+        "due" if cmp == Cmp::Eq => Ok(match lower.as_str() {
+            "overdue" => Query::Due(DueFilter::Overdue),
+            "today" => Query::Due(DueFilter::Today),
+            "tomorrow" => Query::Due(DueFilter::Tomorrow),
+            "soon" => Query::Due(DueFilter::Soon),
+            "week" | "this-week" => Query::Due(DueFilter::ThisWeek),
+            "none" => Query::Due(DueFilter::NoDue),
+            "any" => Query::Due(DueFilter::WithDue),
+            _ => Query::DueDate(cmp, date_parser::parse_date(value)?),
+        }),
+        "due" => Ok(Query::DueDate(cmp, date_parser::parse_date(value)?)),
+        "created" => Ok(Query::Created(cmp, date_parser::parse_date(value)?)),
+        "completed" | "done" => Ok(Query::Completed(cmp, date_parser::parse_date(value)?)),
+        "recur" | "recurrence" => equality_only(Query::Recur(match lower.as_str() {
+            "any" => None,
+            "none" => Some(None),
+            "daily" => Some(Some(Recurrence::Daily)),
+            "weekly" => Some(Some(Recurrence::Weekly)),
+            "monthly" => Some(Some(Recurrence::Monthly)),
+            _ => bail!("unknown recurrence '{}' in query", value),
+        })),
+        "text" => equality_only(Query::Text(value.to_string())),
+        _ => bail!(
+            "unknown query field '{}' (expected status, priority, tag, project, due, \
+             created, completed, recur, or text)",
+            field
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn task(text: &str, priority: Priority, tags: &[&str], due: Option<NaiveDate>) -> Task {
+        let tags = tags.iter().map(|t| t.to_string()).collect();
+        Task::new(text.into(), priority, tags, None, due, None)
+    }
+
+    fn run(query: &str, tasks: &[Task]) -> Vec<String> {
+        let query = parse(query).unwrap();
+        let ctx = QueryContext {
+            all_tasks: tasks,
+            projects: &[],
+            today: d(2030, 1, 10),
+            soon_days: 7,
+        };
+        tasks
+            .iter()
+            .filter(|t| query.matches(t, &ctx))
+            .map(|t| t.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_precedence_and_grouping() {
+        assert_eq!(
+            parse("tag:a or tag:b and tag:c").unwrap(),
+            Query::Or(
+                Box::new(Query::Tag("a".into())),
+                Box::new(Query::And(
+                    Box::new(Query::Tag("b".into())),
+                    Box::new(Query::Tag("c".into()))
+                ))
+            )
+        );
+        assert_eq!(
+            parse("(tag:a or tag:b) -tag:c").unwrap(),
+            Query::And(
+                Box::new(Query::Or(
+                    Box::new(Query::Tag("a".into())),
+                    Box::new(Query::Tag("b".into()))
+                )),
+                Box::new(Query::Not(Box::new(Query::Tag("c".into()))))
+            )
+        );
+    }
+
+    #[test]
+    fn test_matches_combined_filters() {
+        let mut shipped = task("Ship", Priority::High, &["work"], Some(d(2030, 1, 5)));
+        shipped.mark_done();
+        let tasks = vec![
+            task("Report", Priority::Low, &["work"], Some(d(2030, 1, 11))),
+            task("Dentist", Priority::High, &[], Some(d(2030, 2, 1))),
+            task("Groceries", Priority::Medium, &["home"], None),
+            shipped,
+        ];
+
+        let query = "status:pending and (tag:work or priority:high) and due<2030-01-20";
+        assert_eq!(run(query, &tasks), vec!["Report"]);
+        assert_eq!(
+            run("not status:done priority>=medium", &tasks),
+            vec!["Dentist", "Groceries"]
+        );
+        assert_eq!(
+            run("due:none or \"ship\"", &tasks),
+            vec!["Groceries", "Ship"]
+        );
+        assert_eq!(run("due:overdue", &tasks), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("(tag:a").is_err());
+        assert!(parse("tag:a)").is_err());
+        assert!(parse("colour:red").is_err());
+        assert!(parse("tag<a").is_err());
+        assert!(parse("priority:urgent").is_err());
+        assert!(parse("due<\"next").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+//! Inline metadata parsing for unquoted `todo add` input.
+//!
+//! `todo add fix login bug tomorrow !high #work @backend` is split into the
+//! description `fix login bug` plus:
+//!
+//! | Token              | Meaning            |
+//! |--------------------|--------------------|
+//! | `!high` / `!h`     | priority (`!medium`/`!m`, `!low`/`!l`) |
+//! | `#work` / `+work`  | tag                |
+//! | `@backend`         | project            |
+//! | `due:<date>`       | due date           |
+//! | trailing date      | due date (`tomorrow`, `next friday`, `in 3 days`, `2026-05-01`) |
+//!
+//! A trailing date is only recognised when it starts with a date-like word,
+//! so descriptions such as `read chapter 2` are left untouched.
+
+use crate::models::Priority;
+use crate::utils::date_parser;
+
+/// The result of splitting free words into a description and metadata.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct QuickCapture {
+    pub text: String,
+    pub priority: Option<Priority>,
+    pub tags: Vec<String>,
+    pub project: Option<String>,
+    /// Raw date expression, validated later by the `add` handler.
+    pub due: Option<String>,
+}
+
+const DATE_LEAD_WORDS: &[&str] = &[
+    "today",
+    "tomorrow",
+    "next",
+    "in",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+    "mon",
+    "tue",
+    "wed",
+    "thu",
+    "fri",
+    "sat",
+    "sun",
+    "jan",
+    "feb",
+    "mar",
+    "apr",
+    "may",
+    "jun",
+    "jul",
+    "aug",
+    "sep",
+    "oct",
+    "nov",
+    "dec",
+    "january",
+    "february",
+    "march",
+    "april",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Splits `words` into a description and inline metadata tokens.
+pub fn parse(words: &[String]) -> QuickCapture {
+    let mut capture = QuickCapture::default();
+    let mut rest: Vec<&str> = Vec::new();
+
+    for word in words.iter().flat_map(|w| w.split_whitespace()) {
+        if let Some(p) = word.strip_prefix('!').and_then(parse_priority) {
+            capture.priority = Some(p);
+        } else if let Some(tag) = word
+            .strip_prefix('#')
+            .or_else(|| word.strip_prefix('+'))
+            .filter(|t| !t.is_empty())
+        {
+            capture.tags.push(tag.to_string());
+        } else if let Some(project) = word.strip_prefix('@').filter(|p| !p.is_empty()) {
+            capture.project = Some(project.to_string());
+        } else if let Some(due) = word.strip_prefix("due:").filter(|d| !d.is_empty()) {
+            capture.due = Some(due.to_string());
+        } else {
+            rest.push(word);
+        }
+    }
+
+    if capture.due.is_none()
+        && let Some((len, phrase)) = trailing_date(&rest)
+    {
+        capture.due = Some(phrase);
+        rest.truncate(rest.len() - len);
+    }
+
+    capture.text = rest.join(" ");
+    capture
+}
+
+fn parse_priority(s: &str) -> Option<Priority> {
+    match s.to_lowercase().as_str() {
+        "high" | "h" => Some(Priority::High),
+        "medium" | "med" | "m" => Some(Priority::Medium),
+        "low" | "l" => Some(Priority::Low),
+        _ => None,
+    }
+}
+
+/// Finds the longest (up to three words) trailing date phrase, leaving at
+/// least one word of description. Returns `(word_count, phrase)`.
+fn trailing_date(words: &[&str]) -> Option<(usize, String)> {
+    for len in (1..=3).rev() {
+        if words.len() <= len {
+            continue;
+        }
+        let tail = &words[words.len() - len..];
+        let lead = tail[0].to_lowercase();
+        let is_iso = lead.len() == 10 && lead.chars().filter(|c| *c == '-').count() == 2;
+        if !is_iso && !DATE_LEAD_WORDS.contains(&lead.as_str()) {
+            continue;
+        }
+        let phrase = tail.join(" ");
+        if date_parser::parse_date(&phrase).is_ok() {
+            return Some((len, phrase));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_full_example() {
+        let c = parse(&words("fix login bug tomorrow !high #work"));
+        assert_eq!(c.text, "fix login bug");
+        assert_eq!(c.priority, Some(Priority::High));
+        assert_eq!(c.tags, vec!["work"]);
+        assert_eq!(c.due.as_deref(), Some("tomorrow"));
+    }
+
+    #[test]
+    fn test_parse_multiword_date_and_project() {
+        let c = parse(&words("ship release @rustodo next friday"));
+        assert_eq!(c.text, "ship release");
+        assert_eq!(c.project.as_deref(), Some("rustodo"));
+        assert_eq!(c.due.as_deref(), Some("next friday"));
+    }
+
+    #[test]
+    fn test_parse_leaves_plain_numbers_alone() {
+        let c = parse(&words("read chapter 2"));
+        assert_eq!(c.text, "read chapter 2");
+        assert!(c.due.is_none());
+    }
+
+    #[test]
+    fn test_parse_explicit_due_token_wins() {
+        let c = parse(&words("pay rent due:2030-01-01 tomorrow"));
+        assert_eq!(c.text, "pay rent tomorrow");
+        assert_eq!(c.due.as_deref(), Some("2030-01-01"));
+    }
+
+    #[test]
+    fn test_parse_date_alone_is_description() {
+        let c = parse(&words("tomorrow"));
+        assert_eq!(c.text, "tomorrow");
+        assert!(c.due.is_none());
+    }
+}
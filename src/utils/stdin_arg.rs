@@ -0,0 +1,54 @@
+//! `-` placeholder for text arguments.
+//!
+//! `todo add -`, `todo edit <ID> --text -`, and the note body arguments read
+//! their value from stdin when given a lone `-`, so multi-line content can be
+//! piped in (`pbpaste | todo note add -`).
+
+use std::io::{self, Read};
+
+use anyhow::{Context, Result, bail};
+
+/// Returns `value` unchanged, or all of stdin if `value` is `-`.
+pub fn resolve(value: String) -> Result<String> {
+    resolve_from(value, io::stdin().lock())
+}
+
+/// Like [`resolve`], reading from `reader` instead of stdin.
+///
+/// Trailing newlines are trimmed; interior newlines are kept.
+pub fn resolve_from(value: String, mut reader: impl Read) -> Result<String> {
+    if value != "-" {
+        return Ok(value);
+    }
+    let mut content = String::new();
+    reader
+        .read_to_string(&mut content)
+        .context("Failed to read from stdin")?;
+    let content = content.trim_end_matches(['\n', '\r']).to_string();
+    if content.trim().is_empty() {
+        bail!("Nothing was read from stdin");
+    }
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_value_is_returned_as_is() {
+        let value = resolve_from("Buy milk".into(), "ignored".as_bytes()).unwrap();
+        assert_eq!(value, "Buy milk");
+    }
+
+    #[test]
+    fn test_dash_reads_multiline_input() {
+        let value = resolve_from("-".into(), "line one\nline two\n\n".as_bytes()).unwrap();
+        assert_eq!(value, "line one\nline two");
+    }
+
+    #[test]
+    fn test_dash_with_empty_input_fails() {
+        assert!(resolve_from("-".into(), "\n".as_bytes()).is_err());
+    }
+}
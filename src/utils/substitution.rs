@@ -0,0 +1,190 @@
+//! sed-style `s/old/new/flags` expressions for `todo edit --replace-text`.
+//!
+//! The character after `s` is the delimiter, so `s|a/b|c|` works when the
+//! text contains slashes; a delimiter inside a part is escaped with `\`.
+//! The pattern is a regular expression. In the replacement `&` stands for
+//! the whole match and `\1`..`\9` for capture groups.
+//!
+//! | Flag | Meaning                                   |
+//! |------|-------------------------------------------|
+//! | `g`  | Replace every match instead of the first  |
+//! | `i`  | Match case-insensitively                  |
+
+use std::str::FromStr;
+
+use regex::{Regex, RegexBuilder};
+
+/// A parsed substitution.
+#[derive(Debug, Clone)]
+pub struct Substitution {
+    regex: Regex,
+    replacement: String,
+    global: bool,
+}
+
+impl Substitution {
+    /// The substituted text, or `None` when the pattern does not match.
+    pub fn apply(&self, text: &str) -> Option<String> {
+        if !self.regex.is_match(text) {
+            return None;
+        }
+        let limit = if self.global { 0 } else { 1 };
+        Some(
+            self.regex
+                .replacen(text, limit, self.replacement.as_str())
+                .into_owned(),
+        )
+    }
+}
+
+impl FromStr for Substitution {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |why: &str| format!("invalid substitution '{}': {}", s, why);
+
+        let rest = s
+            .strip_prefix('s')
+            .ok_or_else(|| invalid("expected s/old/new/"))?;
+        let mut chars = rest.chars();
+        let delim = chars
+            .next()
+            .filter(|c| !c.is_alphanumeric() && *c != '\\' && !c.is_whitespace())
+            .ok_or_else(|| invalid("expected a delimiter after 's'"))?;
+
+        let mut parts = vec![String::new()];
+        let mut escaped = false;
+        for c in chars {
+            if escaped {
+                // `\<delim>` is the delimiter itself; other escapes are kept
+                // for the regex or the replacement to interpret.
+                if c != delim {
+                    parts.last_mut().unwrap().push('\\');
+                }
+                parts.last_mut().unwrap().push(c);
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == delim {
+                parts.push(String::new());
+            } else {
+                parts.last_mut().unwrap().push(c);
+            }
+        }
+        if escaped {
+            parts.last_mut().unwrap().push('\\');
+        }
+
+        let (pattern, replacement, flags) = match parts.as_slice() {
+            [p, r, f] => (p, r, f.as_str()),
+            [p, r] => (p, r, ""),
+            _ => return Err(invalid("expected s/old/new/")),
+        };
+        if pattern.is_empty() {
+            return Err(invalid("the pattern is empty"));
+        }
+
+        let mut global = false;
+        let mut insensitive = false;
+        for flag in flags.chars() {
+            match flag {
+                'g' => global = true,
+                'i' => insensitive = true,
+                other => return Err(invalid(&format!("unknown flag '{}'", other))),
+            }
+        }
+
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(insensitive)
+            .build()
+            .map_err(|e| invalid(&e.to_string()))?;
+
+        Ok(Self {
+            regex,
+            replacement: to_regex_replacement(replacement),
+            global,
+        })
+    }
+}
+
+/// Translates sed replacement syntax (`&`, `\1`, `\&`) into the `regex`
+/// crate's (`${0}`, `${1}`, `$$`).
+fn to_regex_replacement(sed: &str) -> String {
+    let mut out = String::new();
+    let mut chars = sed.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '$' => out.push_str("$$"),
+            '&' => out.push_str("${0}"),
+            '\\' => match chars.next() {
+                Some(d @ '0'..='9') => out.push_str(&format!("${{{}}}", d)),
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some('$') => out.push_str("$$"),
+                Some(other) => out.push(other),
+                None => out.push('\\'),
+            },
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sub(expr: &str, text: &str) -> Option<String> {
+        expr.parse::<Substitution>().unwrap().apply(text)
+    }
+
+    #[test]
+    fn test_replaces_first_match_unless_global() {
+        assert_eq!(sub("s/a/o/", "banana").as_deref(), Some("bonana"));
+        assert_eq!(sub("s/a/o/g", "banana").as_deref(), Some("bonono"));
+        assert_eq!(sub("s/x/o/", "banana"), None);
+    }
+
+    #[test]
+    fn test_case_insensitive_flag() {
+        assert_eq!(
+            sub("s/login/sign-in/i", "Fix Login page").as_deref(),
+            Some("Fix sign-in page")
+        );
+    }
+
+    #[test]
+    fn test_custom_and_escaped_delimiters() {
+        assert_eq!(sub("s|a/b|c|", "x a/b y").as_deref(), Some("x c y"));
+        assert_eq!(sub(r"s/a\/b/c/", "x a/b y").as_deref(), Some("x c y"));
+    }
+
+    #[test]
+    fn test_groups_ampersand_and_literal_dollar() {
+        assert_eq!(
+            sub(r"s/(\w+) (\w+)/\2 \1/", "hello world").as_deref(),
+            Some("world hello")
+        );
+        assert_eq!(sub("s/v2/[&]/", "ship v2").as_deref(), Some("ship [v2]"));
+        assert_eq!(sub("s/price/$5/", "price").as_deref(), Some("$5"));
+        assert_eq!(
+            sub(r"s/and/\&/", "this and that").as_deref(),
+            Some("this & that")
+        );
+    }
+
+    #[test]
+    fn test_rejects_malformed_expressions() {
+        for bad in [
+            "a/b/c",
+            "s",
+            "s/a",
+            "s/a/b/c/d",
+            "s//b/",
+            "s/a/b/x",
+            "s/(/b/",
+        ] {
+            assert!(bad.parse::<Substitution>().is_err(), "{} should fail", bad);
+        }
+    }
+}
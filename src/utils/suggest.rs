@@ -0,0 +1,103 @@
+//! "Did you mean …?" suggestions for mistyped names.
+//!
+//! Uses the same Levenshtein thresholds as [`tag_normalizer`]: names of
+//! 4 characters or fewer allow one edit, longer names allow two.
+//!
+//! [`tag_normalizer`]: crate::utils::tag_normalizer
+
+use std::io::IsTerminal;
+
+use anyhow::Result;
+use strsim::levenshtein;
+use uuid::Uuid;
+
+use crate::error::TodoError;
+use crate::models::Project;
+
+/// Returns the candidate closest to `input`, if any is within the threshold.
+pub fn closest<'a>(input: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let input_lower = input.to_lowercase();
+    let threshold = threshold(input);
+
+    candidates
+        .into_iter()
+        .map(|c| (c, levenshtein(&input_lower, &c.to_lowercase())))
+        .filter(|(_, dist)| *dist <= threshold)
+        .min_by_key(|(_, dist)| *dist)
+        .map(|(c, _)| c)
+}
+
+/// Edits allowed between `input` and a candidate for them to count as close.
+pub fn threshold(input: &str) -> usize {
+    if input.chars().count() <= 4 { 1 } else { 2 }
+}
+
+/// Whether `a` and `b` are within the typo threshold of `a`, ignoring case.
+pub fn is_close(a: &str, b: &str) -> bool {
+    levenshtein(&a.to_lowercase(), &b.to_lowercase()) <= threshold(a)
+}
+
+/// Resolves a `--project` filter value to a project UUID.
+///
+/// Exact (case-insensitive) matches win. Otherwise, if a close project
+/// name exists, an interactive terminal is asked whether to use it;
+/// declining or running non-interactively fails with a suggestion.
+/// Returns `Ok(None)` when nothing is close enough to suggest.
+pub fn resolve_project_filter(projects: &[Project], name: &str) -> Result<Option<Uuid>> {
+    let live = || projects.iter().filter(|p| !p.is_deleted());
+
+    if let Some(p) = live().find(|p| p.name.to_lowercase() == name.to_lowercase()) {
+        return Ok(Some(p.uuid));
+    }
+
+    let Some(suggestion) = closest(name, live().map(|p| p.name.as_str())) else {
+        return Ok(None);
+    };
+
+    if std::io::stdin().is_terminal()
+        && super::confirm(&format!(
+            "Project '{}' not found. Did you mean '{}'? [y/N]",
+            name, suggestion
+        ))?
+    {
+        return Ok(live().find(|p| p.name == suggestion).map(|p| p.uuid));
+    }
+
+    Err(TodoError::ProjectNotFoundSuggestion {
+        name: name.to_string(),
+        suggestion: suggestion.to_string(),
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_finds_typo() {
+        let names = ["Backend", "Frontend", "Docs"];
+        assert_eq!(closest("Bakend", names), Some("Backend"));
+        assert_eq!(closest("doc", names), Some("Docs"));
+    }
+
+    #[test]
+    fn test_closest_ignores_distant_names() {
+        assert_eq!(closest("Marketing", ["Backend", "Docs"]), None);
+    }
+
+    #[test]
+    fn test_resolve_project_filter_exact_and_unknown() {
+        let projects = vec![Project::new("Backend".to_string())];
+        // Exact, case-insensitive match
+        assert_eq!(
+            resolve_project_filter(&projects, "backend").unwrap(),
+            Some(projects[0].uuid)
+        );
+        // Nothing close
+        assert_eq!(
+            resolve_project_filter(&projects, "Marketing").unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Per-phase durations printed by the global `--timing` flag.
+//!
+//! Meant for checking cold-start latency of prompt integrations:
+//!
+//! ```text
+//! timing: parse args      0.31 ms
+//! timing: open storage    2.04 ms
+//! timing: run command     1.12 ms
+//! timing:   storage       0.87 ms
+//! timing: total           3.47 ms
+//! ```
+
+use std::time::{Duration, Instant};
+
+use colored::Colorize;
+
+/// Records how long each startup phase took.
+pub struct Timing {
+    enabled: bool,
+    start: Instant,
+    last: Instant,
+    phases: Vec<(&'static str, Duration)>,
+}
+
+impl Timing {
+    /// Starts timing from `start`; when not `enabled`, nothing is recorded.
+    pub fn since(start: Instant, enabled: bool) -> Self {
+        Self {
+            enabled,
+            start,
+            last: start,
+            phases: Vec::new(),
+        }
+    }
+
+    /// Ends the current phase, naming it `phase`.
+    pub fn mark(&mut self, phase: &'static str) {
+        if !self.enabled {
+            return;
+        }
+        let now = Instant::now();
+        self.phases.push((phase, now - self.last));
+        self.last = now;
+    }
+
+    /// Records `took` for `phase` without ending the current one, for time
+    /// measured elsewhere (such as storage calls within a command).
+    pub fn record(&mut self, phase: &'static str, took: Duration) {
+        if self.enabled {
+            self.phases.push((phase, took));
+        }
+    }
+
+    /// Recorded phases, in order.
+    pub fn phases(&self) -> &[(&'static str, Duration)] {
+        &self.phases
+    }
+
+    /// Prints the recorded phases and the total to stderr.
+    pub fn report(&self) {
+        if !self.enabled {
+            return;
+        }
+        let total = self.last - self.start;
+        for (phase, took) in self.phases.iter().chain([&("total", total)]) {
+            eprintln!(
+                "{} {:<14} {:>8.2} ms",
+                "timing:".dimmed(),
+                phase,
+                took.as_secs_f64() * 1000.0
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mark_records_only_when_enabled() {
+        let mut on = Timing::since(Instant::now(), true);
+        on.mark("parse args");
+        on.mark("run command");
+        let names: Vec<_> = on.phases().iter().map(|(p, _)| *p).collect();
+        assert_eq!(names, ["parse args", "run command"]);
+
+        let mut off = Timing::since(Instant::now(), false);
+        off.mark("parse args");
+        assert!(off.phases().is_empty());
+    }
+}
@@ -259,6 +259,23 @@ pub fn validate_recurrence(
     Ok(())
 }
 
+/// Validates that a start date does not come after the due date
+///
+/// # Errors
+///
+/// Returns `TodoError::StartAfterDue` if both are set and start is later
+pub fn validate_start_date(
+    start_date: Option<NaiveDate>,
+    due_date: Option<NaiveDate>,
+) -> Result<(), TodoError> {
+    if let (Some(start), Some(due)) = (start_date, due_date)
+        && start > due
+    {
+        return Err(TodoError::StartAfterDue { start, due });
+    }
+    Ok(())
+}
+
 /// Validates a complete task before saving
 ///
 /// Runs all validation checks on a task.
@@ -277,6 +294,7 @@ pub fn validate_task(task: &Task, is_new: bool) -> Result<(), TodoError> {
     validate_tags(&task.tags)?;
     validate_due_date(task.due_date, !is_new)?;
     validate_recurrence(task.recurrence, task.due_date)?;
+    validate_start_date(task.start_date, task.due_date)?;
     Ok(())
 }
 
@@ -464,6 +482,19 @@ mod tests {
         assert!(validate_recurrence(None, Some(future)).is_ok());
     }
 
+    #[test]
+    fn test_validate_start_date() {
+        let due = NaiveDate::from_ymd_opt(2030, 5, 10).unwrap();
+        let before = NaiveDate::from_ymd_opt(2030, 5, 1).unwrap();
+        let after = NaiveDate::from_ymd_opt(2030, 5, 11).unwrap();
+
+        assert!(validate_start_date(Some(before), Some(due)).is_ok());
+        assert!(validate_start_date(Some(due), Some(due)).is_ok());
+        assert!(validate_start_date(Some(after), Some(due)).is_err());
+        assert!(validate_start_date(Some(after), None).is_ok());
+        assert!(validate_start_date(None, Some(due)).is_ok());
+    }
+
     #[test]
     fn test_validate_task_new() {
         use chrono::Local;
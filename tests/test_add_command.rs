@@ -15,12 +15,7 @@ fn test_add_simple_task() {
         env.storage(),
         AddArgs {
             text: "Buy milk".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     );
 
@@ -46,12 +41,11 @@ fn test_add_task_with_all_metadata() {
         env.storage(),
         AddArgs {
             text: "Complete project".to_string(),
-            priority: Priority::High,
+            priority: Some(Priority::High),
             tag: vec!["work".to_string(), "urgent".to_string()],
-            project: None,
             due: Some(due_date.to_string()),
             recurrence: Some(Recurrence::Weekly),
-            depends_on: vec![],
+            ..Default::default()
         },
     );
 
@@ -76,12 +70,8 @@ fn test_add_multiple_tasks_preserves_order() {
         env.storage(),
         AddArgs {
             text: "Task 1".to_string(),
-            priority: Priority::Low,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            priority: Some(Priority::Low),
+            ..Default::default()
         },
     )
     .unwrap();
@@ -89,12 +79,7 @@ fn test_add_multiple_tasks_preserves_order() {
         env.storage(),
         AddArgs {
             text: "Task 2".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -102,12 +87,8 @@ fn test_add_multiple_tasks_preserves_order() {
         env.storage(),
         AddArgs {
             text: "Task 3".to_string(),
-            priority: Priority::High,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            priority: Some(Priority::High),
+            ..Default::default()
         },
     )
     .unwrap();
@@ -127,12 +108,8 @@ fn test_add_recurring_task_requires_due_date() {
         env.storage(),
         AddArgs {
             text: "Daily standup".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None, // No due date
             recurrence: Some(Recurrence::Daily),
-            depends_on: vec![],
+            ..Default::default()
         },
     );
 
@@ -150,12 +127,7 @@ fn test_add_empty_text_fails() {
         env.storage(),
         AddArgs {
             text: "".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     );
 
@@ -171,12 +143,7 @@ fn test_add_whitespace_only_text_fails() {
         env.storage(),
         AddArgs {
             text: "   \t\n  ".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     );
 
@@ -192,12 +159,8 @@ fn test_add_with_invalid_tags_fails() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
             tag: vec!["invalid tag".to_string()],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     );
 
@@ -218,12 +181,8 @@ fn test_add_with_duplicate_tags_fails() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
             tag: vec!["work".to_string(), "Work".to_string()], // Case-insensitive duplicate
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     );
 
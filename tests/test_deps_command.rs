@@ -5,6 +5,7 @@
 //! - Blocked status display
 //! - All deps satisfied
 //! - Invalid task ID
+//! - `deps why` paths to a target and to root blockers
 //! - Self-dependency prevention
 //! - Cycle detection
 //! - Dependency not found on remove
@@ -13,9 +14,8 @@
 mod helpers;
 
 use helpers::TestEnv;
-use rustodo::cli::{AddArgs, EditArgs};
+use rustodo::cli::{AddArgs, EditArgs, OutputFormat};
 use rustodo::commands::task;
-use rustodo::models::Priority;
 
 // ─── helpers ────────────────────────────────────────────────────────────────
 
@@ -24,12 +24,7 @@ fn add_simple(env: &TestEnv, text: &str) -> usize {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -41,12 +36,8 @@ fn add_with_deps(env: &TestEnv, text: &str, depends_on: Vec<usize>) -> usize {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
             depends_on,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -155,6 +146,20 @@ fn test_deps_partially_satisfied() {
     assert_eq!(blocking, vec![dep_b_uuid], "only dep B should be blocking");
 }
 
+#[test]
+fn test_deps_transitive_chain() {
+    let env = TestEnv::new();
+    add_simple(&env, "Design");
+    add_with_deps(&env, "Build", vec![1]);
+    add_with_deps(&env, "Ship", vec![2]);
+
+    // Middle of the chain: one level up, one level down
+    assert!(task::deps::execute(env.storage(), 2).is_ok());
+    // Ends of the chain: two levels in one direction
+    assert!(task::deps::execute(env.storage(), 1).is_ok());
+    assert!(task::deps::execute(env.storage(), 3).is_ok());
+}
+
 // ─── invalid task ID ────────────────────────────────────────────────────────
 
 #[test]
@@ -185,6 +190,56 @@ fn test_deps_empty_storage() {
     assert!(result.is_err());
 }
 
+// ─── deps why ───────────────────────────────────────────────────────────────
+
+/// 1 ← 2 ← 4 and 1 ← 3 ← 4: two equally short paths from #4 to #1.
+fn add_diamond(env: &TestEnv) {
+    add_simple(env, "Design");
+    add_with_deps(env, "Backend", vec![1]);
+    add_with_deps(env, "Frontend", vec![1]);
+    add_with_deps(env, "Ship", vec![2, 3]);
+}
+
+#[test]
+fn test_why_lists_every_shortest_path_to_target() {
+    let env = TestEnv::new();
+    add_diamond(&env);
+    let tasks = env.load_tasks();
+
+    let paths = task::deps::why_paths(&tasks, 3, Some(0));
+    assert_eq!(paths, vec![vec![3, 1, 0], vec![3, 2, 0]]);
+    // Edges only point at dependencies
+    assert!(task::deps::why_paths(&tasks, 0, Some(3)).is_empty());
+    assert!(task::deps::execute_why(env.storage(), 4, Some(1), OutputFormat::Table).is_ok());
+}
+
+#[test]
+fn test_why_without_target_finds_root_blockers() {
+    let env = TestEnv::new();
+    add_diamond(&env);
+
+    let tasks = env.load_tasks();
+    assert_eq!(task::deps::why_paths(&tasks, 3, None), vec![vec![3, 1, 0]]);
+
+    // Once Design is done, Backend and Frontend are the roots
+    task::done::execute(env.storage(), 1).unwrap();
+    let tasks = env.load_tasks();
+    assert_eq!(
+        task::deps::why_paths(&tasks, 3, None),
+        vec![vec![3, 1], vec![3, 2]]
+    );
+    assert!(task::deps::why_paths(&tasks, 0, None).is_empty());
+}
+
+#[test]
+fn test_why_invalid_id() {
+    let env = TestEnv::new();
+    add_simple(&env, "Task");
+
+    let result = task::deps::execute_why(env.storage(), 1, Some(9), OutputFormat::Table);
+    assert!(result.unwrap_err().to_string().contains("invalid"));
+}
+
 // ─── self-dependency prevention ─────────────────────────────────────────────
 
 #[test]
@@ -195,12 +250,8 @@ fn test_add_self_dependency_fails() {
         env.storage(),
         AddArgs {
             text: "Self-referencing task".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
             depends_on: vec![1],
+            ..Default::default()
         },
     );
 
@@ -222,18 +273,8 @@ fn test_edit_add_self_dependency_fails() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
             add_dep: vec![1],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -259,18 +300,8 @@ fn test_edit_direct_cycle_fails() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
             add_dep: vec![2], // add_dep: A depends on B
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -291,18 +322,8 @@ fn test_edit_transitive_cycle_fails() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
             add_dep: vec![3],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -323,18 +344,8 @@ fn test_edit_no_cycle_on_valid_dep() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
             add_dep: vec![3], // A depends on C
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -354,18 +365,8 @@ fn test_edit_duplicate_dependency_fails() {
         env.storage(),
         EditArgs {
             id: 2,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
             add_dep: vec![1], // already a dep
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -386,18 +387,8 @@ fn test_edit_remove_existing_dependency() {
         env.storage(),
         EditArgs {
             id: 2,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
             remove_dep: vec![1],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -417,18 +408,8 @@ fn test_edit_remove_nonexistent_dependency_fails() {
         env.storage(),
         EditArgs {
             id: 2,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
             remove_dep: vec![1],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -448,18 +429,8 @@ fn test_edit_clear_all_dependencies() {
         env.storage(),
         EditArgs {
             id: 3,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
             clear_deps: true, // clear_deps
+            ..Default::default()
         },
     );
 
@@ -516,12 +487,10 @@ fn test_recurrence_does_not_inherit_deps() {
         env.storage(),
         AddArgs {
             text: "Recurring task".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
             due: Some(due_str),
             recurrence: Some(Recurrence::Daily),
             depends_on: vec![1],
+            ..Default::default()
         },
     )
     .unwrap();
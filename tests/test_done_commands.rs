@@ -2,7 +2,7 @@
 
 use std::vec;
 
-use rustodo::{cli::AddArgs, commands::task, models::Priority};
+use rustodo::{cli::AddArgs, commands::task};
 
 use crate::helpers::TestEnv;
 
@@ -13,12 +13,7 @@ fn add_simple(env: &TestEnv, text: &str) -> usize {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -30,12 +25,8 @@ fn add_with_deps(env: &TestEnv, text: &str, depends_on: Vec<usize>) -> usize {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
             depends_on,
+            ..Default::default()
         },
     )
     .unwrap();
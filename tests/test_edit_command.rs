@@ -16,12 +16,7 @@ fn test_edit_text() {
         env.storage(),
         AddArgs {
             text: "Old text".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -32,17 +27,7 @@ fn test_edit_text() {
         EditArgs {
             id: 1, // ID
             text: Some("New text".to_string()),
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -61,12 +46,8 @@ fn test_edit_priority() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Low,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            priority: Some(Priority::Low),
+            ..Default::default()
         },
     )
     .unwrap();
@@ -75,18 +56,8 @@ fn test_edit_priority() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
             priority: Some(Priority::High), // Change to High
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -104,12 +75,7 @@ fn test_edit_add_invalid_tag_fails() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -118,18 +84,8 @@ fn test_edit_add_invalid_tag_fails() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
             add_tag: vec!["invalid tag".to_string()],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -151,12 +107,8 @@ fn test_edit_add_tags_preserves_existing() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
             tag: vec!["work".to_string()],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -166,18 +118,8 @@ fn test_edit_add_tags_preserves_existing() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
             add_tag: vec!["urgent".to_string()],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -199,16 +141,12 @@ fn test_edit_remove_specific_tag() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
             tag: vec![
                 "work".to_string(),
                 "urgent".to_string(),
                 "frontend".to_string(),
             ],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -217,18 +155,8 @@ fn test_edit_remove_specific_tag() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
             remove_tag: vec!["urgent".to_string()],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -251,12 +179,8 @@ fn test_edit_add_and_remove_tags_simultaneously() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
             tag: vec!["work".to_string(), "old".to_string()],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -265,18 +189,9 @@ fn test_edit_add_and_remove_tags_simultaneously() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
             add_tag: vec!["new".to_string()],
             remove_tag: vec!["old".to_string()],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -299,12 +214,8 @@ fn test_edit_clear_all_tags() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
             tag: vec!["work".to_string(), "urgent".to_string()],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -313,18 +224,8 @@ fn test_edit_clear_all_tags() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
             clear_tags: true,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -343,12 +244,8 @@ fn test_edit_remove_nonexistent_tag_fails() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
             tag: vec!["work".to_string()],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -358,18 +255,8 @@ fn test_edit_remove_nonexistent_tag_fails() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
             remove_tag: vec!["nonexistent".to_string()],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -392,18 +279,7 @@ fn test_edit_invalid_id() {
         env.storage(),
         EditArgs {
             id: 99,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -419,12 +295,7 @@ fn test_edit_due_date() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -435,18 +306,8 @@ fn test_edit_due_date() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
             due: Some(due_date.to_string()),
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -465,12 +326,8 @@ fn test_edit_clear_due_date() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
             due: Some(due_date.to_string()),
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -479,18 +336,8 @@ fn test_edit_clear_due_date() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
             clear_due: true, // clear_due
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
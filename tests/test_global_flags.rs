@@ -0,0 +1,64 @@
+//! Tests for the global `--read-only` and `--demo` flags on commands that
+//! bypass storage
+//!
+//! Covers:
+//! - Backup, restore and holidays refresh are refused with --read-only
+//! - Every command touching the real data files is refused with --demo
+//! - The same commands are allowed without the flags
+//! - Commands that go through storage are left to the storage handle
+
+use clap::Parser;
+use rustodo::cli::Cli;
+
+fn check(args: &[&str]) -> anyhow::Result<()> {
+    Cli::parse_from(std::iter::once("todo").chain(args.iter().copied())).check_file_access()
+}
+
+#[test]
+fn test_read_only_refuses_file_commands() {
+    for args in [
+        &["--read-only", "backup"][..],
+        &["--read-only", "restore", "backup.db", "--yes"],
+        &["holidays", "refresh", "--read-only"],
+    ] {
+        let err = check(args).unwrap_err().to_string();
+        assert!(err.contains("--read-only"), "{:?}: {}", args, err);
+    }
+}
+
+#[test]
+fn test_read_only_allows_reading_file_commands() {
+    assert!(check(&["--read-only", "backup-list"]).is_ok());
+    assert!(check(&["--read-only", "open-data", "--print"]).is_ok());
+}
+
+#[test]
+fn test_demo_refuses_real_data_commands() {
+    for args in [
+        &["--demo", "backup"][..],
+        &["--demo", "restore", "backup.db", "--yes"],
+        &["--demo", "backup-list"],
+        &["--demo", "open-data", "--print"],
+        &["holidays", "refresh", "--demo"],
+    ] {
+        let err = check(args).unwrap_err().to_string();
+        assert!(err.contains("--demo"), "{:?}: {}", args, err);
+    }
+}
+
+#[test]
+fn test_file_commands_allowed_without_flags() {
+    assert!(check(&["backup"]).is_ok());
+    assert!(check(&["restore", "backup.db"]).is_ok());
+    assert!(check(&["holidays", "refresh"]).is_ok());
+    assert!(check(&["backup-list"]).is_ok());
+    assert!(check(&["open-data", "--print"]).is_ok());
+}
+
+#[test]
+fn test_flags_leave_storage_commands_to_the_handle() {
+    assert!(check(&["--read-only", "list"]).is_ok());
+    assert!(check(&["--read-only", "add", "task"]).is_ok());
+    assert!(check(&["--read-only"]).is_ok());
+    assert!(check(&["--demo", "list"]).is_ok());
+}
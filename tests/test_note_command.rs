@@ -16,7 +16,6 @@ mod helpers;
 use helpers::TestEnv;
 use rustodo::cli::{AddArgs, NoteAddArgs, NoteEditArgs, NoteListArgs, ResourceAddArgs};
 use rustodo::commands::{note, resource, task};
-use rustodo::models::Priority;
 use rustodo::storage::Storage;
 
 // ─── helpers ─────────────────────────────────────────────────────────────────
@@ -26,12 +25,7 @@ fn add_task(env: &TestEnv, text: &str) {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
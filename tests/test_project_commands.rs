@@ -13,7 +13,7 @@ mod helpers;
 use helpers::TestEnv;
 use rustodo::cli::{AddArgs, NoteAddArgs, ProjectAddArgs, ProjectEditArgs};
 use rustodo::commands::{note, project, task};
-use rustodo::models::{Difficulty, Priority};
+use rustodo::models::Difficulty;
 use rustodo::storage::Storage;
 
 // ─── helpers ─────────────────────────────────────────────────────────────────
@@ -36,12 +36,8 @@ fn add_task_in_project(env: &TestEnv, text: &str, proj: &str) {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
             project: Some(proj.to_string()),
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -11,9 +11,9 @@
 
 mod helpers;
 use helpers::TestEnv;
-use rustodo::cli::{AddArgs, EditArgs};
+use rustodo::cli::{AddArgs, EditArgs, ListArgs};
 use rustodo::commands::{project, task};
-use rustodo::models::{Priority, SortBy, StatusFilter};
+use rustodo::models::{SortBy, StatusFilter};
 use rustodo::storage::Storage;
 
 // ─── helpers ─────────────────────────────────────────────────────────────────
@@ -23,12 +23,8 @@ fn add_task(env: &TestEnv, text: &str, project: Option<&str>) -> usize {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
             project: project.map(|s| s.to_string()),
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -177,13 +173,10 @@ fn test_list_filter_by_project() {
 
     let result = task::list::execute(
         env.storage(),
-        StatusFilter::All,
-        None,
-        None,
-        None,
-        vec![],
-        Some("Backend".to_string()),
-        None,
+        ListArgs {
+            project: Some("Backend".to_string()),
+            ..Default::default()
+        },
     );
     assert!(result.is_ok());
 }
@@ -196,13 +189,10 @@ fn test_list_filter_by_project_case_insensitive() {
     // lowercase "backend" should match "Backend"
     let result = task::list::execute(
         env.storage(),
-        StatusFilter::All,
-        None,
-        None,
-        None,
-        vec![],
-        Some("backend".to_string()),
-        None,
+        ListArgs {
+            project: Some("backend".to_string()),
+            ..Default::default()
+        },
     );
     assert!(result.is_ok());
 }
@@ -214,13 +204,10 @@ fn test_list_filter_by_nonexistent_project_fails() {
 
     let result = task::list::execute(
         env.storage(),
-        StatusFilter::All,
-        None,
-        None,
-        None,
-        vec![],
-        Some("Nonexistent".to_string()),
-        None,
+        ListArgs {
+            project: Some("Nonexistent".to_string()),
+            ..Default::default()
+        },
     );
     assert!(result.is_err());
 }
@@ -235,13 +222,11 @@ fn test_list_filter_project_with_status() {
 
     let result = task::list::execute(
         env.storage(),
-        StatusFilter::Pending,
-        None,
-        None,
-        None,
-        vec![],
-        Some("Backend".to_string()),
-        None,
+        ListArgs {
+            status: StatusFilter::Pending,
+            project: Some("Backend".to_string()),
+            ..Default::default()
+        },
     );
     assert!(result.is_ok());
 }
@@ -256,12 +241,9 @@ fn test_list_filter_project_with_sort() {
         env.storage(),
         AddArgs {
             text: "Later task".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
             project: Some("Backend".to_string()),
             due: Some(days_from_now(10).to_string()),
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -270,25 +252,20 @@ fn test_list_filter_project_with_sort() {
         env.storage(),
         AddArgs {
             text: "Earlier task".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
             project: Some("Backend".to_string()),
             due: Some(days_from_now(2).to_string()),
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
 
     let result = task::list::execute(
         env.storage(),
-        StatusFilter::All,
-        None,
-        None,
-        Some(SortBy::Due),
-        vec![],
-        Some("Backend".to_string()),
-        None,
+        ListArgs {
+            sort: Some(SortBy::Due.into()),
+            project: Some("Backend".to_string()),
+            ..Default::default()
+        },
     );
     assert!(result.is_ok());
 }
@@ -304,18 +281,8 @@ fn test_edit_assign_project() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
             project: Some("Backend".to_string()),
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -339,18 +306,8 @@ fn test_edit_change_project() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
             project: Some("Frontend".to_string()),
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -374,18 +331,8 @@ fn test_edit_clear_project() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
             clear_project: true, // clear_project
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -405,18 +352,8 @@ fn test_edit_no_change_when_same_project() {
         env.storage(),
         EditArgs {
             id: 1,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
             project: Some("Backend".to_string()),
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
-            add_dep: vec![],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     );
 
@@ -433,12 +370,8 @@ fn test_add_empty_project_name_fails() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
             project: Some("".to_string()),
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     );
 
@@ -453,12 +386,8 @@ fn test_add_project_name_too_long_fails() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
             project: Some("x".repeat(101)),
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     );
 
@@ -473,12 +402,8 @@ fn test_add_project_name_exactly_max_length_ok() {
         env.storage(),
         AddArgs {
             text: "Task".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
             project: Some("x".repeat(100)),
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     );
 
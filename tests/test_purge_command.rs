@@ -14,7 +14,6 @@ mod helpers;
 use helpers::TestEnv;
 use rustodo::cli::AddArgs;
 use rustodo::commands::{purge, task};
-use rustodo::models::Priority;
 
 // ─── helpers ─────────────────────────────────────────────────────────────────
 
@@ -23,12 +22,7 @@ fn add_simple(env: &TestEnv, text: &str) {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
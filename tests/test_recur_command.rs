@@ -9,7 +9,11 @@
 //! - norecur: invalid ID fails
 //! - done on recurring task creates next occurrence
 //! - next occurrence has correct due date (daily/weekly/monthly)
+//! - monthly `last-day` policy keeps snapping to the month's final day
+//! - `backfill` catch-up closes overdue occurrences as missed
+//! - `recur history` walks the series and grades each occurrence
 //! - next occurrence does not inherit dependencies
+//! - `--recur-chain-deps` makes each occurrence depend on the previous one
 //! - deduplication: done twice does not create duplicate
 
 mod helpers;
@@ -17,7 +21,9 @@ mod helpers;
 use helpers::{TestEnv, days_from_now};
 use rustodo::cli::AddArgs;
 use rustodo::commands::task;
-use rustodo::models::{Priority, Recurrence};
+use rustodo::models::{
+    CatchUpPolicy, MISSED_REASON, MonthEndPolicy, Priority, Recurrence, Task, Timeliness,
+};
 use rustodo::storage::Storage;
 
 // ─── helpers ─────────────────────────────────────────────────────────────────
@@ -27,12 +33,7 @@ fn add_simple(env: &TestEnv, text: &str) {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -44,12 +45,8 @@ fn add_with_due(env: &TestEnv, text: &str, due_offset_days: i64) -> usize {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
             due: Some(due),
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -62,12 +59,9 @@ fn add_recurring(env: &TestEnv, text: &str, due_offset_days: i64, pattern: Recur
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
             due: Some(due),
             recurrence: Some(pattern),
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -239,8 +233,7 @@ fn test_done_recurring_weekly_creates_next() {
     let all = env.storage().load().unwrap();
     let next = all
         .iter()
-        .filter(|t| !t.is_deleted() && !t.completed)
-        .next()
+        .find(|t| !t.is_deleted() && !t.completed)
         .unwrap();
     let expected_due = due + chrono::Duration::days(7);
     assert_eq!(next.due_date, Some(expected_due));
@@ -257,12 +250,9 @@ fn test_done_recurring_monthly_creates_next() {
         env.storage(),
         AddArgs {
             text: "Monthly report".to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
             due: Some(due_str.to_string()),
             recurrence: Some(Recurrence::Monthly),
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -272,8 +262,7 @@ fn test_done_recurring_monthly_creates_next() {
     let all = env.storage().load().unwrap();
     let next = all
         .iter()
-        .filter(|t| !t.is_deleted() && !t.completed)
-        .next()
+        .find(|t| !t.is_deleted() && !t.completed)
         .unwrap();
     assert_eq!(
         next.due_date,
@@ -281,6 +270,165 @@ fn test_done_recurring_monthly_creates_next() {
     );
 }
 
+#[test]
+fn test_done_recurring_monthly_last_day_policy() {
+    use chrono::NaiveDate;
+
+    let env = TestEnv::new();
+    task::add::execute(
+        env.storage(),
+        AddArgs {
+            text: "Close the books".to_string(),
+            due: Some("2030-01-31".to_string()),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    task::recur::execute_with(
+        env.storage(),
+        1,
+        Recurrence::Monthly,
+        Some(MonthEndPolicy::LastDay),
+        None,
+        None,
+    )
+    .unwrap();
+
+    task::done::execute(env.storage(), 1).unwrap();
+    task::done::execute(env.storage(), 2).unwrap();
+
+    let due_dates: Vec<_> = env.load_tasks().iter().map(|t| t.due_date).collect();
+    let date = |m, d| Some(NaiveDate::from_ymd_opt(2030, m, d).unwrap());
+    assert_eq!(due_dates, vec![date(1, 31), date(2, 28), date(3, 31)]);
+    assert_eq!(env.load_tasks()[2].month_end, MonthEndPolicy::LastDay);
+}
+
+#[test]
+fn test_done_late_with_backfill_closes_missed_occurrences() {
+    let env = TestEnv::new();
+    let today = chrono::Local::now().date_naive();
+    let task = Task::new(
+        "Water plants".to_string(),
+        Priority::Medium,
+        vec![],
+        None,
+        Some(today - chrono::Duration::days(3)),
+        Some(Recurrence::Daily),
+    );
+    env.storage().save(&[task]).unwrap();
+    task::recur::execute_with(
+        env.storage(),
+        1,
+        Recurrence::Daily,
+        None,
+        Some(CatchUpPolicy::Backfill),
+        None,
+    )
+    .unwrap();
+
+    task::done::execute(env.storage(), 1).unwrap();
+
+    let tasks = env.load_tasks();
+    assert_eq!(tasks.len(), 4);
+    let missed: Vec<_> = tasks[1..3].iter().collect();
+    assert!(
+        missed
+            .iter()
+            .all(|t| t.is_cancelled() && t.cancel_reason.as_deref() == Some(MISSED_REASON))
+    );
+    assert_eq!(tasks[3].due_date, Some(today));
+    assert_eq!(tasks[3].missed, 2);
+    assert!(!tasks[3].completed);
+}
+
+#[test]
+fn test_recur_history_grades_each_occurrence() {
+    let env = TestEnv::new();
+    let today = chrono::Local::now().date_naive();
+    let mut task = Task::new(
+        "Water plants".to_string(),
+        Priority::Medium,
+        vec![],
+        None,
+        Some(today - chrono::Duration::days(3)),
+        Some(Recurrence::Daily),
+    );
+    task.catch_up = CatchUpPolicy::Backfill;
+    env.storage().save(&[task]).unwrap();
+    task::done::execute(env.storage(), 1).unwrap();
+
+    let tasks = env.load_tasks();
+    let series = task::recur::occurrences(&tasks, &tasks[3]);
+    let grades: Vec<_> = series.iter().map(|t| t.timeliness()).collect();
+    assert_eq!(
+        grades,
+        vec![
+            Some(Timeliness::Late(3)),
+            Some(Timeliness::Missed),
+            Some(Timeliness::Missed),
+            None,
+        ]
+    );
+    assert_eq!(task::recur::occurrences(&tasks, &tasks[0]).len(), 4);
+    task::recur::execute_history(env.storage(), 1).unwrap();
+}
+
+#[test]
+fn test_recur_history_requires_recurring_task() {
+    let env = TestEnv::new();
+    add_with_due(&env, "One-off", 1);
+    assert!(task::recur::execute_history(env.storage(), 1).is_err());
+}
+
+#[test]
+fn test_recur_chain_deps_links_occurrences_in_order() {
+    let env = TestEnv::new();
+    add_recurring(&env, "Weekly report", 1, Recurrence::Weekly);
+    task::recur::execute_with(env.storage(), 1, Recurrence::Weekly, None, None, Some(true))
+        .unwrap();
+
+    task::done::execute(env.storage(), 1).unwrap();
+    let tasks = env.load_tasks();
+    assert_eq!(tasks[1].depends_on, vec![tasks[0].uuid]);
+    assert!(tasks[1].recur_chain_deps);
+
+    // Reopening the earlier occurrence blocks the later one
+    task::undone::execute(env.storage(), 1).unwrap();
+    assert!(task::done::execute(env.storage(), 2).is_err());
+}
+
+#[test]
+fn test_recur_chain_deps_follows_backfilled_occurrences() {
+    let env = TestEnv::new();
+    let today = chrono::Local::now().date_naive();
+    let task = Task::new(
+        "Water plants".to_string(),
+        Priority::Medium,
+        vec![],
+        None,
+        Some(today - chrono::Duration::days(2)),
+        Some(Recurrence::Daily),
+    );
+    env.storage().save(&[task]).unwrap();
+    task::recur::execute_with(
+        env.storage(),
+        1,
+        Recurrence::Daily,
+        None,
+        Some(CatchUpPolicy::Backfill),
+        Some(true),
+    )
+    .unwrap();
+
+    task::done::execute(env.storage(), 1).unwrap();
+
+    let tasks = env.load_tasks();
+    assert_eq!(tasks.len(), 3);
+    assert_eq!(tasks[1].depends_on, vec![tasks[0].uuid]);
+    assert_eq!(tasks[2].depends_on, vec![tasks[1].uuid]);
+    assert!(tasks[2].blocking_deps(&tasks).is_empty());
+}
+
 #[test]
 fn test_done_recurring_does_not_create_duplicate() {
     let env = TestEnv::new();
@@ -309,18 +457,8 @@ fn test_done_recurring_next_does_not_inherit_deps() {
         env.storage(),
         rustodo::cli::EditArgs {
             id: 2,
-            text: None,
-            priority: None,
-            add_tag: vec![],
-            remove_tag: vec![],
-            project: None,
-            clear_project: false,
-            due: None,
-            clear_due: false,
-            clear_tags: false,
             add_dep: vec![1],
-            remove_dep: vec![],
-            clear_deps: false,
+            ..Default::default()
         },
     )
     .unwrap();
@@ -332,8 +470,7 @@ fn test_done_recurring_next_does_not_inherit_deps() {
     let all = env.storage().load().unwrap();
     let next = all
         .iter()
-        .filter(|t| !t.is_deleted() && !t.completed)
-        .next()
+        .find(|t| !t.is_deleted() && !t.completed)
         .unwrap();
     assert!(
         next.depends_on.is_empty(),
@@ -21,12 +21,7 @@ fn add_simple(env: &TestEnv, text: &str) -> usize {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -182,12 +177,9 @@ fn test_remove_preserves_task_metadata() {
         env.storage(),
         AddArgs {
             text: "Remove this".to_string(),
-            priority: Priority::High,
+            priority: Some(Priority::High),
             tag: vec!["work".to_string()],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
@@ -14,7 +14,7 @@ mod helpers;
 use helpers::TestEnv;
 use rustodo::cli::AddArgs;
 use rustodo::commands::{search, task};
-use rustodo::models::{Priority, StatusFilter};
+use rustodo::models::StatusFilter;
 use rustodo::storage::Storage;
 
 // ─── helpers ─────────────────────────────────────────────────────────────────
@@ -24,12 +24,9 @@ fn add_task(env: &TestEnv, text: &str, tags: Vec<&str>, project: Option<&str>) -
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
             tag: tags.into_iter().map(|s| s.to_string()).collect(),
             project: project.map(|s| s.to_string()),
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();
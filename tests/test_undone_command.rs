@@ -12,7 +12,6 @@ mod helpers;
 use helpers::TestEnv;
 use rustodo::cli::AddArgs;
 use rustodo::commands::task;
-use rustodo::models::Priority;
 
 // ─── helpers ─────────────────────────────────────────────────────────────────
 
@@ -21,12 +20,7 @@ fn add_simple(env: &TestEnv, text: &str) -> usize {
         env.storage(),
         AddArgs {
             text: text.to_string(),
-            priority: Priority::Medium,
-            tag: vec![],
-            project: None,
-            due: None,
-            recurrence: None,
-            depends_on: vec![],
+            ..Default::default()
         },
     )
     .unwrap();